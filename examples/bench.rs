@@ -0,0 +1,5 @@
+fn main() {
+    for result in aule::bench::all() {
+        println!("{}", result);
+    }
+}
@@ -31,7 +31,7 @@ fn main() -> ! {
 
     for sim_state in time {
         let error_signal = error.output(dt);
-        let control_signal = error_signal * pid.as_block();
+        let control_signal = error_signal >> pid.as_block();
         plant.output(control_signal);
     }
 
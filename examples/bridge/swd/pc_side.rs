@@ -14,17 +14,17 @@ fn main() {
     let mut plotter = Plotter::new("Third Order System".to_string(), ["input", "output"]);
 
     for sim_state in simulation {
-        let input = sim_state * step.as_block();
+        let input = sim_state >> step.as_block();
         let error = input - plant.last_output();
         iae.output(error);
         ise.output(error);
         itae.output(error);
 
         let control_signal = remote_pid.output(error);
-        let output = control_signal * plant.as_block();
+        let output = control_signal >> plant.as_block();
 
-        let _ = (error, control_signal).pack() * good_hart.as_block();
-        let _ = [input, output].pack() * plotter.as_block();
+        let _ = (error, control_signal).pack() >> good_hart.as_block();
+        let _ = [input, output].pack() >> plotter.as_block();
     }
 
     println!("IAE Value: {}", iae.value());
@@ -33,11 +33,11 @@ fn test_rt_dc_motor() -> RTPlotter<2, f64> {
         .with_legend_position(LegendPosition::Right);
 
     for sim_state in simulation {
-        let signal = sim_state * input.as_block();
-        let output = (signal - plant.last_output()) * pid.as_block() * plant.as_block();
-        let output = output * writer.as_block();
+        let signal = sim_state >> input.as_block();
+        let output = (signal - plant.last_output()) >> pid.as_block() >> plant.as_block();
+        let _ = output >> writer.as_block();
 
-        let _ = [signal, output].pack() * plotter.as_block();
+        let _ = [signal, output].pack() >> plotter.as_block();
 
         sleep(sim_state.dt());
     }
@@ -68,16 +68,16 @@ fn test_dc_motor() -> Plotter<2, f64> {
     let mut plotter = Plotter::new("DC Motor".to_string(), ["input", "output"]);
 
     for sim_state in simulation {
-        let signal = sim_state * input.as_block();
+        let signal = sim_state >> input.as_block();
         let error = signal - plant.last_output();
-        let control_signal = error * pid.as_block();
-        let output = control_signal * plant.as_block();
+        let control_signal = error >> pid.as_block();
+        let output = control_signal >> plant.as_block();
 
-        let _ = error * iae.as_block() * ise.as_block() * itae.as_block();
-        let _ = (error, control_signal).pack() * good_hart.as_block();
+        let _ = error >> iae.as_block() >> ise.as_block() >> itae.as_block();
+        let _ = (error, control_signal).pack() >> good_hart.as_block();
 
-        let _ = output * writer.as_block();
-        let _ = [signal, output].pack() * plotter.as_block();
+        let _ = output >> writer.as_block();
+        let _ = [signal, output].pack() >> plotter.as_block();
     }
 
     println!(
@@ -32,13 +32,13 @@ fn main() -> ! {
     println!("Starting simulation...");
 
     for sim_state in time {
-        let input = dt * step.as_block();
+        let input = dt >> step.as_block();
         let error = input - plant.last_output();
         iae.output(error);
         ise.output(error);
         itae.output(error);
 
-        let control_signal = error * pid.as_block();
+        let control_signal = error >> pid.as_block();
         let output = plant.output(control_signal);
 
         let printer_input = input.map(|i| [i, output.value]);
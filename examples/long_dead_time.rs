@@ -18,12 +18,12 @@ fn open_loop() {
     let mut plotter = Plotter::new("[Long Dead Time] Open Loop".to_string(), ["delayed_output"]);
 
     for sim_state in simulation {
-        let reference = sim_state * step.as_block();
+        let reference = sim_state >> step.as_block();
 
-        let plant_output = reference * plant.as_block();
+        let plant_output = reference >> plant.as_block();
         let delayed_output = delay.output(plant_output);
 
-        let _ = delayed_output * plotter.as_block();
+        let _ = delayed_output >> plotter.as_block();
     }
 
     plotter.display();
@@ -45,14 +45,14 @@ fn pi_controller() {
     );
 
     for sim_state in simulation {
-        let reference = sim_state * step.as_block();
+        let reference = sim_state >> step.as_block();
         let mut outputs = [(); 3].map(|_| 0.0.as_signal(sim_state));
 
         for i in 0..3 {
             let error = reference - delay[i].last_output();
-            let control_signal = error * controller[i].as_block();
+            let control_signal = error >> controller[i].as_block();
 
-            let plant_output = control_signal * plant[i].as_block();
+            let plant_output = control_signal >> plant[i].as_block();
             outputs[i] = delay[i].output(plant_output);
         }
 
@@ -103,10 +103,10 @@ fn smith_predictor() {
     };
 
     for sim_state in simulation {
-        let reference = sim_state * step.as_block();
+        let reference = sim_state >> step.as_block();
 
-        let with_predictor_output = reference * with_predictor.as_block();
-        let without_predictor_output = reference * without_predictor.as_block();
+        let with_predictor_output = reference >> with_predictor.as_block();
+        let without_predictor_output = reference >> without_predictor.as_block();
 
         let plotter_input = [with_predictor_output, without_predictor_output].pack();
         plotter.output(plotter_input);
@@ -133,21 +133,21 @@ impl Block for BlockCollection {
         if let Some(smith_predictor) = &mut self.smith_predictor {
             let preditor_last_output = smith_predictor.last_output();
             let error = input.as_signal(sim_state) - preditor_last_output;
-            let control_signal = error * self.controller.as_block();
+            let control_signal = error >> self.controller.as_block();
 
-            let plant_output = control_signal * self.plant.as_block();
-            let delayed_output = plant_output * self.delay.as_block();
+            let plant_output = control_signal >> self.plant.as_block();
+            let delayed_output = plant_output >> self.delay.as_block();
             let _predicted_output =
                 SmithPredictorInput::from_signals(control_signal, delayed_output)
-                    * smith_predictor.as_block();
+                    >> smith_predictor.as_block();
 
             delayed_output.value
         } else {
             let error = input.as_signal(sim_state) - self.delay.last_output();
-            let control_signal = error * self.controller.as_block();
+            let control_signal = error >> self.controller.as_block();
 
-            let plant_output = control_signal * self.plant.as_block();
-            let output = plant_output * self.delay.as_block();
+            let plant_output = control_signal >> self.plant.as_block();
+            let output = plant_output >> self.delay.as_block();
 
             output.value
         }
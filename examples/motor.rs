@@ -28,9 +28,9 @@ impl Block for Motor {
     type Output = f64;
 
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
-        let eletrical =
-            (input.as_signal(sim_state) - self.last_output) * self.kv * self.eletrical.as_block();
-        let mechanical = (eletrical * self.km - self.tau_l) * self.mechanical.as_block();
+        let eletrical = ((input.as_signal(sim_state) - self.last_output) * self.kv)
+            >> self.eletrical.as_block();
+        let mechanical = (eletrical * self.km - self.tau_l) >> self.mechanical.as_block();
 
         self.last_output = Some(mechanical.value);
 
@@ -66,10 +66,10 @@ fn open_loop_motor() -> Plotter<1, f64> {
     let mut plotter = Plotter::new("Open loop Motor".to_string(), ["output"]);
 
     for sim_state in simulation {
-        let input = sim_state * step.as_block();
-        let output = input * motor.as_block() * writer.as_block();
+        let input = sim_state >> step.as_block();
+        let output = input >> motor.as_block() >> writer.as_block();
 
-        let _ = output * plotter.as_block();
+        let _ = output >> plotter.as_block();
     }
 
     plotter.display();
@@ -90,12 +90,12 @@ fn closed_loop_motor() -> Plotter<1, f64> {
     let mut plotter = Plotter::new("Closed Loop Motor".to_string(), ["output"]);
 
     for sim_state in simulation {
-        let input = sim_state * step.as_block();
+        let input = sim_state >> step.as_block();
         let error = input - motor.last_output();
-        let control_signal = error * pid.as_block();
-        let output = control_signal * motor.as_block() * writer.as_block();
+        let control_signal = error >> pid.as_block();
+        let output = control_signal >> motor.as_block() >> writer.as_block();
 
-        let _ = output * plotter.as_block();
+        let _ = output >> plotter.as_block();
     }
 
     plotter.display();
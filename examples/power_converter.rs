@@ -0,0 +1,37 @@
+use aule::prelude::*;
+
+fn main() {
+    let simulation = Simulation::new(5e-7, 0.04);
+
+    let mut reference = Step::new(5.0);
+    let mut pwm = Pwm::new(20e3).with_carrier(PwmCarrier::Triangle);
+
+    let mut averaged_pi = PID::new(0.02, 20.0, 0.0).with_anti_windup(0.0, 1.0);
+    let mut switched_pi = PID::new(0.02, 20.0, 0.0).with_anti_windup(0.0, 1.0);
+    let mut averaged = Converter::buck(12.0, 100e-6, 100e-6, 5.0, RK4);
+    let mut switched =
+        Converter::buck(12.0, 100e-6, 100e-6, 5.0, RK4).with_model(ConverterModel::Switched);
+
+    let mut plotter = Plotter::new(
+        "Buck Converter: Averaged vs Switched".to_string(),
+        ["reference", "averaged", "switched"],
+    );
+
+    for sim_state in simulation {
+        let reference = sim_state >> reference.as_block();
+
+        let averaged_voltage = averaged.last_output().map(|(_, vc)| vc);
+        let duty = (reference - averaged_voltage) >> averaged_pi.as_block();
+        let (_, averaged_voltage) = (duty >> averaged.as_block()).unpack();
+
+        let switched_voltage = switched.last_output().map(|(_, vc)| vc);
+        let duty = (reference - switched_voltage) >> switched_pi.as_block();
+        let gate = duty >> pwm.as_block();
+        let (_, switched_voltage) = (gate >> switched.as_block()).unpack();
+
+        let _ = [reference, averaged_voltage, switched_voltage].pack() >> plotter.as_block();
+    }
+
+    plotter.display();
+    plotter.join();
+}
@@ -53,8 +53,8 @@ fn open_loop_rl_circuit() {
     let mut writer = Writter::new("output/open_loop_rl_circuit.csv", ["output"]);
 
     for sim_state in simulation {
-        let input = sim_state * step.as_block();
-        let _ = input * rl_circuit.as_block() * writer.as_block();
+        let input = sim_state >> step.as_block();
+        let _ = input >> rl_circuit.as_block() >> writer.as_block();
     }
 }
 
@@ -67,10 +67,8 @@ fn closed_loop_rl_circuit() {
     let mut writer = Writter::new("output/closed_loop_rl_circuit.csv", ["output"]);
 
     for sim_state in simulation {
-        let input = sim_state * step.as_block();
-        let _ = (input - rl_circuit.last_output())
-            * pid.as_block()
-            * rl_circuit.as_block()
-            * writer.as_block();
+        let input = sim_state >> step.as_block();
+        let output = (input - rl_circuit.last_output()) >> pid.as_block() >> rl_circuit.as_block();
+        let _ = output >> writer.as_block();
     }
 }
@@ -0,0 +1,36 @@
+use aule::prelude::*;
+
+fn main() {
+    let simulation = Simulation::new(0.1, 1800.0);
+
+    let mut chamber =
+        ThermalChamber::<3, RK4>::new(2000.0, 2.0, 1.0, 50.0, 25.0, RK4).with_max_power(1, 30.0);
+    let (a, b, e) = chamber.linearized();
+    println!("A = {:?}\nB = {:?}\ne = {:?}", a, b, e);
+    println!("Power limits: {:?}", chamber.power_limits());
+
+    let setpoints = [60.0, 70.0, 60.0];
+    let mut controllers = [(); 3].map(|_| PID::new(20.0, 0.05, 0.0).with_anti_windup(0.0, 50.0));
+    let mut plotter = Plotter::new(
+        "Thermal Chamber".to_string(),
+        ["zone 1", "zone 2", "zone 3"],
+    );
+
+    for sim_state in simulation {
+        let temperatures = chamber.last_output().unwrap_or(chamber.temperatures());
+
+        let power: [f64; 3] = core::array::from_fn(|i| {
+            let error = (setpoints[i] - temperatures[i]).as_signal(sim_state);
+            (error >> controllers[i].as_block()).value
+        });
+
+        let temperatures = power.as_signal(sim_state) >> chamber.as_block();
+        let _ = temperatures >> plotter.as_block();
+    }
+
+    println!("Final temperatures: {:?}", chamber.temperatures());
+    println!("Final power: {:?}", chamber.applied_power());
+
+    plotter.display();
+    plotter.join();
+}
@@ -18,18 +18,18 @@ fn main() {
             .with_legend_position(LegendPosition::BottomRight);
 
     for sim_state in simulation {
-        let input = sim_state * step.as_block();
+        let input = sim_state >> step.as_block();
         let error = input - plant.last_output();
         iae.output(error);
         ise.output(error);
         itae.output(error);
 
-        let control_signal = error * pid.as_block();
-        let control_signal = control_signal * saturation.as_block();
-        let output = control_signal * plant.as_block();
+        let control_signal = error >> pid.as_block();
+        let control_signal = control_signal >> saturation.as_block();
+        let output = control_signal >> plant.as_block();
 
-        let _ = (error, control_signal).pack() * good_hart.as_block();
-        let _ = [input, output].pack() * plotter.as_block() * writer.as_block();
+        let _ = (error, control_signal).pack() >> good_hart.as_block();
+        let _ = [input, output].pack() >> plotter.as_block() >> writer.as_block();
     }
 
     println!("IAE Value: {}", iae.value());
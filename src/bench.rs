@@ -0,0 +1,217 @@
+//! Standard scenarios for tracking the performance of the solvers and
+//! blocks across releases and comparing hardware against published
+//! numbers.
+//!
+//! Each scenario runs a fixed closed loop with a [`Profiler`] around the
+//! simulation and returns its timings with a checksum of the run. The
+//! checksum only depends on the numerics, so two machines agreeing on it
+//! ran the same work.
+
+use crate::prelude::*;
+use core::{fmt, time::Duration};
+use faer::{Mat, mat};
+use std::time::Instant;
+use std::vec::Vec;
+
+/// Timings of one scenario.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchResult {
+    pub name: &'static str,
+    /// Wall-clock time of the whole run.
+    pub elapsed: Duration,
+    /// Per-step timings of the simulation.
+    pub profile: ProfilerReport,
+    /// Integral of the absolute tracking error.
+    pub checksum: f64,
+}
+
+impl BenchResult {
+    /// Simulated steps per wall-clock second.
+    pub fn steps_per_second(&self) -> f64 {
+        self.profile.steps as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+impl fmt::Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} steps in {:?} ({:.0} steps/s, mean {:?}, p99 {:?}, max {:?}), checksum {:.9}",
+            self.name,
+            self.profile.steps,
+            self.elapsed,
+            self.steps_per_second(),
+            self.profile.mean,
+            self.profile.p99,
+            self.profile.max,
+            self.checksum
+        )
+    }
+}
+
+/// Every scenario, in order.
+pub fn all() -> Vec<BenchResult> {
+    std::vec![pid_third_order(), mpc_horizon_10()]
+}
+
+/// Step response of `1 / (s^3 + 6 s^2 + 11 s + 6)` under a saturated PID,
+/// at 1 kHz for 100 s with RK4.
+pub fn pid_third_order() -> BenchResult {
+    let mut step = Step::default();
+    let mut pid = PID::new(40.0, 10.0, 10.0);
+    let mut saturation = Saturation::new(0.0, 15.0);
+    let mut plant = Tf::new(&[1.0], &[1.0, 6.0, 11.0, 6.0]).to_ss_controllable(RK4);
+    let mut iae = IAE::default().with_accumulation(Accumulation::Integral);
+
+    let start = Instant::now();
+    let mut profiler = Profiler::new(Simulation::new(1e-3, 100.0));
+    for sim_state in &mut profiler {
+        let error = (sim_state >> step.as_block()) - plant.last_output();
+        let _ = error >> iae.as_block();
+        let _ = error >> pid.as_block() >> saturation.as_block() >> plant.as_block();
+    }
+
+    BenchResult {
+        name: "pid_third_order",
+        elapsed: start.elapsed(),
+        profile: profiler.report(),
+        checksum: iae.value(),
+    }
+}
+
+/// Sampling period of the MPC scenario.
+const MPC_PERIOD: Duration = Duration::from_millis(100);
+/// Prediction and control horizon of the MPC scenario.
+const MPC_HORIZON: usize = 10;
+/// Projected gradient iterations per MPC solve.
+const MPC_ITERATIONS: usize = 100;
+
+/// Step response of the same plant as [`pid_third_order`] under a
+/// horizon-10 linear MPC with the input bounded to `[0, 15]`. The plant
+/// runs at 1 kHz with RK4 for 100 s and the MPC solves its box-constrained
+/// QP every 100 ms, with a fixed number of projected gradient iterations so
+/// every solve does the same work.
+pub fn mpc_horizon_10() -> BenchResult {
+    let a = mat![[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [-6.0, -11.0, -6.0]];
+    let b = mat![[0.0], [0.0], [1.0]];
+    let c = mat![[1.0, 0.0, 0.0]];
+    let mut mpc = Mpc::new(&a, &b, &c, MPC_PERIOD.as_secs_f64(), 1.0, 1e-3);
+    let mut plant = SS::new(a, b, c, 0.0).with_integrator(RK4);
+    let mut step = Step::default();
+    let mut iae = IAE::default().with_accumulation(Accumulation::Integral);
+
+    let start = Instant::now();
+    let mut profiler = Profiler::new(Simulation::new(1e-3, 100.0));
+    let ratio = MPC_PERIOD.as_millis() as usize;
+    let mut u = 0.0;
+    for (k, sim_state) in (&mut profiler).enumerate() {
+        let reference = sim_state >> step.as_block();
+        let error = reference - plant.last_output();
+        let _ = error >> iae.as_block();
+
+        if k % ratio == 0 {
+            u = mpc.solve(plant.state(), reference.value);
+        }
+        let _ = u.as_signal(sim_state) >> plant.as_block();
+    }
+
+    BenchResult {
+        name: "mpc_horizon_10",
+        elapsed: start.elapsed(),
+        profile: profiler.report(),
+        checksum: iae.value(),
+    }
+}
+
+/// Condensed linear MPC on the zero-order hold model, penalizing the
+/// tracking error and the input moves over the horizon.
+struct Mpc {
+    /// Free response `C A^j`, `j = 1..=N`, one row per step.
+    phi: Mat<f64>,
+    /// Forced response, lower triangular Toeplitz in `C A^j B`.
+    gamma: Mat<f64>,
+    hessian: Mat<f64>,
+    /// Gradient step, the inverse of a bound on the Hessian's largest
+    /// eigenvalue.
+    step: f64,
+    move_weight: f64,
+    inputs: Mat<f64>,
+}
+
+impl Mpc {
+    fn new(a: &Mat<f64>, b: &Mat<f64>, c: &Mat<f64>, ts: f64, q: f64, r: f64) -> Self {
+        let n = MPC_HORIZON;
+        let (ad, bd) = crate::linalg::dense::zoh(a.as_ref(), b.as_ref(), ts);
+
+        let mut powers = Vec::with_capacity(n + 1);
+        powers.push(c.clone());
+        for j in 0..n {
+            powers.push(&powers[j] * &ad);
+        }
+        let markov = powers[..n]
+            .iter()
+            .map(|row| (row * &bd)[(0, 0)])
+            .collect::<Vec<_>>();
+
+        let phi = Mat::from_fn(n, a.nrows(), |j, k| powers[j + 1][(0, k)]);
+        let gamma = Mat::from_fn(n, n, |j, i| if i <= j { markov[j - i] } else { 0.0 });
+        // Moves `u_i - u_(i-1)` as `D U`, the first one against the last
+        // applied input.
+        let moves = Mat::from_fn(n, n, |i, j| match i.checked_sub(j) {
+            Some(0) => 1.0,
+            Some(1) => -1.0,
+            _ => 0.0,
+        });
+        let hessian = gamma.transpose() * &gamma * q + moves.transpose() * &moves * r;
+        let frobenius = hessian.norm_l2();
+
+        Self {
+            phi,
+            gamma: gamma * q,
+            hessian,
+            step: 1.0 / frobenius,
+            move_weight: r,
+            inputs: Mat::zeros(n, 1),
+        }
+    }
+
+    fn solve(&mut self, state: &Mat<f64>, reference: f64) -> f64 {
+        let n = MPC_HORIZON;
+        let free = &self.phi * state;
+        let error = Mat::from_fn(n, 1, |j, _| free[(j, 0)] - reference);
+        let mut linear = self.gamma.transpose() * &error;
+        linear[(0, 0)] -= self.move_weight * self.inputs[(0, 0)];
+
+        // Warm start from the previous plan shifted by one period.
+        let last = self.inputs[(n - 1, 0)];
+        let mut inputs = Mat::from_fn(n, 1, |i, _| {
+            if i + 1 < n {
+                self.inputs[(i + 1, 0)]
+            } else {
+                last
+            }
+        });
+        for _ in 0..MPC_ITERATIONS {
+            let gradient = &self.hessian * &inputs + &linear;
+            inputs = Mat::from_fn(n, 1, |i, _| {
+                (inputs[(i, 0)] - self.step * gradient[(i, 0)]).clamp(0.0, 15.0)
+            });
+        }
+
+        self.inputs = inputs;
+        self.inputs[(0, 0)]
+    }
+}
+
+#[cfg(all(test, feature = "bench"))]
+mod tests {
+    use crate::bench;
+
+    #[test]
+    fn test_bench_scenarios_track_the_step() {
+        for result in bench::all() {
+            assert_eq!(result.profile.steps, 100_000, "{}", result);
+            assert!(result.checksum > 0.1 && result.checksum < 5.0, "{}", result);
+        }
+    }
+}
@@ -1,4 +1,12 @@
 use crate::{prelude::SimulationState, signal::Signal};
+use core::time::Duration;
+
+/// Type names of the signals a block consumes and produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockPorts {
+    pub input: &'static str,
+    pub output: &'static str,
+}
 
 pub trait Block {
     type Input;
@@ -7,7 +15,7 @@ pub trait Block {
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output;
 
     fn output(&mut self, input: Signal<Self::Input>) -> Signal<Self::Output> {
-        let value = self.block(input.value, input.sim_state);
+        let value = run(self, input.value, input.sim_state);
         Signal {
             value,
             sim_state: input.sim_state,
@@ -26,4 +34,37 @@ pub trait Block {
     }
 
     fn reset(&mut self) {}
+
+    fn name(&self) -> &str {
+        core::any::type_name::<Self>()
+    }
+
+    fn ports(&self) -> BlockPorts {
+        BlockPorts {
+            input: core::any::type_name::<Self::Input>(),
+            output: core::any::type_name::<Self::Output>(),
+        }
+    }
+
+    /// Sample time this block needs to behave as intended, if it has one.
+    fn preferred_sample_time(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Block defined in sample units: each call is one sample, whatever the
+/// `dt` of the loop. Wrap it in [`Sampled`](crate::prelude::Sampled) to run
+/// it at its own sample time inside a faster loop.
+pub trait Discrete: Block {}
+
+/// Runs one step of `block`, inside a span when the `trace` feature is on.
+pub(crate) fn run<B>(block: &mut B, input: B::Input, sim_state: SimulationState) -> B::Output
+where
+    B: Block + ?Sized,
+{
+    #[cfg(feature = "trace")]
+    return crate::trace::instrument(block, input, sim_state);
+
+    #[cfg(not(feature = "trace"))]
+    block.block(input, sim_state)
 }
@@ -0,0 +1,263 @@
+use crate::{
+    Error,
+    block::Block,
+    continuous::{
+        solver::{SolverBuffers, StateEstimation},
+        ss::SS,
+    },
+    prelude::{SimulationState, Solver},
+};
+use alloc::vec::Vec;
+use core::{fmt::Debug, marker::PhantomData};
+use faer::{Accum, Mat, Par, linalg::matmul::matmul, traits::ComplexField};
+use num_traits::Zero;
+
+/// `N` single-input single-output state-space models with the same state
+/// dimension advanced together. The states are the columns of one `n x N`
+/// matrix, stepped with a single `A X + B U` product when the members share
+/// their matrices and with one product per column otherwise.
+#[derive(Debug, Clone)]
+pub struct SsEnsemble<I, T>
+where
+    T: Copy + Zero + ComplexField,
+    I: Solver<T> + Debug,
+{
+    /// A single model shared by every member, or one model per member.
+    models: Vec<Member<T>>,
+    state: Mat<T>,
+    initial_state: Mat<T>,
+    /// Inputs of the members, as a row.
+    input: Mat<T>,
+    buffers: SolverBuffers<T>,
+    last_output: Option<Vec<T>>,
+    _marker: PhantomData<I>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Member<T> {
+    a: Mat<T>,
+    b: Mat<T>,
+    c: Mat<T>,
+    d: T,
+}
+
+impl<I, T> SsEnsemble<I, T>
+where
+    T: Copy + Zero + ComplexField,
+    I: Solver<T> + Debug,
+{
+    /// Ensemble of `members`, see [`try_new`](Self::try_new). Panics on an
+    /// empty ensemble or mismatched members.
+    pub fn new(members: Vec<SS<I, T>>) -> Self {
+        Self::try_new(members).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Ensemble of `members`, which must all be single-input single-output
+    /// models with as many states as the first one.
+    pub fn try_new(members: Vec<SS<I, T>>) -> Result<Self, Error> {
+        let Some(first) = members.first() else {
+            return Err(Error::EmptyEnsemble);
+        };
+        let n = first.matrices().0.nrows();
+        for member in &members {
+            let (a, b, c, _) = member.matrices();
+            Error::check_dimension("A", (n, n), a.shape())?;
+            Error::check_dimension("B", (n, 1), b.shape())?;
+            Error::check_dimension("C", (1, n), c.shape())?;
+        }
+
+        let mut models = members
+            .iter()
+            .map(|member| {
+                let (a, b, c, d) = member.matrices();
+                Member {
+                    a: a.clone(),
+                    b: b.clone(),
+                    c: c.clone(),
+                    d,
+                }
+            })
+            .collect::<Vec<_>>();
+        if models.iter().all(|model| *model == models[0]) {
+            models.truncate(1);
+        }
+        let state = Mat::from_fn(n, members.len(), |i, k| members[k].state()[(i, 0)]);
+
+        Ok(Self {
+            models,
+            initial_state: state.clone(),
+            input: Mat::zeros(1, state.ncols()),
+            state,
+            buffers: SolverBuffers::new(),
+            last_output: None,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.ncols()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// States of all members, one column per member.
+    pub fn states(&self) -> &Mat<T> {
+        &self.state
+    }
+}
+
+impl<I, T> StateEstimation<T> for SsEnsemble<I, T>
+where
+    T: Copy + Zero + ComplexField,
+    I: Solver<T> + Debug,
+{
+    fn estimate(&self, state: Mat<T>) -> Mat<T> {
+        let mut derivative = Mat::zeros(state.nrows(), state.ncols());
+        self.estimate_into(&state, &mut derivative);
+        derivative
+    }
+
+    fn estimate_into(&self, state: &Mat<T>, derivative: &mut Mat<T>) {
+        if let [model] = self.models.as_slice() {
+            matmul(
+                &mut *derivative,
+                Accum::Replace,
+                &model.a,
+                state,
+                T::one_impl(),
+                Par::Seq,
+            );
+            matmul(
+                derivative,
+                Accum::Add,
+                &model.b,
+                &self.input,
+                T::one_impl(),
+                Par::Seq,
+            );
+            return;
+        }
+
+        for (k, model) in self.models.iter().enumerate() {
+            let mut column = derivative.col_mut(k).as_mat_mut();
+            matmul(
+                column.as_mut(),
+                Accum::Replace,
+                &model.a,
+                state.col(k).as_mat(),
+                T::one_impl(),
+                Par::Seq,
+            );
+            matmul(
+                column,
+                Accum::Add,
+                &model.b,
+                self.input.get(.., k..k + 1),
+                T::one_impl(),
+                Par::Seq,
+            );
+        }
+    }
+}
+
+impl<I, T> Block for SsEnsemble<I, T>
+where
+    T: Copy + Zero + ComplexField,
+    I: Solver<T> + Debug,
+{
+    type Input = Vec<T>;
+    type Output = Vec<T>;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        assert_eq!(
+            input.len(),
+            self.len(),
+            "Ensemble needs one input per member"
+        );
+
+        for (k, u) in input.iter().enumerate() {
+            self.input[(0, k)] = *u;
+        }
+        let mut buffers = core::mem::take(&mut self.buffers);
+        let mut state = core::mem::replace(&mut self.state, Mat::new());
+        I::integrate_in(&mut state, sim_state.dt(), self, &mut buffers);
+        (self.state, self.buffers) = (state, buffers);
+
+        let output = (0..self.len())
+            .map(|k| {
+                let model = &self.models[k.min(self.models.len() - 1)];
+                let y = &model.c * self.state.col(k);
+                y[0] + model.d * input[k]
+            })
+            .collect::<Vec<_>>();
+
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+        self.input.fill(T::zero());
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::Error;
+    use crate::prelude::*;
+    use std::vec;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_ensemble_matches_individual_models() {
+        let plant = |k: f64, tau: f64| Tf::new(&[k], &[tau, 1.0, 1.0]).to_ss_controllable(RK4);
+
+        let shared_params = [(2.0, 1.0); 3];
+        let shared_dynamics = [(1.0, 1.0), (2.0, 1.0), (0.5, 1.0)];
+        let varied_params = [(1.0, 0.5), (1.0, 1.0), (3.0, 2.0)];
+
+        for params in [shared_params, shared_dynamics, varied_params] {
+            let mut ensemble =
+                SsEnsemble::new(params.iter().map(|(k, tau)| plant(*k, *tau)).collect());
+            let mut members = params
+                .iter()
+                .map(|(k, tau)| plant(*k, *tau))
+                .collect::<Vec<_>>();
+
+            for sim_state in Simulation::new(0.01, 3.0) {
+                let outputs = vec![1.0, 0.5, -1.0].as_signal(sim_state) >> ensemble.as_block();
+
+                for ((member, u), y) in members.iter_mut().zip([1.0, 0.5, -1.0]).zip(outputs.value)
+                {
+                    assert!((member.block(u, sim_state) - y).abs() < 1e-12);
+                }
+            }
+            let states = ensemble.states();
+            for (k, member) in members.iter().enumerate() {
+                assert!((states[(1, k)] - member.state_at(1)).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ensemble_rejects_mismatched_members() {
+        let first = Tf::new(&[1.0], &[1.0, 1.0, 1.0]).to_ss_controllable(RK4);
+        let third_order = Tf::new(&[1.0], &[1.0, 1.0, 1.0, 1.0]).to_ss_controllable(RK4);
+
+        assert!(matches!(
+            SsEnsemble::try_new(vec![first, third_order]),
+            Err(Error::Dimension { matrix: "A", .. })
+        ));
+        assert_eq!(
+            SsEnsemble::<RK4, f64>::try_new(vec![]).unwrap_err(),
+            Error::EmptyEnsemble
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{
+    Error,
+    block::Block,
+    continuous::solver::{FixedSolver, FixedStateEstimation},
+    prelude::{HasState, SimulationState},
+};
+use core::{fmt::Debug, marker::PhantomData};
+use num_traits::Float;
+
+/// Single-input single-output state-space model with `N` states stored in
+/// fixed-size arrays, so it needs neither `alloc` nor a matrix library.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsFixed<const N: usize, I, T = f64>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    a: [[T; N]; N],
+    b: [T; N],
+    c: [T; N],
+    d: T,
+    state: [T; N],
+    initial_state: [T; N],
+    current_input: T,
+    last_output: Option<T>,
+    _marker: PhantomData<I>,
+}
+
+impl<const N: usize, I, T> SsFixed<N, I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    pub fn new(a: [[T; N]; N], b: [T; N], c: [T; N], d: T) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            d,
+            state: [T::zero(); N],
+            initial_state: [T::zero(); N],
+            current_input: T::zero(),
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Controllable canonical realization of `N(s) / D(s)`, coefficients
+    /// highest degree first, like [`Tf::try_new`](crate::prelude::Tf::try_new).
+    /// The denominator must be of degree `N`. Unlike `Tf` it takes any
+    /// [`Float`], e.g. a [`Dual`](crate::prelude::Dual) to differentiate
+    /// through the coefficients.
+    pub fn try_from_tf(numerator: &[T], denominator: &[T]) -> Result<Self, Error> {
+        if numerator.is_empty() {
+            return Err(Error::EmptyNumerator);
+        }
+        if denominator.len() != N + 1 {
+            return Err(Error::Dimension {
+                matrix: "denominator",
+                expected: (1, N + 1),
+                found: (1, denominator.len()),
+            });
+        }
+        if numerator.len() > denominator.len() {
+            return Err(Error::ImproperTf {
+                numerator_degree: numerator.len() - 1,
+                denominator_degree: N,
+            });
+        }
+
+        let a0 = denominator[0];
+        let offset = N + 1 - numerator.len();
+        // Coefficient of `s^(N - k)`, both polynomials made monic by `a0`.
+        let num = |k: usize| {
+            k.checked_sub(offset)
+                .map_or(T::zero(), |k| numerator[k] / a0)
+        };
+        let den = |k: usize| denominator[k] / a0;
+
+        let d = num(0);
+        let a = core::array::from_fn(|i| {
+            core::array::from_fn(|j| match i + 1 {
+                last if last == N => -den(N - j),
+                next if next == j => T::one(),
+                _ => T::zero(),
+            })
+        });
+        let b = core::array::from_fn(|i| if i + 1 == N { T::one() } else { T::zero() });
+        let c = core::array::from_fn(|j| num(N - j) - d * den(N - j));
+
+        Ok(Self::new(a, b, c, d))
+    }
+
+    pub fn with_initial_state(mut self, initial_state: [T; N]) -> Self {
+        self.initial_state = initial_state;
+        self.state = initial_state;
+        self
+    }
+
+    pub fn with_integrator(self, _integrator: I) -> Self {
+        self
+    }
+
+    pub fn state(&self) -> &[T; N] {
+        &self.state
+    }
+}
+
+impl<const N: usize, I, T> FixedStateEstimation<T, N> for SsFixed<N, I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    fn estimate(&self, state: &[T; N]) -> [T; N] {
+        core::array::from_fn(|i| {
+            self.a[i]
+                .iter()
+                .zip(state)
+                .fold(self.b[i] * self.current_input, |acc, (a, x)| acc + *a * *x)
+        })
+    }
+}
+
+impl<const N: usize, I, T> Block for SsFixed<N, I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.current_input = input;
+        self.state = I::integrate(self.state, sim_state.dt(), self);
+
+        let output = self
+            .c
+            .iter()
+            .zip(&self.state)
+            .fold(self.d * input, |acc, (c, x)| acc + *c * *x);
+        self.last_output = Some(output);
+
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial_state;
+        self.current_input = T::zero();
+        self.last_output = None;
+    }
+}
+
+impl<const N: usize, I, T> HasState<T> for SsFixed<N, I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    fn order(&self) -> usize {
+        N
+    }
+
+    fn state_at(&self, index: usize) -> T {
+        self.state[index]
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use faer::mat;
+
+    #[test]
+    fn test_ss_fixed_matches_ss() {
+        let mut fixed =
+            SsFixed::<2, RK4>::new([[0.0, 1.0], [-2.0, -3.0]], [0.0, 1.0], [1.0, 0.0], 0.5)
+                .with_initial_state([1.0, 0.0]);
+        let mut ss: SS<RK4, f64> = SS::new(
+            mat![[0.0, 1.0], [-2.0, -3.0]],
+            mat![[0.0], [1.0]],
+            mat![[1.0, 0.0]],
+            0.5,
+        )
+        .with_initial_state(mat![[1.0], [0.0]]);
+
+        for sim_state in Simulation::new(0.01, 5.0) {
+            let u = libm::sin(sim_state.sim_time().as_secs_f64());
+            assert!((fixed.block(u, sim_state) - ss.block(u, sim_state)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ss_fixed_from_tf_matches_tf() {
+        let (num, den) = ([2.0, 1.0, 3.0], [2.0, 3.0, 2.0]);
+        let mut fixed = SsFixed::<2, RK4>::try_from_tf(&num, &den).unwrap();
+        let mut tf = Tf::new(&num, &den).to_ss_controllable(RK4);
+
+        for sim_state in Simulation::new(0.01, 5.0) {
+            let u = libm::sin(sim_state.sim_time().as_secs_f64());
+            assert!((fixed.block(u, sim_state) - tf.block(u, sim_state)).abs() < 1e-12);
+        }
+
+        assert!(SsFixed::<1, RK4>::try_from_tf(&num, &den).is_err());
+        assert!(SsFixed::<2, RK4>::try_from_tf(&[], &den).is_err());
+    }
+
+    #[test]
+    fn test_ss_fixed_from_tf_on_dual() {
+        // d/dk of the step response of k / (s + 1) is 1 - e^-t.
+        let [k] = Dual::variables([2.0]);
+        let mut plant = SsFixed::<1, RK4, Dual<1>>::try_from_tf(
+            &[k],
+            &[Dual::constant(1.0), Dual::constant(1.0)],
+        )
+        .unwrap();
+
+        let mut output = Dual::constant(0.0);
+        for sim_state in Simulation::new(0.01, 2.0) {
+            output = plant.block(Dual::constant(1.0), sim_state);
+        }
+        let expected = 1.0 - libm::exp(-2.0);
+        assert!((output.re() - 2.0 * expected).abs() < 1e-6);
+        assert!((output.eps()[0] - expected).abs() < 1e-6);
+    }
+}
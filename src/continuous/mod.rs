@@ -1,9 +1,21 @@
+#[cfg(feature = "alloc")]
+pub mod ensemble;
+pub mod fixed;
+#[cfg(feature = "alloc")]
 pub mod poly;
+#[cfg(feature = "alloc")]
 pub mod s_var;
 pub mod solver;
+#[cfg(feature = "alloc")]
 pub mod ss;
+#[cfg(feature = "nalgebra")]
+pub mod static_ss;
+#[cfg(feature = "alloc")]
 pub mod tf;
 
+#[cfg(feature = "alloc")]
 pub use poly::Polynomial;
+#[cfg(feature = "alloc")]
 pub use s_var::s;
+#[cfg(feature = "alloc")]
 pub use tf::Tf;
@@ -42,6 +42,10 @@ where
         self.0.lead_coeff()
     }
 
+    pub fn real_coeffs(&self) -> Vec<f64> {
+        self.0.real_coeffs()
+    }
+
     pub fn inner(&self) -> &crate::poly::Polynomial<T> {
         &self.0
     }
@@ -86,8 +90,10 @@ where
 {
     type Output = Tf<T>;
 
+    /// Panics if `rhs` has a lower degree than `self`; see
+    /// [`Tf::try_new`].
     fn div(self, rhs: Self) -> Self::Output {
-        Tf::new(self.coeff(), rhs.coeff())
+        Tf::try_new(self.coeff(), rhs.coeff()).unwrap_or_else(|error| panic!("{}", error))
     }
 }
 
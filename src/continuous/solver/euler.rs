@@ -1,13 +1,17 @@
-use crate::{continuous::solver::StateEstimation, prelude::Solver};
-use core::{
-    ops::{Add, Mul},
-    time::Duration,
-};
+use crate::continuous::solver::{FixedSolver, FixedStateEstimation, fixed_axpy};
+#[cfg(feature = "alloc")]
+use crate::continuous::solver::{Solver, SolverBuffers, StateEstimation, axpy};
+#[cfg(feature = "alloc")]
+use core::ops::{Add, Mul};
+use core::time::Duration;
+#[cfg(feature = "alloc")]
 use faer::{Mat, traits::ComplexField};
+use num_traits::Float;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Euler;
 
+#[cfg(feature = "alloc")]
 impl<T> Solver<T> for Euler
 where
     T: Copy + Add<Output = T> + Mul<f64, Output = T> + ComplexField,
@@ -17,8 +21,38 @@ where
         dt: Duration,
         state_estimation: &impl StateEstimation<T>,
     ) -> Mat<T> {
-        let dt_seconds = dt.as_secs_f64();
-        let estimation = state_estimation.estimate(old_value.clone());
-        old_value + estimation * dt_seconds
+        let mut new_value = old_value;
+        Self::integrate_in(
+            &mut new_value,
+            dt,
+            state_estimation,
+            &mut SolverBuffers::new(),
+        );
+        new_value
+    }
+
+    fn integrate_in(
+        state: &mut Mat<T>,
+        dt: Duration,
+        state_estimation: &impl StateEstimation<T>,
+        buffers: &mut SolverBuffers<T>,
+    ) {
+        let (_, [derivative, ..]) = buffers.shaped_like(state);
+        state_estimation.estimate_into(state, derivative);
+        axpy(state, derivative, dt.as_secs_f64());
+    }
+}
+
+impl<T> FixedSolver<T> for Euler
+where
+    T: Float,
+{
+    fn integrate<const N: usize>(
+        old_value: [T; N],
+        dt: Duration,
+        state_estimation: &impl FixedStateEstimation<T, N>,
+    ) -> [T; N] {
+        let dt = T::from(dt.as_secs_f64()).unwrap();
+        fixed_axpy(&old_value, &state_estimation.estimate(&old_value), dt)
     }
 }
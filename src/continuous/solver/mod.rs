@@ -1,17 +1,118 @@
+#[cfg(feature = "alloc")]
+use core::ops::{Add, Mul};
 use core::time::Duration;
-use faer::Mat;
+#[cfg(feature = "alloc")]
+use faer::{Mat, traits::ComplexField, unzip, zip};
 
 pub mod euler;
 pub mod runge_kutta;
 
+#[cfg(feature = "alloc")]
 pub trait StateEstimation<T> {
     fn estimate(&self, state: Mat<T>) -> Mat<T>;
+
+    /// Writes the derivative of `state` into `derivative`, of the same
+    /// shape. Models on a hot path override it to skip the allocations of
+    /// [`estimate`](Self::estimate).
+    fn estimate_into(&self, state: &Mat<T>, derivative: &mut Mat<T>)
+    where
+        T: Clone,
+    {
+        *derivative = self.estimate(state.clone());
+    }
 }
 
+#[cfg(feature = "alloc")]
 pub trait Solver<T> {
     fn integrate(
         old_value: Mat<T>,
         dt: Duration,
         state_estimation: &impl StateEstimation<T>,
     ) -> Mat<T>;
+
+    /// Integrates `state` in place, reusing `buffers` across steps.
+    fn integrate_in(
+        state: &mut Mat<T>,
+        dt: Duration,
+        state_estimation: &impl StateEstimation<T>,
+        _buffers: &mut SolverBuffers<T>,
+    ) where
+        T: ComplexField,
+    {
+        let old_value = core::mem::replace(state, Mat::new());
+        *state = Self::integrate(old_value, dt, state_estimation);
+    }
+}
+
+/// Stage and derivative matrices a [`Solver`] reuses from one step to the
+/// next, kept by the block it integrates.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct SolverBuffers<T> {
+    stage: Mat<T>,
+    derivatives: [Mat<T>; 4],
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SolverBuffers<T>
+where
+    T: ComplexField,
+{
+    pub fn new() -> Self {
+        Self {
+            stage: Mat::new(),
+            derivatives: core::array::from_fn(|_| Mat::new()),
+        }
+    }
+
+    /// The buffers shaped like `state`, reallocated only when its shape
+    /// changes.
+    pub(crate) fn shaped_like(&mut self, state: &Mat<T>) -> (&mut Mat<T>, &mut [Mat<T>; 4]) {
+        if self.stage.shape() != state.shape() {
+            let (rows, cols) = state.shape();
+            self.stage = Mat::zeros(rows, cols);
+            self.derivatives = core::array::from_fn(|_| Mat::zeros(rows, cols));
+        }
+        (&mut self.stage, &mut self.derivatives)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for SolverBuffers<T>
+where
+    T: ComplexField,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counterpart of [`StateEstimation`] for states stored in fixed-size arrays.
+pub trait FixedStateEstimation<T, const N: usize> {
+    fn estimate(&self, state: &[T; N]) -> [T; N];
+}
+
+/// Counterpart of [`Solver`] for states stored in fixed-size arrays.
+pub trait FixedSolver<T> {
+    fn integrate<const N: usize>(
+        old_value: [T; N],
+        dt: Duration,
+        state_estimation: &impl FixedStateEstimation<T, N>,
+    ) -> [T; N];
+}
+
+/// `dst += src * alpha`, in place.
+#[cfg(feature = "alloc")]
+pub(crate) fn axpy<T>(dst: &mut Mat<T>, src: &Mat<T>, alpha: f64)
+where
+    T: Copy + Add<Output = T> + Mul<f64, Output = T> + ComplexField,
+{
+    zip!(dst, src).for_each(|unzip!(dst, src)| *dst += *src * alpha);
+}
+
+pub(crate) fn fixed_axpy<T, const N: usize>(dst: &[T; N], src: &[T; N], alpha: T) -> [T; N]
+where
+    T: num_traits::Float,
+{
+    core::array::from_fn(|i| dst[i] + src[i] * alpha)
 }
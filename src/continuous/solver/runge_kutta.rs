@@ -1,13 +1,17 @@
-use crate::{continuous::solver::StateEstimation, prelude::Solver};
-use core::{
-    ops::{Add, Mul},
-    time::Duration,
-};
+use crate::continuous::solver::{FixedSolver, FixedStateEstimation, fixed_axpy};
+#[cfg(feature = "alloc")]
+use crate::continuous::solver::{Solver, SolverBuffers, StateEstimation, axpy};
+#[cfg(feature = "alloc")]
+use core::ops::{Add, Mul};
+use core::time::Duration;
+#[cfg(feature = "alloc")]
 use faer::{Mat, traits::ComplexField};
+use num_traits::Float;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RK4;
 
+#[cfg(feature = "alloc")]
 impl<T> Solver<T> for RK4
 where
     T: Copy + Add<Output = T> + Mul<f64, Output = T> + ComplexField,
@@ -17,12 +21,65 @@ where
         dt: Duration,
         state_estimation: &impl StateEstimation<T>,
     ) -> Mat<T> {
+        let mut new_value = old_value;
+        Self::integrate_in(
+            &mut new_value,
+            dt,
+            state_estimation,
+            &mut SolverBuffers::new(),
+        );
+        new_value
+    }
+
+    fn integrate_in(
+        state: &mut Mat<T>,
+        dt: Duration,
+        state_estimation: &impl StateEstimation<T>,
+        buffers: &mut SolverBuffers<T>,
+    ) {
         let dt_seconds = dt.as_secs_f64();
-        let k1 = state_estimation.estimate(old_value.clone());
-        let k2 = state_estimation.estimate(old_value.clone() + k1.clone() * (dt_seconds / 2.0));
-        let k3 = state_estimation.estimate(old_value.clone() + k2.clone() * (dt_seconds / 2.0));
-        let k4 = state_estimation.estimate(old_value.clone() + k3.clone() * dt_seconds);
+        let (stage, [k1, k2, k3, k4]) = buffers.shaped_like(state);
+
+        state_estimation.estimate_into(state, k1);
+        stage.copy_from(&*state);
+        axpy(stage, k1, dt_seconds / 2.0);
+        state_estimation.estimate_into(stage, k2);
+
+        stage.copy_from(&*state);
+        axpy(stage, k2, dt_seconds / 2.0);
+        state_estimation.estimate_into(stage, k3);
+
+        stage.copy_from(&*state);
+        axpy(stage, k3, dt_seconds);
+        state_estimation.estimate_into(stage, k4);
+
+        axpy(state, k1, dt_seconds / 6.0);
+        axpy(state, k2, dt_seconds / 3.0);
+        axpy(state, k3, dt_seconds / 3.0);
+        axpy(state, k4, dt_seconds / 6.0);
+    }
+}
+
+impl<T> FixedSolver<T> for RK4
+where
+    T: Float,
+{
+    fn integrate<const N: usize>(
+        old_value: [T; N],
+        dt: Duration,
+        state_estimation: &impl FixedStateEstimation<T, N>,
+    ) -> [T; N] {
+        let dt = T::from(dt.as_secs_f64()).unwrap();
+        let two = T::one() + T::one();
+        let six = two + two + two;
+
+        let k1 = state_estimation.estimate(&old_value);
+        let k2 = state_estimation.estimate(&fixed_axpy(&old_value, &k1, dt / two));
+        let k3 = state_estimation.estimate(&fixed_axpy(&old_value, &k2, dt / two));
+        let k4 = state_estimation.estimate(&fixed_axpy(&old_value, &k3, dt));
 
-        old_value + (k1 + k2 * 2.0 + k3 * 2.0 + k4) * (dt_seconds / 6.0)
+        core::array::from_fn(|i| {
+            old_value[i] + (k1[i] + two * k2[i] + two * k3[i] + k4[i]) * dt / six
+        })
     }
 }
@@ -1,13 +1,14 @@
 use crate::{
+    Error,
     block::Block,
-    continuous::solver::StateEstimation,
-    prelude::{SimulationState, Solver},
+    continuous::solver::{SolverBuffers, StateEstimation},
+    prelude::{HasState, SimulationState, Solver},
 };
 use core::{
     fmt::{Debug, Display},
     marker::PhantomData,
 };
-use faer::{Mat, mat, traits::ComplexField};
+use faer::{Accum, Mat, Par, linalg::matmul::matmul, mat, traits::ComplexField};
 use num_traits::Zero;
 
 #[derive(Debug, Clone)]
@@ -23,6 +24,7 @@ where
     state: Mat<T>,
     initial_state: Option<Mat<T>>,
     current_input: Mat<T>,
+    buffers: SolverBuffers<T>,
     last_output: Option<T>,
     _marker: PhantomData<I>,
 }
@@ -32,16 +34,36 @@ where
     T: Copy + Zero + ComplexField,
     I: Solver<T> + Debug,
 {
+    /// Model from its matrices, see [`try_new`](Self::try_new). Mismatched
+    /// dimensions panic under `strict-panic`, and otherwise are logged and
+    /// give a model without states whose output is NaN. Use `try_new` to
+    /// handle them.
+    #[track_caller]
     pub fn new(a: Mat<T>, b: Mat<T>, c: Mat<T>, d: T) -> Self {
-        let n = a.shape().0;
-        assert_eq!(a.shape().0, a.shape().1, "A must be a square matrix");
+        crate::error::or_fallback(Self::try_new(a, b, c, d), |_| {
+            Self::new_unchecked(
+                Mat::zeros(0, 0),
+                Mat::zeros(0, 1),
+                Mat::zeros(1, 0),
+                T::nan_impl(),
+            )
+        })
+    }
 
-        assert_eq!(b.shape().0, n, "B must has {} rows", n);
-        assert_eq!(b.shape().1, 1, "B must be a column matrix");
+    /// Single-input single-output model `x' = A x + B u`, `y = C x + D u`,
+    /// with `A` square, `B` a column and `C` a row.
+    pub fn try_new(a: Mat<T>, b: Mat<T>, c: Mat<T>, d: T) -> Result<Self, Error> {
+        let n = a.nrows();
+        Error::check_dimension("A", (n, n), a.shape())?;
+        Error::check_dimension("B", (n, 1), b.shape())?;
+        Error::check_dimension("C", (1, n), c.shape())?;
 
-        assert_eq!(c.shape().0, 1, "C must be a row matrix");
-        assert_eq!(c.shape().1, n, "C must has {} columns", n);
+        Ok(Self::new_unchecked(a, b, c, d))
+    }
 
+    /// For matrices whose shapes are valid by construction.
+    pub(crate) fn new_unchecked(a: Mat<T>, b: Mat<T>, c: Mat<T>, d: T) -> Self {
+        let n = a.nrows();
         Self {
             a,
             b,
@@ -51,6 +73,7 @@ where
             initial_state: None,
             last_output: None,
             current_input: mat![[T::zero()]],
+            buffers: SolverBuffers::new(),
             _marker: PhantomData,
         }
     }
@@ -77,6 +100,14 @@ where
     pub fn with_integrator(self, _integrator: I) -> Self {
         self
     }
+
+    pub(crate) fn matrices(&self) -> (&Mat<T>, &Mat<T>, &Mat<T>, T) {
+        (&self.a, &self.b, &self.c, self.d[(0, 0)])
+    }
+
+    pub(crate) fn state(&self) -> &Mat<T> {
+        &self.state
+    }
 }
 
 impl<I, T> StateEstimation<T> for SS<I, T>
@@ -85,7 +116,28 @@ where
     I: Solver<T> + Debug,
 {
     fn estimate(&self, state: Mat<T>) -> Mat<T> {
-        &self.a * &state + &self.b * &self.current_input
+        let mut derivative = Mat::zeros(state.nrows(), 1);
+        self.estimate_into(&state, &mut derivative);
+        derivative
+    }
+
+    fn estimate_into(&self, state: &Mat<T>, derivative: &mut Mat<T>) {
+        matmul(
+            &mut *derivative,
+            Accum::Replace,
+            &self.a,
+            state,
+            T::one_impl(),
+            Par::Seq,
+        );
+        matmul(
+            derivative,
+            Accum::Add,
+            &self.b,
+            &self.current_input,
+            T::one_impl(),
+            Par::Seq,
+        );
     }
 }
 
@@ -99,11 +151,12 @@ where
 
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
         self.current_input[(0, 0)] = input;
-        self.state = I::integrate(self.state.clone(), sim_state.dt(), self);
+        let mut buffers = core::mem::take(&mut self.buffers);
+        let mut state = core::mem::replace(&mut self.state, Mat::new());
+        I::integrate_in(&mut state, sim_state.dt(), self, &mut buffers);
+        (self.state, self.buffers) = (state, buffers);
 
-        let input_matrix = mat![[input]];
-        let output = &self.c * &self.state + &self.d * &input_matrix;
-        let output = output[(0, 0)];
+        let output = self.c.row(0) * self.state.col(0) + self.d[(0, 0)] * input;
         self.last_output = Some(output);
 
         output
@@ -124,6 +177,20 @@ where
     }
 }
 
+impl<I, T> HasState<T> for SS<I, T>
+where
+    T: Copy + Zero + ComplexField,
+    I: Solver<T> + Debug,
+{
+    fn order(&self) -> usize {
+        self.state.nrows()
+    }
+
+    fn state_at(&self, index: usize) -> T {
+        self.state[(index, 0)]
+    }
+}
+
 impl<I, T> Display for SS<I, T>
 where
     T: Copy + Zero + Display + ComplexField,
@@ -0,0 +1,129 @@
+use crate::{
+    block::Block,
+    continuous::{
+        fixed::SsFixed,
+        solver::{FixedSolver, FixedStateEstimation},
+    },
+    prelude::{HasState, SimulationState},
+};
+use core::{fmt::Debug, time::Duration};
+use nalgebra::{RealField, RowSVector, SMatrix, SVector};
+use num_traits::Float;
+
+/// Adapter that builds an [`SsFixed`] from nalgebra's stack-allocated static
+/// matrices and reads its state back as an [`SVector`], for code that already
+/// keeps its models in nalgebra types. The solver is picked by the type
+/// parameter, e.g. `SsStatic::<2, RK4>::new(..)`.
+///
+/// The matrices are copied into arrays once, in `new`, and every step runs
+/// the wrapped `SsFixed`, so this is a conversion convenience and not a
+/// faster path; use `SsFixed` directly when no nalgebra types are involved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SsStatic<const N: usize, I, T = f64>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    model: SsFixed<N, I, T>,
+}
+
+/// Solvers of [`FixedSolver`] stepping nalgebra column vectors, converted to
+/// and from the arrays the solver works on.
+pub trait StaticSolver<T>: FixedSolver<T> {
+    fn integrate_static<const N: usize>(
+        old_value: SVector<T, N>,
+        dt: Duration,
+        state_estimation: &impl FixedStateEstimation<T, N>,
+    ) -> SVector<T, N>
+    where
+        T: RealField + Copy,
+    {
+        SVector::from(Self::integrate(old_value.into(), dt, state_estimation))
+    }
+}
+
+impl<T, I> StaticSolver<T> for I where I: FixedSolver<T> {}
+
+impl<const N: usize, I, T> SsStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    pub fn new(a: SMatrix<T, N, N>, b: SVector<T, N>, c: RowSVector<T, N>, d: T) -> Self {
+        let a = core::array::from_fn(|i| core::array::from_fn(|j| a[(i, j)]));
+        Self {
+            model: SsFixed::new(a, b.into(), c.transpose().into(), d),
+        }
+    }
+
+    pub fn with_initial_state(mut self, initial_state: SVector<T, N>) -> Self {
+        self.model = self.model.with_initial_state(initial_state.into());
+        self
+    }
+
+    pub fn state(&self) -> SVector<T, N> {
+        SVector::from(*self.model.state())
+    }
+}
+
+impl<const N: usize, I, T> Block for SsStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.model.block(input, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.model.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.model.reset();
+    }
+}
+
+impl<const N: usize, I, T> HasState<T> for SsStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    fn order(&self) -> usize {
+        N
+    }
+
+    fn state_at(&self, index: usize) -> T {
+        self.model.state_at(index)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use nalgebra::{Matrix2, RowVector2, Vector2};
+
+    #[test]
+    fn test_ss_static_matches_ss_fixed() {
+        let mut fixed =
+            SsFixed::<2, RK4>::new([[0.0, 1.0], [-2.0, -3.0]], [0.0, 1.0], [1.0, 0.0], 0.5)
+                .with_initial_state([1.0, 0.0]);
+        let mut static_ss = SsStatic::<2, RK4>::new(
+            Matrix2::new(0.0, 1.0, -2.0, -3.0),
+            Vector2::new(0.0, 1.0),
+            RowVector2::new(1.0, 0.0),
+            0.5,
+        )
+        .with_initial_state(Vector2::new(1.0, 0.0));
+
+        for sim_state in Simulation::new(0.01, 5.0) {
+            let u = libm::sin(sim_state.sim_time().as_secs_f64());
+            let expected = fixed.block(u, sim_state);
+            assert!((static_ss.block(u, sim_state) - expected).abs() < 1e-12);
+        }
+        assert_eq!(static_ss.state(), Vector2::from(*fixed.state()));
+    }
+}
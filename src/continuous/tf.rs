@@ -1,10 +1,10 @@
-use crate::{continuous::ss::SS, poly::Polynomial, prelude::Solver};
+use crate::{Error, continuous::ss::SS, poly::Polynomial, prelude::Solver};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::ops::AddAssign;
-use faer::Mat;
 use faer::traits::ComplexField;
+use faer::{Mat, c64};
 use num_traits::Float;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,20 +20,78 @@ impl<T> Tf<T>
 where
     T: Float + Default + AddAssign<T> + ComplexField,
 {
+    /// Transfer function from its coefficients, see [`try_new`](Self::try_new).
+    /// Invalid coefficients panic under `strict-panic`, and otherwise are
+    /// logged and give the constant NaN transfer function. Use `try_new` to
+    /// handle them.
+    #[track_caller]
     pub fn new(numerator: &[T], denominator: &[T]) -> Self {
-        assert!(!numerator.is_empty(), "Numerator cannot be empty.");
-        assert!(!denominator.is_empty(), "Denominator cannot be empty.");
-        assert!(
-            denominator.len() >= numerator.len(),
-            "Denominator must have degree greater than or equal to numerator."
-        );
+        crate::error::or_fallback(Self::try_new(numerator, denominator), |_| {
+            Self::new_unchecked(&[T::nan()], &[T::one()])
+        })
+    }
+
+    /// Transfer function from its coefficients, highest degree first. The
+    /// denominator must be at least of the degree of the numerator.
+    pub fn try_new(numerator: &[T], denominator: &[T]) -> Result<Self, Error> {
+        if numerator.is_empty() {
+            return Err(Error::EmptyNumerator);
+        }
+        if denominator.is_empty() {
+            return Err(Error::EmptyDenominator);
+        }
+        if denominator.len() < numerator.len() {
+            return Err(Error::ImproperTf {
+                numerator_degree: numerator.len() - 1,
+                denominator_degree: denominator.len() - 1,
+            });
+        }
+
+        Ok(Self::new_unchecked(numerator, denominator))
+    }
 
+    /// For coefficients that are valid by construction.
+    pub(crate) fn new_unchecked(numerator: &[T], denominator: &[T]) -> Self {
         Tf {
             numerator: crate::continuous::poly::Polynomial::new(numerator),
             denominator: crate::continuous::poly::Polynomial::new(denominator),
         }
     }
 
+    pub fn numerator(&self) -> &crate::continuous::poly::Polynomial<T> {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &crate::continuous::poly::Polynomial<T> {
+        &self.denominator
+    }
+
+    /// Value of `N(s) / D(s)` at the complex point `s`.
+    pub fn eval(&self, s: c64) -> c64 {
+        self.numerator.inner().eval(s) / self.denominator.inner().eval(s)
+    }
+
+    /// Steady-state gain, `H(0)`. Infinite when the model has a pole at the
+    /// origin.
+    pub fn dc_gain(&self) -> f64 {
+        let num = self.numerator.real_coeffs().last().copied().unwrap_or(0.0);
+        let den = self
+            .denominator
+            .real_coeffs()
+            .last()
+            .copied()
+            .unwrap_or(0.0);
+        num / den
+    }
+
+    /// `H(j omega)` for each angular frequency in rad/s.
+    pub fn freq_response(&self, omegas: &[f64]) -> Vec<c64> {
+        omegas
+            .iter()
+            .map(|&omega| self.eval(c64::new(0.0, omega)))
+            .collect()
+    }
+
     pub fn to_ss_controllable<I>(self, _integrator: I) -> SS<I, T>
     where
         I: Solver<T> + Debug,
@@ -61,6 +119,10 @@ where
         let denominator = self.denominator;
 
         let d = quotient.coeff().first().copied().unwrap_or(T::zero());
+        if n == 0 {
+            // A static gain, no states to realize.
+            return SS::new_unchecked(Mat::zeros(0, 0), Mat::zeros(0, 1), Mat::zeros(1, 0), d);
+        }
 
         let a0 = denominator.lead_coeff();
         let a = denominator
@@ -87,6 +149,63 @@ where
         let c_mat = b.iter().rev().copied().collect::<Vec<_>>();
         let c_mat = Mat::from_fn(1, n, |_, j| c_mat[j]);
 
-        SS::new(a_mat, b_mat, c_mat, d)
+        SS::new_unchecked(a_mat, b_mat, c_mat, d)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::Error;
+    use crate::prelude::*;
+    use faer::c64;
+    use std::string::ToString;
+
+    #[test]
+    fn test_tf_eval_and_dc_gain() {
+        let tf = Tf::new(&[2.0], &[1.0, 1.0]);
+
+        assert_eq!(tf.dc_gain(), 2.0);
+        let h = tf.freq_response(&[1.0])[0];
+        assert!((libm::hypot(h.re, h.im) - 2.0 / 2.0f64.sqrt()).abs() < 1e-12);
+        assert!((libm::atan2(h.im, h.re) + core::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        assert_eq!(tf.eval(c64::new(1.0, 0.0)), c64::new(1.0, 0.0));
+        assert!(Tf::new(&[1.0], &[1.0, 0.0]).dc_gain().is_infinite());
+    }
+
+    #[test]
+    fn test_fallible_constructors() {
+        assert_eq!(
+            Tf::try_new(&[1.0, 0.0, 0.0], &[1.0, 1.0]),
+            Err(Error::ImproperTf {
+                numerator_degree: 2,
+                denominator_degree: 1
+            })
+        );
+        assert_eq!(Tf::<f64>::try_new(&[], &[1.0]), Err(Error::EmptyNumerator));
+
+        let error =
+            SS::<RK4, f64>::try_new(Mat::identity(2, 2), Mat::zeros(3, 1), Mat::zeros(1, 2), 0.0)
+                .unwrap_err();
+        assert_eq!(
+            error,
+            Error::Dimension {
+                matrix: "B",
+                expected: (2, 1),
+                found: (3, 1)
+            }
+        );
+        assert_eq!(error.to_string(), "B must be a 2x1 matrix, found 3x1");
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-panic"))]
+    fn test_invalid_models_output_nan() {
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+        let mut tf = Tf::new(&[1.0, 0.0, 0.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut ss =
+            SS::<RK4, f64>::new(Mat::identity(2, 2), Mat::zeros(3, 1), Mat::zeros(1, 2), 0.0);
+
+        assert!(tf.block(1.0, sim_state).is_nan());
+        assert!(ss.block(1.0, sim_state).is_nan());
     }
 }
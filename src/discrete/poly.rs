@@ -42,6 +42,10 @@ where
         self.0.lead_coeff()
     }
 
+    pub fn real_coeffs(&self) -> Vec<f64> {
+        self.0.real_coeffs()
+    }
+
     pub fn inner(&self) -> &crate::poly::Polynomial<T> {
         &self.0
     }
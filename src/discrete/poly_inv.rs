@@ -26,6 +26,11 @@ where
         Self(crate::poly::Polynomial::empty())
     }
 
+    /// Keeps leading zero coefficients, i.e. the delay of a numerator.
+    pub(crate) fn from_coeffs(coeff: &[T]) -> Self {
+        Self(crate::poly::Polynomial::from_coeffs(coeff))
+    }
+
     pub fn pow(self, exp: usize) -> Self {
         Self(self.0.pow(exp))
     }
@@ -42,6 +47,10 @@ where
         self.0.lead_coeff()
     }
 
+    pub fn real_coeffs(&self) -> Vec<f64> {
+        self.0.real_coeffs()
+    }
+
     pub fn inner(&self) -> &crate::poly::Polynomial<T> {
         &self.0
     }
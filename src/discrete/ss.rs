@@ -1,8 +1,17 @@
-use crate::{block::Block, prelude::SimulationState};
+use crate::discrete::tf::DTf;
+use crate::{
+    block::Block,
+    prelude::{HasState, SimulationState},
+};
+use alloc::vec;
 use core::fmt::Display;
-use faer::{Mat, mat, traits::ComplexField};
-use num_traits::Zero;
+use core::ops::AddAssign;
+use faer::{Accum, Mat, Par, linalg::matmul::matmul, mat, traits::ComplexField};
+use num_traits::{Float, Zero};
 
+/// Discrete state-space model `x[k+1] = A x[k] + B u[k]`,
+/// `y[k] = C x[k] + D u[k]`, advancing one sample per step.
+#[derive(Debug, Clone)]
 pub struct DSS<T>
 where
     T: Copy + Zero + ComplexField,
@@ -13,6 +22,7 @@ where
     d: Mat<T>,
     initial_state: Option<Mat<T>>,
     state: Mat<T>,
+    next_state: Mat<T>,
     last_output: Option<T>,
 }
 
@@ -36,6 +46,7 @@ where
             c,
             d: mat![[d]],
             state: Mat::zeros(n, 1),
+            next_state: Mat::zeros(n, 1),
             initial_state: None,
             last_output: None,
         }
@@ -59,6 +70,88 @@ where
         self.state = initial_state;
         self
     }
+
+    pub fn state(&self) -> &Mat<T> {
+        &self.state
+    }
+
+    pub fn order(&self) -> usize {
+        self.a.nrows()
+    }
+
+    pub fn a(&self) -> &Mat<T> {
+        &self.a
+    }
+
+    pub fn b(&self) -> &Mat<T> {
+        &self.b
+    }
+
+    pub fn c(&self) -> &Mat<T> {
+        &self.c
+    }
+
+    pub fn d(&self) -> T {
+        self.d[(0, 0)]
+    }
+}
+
+impl<T> DSS<T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+{
+    /// Transfer function `C (zI - A)^-1 B + D`, by the Faddeev-LeVerrier
+    /// recursion on the characteristic polynomial and the adjugate of
+    /// `zI - A`.
+    pub fn to_dtf(&self) -> DTf<T> {
+        let n = self.order();
+        let d = self.d();
+
+        // Adjugate `sum M_k z^(n-k)` and characteristic polynomial
+        // `sum c_k z^-k`, both read in powers of `z^-1` after dividing by
+        // `z^n`.
+        let mut m = Mat::<T>::zeros(n, n);
+        let mut den = vec![T::one()];
+        let mut num = vec![d];
+        for k in 1..=n {
+            let c_prev = den[k - 1];
+            let mut next = Mat::<T>::zeros(n, n);
+            matmul(
+                &mut next,
+                Accum::Replace,
+                &self.a,
+                &m,
+                T::one_impl(),
+                Par::Seq,
+            );
+            for i in 0..n {
+                next[(i, i)] += c_prev;
+            }
+            m = next;
+
+            let mut am = Mat::<T>::zeros(n, n);
+            matmul(
+                &mut am,
+                Accum::Replace,
+                &self.a,
+                &m,
+                T::one_impl(),
+                Par::Seq,
+            );
+            let trace = (0..n).fold(T::zero(), |acc, i| acc + am[(i, i)]);
+            let c_k = -trace / T::from(k).unwrap();
+            den.push(c_k);
+
+            let cmb = (0..n).fold(T::zero(), |acc, i| {
+                acc + (0..n).fold(T::zero(), |acc, j| {
+                    acc + self.c[(0, i)] * m[(i, j)] * self.b[(j, 0)]
+                })
+            });
+            num.push(cmb + d * c_k);
+        }
+
+        DTf::new(&num, &den)
+    }
 }
 
 impl<T> Block for DSS<T>
@@ -69,11 +162,26 @@ where
     type Output = T;
 
     fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        let input_matrix = mat![[input]];
-        self.state = &self.a * &self.state + &self.b * &input_matrix;
+        let output = self.c.row(0) * self.state.col(0) + self.d[(0, 0)] * input;
+
+        matmul(
+            &mut self.next_state,
+            Accum::Replace,
+            &self.a,
+            &self.state,
+            T::one_impl(),
+            Par::Seq,
+        );
+        matmul(
+            &mut self.next_state,
+            Accum::Add,
+            &self.b,
+            mat![[input]],
+            T::one_impl(),
+            Par::Seq,
+        );
+        core::mem::swap(&mut self.state, &mut self.next_state);
 
-        let output = &self.c * &self.state + &self.d * &input_matrix;
-        let output = output[(0, 0)];
         self.last_output = Some(output);
         output
     }
@@ -92,6 +200,19 @@ where
     }
 }
 
+impl<T> HasState<T> for DSS<T>
+where
+    T: Copy + Zero + ComplexField,
+{
+    fn order(&self) -> usize {
+        self.a.nrows()
+    }
+
+    fn state_at(&self, index: usize) -> T {
+        self.state[(index, 0)]
+    }
+}
+
 impl<T> Display for DSS<T>
 where
     T: Copy + Zero + Display + ComplexField,
@@ -104,3 +225,31 @@ where
         )
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dss_realizations_match_dtf() {
+        let mut dtf = DTf::<f64>::new(&[0.5, 0.2, 0.1], &[1.0, -0.6, 0.08]);
+        let mut controllable = dtf.to_dss_controllable();
+        let mut observable = dtf.to_dss_observable();
+
+        assert_eq!(controllable.order(), 2);
+        for (k, sim_state) in Simulation::new(0.1, 3.0).enumerate() {
+            let u = if k % 7 < 3 { 1.0 } else { -0.5 };
+            let expected = dtf.block(u, sim_state);
+            assert!((controllable.block(u, sim_state) - expected).abs() < 1e-12);
+            assert!((observable.block(u, sim_state) - expected).abs() < 1e-12);
+        }
+
+        let back = controllable.to_dtf();
+        for (got, want) in back.numerator().coeff().iter().zip([0.5, 0.2, 0.1]) {
+            assert!((got - want).abs() < 1e-12);
+        }
+        for (got, want) in back.denominator().coeff().iter().zip([1.0, -0.6, 0.08]) {
+            assert!((got - want).abs() < 1e-12);
+        }
+    }
+}
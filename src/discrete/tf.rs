@@ -1,9 +1,12 @@
 use crate::prelude::SimulationState;
-use crate::{block::Block, discrete::PolynomialInverse};
+use crate::{
+    block::Block,
+    discrete::{PolynomialInverse, ss::DSS},
+};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::ops::AddAssign;
-use faer::traits::ComplexField;
+use faer::{Mat, c64, traits::ComplexField};
 use num_traits::Float;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -30,7 +33,7 @@ where
         );
 
         Self {
-            numerator: PolynomialInverse::new(numerator),
+            numerator: PolynomialInverse::from_coeffs(numerator),
             denominator: PolynomialInverse::new(denominator),
             last_inputs: vec![T::zero(); numerator.len()],
             last_outputs: vec![T::zero(); denominator.len() - 1],
@@ -59,6 +62,105 @@ where
         self.last_outputs = initial_outputs;
         self
     }
+
+    pub fn numerator(&self) -> &PolynomialInverse<T> {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &PolynomialInverse<T> {
+        &self.denominator
+    }
+
+    /// Value of the transfer function at the complex point `z`.
+    pub fn eval(&self, z: c64) -> c64 {
+        let z_inv = c64::new(1.0, 0.0) / z;
+        let horner = |coeff: Vec<f64>| {
+            coeff
+                .iter()
+                .rev()
+                .fold(c64::new(0.0, 0.0), |acc, c| acc * z_inv + c64::new(*c, 0.0))
+        };
+
+        horner(self.numerator.real_coeffs()) / horner(self.denominator.real_coeffs())
+    }
+
+    /// Steady-state gain, `H(1)`. Infinite when the model has a pole at
+    /// `z = 1`.
+    pub fn dc_gain(&self) -> f64 {
+        let num = self.numerator.real_coeffs().iter().sum::<f64>();
+        let den = self.denominator.real_coeffs().iter().sum::<f64>();
+        num / den
+    }
+
+    /// `H(e^(j omega ts))` for each angular frequency in rad/s, with `ts` the
+    /// sample time in seconds.
+    pub fn freq_response(&self, omegas: &[f64], ts: f64) -> Vec<c64> {
+        omegas
+            .iter()
+            .map(|&omega| self.eval(c64::new(libm::cos(omega * ts), libm::sin(omega * ts))))
+            .collect()
+    }
+
+    /// Normalized `(b, a)` of `b0 + b1 z^-1 + ... / 1 + a1 z^-1 + ...`, both
+    /// with `n + 1` coefficients.
+    fn normalized(&self) -> (Vec<T>, Vec<T>) {
+        let a0 = self.denominator.lead_coeff();
+        let a = self
+            .denominator
+            .coeff()
+            .iter()
+            .map(|c| *c / a0)
+            .collect::<Vec<_>>();
+        let mut b = self
+            .numerator
+            .coeff()
+            .iter()
+            .map(|c| *c / a0)
+            .collect::<Vec<_>>();
+        b.resize(a.len(), T::zero());
+        (b, a)
+    }
+
+    /// State-space realization in controllable canonical form.
+    pub fn to_dss_controllable(&self) -> DSS<T> {
+        let (b, a) = self.normalized();
+        let n = a.len() - 1;
+
+        let a_mat = Mat::from_fn(n, n, |i, j| {
+            if i == 0 {
+                -a[j + 1]
+            } else if j + 1 == i {
+                T::one()
+            } else {
+                T::zero()
+            }
+        });
+        let b_mat = Mat::from_fn(n, 1, |i, _| if i == 0 { T::one() } else { T::zero() });
+        let c_mat = Mat::from_fn(1, n, |_, j| b[j + 1] - a[j + 1] * b[0]);
+
+        DSS::new(a_mat, b_mat, c_mat, b[0])
+    }
+
+    /// State-space realization in observable canonical form, the dual of
+    /// [`DTf::to_dss_controllable`].
+    pub fn to_dss_observable(&self) -> DSS<T> {
+        let (b, a) = self.normalized();
+        let n = a.len() - 1;
+
+        let a_mat = Mat::from_fn(n, n, |i, j| {
+            if j == 0 {
+                -a[i + 1]
+            } else if i + 1 == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        });
+        let b_mat = Mat::from_fn(n, 1, |i, _| b[i + 1] - a[i + 1] * b[0]);
+        let c_mat = Mat::from_fn(1, n, |_, j| if j == 0 { T::one() } else { T::zero() });
+
+        DSS::new(a_mat, b_mat, c_mat, b[0])
+    }
 }
 
 impl<T> Block for DTf<T>
@@ -108,3 +210,24 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dtf_dc_gain_matches_step_response() {
+        let mut dtf = DTf::<f64>::new(&[0.2, 0.1], &[1.0, -0.7]);
+        let mut y = 0.0;
+        for sim_state in Simulation::new(0.1, 20.0) {
+            y = dtf.block(1.0, sim_state);
+        }
+
+        assert!((dtf.dc_gain() - 1.0).abs() < 1e-12);
+        assert!((y - dtf.dc_gain()).abs() < 1e-9);
+
+        let nyquist = dtf.freq_response(&[core::f64::consts::PI / 0.1], 0.1)[0];
+        assert!((nyquist.re - 0.1 / 1.7).abs() < 1e-12);
+        assert!(nyquist.im.abs() < 1e-12);
+    }
+}
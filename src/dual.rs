@@ -0,0 +1,588 @@
+use core::num::FpCategory;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+
+/// Dual number `re + sum eps_i e_i` for forward-mode automatic
+/// differentiation with respect to `N` variables at once.
+///
+/// It implements [`Float`] and the scalar operations blocks require, so a
+/// loop built from `PID<Dual<N>>`, `SsFixed<_, _, Dual<N>>`, `Integrator`
+/// and the error metrics yields the exact gradient of its cost with respect
+/// to the seeded variables in a single run. `Tf` and `SS` stay on `f64`,
+/// as their matrices need a complex field; realize a transfer function on
+/// duals with [`SsFixed::try_from_tf`](crate::prelude::SsFixed::try_from_tf).
+/// The gradient is for your own optimizer, no tuner in the crate uses it:
+///
+/// ```ignore
+/// let [kp, ki] = Dual::variables([2.0, 1.0]);
+/// let mut pid = PID::new(kp, ki, Dual::constant(0.0));
+/// // ... run the loop, accumulating `cost` ...
+/// let (value, gradient) = (cost.re(), cost.eps());
+/// ```
+///
+/// Comparisons only look at the real part, so branches (saturation,
+/// anti-windup) follow the nominal trajectory.
+#[derive(Debug, Clone, Copy)]
+pub struct Dual<const N: usize> {
+    re: f64,
+    eps: [f64; N],
+}
+
+impl<const N: usize> Dual<N> {
+    pub fn new(re: f64, eps: [f64; N]) -> Self {
+        Self { re, eps }
+    }
+
+    /// Value with no dependence on the variables.
+    pub fn constant(re: f64) -> Self {
+        Self { re, eps: [0.0; N] }
+    }
+
+    /// The `index`-th variable, with unit derivative along itself.
+    pub fn variable(re: f64, index: usize) -> Self {
+        let mut eps = [0.0; N];
+        eps[index] = 1.0;
+        Self { re, eps }
+    }
+
+    /// Seeds every value of `values` as its own variable.
+    pub fn variables(values: [f64; N]) -> [Self; N] {
+        core::array::from_fn(|i| Self::variable(values[i], i))
+    }
+
+    pub fn re(&self) -> f64 {
+        self.re
+    }
+
+    /// Derivatives with respect to each variable.
+    pub fn eps(&self) -> [f64; N] {
+        self.eps
+    }
+
+    /// Applies `f` with derivative `df`, both evaluated at the real part.
+    fn chain(self, f: f64, df: f64) -> Self {
+        Self {
+            re: f,
+            eps: self.eps.map(|e| e * df),
+        }
+    }
+}
+
+impl<const N: usize> Default for Dual<N> {
+    fn default() -> Self {
+        Self::constant(0.0)
+    }
+}
+
+impl<const N: usize> From<f64> for Dual<N> {
+    fn from(re: f64) -> Self {
+        Self::constant(re)
+    }
+}
+
+impl<const N: usize> PartialEq for Dual<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re
+    }
+}
+
+impl<const N: usize> PartialOrd for Dual<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            re: self.re + rhs.re,
+            eps: core::array::from_fn(|i| self.eps[i] + rhs.eps[i]),
+        }
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            re: self.re - rhs.re,
+            eps: core::array::from_fn(|i| self.eps[i] - rhs.eps[i]),
+        }
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            re: self.re * rhs.re,
+            eps: core::array::from_fn(|i| self.eps[i] * rhs.re + self.re * rhs.eps[i]),
+        }
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let re = self.re / rhs.re;
+        Self {
+            re,
+            eps: core::array::from_fn(|i| (self.eps[i] - re * rhs.eps[i]) / rhs.re),
+        }
+    }
+}
+
+impl<const N: usize> Rem for Dual<N> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self - (self / rhs).trunc() * rhs
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            re: -self.re,
+            eps: self.eps.map(|e| -e),
+        }
+    }
+}
+
+impl<const N: usize> Mul<f64> for Dual<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            re: self.re * rhs,
+            eps: self.eps.map(|e| e * rhs),
+        }
+    }
+}
+
+impl<const N: usize> Div<f64> for Dual<N> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self {
+            re: self.re / rhs,
+            eps: self.eps.map(|e| e / rhs),
+        }
+    }
+}
+
+impl<const N: usize> AddAssign for Dual<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for Dual<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> MulAssign for Dual<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize> DivAssign for Dual<N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize> Zero for Dual<N> {
+    fn zero() -> Self {
+        Self::constant(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0.0
+    }
+}
+
+impl<const N: usize> One for Dual<N> {
+    fn one() -> Self {
+        Self::constant(1.0)
+    }
+}
+
+impl<const N: usize> Num for Dual<N> {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(str, radix).map(Self::constant)
+    }
+}
+
+impl<const N: usize> ToPrimitive for Dual<N> {
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.re)
+    }
+}
+
+impl<const N: usize> NumCast for Dual<N> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f64().map(Self::constant)
+    }
+}
+
+impl<const N: usize> Float for Dual<N> {
+    fn nan() -> Self {
+        Self::constant(f64::NAN)
+    }
+
+    fn infinity() -> Self {
+        Self::constant(f64::INFINITY)
+    }
+
+    fn neg_infinity() -> Self {
+        Self::constant(f64::NEG_INFINITY)
+    }
+
+    fn neg_zero() -> Self {
+        Self::constant(-0.0)
+    }
+
+    fn min_value() -> Self {
+        Self::constant(f64::MIN)
+    }
+
+    fn min_positive_value() -> Self {
+        Self::constant(f64::MIN_POSITIVE)
+    }
+
+    fn epsilon() -> Self {
+        Self::constant(f64::EPSILON)
+    }
+
+    fn max_value() -> Self {
+        Self::constant(f64::MAX)
+    }
+
+    fn is_nan(self) -> bool {
+        self.re.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.re.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.re.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.re.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.re.classify()
+    }
+
+    fn floor(self) -> Self {
+        self.chain(Float::floor(self.re), 0.0)
+    }
+
+    fn ceil(self) -> Self {
+        self.chain(Float::ceil(self.re), 0.0)
+    }
+
+    fn round(self) -> Self {
+        self.chain(Float::round(self.re), 0.0)
+    }
+
+    fn trunc(self) -> Self {
+        self.chain(Float::trunc(self.re), 0.0)
+    }
+
+    fn fract(self) -> Self {
+        self.chain(Float::fract(self.re), 1.0)
+    }
+
+    fn abs(self) -> Self {
+        self.chain(Float::abs(self.re), Float::signum(self.re))
+    }
+
+    fn signum(self) -> Self {
+        self.chain(Float::signum(self.re), 0.0)
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        self.chain(1.0 / self.re, -1.0 / (self.re * self.re))
+    }
+
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        self.chain(
+            Float::powi(self.re, n),
+            n as f64 * Float::powi(self.re, n - 1),
+        )
+    }
+
+    fn powf(self, n: Self) -> Self {
+        if n.eps.iter().all(|e| *e == 0.0) {
+            if n.re == 0.0 {
+                return Self::one();
+            }
+            return self.chain(
+                Float::powf(self.re, n.re),
+                n.re * Float::powf(self.re, n.re - 1.0),
+            );
+        }
+        (n * self.ln()).exp()
+    }
+
+    fn sqrt(self) -> Self {
+        let re = Float::sqrt(self.re);
+        self.chain(re, 0.5 / re)
+    }
+
+    fn exp(self) -> Self {
+        let re = Float::exp(self.re);
+        self.chain(re, re)
+    }
+
+    fn exp2(self) -> Self {
+        let re = Float::exp2(self.re);
+        self.chain(re, re * core::f64::consts::LN_2)
+    }
+
+    fn ln(self) -> Self {
+        self.chain(Float::ln(self.re), 1.0 / self.re)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.chain(
+            Float::log2(self.re),
+            1.0 / (self.re * core::f64::consts::LN_2),
+        )
+    }
+
+    fn log10(self) -> Self {
+        self.chain(
+            Float::log10(self.re),
+            1.0 / (self.re * core::f64::consts::LN_10),
+        )
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.re > self.re || self.re.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.re < self.re || self.re.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self.re > other.re {
+            self - other
+        } else {
+            Self::zero()
+        }
+    }
+
+    fn cbrt(self) -> Self {
+        let re = Float::cbrt(self.re);
+        self.chain(re, 1.0 / (3.0 * re * re))
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self * self + other * other).sqrt()
+    }
+
+    fn sin(self) -> Self {
+        self.chain(Float::sin(self.re), Float::cos(self.re))
+    }
+
+    fn cos(self) -> Self {
+        self.chain(Float::cos(self.re), -Float::sin(self.re))
+    }
+
+    fn tan(self) -> Self {
+        let re = Float::tan(self.re);
+        self.chain(re, 1.0 + re * re)
+    }
+
+    fn asin(self) -> Self {
+        self.chain(
+            Float::asin(self.re),
+            1.0 / Float::sqrt(1.0 - self.re * self.re),
+        )
+    }
+
+    fn acos(self) -> Self {
+        self.chain(
+            Float::acos(self.re),
+            -1.0 / Float::sqrt(1.0 - self.re * self.re),
+        )
+    }
+
+    fn atan(self) -> Self {
+        self.chain(Float::atan(self.re), 1.0 / (1.0 + self.re * self.re))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let denominator = self.re * self.re + other.re * other.re;
+        Self {
+            re: Float::atan2(self.re, other.re),
+            eps: core::array::from_fn(|i| {
+                (other.re * self.eps[i] - self.re * other.eps[i]) / denominator
+            }),
+        }
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        self.chain(Float::exp_m1(self.re), Float::exp(self.re))
+    }
+
+    fn ln_1p(self) -> Self {
+        self.chain(Float::ln_1p(self.re), 1.0 / (1.0 + self.re))
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(Float::sinh(self.re), Float::cosh(self.re))
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(Float::cosh(self.re), Float::sinh(self.re))
+    }
+
+    fn tanh(self) -> Self {
+        let re = Float::tanh(self.re);
+        self.chain(re, 1.0 - re * re)
+    }
+
+    fn asinh(self) -> Self {
+        self.chain(
+            Float::asinh(self.re),
+            1.0 / Float::sqrt(self.re * self.re + 1.0),
+        )
+    }
+
+    fn acosh(self) -> Self {
+        self.chain(
+            Float::acosh(self.re),
+            1.0 / Float::sqrt(self.re * self.re - 1.0),
+        )
+    }
+
+    fn atanh(self) -> Self {
+        self.chain(Float::atanh(self.re), 1.0 / (1.0 - self.re * self.re))
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.re)
+    }
+
+    fn to_degrees(self) -> Self {
+        self * (180.0 / core::f64::consts::PI)
+    }
+
+    fn to_radians(self) -> Self {
+        self * (core::f64::consts::PI / 180.0)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use num_traits::Float;
+
+    #[test]
+    fn test_dual_derivatives() {
+        let [x, y] = Dual::variables([0.5, 2.0]);
+        let f = x.sin() * y.powi(3) + (x / y).exp();
+
+        let expected = 0.5.sin() * 8.0 + 0.25.exp();
+        assert!((f.re() - expected).abs() < 1e-12);
+        assert!((f.eps()[0] - (0.5.cos() * 8.0 + 0.5 * 0.25.exp())).abs() < 1e-12);
+        assert!((f.eps()[1] - (0.5.sin() * 12.0 - 0.125 * 0.25.exp())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closed_loop_gradient_matches_finite_differences() {
+        fn cost<T>(kp: T, ki: T) -> T
+        where
+            T: Float
+                + Default
+                + 'static
+                + core::ops::AddAssign
+                + core::ops::Mul<f64, Output = T>
+                + core::ops::Div<f64, Output = T>,
+        {
+            let mut pid = PID::new(kp, ki, T::zero());
+            let mut plant =
+                SsFixed::<1, RK4, T>::new([[-T::one()]], [T::one()], [T::one()], T::zero());
+            let mut ise = ISE::default().with_accumulation(Accumulation::Integral);
+            for sim_state in Simulation::new(0.01, 5.0) {
+                let output = plant.last_output().unwrap_or(T::zero());
+                let error = T::one().as_signal(sim_state) - output.as_signal(sim_state);
+                let _ = error >> ise.as_block() >> pid.as_block() >> plant.as_block();
+            }
+            ise.value()
+        }
+
+        let [kp, ki] = Dual::variables([2.0, 1.0]);
+        let exact = cost(kp, ki);
+        assert!((exact.re() - cost(2.0, 1.0)).abs() < 1e-12);
+
+        let h = 1e-6;
+        let d_kp = (cost(2.0 + h, 1.0) - cost(2.0 - h, 1.0)) / (2.0 * h);
+        let d_ki = (cost(2.0, 1.0 + h) - cost(2.0, 1.0 - h)) / (2.0 * h);
+        assert!((exact.eps()[0] - d_kp).abs() < 1e-6);
+        assert!((exact.eps()[1] - d_ki).abs() < 1e-6);
+        assert!(exact.eps()[0] < 0.0);
+    }
+}
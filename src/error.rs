@@ -0,0 +1,124 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Errors of the fallible `try_*` constructors. Their infallible `new`
+/// counterparts panic on them under the `strict-panic` feature.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    EmptyNumerator,
+    EmptyDenominator,
+    /// Ensemble without members.
+    EmptyEnsemble,
+    /// Transfer function with more zeros than poles.
+    ImproperTf {
+        numerator_degree: usize,
+        denominator_degree: usize,
+    },
+    /// Matrix whose shape does not match the others, as `(rows, columns)`.
+    Dimension {
+        matrix: &'static str,
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// Bridge name already used on the same connection.
+    BridgeTaken,
+    /// Failure to reach the target of a bridge, e.g. no debug probe.
+    #[cfg(feature = "std")]
+    Connection(String),
+    /// Failure to create or write a file sink.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+#[cfg(feature = "alloc")]
+impl Error {
+    /// `Err` unless `found` is `expected`.
+    pub(crate) fn check_dimension(
+        matrix: &'static str,
+        expected: (usize, usize),
+        found: (usize, usize),
+    ) -> Result<(), Self> {
+        if expected == found {
+            Ok(())
+        } else {
+            Err(Error::Dimension {
+                matrix,
+                expected,
+                found,
+            })
+        }
+    }
+}
+
+/// Result of a constructor that cannot fail. With `strict-panic` an error
+/// panics with its message; without it the error is logged with the
+/// caller's location and `fallback` builds a value that keeps the loop
+/// running, e.g. a model that only outputs NaN for a
+/// [`NanGuard`](crate::prelude::NanGuard) to catch.
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub(crate) fn or_fallback<T>(result: Result<T, Error>, fallback: impl FnOnce(Error) -> T) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) if cfg!(feature = "strict-panic") => panic!("{}", error),
+        Err(error) => {
+            log_fallback(&error, core::panic::Location::caller());
+            fallback(error)
+        }
+    }
+}
+
+/// Reports a fallback as a `tracing` error with `trace`, or on stderr with
+/// `std`. Without either there is nowhere to log to.
+#[cfg(feature = "alloc")]
+fn log_fallback(error: &Error, location: &core::panic::Location<'_>) {
+    #[cfg(feature = "trace")]
+    tracing::error!(%location, %error, "invalid constructor input, using a fallback");
+    #[cfg(all(feature = "std", not(feature = "trace")))]
+    std::eprintln!("{}: {}, using a fallback", location, error);
+    #[cfg(not(any(feature = "std", feature = "trace")))]
+    let _ = (error, location);
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyNumerator => write!(f, "Numerator cannot be empty."),
+            Error::EmptyDenominator => write!(f, "Denominator cannot be empty."),
+            Error::EmptyEnsemble => write!(f, "Ensemble must have at least one member."),
+            Error::ImproperTf {
+                numerator_degree,
+                denominator_degree,
+            } => write!(
+                f,
+                "Denominator must have degree greater than or equal to numerator. \
+                 Numerator has degree {}, denominator {}.",
+                numerator_degree, denominator_degree
+            ),
+            Error::Dimension {
+                matrix,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} must be a {}x{} matrix, found {}x{}",
+                matrix, expected.0, expected.1, found.0, found.1
+            ),
+            Error::BridgeTaken => write!(f, "A bridge with this name is already taken"),
+            #[cfg(feature = "std")]
+            Error::Connection(reason) => write!(f, "Failed to connect: {}", reason),
+            #[cfg(feature = "std")]
+            Error::Io(kind) => write!(f, "I/O error: {}", kind),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error.kind())
+    }
+}
@@ -0,0 +1,166 @@
+use crate::simulation::SimulationState;
+use core::time::Duration;
+
+/// Timing statistics of an [`EmbeddedExecutor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutorStats {
+    pub steps: u64,
+    /// Steps that took longer than the period, or started a full period or
+    /// more after their deadline.
+    pub overruns: u64,
+    pub max_execution: Duration,
+    /// Largest delay between a deadline and the start of its step.
+    pub max_lateness: Duration,
+}
+
+/// Steps a block diagram at a fixed rate on a target, timing each step
+/// against a monotonic `clock`, e.g. a hardware timer counter or
+/// `embassy_time::Instant::now().as_micros()` converted to a [`Duration`].
+///
+/// Call [`EmbeddedExecutor::step`] from a timer interrupt or after each
+/// `Ticker::next().await`, or [`EmbeddedExecutor::poll`] from a busy loop,
+/// e.g. through [`embedded_loop!`](crate::embedded_loop). The diagram always
+/// sees a fixed `dt` of one period; late steps are counted rather than
+/// stretched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedExecutor<C>
+where
+    C: FnMut() -> Duration,
+{
+    clock: C,
+    period: Duration,
+    sim_time: Duration,
+    deadline: Option<Duration>,
+    stats: ExecutorStats,
+}
+
+impl<C> EmbeddedExecutor<C>
+where
+    C: FnMut() -> Duration,
+{
+    pub fn new(period: Duration, clock: C) -> Self {
+        assert!(!period.is_zero(), "Period must be greater than zero");
+
+        Self {
+            clock,
+            period,
+            sim_time: Duration::default(),
+            deadline: None,
+            stats: ExecutorStats::default(),
+        }
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    pub fn stats(&self) -> ExecutorStats {
+        self.stats
+    }
+
+    /// Runs one step now. Meant for code already paced by a timer.
+    pub fn step<R>(&mut self, body: impl FnOnce(SimulationState) -> R) -> R {
+        let start = (self.clock)();
+        self.run(start, body)
+    }
+
+    /// Runs one step if its deadline has passed, without blocking. A step
+    /// missed by one or more full periods is counted as an overrun and the
+    /// schedule restarts from now instead of bursting to catch up.
+    pub fn poll<R>(&mut self, body: impl FnOnce(SimulationState) -> R) -> Option<R> {
+        let now = (self.clock)();
+        let deadline = *self.deadline.get_or_insert(now);
+        if now < deadline {
+            return None;
+        }
+
+        let lateness = now - deadline;
+        self.stats.max_lateness = self.stats.max_lateness.max(lateness);
+        self.deadline = if lateness >= self.period {
+            self.stats.overruns += 1;
+            Some(now + self.period)
+        } else {
+            Some(deadline + self.period)
+        };
+
+        Some(self.run(now, body))
+    }
+
+    pub fn reset(&mut self) {
+        self.sim_time = Duration::default();
+        self.deadline = None;
+        self.stats = ExecutorStats::default();
+    }
+
+    fn run<R>(&mut self, start: Duration, body: impl FnOnce(SimulationState) -> R) -> R {
+        self.sim_time += self.period;
+        let output = body(SimulationState::new(self.period, self.sim_time));
+
+        let execution = (self.clock)().saturating_sub(start);
+        self.stats.max_execution = self.stats.max_execution.max(execution);
+        if execution > self.period {
+            self.stats.overruns += 1;
+        }
+        self.stats.steps += 1;
+
+        output
+    }
+}
+
+/// Steps `executor` forever from a busy loop, binding the current
+/// [`SimulationState`] to the given name inside `body`.
+///
+/// ```ignore
+/// embedded_loop!(executor, |sim_state| {
+///     let error = (sim_state >> reference.as_block()) - adc.output(sim_state);
+///     let _ = error >> pid.as_block() >> pwm.as_block();
+/// });
+/// ```
+#[macro_export]
+macro_rules! embedded_loop {
+    ($executor:expr, |$sim_state:ident| $body:block) => {
+        loop {
+            let _ = $executor.poll(|$sim_state| $body);
+        }
+    };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::{cell::Cell, time::Duration};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_executor_paces_and_counts_overruns() {
+        let now = Rc::new(Cell::new(Duration::ZERO));
+        let clock = now.clone();
+        let mut executor = EmbeddedExecutor::new(Duration::from_millis(1), move || clock.get());
+        let mut integrator = Integrator::<f64>::new();
+
+        // Busy loop sampled every 100 us; step 5 takes 1.5 ms.
+        let mut ran = 0;
+        while ran < 10 {
+            let stepped = executor.poll(|sim_state| {
+                if sim_state.sim_time() == Duration::from_millis(5) {
+                    now.set(now.get() + Duration::from_micros(1500));
+                }
+                let _ = 1.0.as_signal(sim_state) >> integrator.as_block();
+            });
+            if stepped.is_some() {
+                ran += 1;
+            }
+            now.set(now.get() + Duration::from_micros(100));
+        }
+
+        let stats = executor.stats();
+        assert_eq!(stats.steps, 10);
+        assert_eq!(stats.overruns, 1);
+        assert_eq!(stats.max_lateness, Duration::from_micros(600));
+        assert_eq!(stats.max_execution, Duration::from_micros(1500));
+        assert!((integrator.last_output().unwrap() - 0.01).abs() < 1e-12);
+
+        executor.step(|sim_state| assert_eq!(sim_state.sim_time(), Duration::from_millis(11)));
+        assert_eq!(executor.stats().steps, 11);
+    }
+}
@@ -32,7 +32,7 @@ impl TryFrom<FirstOrderModel> for (Tf<f64>, Delay<f64>) {
             return Err(FirstOrderModelError::NegativeTheta(value.theta));
         }
 
-        let tf = Tf::new(&[value.k], &[value.tau, 1.0]);
+        let tf = Tf::new_unchecked(&[value.k], &[value.tau, 1.0]);
         let delay = Delay::<f64>::new(Duration::from_secs_f64(value.theta));
 
         Ok((tf, delay))
@@ -33,7 +33,7 @@ impl TryFrom<SecondOrderModel> for (Tf<f64>, Delay<f64>) {
         }
 
         let omega_n2 = value.omega_n.powi(2);
-        let tf = Tf::new(
+        let tf = Tf::new_unchecked(
             &[value.k * omega_n2],
             &[1.0, 2.0 * value.zeta * value.omega_n, omega_n2],
         );
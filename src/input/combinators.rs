@@ -0,0 +1,146 @@
+use crate::{block::Block, prelude::SimulationState};
+use core::{
+    ops::{Add, Mul},
+    time::Duration,
+};
+use num_traits::Zero;
+
+/// Composition helpers for sources, i.e. blocks that take no input.
+pub trait InputExt: Block<Input = ()> + Sized {
+    /// Sum of both sources.
+    fn plus<B>(self, other: B) -> Plus<Self, B>
+    where
+        B: Block<Input = ()>,
+        Self::Output: Add<B::Output>,
+    {
+        Plus { a: self, b: other }
+    }
+
+    /// Product of both sources, e.g. a sinusoid with a ramping amplitude.
+    fn scaled_by<B>(self, other: B) -> ScaledBy<Self, B>
+    where
+        B: Block<Input = ()>,
+        Self::Output: Mul<B::Output>,
+    {
+        ScaledBy { a: self, b: other }
+    }
+
+    /// Outputs this source only for `t_on <= t < t_off`, zero otherwise.
+    fn gated(self, t_on: Duration, t_off: Duration) -> Gated<Self>
+    where
+        Self::Output: Zero,
+    {
+        assert!(t_on <= t_off, "Gate must open before it closes");
+
+        Gated {
+            input: self,
+            t_on,
+            t_off,
+        }
+    }
+}
+
+impl<B> InputExt for B where B: Block<Input = ()> {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plus<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Block for Plus<A, B>
+where
+    A: Block<Input = ()>,
+    B: Block<Input = ()>,
+    A::Output: Add<B::Output>,
+{
+    type Input = ();
+    type Output = <A::Output as Add<B::Output>>::Output;
+
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.a.block((), sim_state) + self.b.block((), sim_state)
+    }
+
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledBy<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Block for ScaledBy<A, B>
+where
+    A: Block<Input = ()>,
+    B: Block<Input = ()>,
+    A::Output: Mul<B::Output>,
+{
+    type Input = ();
+    type Output = <A::Output as Mul<B::Output>>::Output;
+
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.a.block((), sim_state) * self.b.block((), sim_state)
+    }
+
+    fn reset(&mut self) {
+        self.a.reset();
+        self.b.reset();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gated<A> {
+    input: A,
+    t_on: Duration,
+    t_off: Duration,
+}
+
+impl<A> Block for Gated<A>
+where
+    A: Block<Input = ()>,
+    A::Output: Zero,
+{
+    type Input = ();
+    type Output = A::Output;
+
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let t = sim_state.sim_time();
+        if t < self.t_on || t >= self.t_off {
+            return A::Output::zero();
+        }
+
+        self.input.block((), sim_state)
+    }
+
+    fn reset(&mut self) {
+        self.input.reset();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_input_combinators() {
+        let mut input = Step::new(1.0)
+            .plus(Ramp::new(1.0))
+            .scaled_by(Step::new(2.0))
+            .gated(Duration::from_secs(1), Duration::from_secs(3));
+
+        for sim_state in Simulation::new(0.5, 4.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let expected = if (1.0..3.0).contains(&t) {
+                2.0 * (1.0 + t)
+            } else {
+                0.0
+            };
+            assert_eq!(input.block((), sim_state), expected);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{block::Block, prelude::SimulationState};
+use core::marker::PhantomData;
+use num_traits::Zero;
+
+/// Source that always emits zero, used to tie unused inputs explicitly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ground<T>
+where
+    T: Zero + Copy,
+{
+    _marker: PhantomData<T>,
+}
+
+impl<T> Ground<T>
+where
+    T: Zero + Copy,
+{
+    pub fn new() -> Self {
+        Ground {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for Ground<T>
+where
+    T: Zero + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Block for Ground<T>
+where
+    T: Zero + Copy,
+{
+    type Input = ();
+    type Output = T;
+
+    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        T::zero()
+    }
+}
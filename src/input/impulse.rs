@@ -1,47 +1,127 @@
 use crate::{block::Block, prelude::SimulationState};
+use core::{ops::Div, time::Duration};
 use num_traits::{Bounded, Zero};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Impulse<T>
 where
-    T: Zero + Bounded,
+    T: Zero + Copy,
 {
-    value: Option<T>,
+    value: T,
+    /// Spreads an area over a step of the given length.
+    spread: Option<fn(T, f64) -> T>,
+    trigger: Duration,
+    fired: bool,
 }
 
 impl<T> Impulse<T>
 where
-    T: Zero + Bounded,
+    T: Zero + Copy,
 {
+    /// Impulse that outputs `value` for a single step.
     pub fn new(value: T) -> Self {
-        Impulse { value: Some(value) }
+        Impulse {
+            value,
+            spread: None,
+            trigger: Duration::ZERO,
+            fired: false,
+        }
+    }
+
+    /// Impulse of the given area, output as `area / dt` for a single step so
+    /// its integral doesn't depend on the step size.
+    pub fn with_area(area: T) -> Self
+    where
+        T: Div<f64, Output = T>,
+    {
+        Impulse {
+            spread: Some(|area, dt| area / dt),
+            ..Impulse::new(area)
+        }
+    }
+
+    /// Fires at the first step at or after `trigger` instead of the first one.
+    pub fn at(mut self, trigger: Duration) -> Self {
+        self.trigger = trigger;
+        self
+    }
+}
+
+/// Function pointers have no meaningful equality, so only whether both
+/// impulses spread an area is compared.
+impl<T> PartialEq for Impulse<T>
+where
+    T: Zero + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && self.spread.is_some() == other.spread.is_some()
+            && self.trigger == other.trigger
+            && self.fired == other.fired
     }
 }
 
 impl<T> Default for Impulse<T>
 where
-    T: Zero + Bounded,
+    T: Zero + Copy + Bounded,
 {
     fn default() -> Self {
-        Self {
-            value: Some(T::max_value()),
-        }
+        Impulse::new(T::max_value())
     }
 }
 
 impl<T> Block for Impulse<T>
 where
-    T: Zero + Bounded,
+    T: Zero + Copy,
 {
     type Input = ();
     type Output = T;
 
-    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        let Some(value) = self.value.take() else {
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if self.fired || sim_state.sim_time() < self.trigger {
             return T::zero();
+        }
+
+        let Some(spread) = self.spread else {
+            self.fired = true;
+            return self.value;
         };
 
-        self.value = None;
-        value
+        // An area can't be spread over a zero-length step, wait for the next.
+        if sim_state.dt().is_zero() {
+            return T::zero();
+        }
+
+        self.fired = true;
+        spread(self.value, sim_state.dt().as_secs_f64())
+    }
+
+    fn reset(&mut self) {
+        self.fired = false;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_impulse_area_at_trigger_time() {
+        let mut impulse = Impulse::with_area(3.0).at(Duration::from_secs(1));
+        let mut integrator = Integrator::<f64>::new();
+        let mut fired_at = None;
+
+        for sim_state in Simulation::new(0.01, 2.0) {
+            let value = impulse.block((), sim_state);
+            if value != 0.0 {
+                fired_at = Some(sim_state.sim_time());
+            }
+            let _ = value.as_signal(sim_state) >> integrator.as_block();
+        }
+
+        let fired_at = fired_at.unwrap().as_secs_f64();
+        assert!((fired_at - 1.0).abs() < 0.011);
+        assert!((integrator.last_output().unwrap() - 3.0).abs() < 1e-9);
     }
 }
@@ -1,7 +1,11 @@
 #[cfg(feature = "std")]
 pub mod file_samples;
 
+pub mod combinators;
+pub mod ground;
 pub mod impulse;
+#[cfg(feature = "alloc")]
+pub mod multisine;
 pub mod ramp;
 pub mod sawtooth;
 pub mod sinusoid;
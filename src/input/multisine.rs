@@ -0,0 +1,109 @@
+use crate::{block::Block, prelude::SimulationState};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use num_traits::Float;
+
+/// Sum of sinusoids `amp * sin(2*pi*freq*t + phase)`, with frequencies in Hz,
+/// to excite several frequencies in a single identification experiment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiSine<T>
+where
+    T: Float,
+{
+    components: Vec<(T, T, T)>,
+}
+
+impl<T> MultiSine<T>
+where
+    T: Float,
+{
+    pub fn new(components: &[(T, T, T)]) -> Self {
+        assert!(
+            !components.is_empty(),
+            "MultiSine needs at least one component"
+        );
+
+        Self {
+            components: components.to_vec(),
+        }
+    }
+
+    /// Replaces the phases with Schroeder's, which keep the peak of the sum
+    /// low for a given power and so let each tone be excited harder.
+    pub fn with_schroeder_phases(mut self) -> Self {
+        let power = self
+            .components
+            .iter()
+            .map(|(_, amp, _)| amp.to_f64().unwrap().powi(2))
+            .sum::<f64>();
+        let shares = self
+            .components
+            .iter()
+            .map(|(_, amp, _)| amp.to_f64().unwrap().powi(2) / power)
+            .collect::<Vec<_>>();
+
+        for k in 0..self.components.len() {
+            let phase = -2.0 * PI * (0..k).map(|l| (k - l) as f64 * shares[l]).sum::<f64>();
+            self.components[k].2 = T::from(phase % (2.0 * PI)).unwrap();
+        }
+        self
+    }
+
+    pub fn components(&self) -> &[(T, T, T)] {
+        &self.components
+    }
+
+    pub fn value_at(&self, t: f64) -> T {
+        let value = self
+            .components
+            .iter()
+            .map(|(freq, amp, phase)| {
+                let freq = freq.to_f64().unwrap();
+                let phase = phase.to_f64().unwrap();
+                amp.to_f64().unwrap() * libm::sin(2.0 * PI * freq * t + phase)
+            })
+            .sum::<f64>();
+        T::from(value).unwrap()
+    }
+
+    /// Peak over RMS of the signal sampled every `dt` over `duration` seconds.
+    pub fn crest_factor(&self, duration: f64, dt: f64) -> f64 {
+        let samples = (duration / dt) as usize;
+        let (peak, sum_sq) = (0..samples)
+            .map(|k| self.value_at(k as f64 * dt).to_f64().unwrap())
+            .fold((0.0f64, 0.0), |(peak, sum_sq), x| {
+                (peak.max(x.abs()), sum_sq + x * x)
+            });
+
+        peak / libm::sqrt(sum_sq / samples as f64)
+    }
+}
+
+impl<T> Block for MultiSine<T>
+where
+    T: Float,
+{
+    type Input = ();
+    type Output = T;
+
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.value_at(sim_state.sim_time().as_secs_f64())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_schroeder_phases_lower_crest_factor() {
+        let components = (1..=10).map(|k| (k as f64, 1.0, 0.0)).collect::<Vec<_>>();
+        let aligned = MultiSine::new(&components);
+        let schroeder = MultiSine::new(&components).with_schroeder_phases();
+
+        assert!((aligned.value_at(0.0) - 0.0).abs() < 1e-12);
+        assert!(aligned.crest_factor(1.0, 1e-3) > 3.0);
+        assert!(schroeder.crest_factor(1.0, 1e-3) < 2.0);
+    }
+}
@@ -1,50 +1,62 @@
 use crate::{block::Block, prelude::SimulationState};
-use core::{
-    f32::consts::PI,
-    ops::{Div, Mul},
-    time::Duration,
-};
-use num_traits::{One, Zero};
+use core::{f64::consts::PI, time::Duration};
+use num_traits::Float;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Sawtooth<T>
 where
-    T: Zero + One + Copy + Div<f64, Output = T> + Mul<f64, Output = T>,
+    T: Float,
 {
     amplitude: T,
     period: Duration,
     offset: T,
+    symmetry: f64,
 }
 
 impl<T> Sawtooth<T>
 where
-    T: Zero + One + Copy + Div<f64, Output = T> + Mul<f64, Output = T>,
+    T: Float,
 {
     pub fn new(amplitude: T, period: Duration, offset: T) -> Self {
         Sawtooth {
             amplitude,
             period,
             offset,
+            symmetry: 1.0,
         }
     }
+
+    /// Fraction of the period spent rising, in [0, 1]. `1.0` is the classic
+    /// sawtooth, `0.5` a triangle and `0.0` a falling ramp.
+    pub fn with_symmetry(mut self, symmetry: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&symmetry),
+            "Symmetry must be in [0, 1], got {}",
+            symmetry
+        );
+
+        self.symmetry = symmetry;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: T) -> Self {
+        self.offset = offset;
+        self
+    }
 }
 
 impl<T> Default for Sawtooth<T>
 where
-    T: Zero + One + Copy + Div<f64, Output = T> + Mul<f64, Output = T>,
+    T: Float,
 {
     fn default() -> Self {
-        Self {
-            amplitude: T::one(),
-            period: Duration::from_secs_f32(2.0 * PI),
-            offset: T::zero(),
-        }
+        Sawtooth::new(T::one(), Duration::from_secs_f64(2.0 * PI), T::zero())
     }
 }
 
 impl<T> Block for Sawtooth<T>
 where
-    T: Zero + One + Copy + Div<f64, Output = T> + Mul<f64, Output = T>,
+    T: Float,
 {
     type Input = ();
     type Output = T;
@@ -52,7 +64,35 @@ where
     fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
         let t = sim_state.sim_time().as_secs_f64();
         let period_secs = self.period.as_secs_f64();
+        let phase = (t % period_secs) / period_secs;
+
+        let ramp = if phase < self.symmetry {
+            phase / self.symmetry
+        } else {
+            (1.0 - phase) / (1.0 - self.symmetry)
+        };
+
+        self.amplitude * T::from(ramp).unwrap() + self.offset
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_sawtooth_symmetry_shapes_a_triangle() {
+        let period = Duration::from_secs(1);
+        let mut triangle = Sawtooth::new(2.0f64, period, 0.0).with_symmetry(0.5);
+
+        let at = |t: f64| {
+            let mut simulation = Simulation::new(t as f32, 10.0);
+            simulation.next().unwrap()
+        };
 
-        (self.amplitude / period_secs) * (t % period_secs) + self.offset
+        assert_signal_eq!(triangle.block((), at(0.25)), 1.0, 0.0, 1e-6);
+        assert_signal_eq!(triangle.block((), at(0.5)), 2.0, 0.0, 1e-6);
+        assert_signal_eq!(triangle.block((), at(0.75)), 1.0, 0.0, 1e-6);
     }
 }
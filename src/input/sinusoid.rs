@@ -1,54 +1,86 @@
 use crate::{block::Block, prelude::SimulationState};
-use core::{f32::consts::PI, ops::Add, time::Duration};
+use core::{
+    f64::consts::{PI, TAU},
+    time::Duration,
+};
 use num_traits::Float;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Sinusoid<T>
 where
-    T: Float + Add<f64, Output = T>,
+    T: Float,
 {
     amplitude: T,
     period: Duration,
     phase: T,
+    offset: T,
 }
 
 impl<T> Sinusoid<T>
 where
-    T: Float + Add<f64, Output = T>,
+    T: Float,
 {
+    /// `amplitude * sin(2*pi*t/period + phase)`, with the phase in radians.
     pub fn new(amplitude: T, period: Duration, phase: T) -> Self {
         Sinusoid {
             amplitude,
             period,
             phase,
+            offset: T::zero(),
         }
     }
+
+    pub fn with_phase(mut self, phase: T) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: T) -> Self {
+        self.offset = offset;
+        self
+    }
 }
 
 impl<T> Default for Sinusoid<T>
 where
-    T: Float + Add<f64, Output = T>,
+    T: Float,
 {
     fn default() -> Self {
-        Self {
-            amplitude: T::one(),
-            period: Duration::from_secs_f32(2.0 * PI),
-            phase: T::zero(),
-        }
+        Sinusoid::new(T::one(), Duration::from_secs_f64(2.0 * PI), T::zero())
     }
 }
 
 impl<T> Block for Sinusoid<T>
 where
-    T: Float + Add<f64, Output = T>,
+    T: Float,
 {
     type Input = ();
     type Output = T;
 
     fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
         let t = sim_state.sim_time().as_secs_f64();
-        let value = self.amplitude * (self.phase + t / self.period.as_secs_f64());
-        let value = libm::sin(value.to_f64().unwrap_or(0.0));
-        T::from(value).unwrap_or(T::zero())
+        let angle = TAU * t / self.period.as_secs_f64() + self.phase.to_f64().unwrap_or(0.0);
+        let value = T::from(libm::sin(angle)).unwrap_or(T::zero());
+        self.amplitude * value + self.offset
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::TAU;
+    use core::time::Duration;
+
+    #[test]
+    fn test_sinusoid_amplitude_phase_and_offset() {
+        let (amplitude, period, phase, offset) = (2.0, 0.5, 0.3, 3.0);
+        let mut sine =
+            Sinusoid::new(amplitude, Duration::from_secs_f64(period), phase).with_offset(offset);
+
+        for sim_state in Simulation::new(0.01, 1.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let expected = amplitude * libm::sin(TAU * t / period + phase) + offset;
+            assert_signal_eq!(sine.block((), sim_state), expected, 0.0, 1e-9);
+        }
     }
 }
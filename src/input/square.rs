@@ -1,55 +1,70 @@
 use crate::{block::Block, prelude::SimulationState};
-use core::{f32::consts::PI, time::Duration};
-use num_traits::{One, Zero};
+use core::{f64::consts::PI, time::Duration};
+use num_traits::Float;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Square<T>
 where
-    T: Zero + One + Copy,
+    T: Float,
 {
     amplitude: T,
     period: Duration,
     offset: T,
+    duty: f64,
 }
 
 impl<T> Square<T>
 where
-    T: Zero + One + Copy,
+    T: Float,
 {
     pub fn new(amplitude: T, period: Duration, offset: T) -> Self {
         Square {
             amplitude,
             period,
             offset,
+            duty: 0.5,
         }
     }
+
+    /// Fraction of the period spent high, in [0, 1].
+    pub fn with_duty(mut self, duty: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&duty),
+            "Duty cycle must be in [0, 1], got {}",
+            duty
+        );
+
+        self.duty = duty;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: T) -> Self {
+        self.offset = offset;
+        self
+    }
 }
 
 impl<T> Default for Square<T>
 where
-    T: Zero + One + Copy,
+    T: Float,
 {
     fn default() -> Self {
-        Self {
-            amplitude: T::one(),
-            period: Duration::from_secs_f32(2.0 * PI),
-            offset: T::zero(),
-        }
+        Square::new(T::one(), Duration::from_secs_f64(2.0 * PI), T::zero())
     }
 }
 
 impl<T> Block for Square<T>
 where
-    T: Zero + One + Copy,
+    T: Float,
 {
     type Input = ();
     type Output = T;
 
     fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
-        let t = sim_state.sim_time().as_secs_f32();
-        let period_secs = self.period.as_secs_f32();
+        let t = sim_state.sim_time().as_secs_f64();
+        let period_secs = self.period.as_secs_f64();
 
-        let amplitude = if (t % period_secs) < (period_secs / 2.0) {
+        let amplitude = if (t % period_secs) < period_secs * self.duty {
             self.amplitude
         } else {
             T::zero()
@@ -58,3 +73,22 @@ where
         amplitude + self.offset
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_square_duty() {
+        let mut square = Square::new(1.0f32, Duration::from_secs(1), 0.0).with_duty(0.25);
+
+        let at = |t: f64| {
+            let mut simulation = Simulation::new(t as f32, 10.0);
+            simulation.next().unwrap()
+        };
+
+        assert_signal_eq!(square.block((), at(0.2)), 1.0, 0.0, 1e-6);
+        assert_signal_eq!(square.block((), at(0.3)), 0.0, 0.0, 1e-6);
+    }
+}
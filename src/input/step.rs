@@ -1,36 +1,54 @@
 use crate::{block::Block, prelude::SimulationState};
-use core::fmt::Display;
-use num_traits::One;
+use core::{fmt::Display, ops::Add, time::Duration};
+use num_traits::{One, Zero};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Step<T>
 where
-    T: One + Copy,
+    T: One + Zero + Copy + Add<Output = T>,
 {
     value: T,
+    offset: T,
+    start: Duration,
 }
 
 impl<T> Step<T>
 where
-    T: One + Copy,
+    T: One + Zero + Copy + Add<Output = T>,
 {
     pub fn new(value: T) -> Self {
-        Step { value }
+        Step {
+            value,
+            offset: T::zero(),
+            start: Duration::ZERO,
+        }
+    }
+
+    /// Delays the step edge to `start`.
+    pub fn at(mut self, start: Duration) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Output before the edge; the step adds its amplitude on top of it.
+    pub fn with_offset(mut self, offset: T) -> Self {
+        self.offset = offset;
+        self
     }
 }
 
 impl<T> Default for Step<T>
 where
-    T: One + Copy,
+    T: One + Zero + Copy + Add<Output = T>,
 {
     fn default() -> Self {
-        Step { value: T::one() }
+        Step::new(T::one())
     }
 }
 
 impl<T> Display for Step<T>
 where
-    T: One + Copy + Display,
+    T: One + Zero + Copy + Add<Output = T> + Display,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Step({})", self.value)
@@ -39,12 +57,36 @@ where
 
 impl<T> Block for Step<T>
 where
-    T: One + Copy,
+    T: One + Zero + Copy + Add<Output = T>,
 {
     type Input = ();
     type Output = T;
 
-    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        self.value
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if sim_state.sim_time() < self.start {
+            self.offset
+        } else {
+            self.offset + self.value
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_delayed_step_with_offset() {
+        let mut step = Step::new(2.0).at(Duration::from_secs(1)).with_offset(0.5);
+
+        for sim_state in Simulation::new(0.25, 2.0) {
+            let expected = if sim_state.sim_time() < Duration::from_secs(1) {
+                0.5
+            } else {
+                2.5
+            };
+            assert_eq!(step.block((), sim_state), expected);
+        }
     }
 }
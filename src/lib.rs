@@ -5,25 +5,53 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "bench")]
+pub mod bench;
 mod block;
-#[cfg(feature = "alloc")]
 pub mod continuous;
 #[cfg(feature = "alloc")]
 mod discrete;
+mod dual;
+mod error;
+mod executor;
 #[cfg(feature = "std")]
 mod identification;
 mod input;
+pub mod linalg;
 mod line_equation;
 mod metrics;
 #[cfg(feature = "std")]
 mod output;
+#[cfg(feature = "std")]
+mod params;
+#[cfg(feature = "alloc")]
+mod plant;
 #[cfg(feature = "alloc")]
 pub mod poly;
+#[cfg(feature = "std")]
+mod profiler;
+mod rewind;
+mod rng;
+mod rotation;
+#[cfg(feature = "alloc")]
+mod script;
 mod signal;
 mod simulation;
+#[cfg(feature = "std")]
+mod sync;
+pub mod testing;
 mod tier1;
 pub mod tier2;
 pub mod tier3;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "std")]
+mod tuning;
+mod vector;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use crate::error::Error;
 
 #[cfg(feature = "alloc")]
 pub use crate::continuous::s_var::s;
@@ -32,78 +60,171 @@ pub use crate::discrete::z_inv_var::z_inv;
 #[cfg(feature = "alloc")]
 pub use crate::discrete::z_var::z;
 
+/// Everything the enabled features provide, with one import.
+///
+/// The same items are split by what they need from the target, for code
+/// that must stay within a tier whatever features end up enabled:
+/// [`no_std`](prelude::no_std) for firmware, [`sim`](prelude::sim) for
+/// anything with an allocator and [`host`](prelude::host) for the plotting,
+/// files and bridges of a desktop run. The first tier is not named `core`,
+/// which would shadow the `core` crate wherever the prelude is glob imported.
 pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use self::host::*;
+    pub use self::no_std::*;
     #[cfg(feature = "alloc")]
-    pub use faer::prelude::*;
+    pub use self::sim::*;
 
-    pub use crate::block::Block;
-    #[cfg(feature = "alloc")]
-    pub use crate::continuous::Tf;
-    #[cfg(feature = "alloc")]
-    pub use crate::continuous::solver::Solver;
-    #[cfg(feature = "alloc")]
-    pub use crate::continuous::solver::StateEstimation;
-    #[cfg(feature = "alloc")]
-    pub use crate::continuous::solver::euler::Euler;
-    #[cfg(feature = "alloc")]
-    pub use crate::continuous::solver::runge_kutta::RK4;
-    #[cfg(feature = "alloc")]
-    pub use crate::continuous::ss::SS;
-    #[cfg(feature = "alloc")]
-    pub use crate::discrete::ss::DSS;
-    #[cfg(feature = "alloc")]
-    pub use crate::discrete::tf::DTf;
-    #[cfg(feature = "std")]
-    pub use crate::identification::first_order::{
-        FirstOrderIdentification, FirstOrderModel, FirstOrderModelError, hagglund::Hagglund,
-        smith::Smith1, sundaresan_krishnaswamy::SundaresanKrishnaswamy,
-        ziegler_nichols::ZieglerNichols,
-    };
-    #[cfg(feature = "std")]
-    pub use crate::identification::second_order::{
-        SecondOrderIdentification, SecondOrderModel, SecondOrderModelError, mollenkamp::Mollenkamp,
-        smith::Smith2,
-    };
-    #[cfg(feature = "std")]
-    pub use crate::input::file_samples::FileSamples;
-    pub use crate::input::impulse::Impulse;
-    pub use crate::input::ramp::Ramp;
-    pub use crate::input::sawtooth::Sawtooth;
-    pub use crate::input::sinusoid::Sinusoid;
-    pub use crate::input::square::Square;
-    pub use crate::input::step::Step;
-    pub use crate::line_equation::LineEquation;
+    /// Blocks, signals and solvers that run without an allocator.
+    pub mod no_std {
+        pub use crate::assert_signal_eq;
+        pub use crate::block::{Block, BlockPorts, Discrete};
+        pub use crate::continuous::fixed::SsFixed;
+        pub use crate::continuous::solver::euler::Euler;
+        pub use crate::continuous::solver::runge_kutta::RK4;
+        pub use crate::continuous::solver::{FixedSolver, FixedStateEstimation};
+        #[cfg(feature = "nalgebra")]
+        pub use crate::continuous::static_ss::{SsStatic, StaticSolver};
+        pub use crate::dual::Dual;
+        pub use crate::executor::{EmbeddedExecutor, ExecutorStats};
+        pub use crate::input::combinators::{Gated, InputExt, Plus, ScaledBy};
+        pub use crate::input::ground::Ground;
+        pub use crate::input::impulse::Impulse;
+        pub use crate::input::ramp::Ramp;
+        pub use crate::input::sawtooth::Sawtooth;
+        pub use crate::input::sinusoid::Sinusoid;
+        pub use crate::input::square::Square;
+        pub use crate::input::step::Step;
+        pub use crate::line_equation::LineEquation;
+        pub use crate::metrics::harmonics::Harmonics;
+        pub use crate::metrics::iae::IAE;
+        pub use crate::metrics::ise::ISE;
+        pub use crate::metrics::itae::ITAE;
+        pub use crate::metrics::limits::{LimitStats, Limited};
+        pub use crate::metrics::{Accumulation, Metric};
+        pub use crate::rewind::Rewindable;
+        pub use crate::rotation::{Quaternion, Rotation};
+        pub use crate::signal::{AsSignal, Cast, Elementwise, Pack, Signal, Unpack};
+        pub use crate::simulation::{
+            EndlessSimulation, ExternalSimulation, Simulation, SimulationState,
+        };
+        pub use crate::testing::ApproxEq;
+        pub use crate::tier1::attitude::{ComplementaryFilter, Madgwick, Mahony};
+        pub use crate::tier1::bias::{Bias, VectorBias};
+        pub use crate::tier1::convert::Convert;
+        pub use crate::tier1::differentiator::Differentiator;
+        pub use crate::tier1::discrete_pid::{DiscreteDelay, DiscretePID, Sampled};
+        pub use crate::tier1::disturbance::{AtInput, AtOutput, WithDisturbance};
+        pub use crate::tier1::filter::{
+            Filter,
+            first_order::{high_pass::HighPass, low_pass::LowPass},
+            second_order::{
+                band_pass::BandPass, band_stop::BandStop, bessel::Bessel, biquad::Biquad,
+                butterworth::Butterworth, chebyshev1::Chebyshev1, chebyshev2::Chebyshev2,
+            },
+        };
+        pub use crate::tier1::friction::{CoulombViscous, LuGre};
+        pub use crate::tier1::gain::{Gain, VectorGain};
+        #[cfg(feature = "hal")]
+        pub use crate::tier1::hal::{AdcChannel, AdcInput, DigitalInput, DigitalOutput, PwmOutput};
+        pub use crate::tier1::integrator::{Integrator, ResettableIntegrator};
+        pub use crate::tier1::local_clock::LocalClock;
+        pub use crate::tier1::nan_guard::{Finite, NanFault, NanGuard, NanGuardAction};
+        pub use crate::tier1::pid::PID;
+        pub use crate::tier1::pr::PR;
+        pub use crate::tier1::pwm::{Pwm, PwmCarrier};
+        pub use crate::tier1::saturation::Saturation;
+        pub use crate::tier1::scale::{Normalize, Scale};
+        pub use crate::tier1::state_output::{HasState, StateOutput};
+        pub use crate::tier1::static_fn::{PolyFn, StaticFn};
+        #[cfg(feature = "nalgebra")]
+        pub use crate::tier1::static_observer::ObserverStatic;
+        pub use crate::tier1::terminator::Terminator;
+        pub use crate::tier1::torque_bias::TorqueBias;
+        pub use crate::tier1::watchdog::{TripCause, Watchdog, WatchdogTrip};
+        pub use crate::vector::Vector;
+    }
+
+    /// Models, state-space systems and analysis tools that need `alloc`.
     #[cfg(feature = "alloc")]
-    pub use crate::metrics::good_hart::GoodHart;
-    pub use crate::metrics::iae::IAE;
-    pub use crate::metrics::ise::ISE;
-    pub use crate::metrics::itae::ITAE;
-    #[cfg(feature = "std")]
-    pub use crate::output::plotter::{
-        JoinAll, Joinable, LegendPosition, Plotter, PlotterDynamic, RTPlotter, Savable,
-    };
-    #[cfg(feature = "std")]
-    pub use crate::output::printer::Printer;
+    pub mod sim {
+        pub use crate::continuous::Tf;
+        pub use crate::continuous::ensemble::SsEnsemble;
+        pub use crate::continuous::solver::StateEstimation;
+        pub use crate::continuous::solver::{Solver, SolverBuffers};
+        pub use crate::continuous::ss::SS;
+        pub use crate::discrete::ss::DSS;
+        pub use crate::discrete::tf::DTf;
+        pub use crate::input::multisine::MultiSine;
+        pub use crate::metrics::good_hart::{GoodHart, GoodHartTerm, GoodHartTerms};
+        pub use crate::metrics::limits::LimitsReport;
+        pub use crate::plant::converter::{Converter, ConverterModel};
+        pub use crate::plant::cstr::{CSTR, CstrParams};
+        pub use crate::plant::gear::DualMotorGear;
+        pub use crate::plant::hydraulic::HydraulicCylinder;
+        pub use crate::plant::thermal::ThermalChamber;
+        pub use crate::script::Script;
+        #[cfg(feature = "swd")]
+        pub use crate::tier1::bridge::{BridgeSwdDown, BridgeSwdUp, RemoteSwd, SwdConnection};
+        pub use crate::tier1::delay::Delay;
+        pub use crate::tier1::hot_swap::{Handover, HotSwap};
+        pub use crate::tier1::imu::{ImuModel, ImuSensor};
+        pub use crate::tier1::input_shaper::{EI, InputShaper, ZV, ZVD};
+        pub use crate::tier1::lead_lag::LeadLag;
+        pub use crate::tier1::lookup_table::{Lut1D, Lut2D, LutBoundary};
+        pub use crate::tier1::network::{ChannelStats, DropPolicy, NetworkChannel};
+        pub use crate::tier1::observer::Observer;
+        pub use crate::tier1::repetitive::Repetitive;
+        pub use crate::tier1::washout::Washout;
+        #[cfg(feature = "wasm")]
+        pub use crate::wasm::{CanvasPlotter, WasmLoop};
+        pub use faer::prelude::*;
+        // Shadows `faer::Scale`, the tiers are glob re-exported side by side.
+        pub use crate::tier1::scale::Scale;
+    }
+
+    /// Plotting, file outputs, identification and bridges of host runs.
     #[cfg(feature = "std")]
-    pub use crate::output::writer::Writter;
-    pub use crate::signal::{AsSignal, Pack, Signal, Unpack};
-    pub use crate::simulation::{EndlessSimulation, Simulation, SimulationState};
-    #[cfg(all(feature = "alloc", feature = "swd"))]
-    pub use crate::tier1::bridge::{BridgeSwdDown, BridgeSwdUp, RemoteSwd, SwdConnection};
-    #[cfg(feature = "alloc")]
-    pub use crate::tier1::delay::Delay;
-    pub use crate::tier1::filter::{
-        Filter,
-        first_order::{high_pass::HighPass, low_pass::LowPass},
-        second_order::{
-            band_pass::BandPass, band_stop::BandStop, bessel::Bessel, biquad::Biquad,
-            butterworth::Butterworth, chebyshev1::Chebyshev1, chebyshev2::Chebyshev2,
-        },
-    };
-    #[cfg(feature = "alloc")]
-    pub use crate::tier1::observer::Observer;
-    pub use crate::tier1::pid::PID;
-    pub use crate::tier1::saturation::Saturation;
+    pub mod host {
+        pub use crate::identification::first_order::{
+            FirstOrderIdentification, FirstOrderModel, FirstOrderModelError, hagglund::Hagglund,
+            smith::Smith1, sundaresan_krishnaswamy::SundaresanKrishnaswamy,
+            ziegler_nichols::ZieglerNichols,
+        };
+        pub use crate::identification::second_order::{
+            SecondOrderIdentification, SecondOrderModel, SecondOrderModelError,
+            mollenkamp::Mollenkamp, smith::Smith2,
+        };
+        pub use crate::input::file_samples::FileSamples;
+        pub use crate::output::diagram::{Diagram, NodeId};
+        pub use crate::output::event_log::{Event, EventKind, EventLog};
+        pub use crate::output::mcap::McapWriter;
+        pub use crate::output::merger::{Fill, LogMerger};
+        #[cfg(feature = "mqtt")]
+        pub use crate::output::mqtt::{MqttMonitor, MqttOptions, MqttStats, QoS};
+        pub use crate::output::plotter::{
+            JoinAll, Joinable, LegendPosition, Plotter, PlotterDynamic, RTPlotter, Savable,
+        };
+        pub use crate::output::printer::Printer;
+        pub use crate::output::writer::Writter;
+        pub use crate::params::{ParamError, Params, Tunable, TunableParam};
+        pub use crate::profiler::{Profiler, ProfilerReport};
+        pub use crate::sync::{SyncBarrier, SyncPort};
+        #[cfg(feature = "tokio")]
+        pub use crate::tier1::bridge::async_io::{AsyncSink, AsyncSource, ChannelMetrics};
+        #[cfg(feature = "grpc")]
+        pub use crate::tier1::bridge::grpc::{
+            REMOTE_BLOCK_PROTO, RemoteBlock, RemoteBlockServer, RemoteValue,
+        };
+        #[cfg(feature = "ros2")]
+        pub use crate::tier1::bridge::ros2::{Ros2Bridge, Ros2Publisher, Ros2Subscriber};
+        #[cfg(feature = "onnx")]
+        pub use crate::tier1::nn_controller::{NnController, OnnxError};
+        pub use crate::tier1::parallel::{Parallel, ParallelDiagram, ParallelPair};
+        #[cfg(feature = "trace")]
+        pub use crate::trace::{BlockStats, Traced};
+        pub use crate::tuning::TuningServer;
+    }
 }
 
 #[cfg(all(test, feature = "std"))]
@@ -117,8 +238,43 @@ mod tests {
         let mut pid = PID::new(1.0, 0.1, 0.01);
 
         for sim_state in simulation {
-            let r = sim_state * step.as_block();
+            let r = sim_state >> step.as_block();
             let _y = pid.output(r);
         }
     }
+
+    #[test]
+    fn test_no_std_prelude_tier() {
+        use crate::prelude::no_std::{Block, PID, RK4, Simulation, SsFixed, Step};
+
+        let mut step = Step::default();
+        let mut pid = PID::new(1.0, 0.5, 0.0);
+        let mut plant = SsFixed::<1, RK4>::new([[-1.0]], [1.0], [1.0], 0.0);
+
+        for sim_state in Simulation::new(0.01, 1.0) {
+            let error = (sim_state >> step.as_block()) - plant.last_output();
+            let _ = error >> pid.as_block() >> plant.as_block();
+        }
+        assert!(plant.last_output().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_external_simulation_paces_from_source() {
+        use core::time::Duration;
+
+        let mut ticks = [10u64, 12, 9].into_iter();
+        let simulation = ExternalSimulation::new(|| ticks.next().map(Duration::from_millis));
+        let mut integrator = Integrator::<f64>::new();
+
+        let mut last = None;
+        for sim_state in simulation {
+            last = Some(sim_state);
+            let _ = 1.0.as_signal(sim_state) >> integrator.as_block();
+        }
+
+        let last = last.unwrap();
+        assert_eq!(last.dt(), Duration::from_millis(9));
+        assert_eq!(last.sim_time(), Duration::from_millis(31));
+        assert!((integrator.last_output().unwrap() - 0.031).abs() < 1e-12);
+    }
 }
@@ -0,0 +1,179 @@
+use alloc::vec::Vec;
+use faer::{Mat, MatRef, c64};
+
+/// Thin singular value decomposition `m = U diag(s) V^T`, singular values in
+/// decreasing order.
+pub fn svd(m: MatRef<'_, f64>) -> (Mat<f64>, Vec<f64>, Mat<f64>) {
+    let svd = m.thin_svd().expect("SVD did not converge");
+    (
+        svd.U().to_owned(),
+        svd.S().column_vector().iter().copied().collect(),
+        svd.V().to_owned(),
+    )
+}
+
+/// Thin QR decomposition `m = Q R`, with orthonormal columns in `Q` and `R`
+/// upper triangular.
+pub fn qr(m: MatRef<'_, f64>) -> (Mat<f64>, Mat<f64>) {
+    let qr = m.qr();
+    (qr.compute_thin_Q(), qr.thin_R().to_owned())
+}
+
+pub fn eigenvalues(m: MatRef<'_, f64>) -> Vec<c64> {
+    m.eigenvalues().expect("Eigenvalues did not converge")
+}
+
+/// Matrix exponential `e^A`, by Taylor series with scaling and squaring.
+pub fn expm(a: MatRef<'_, f64>) -> Mat<f64> {
+    let n = a.nrows();
+    let norm = (0..n)
+        .map(|i| (0..n).map(|j| a[(i, j)].abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let mut squarings = 0;
+    let mut scale = 1.0;
+    while norm * scale > 0.5 && squarings < 64 {
+        scale *= 0.5;
+        squarings += 1;
+    }
+
+    let scaled = a * scale;
+    let mut exp = Mat::<f64>::identity(n, n);
+    let mut term = Mat::<f64>::identity(n, n);
+    for k in 1..=20 {
+        term = &term * &scaled / k as f64;
+        exp += &term;
+    }
+    for _ in 0..squarings {
+        exp = &exp * &exp;
+    }
+    exp
+}
+
+/// `∫₀ᵗ e^(Aτ) dτ`, the top right block of `e^([[A, I], [0, 0]] t)`.
+pub fn expm_integral(a: MatRef<'_, f64>, t: f64) -> Mat<f64> {
+    let n = a.nrows();
+    let augmented = Mat::from_fn(2 * n, 2 * n, |i, j| match (i < n, j < n) {
+        (true, true) => a[(i, j)] * t,
+        (true, false) if j - n == i => t,
+        _ => 0.0,
+    });
+    expm(augmented.as_ref()).submatrix(0, n, n, n).to_owned()
+}
+
+/// Zero-order hold discretization of `x' = A x + B u` over `dt`, returning
+/// `(e^(A dt), ∫₀^dt e^(Aτ) dτ B)`, e.g. to build a
+/// [`DSS`](crate::prelude::DSS).
+pub fn zoh(a: MatRef<'_, f64>, b: MatRef<'_, f64>, dt: f64) -> (Mat<f64>, Mat<f64>) {
+    let (n, m) = (a.nrows(), b.ncols());
+    let augmented = Mat::from_fn(n + m, n + m, |i, j| match (i < n, j < n) {
+        (true, true) => a[(i, j)] * dt,
+        (true, false) => b[(i, j - n)] * dt,
+        _ => 0.0,
+    });
+    let exp = expm(augmented.as_ref());
+    (
+        exp.submatrix(0, 0, n, n).to_owned(),
+        exp.submatrix(0, n, n, m).to_owned(),
+    )
+}
+
+/// Discrete process noise covariance `Qd = ∫₀^ts e^(Aτ) Q e^(Aᵀτ) dτ` by
+/// Van Loan's method, from `e^([[-A, Q], [0, Aᵀ]] ts)`.
+pub fn discretize_noise(a: MatRef<'_, f64>, q: MatRef<'_, f64>, ts: f64) -> Mat<f64> {
+    let n = a.nrows();
+    let augmented = Mat::from_fn(2 * n, 2 * n, |i, j| match (i < n, j < n) {
+        (true, true) => -a[(i, j)] * ts,
+        (true, false) => q[(i, j - n)] * ts,
+        (false, false) => a[(j - n, i - n)] * ts,
+        (false, true) => 0.0,
+    });
+    let exp = expm(augmented.as_ref());
+    let phi = exp.submatrix(n, n, n, n).transpose().to_owned();
+    let qd = &phi * exp.submatrix(0, n, n, n);
+    // Symmetric up to rounding.
+    Mat::from_fn(n, n, |i, j| 0.5 * (qd[(i, j)] + qd[(j, i)]))
+}
+
+/// Pseudo-inverse dropping singular values below `tolerance` times the
+/// largest.
+pub fn pinv(m: MatRef<'_, f64>, tolerance: f64) -> Mat<f64> {
+    let (u, s, v) = svd(m);
+    let cutoff = s.first().copied().unwrap_or(0.0) * tolerance;
+    let kept = s.iter().take_while(|s| **s > cutoff).count();
+    let v_scaled = Mat::from_fn(v.nrows(), kept, |r, c| v[(r, c)] / s[c]);
+    v_scaled * u.subcols(0, kept).transpose()
+}
+
+/// Numerical rank, singular values above `tolerance` times the largest.
+pub fn rank(m: MatRef<'_, f64>, tolerance: f64) -> usize {
+    let (_, s, _) = svd(m);
+    let cutoff = s.first().copied().unwrap_or(0.0) * tolerance;
+    s.iter().take_while(|s| **s > cutoff).count()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::linalg::dense::{discretize_noise, expm, expm_integral, pinv, qr, rank, svd, zoh};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_dense_decompositions() {
+        let m = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+
+        let (q, r) = qr(m.as_ref());
+        assert_eq!((q.nrows(), q.ncols(), r.nrows(), r.ncols()), (3, 2, 2, 2));
+        assert!((&q * &r - &m).norm_l2() < 1e-12);
+        assert!((q.transpose() * &q - Mat::<f64>::identity(2, 2)).norm_l2() < 1e-12);
+        assert_eq!(r[(1, 0)], 0.0);
+
+        let (u, s, v) = svd(mat![[3.0, 0.0], [0.0, -2.0]].as_ref());
+        assert_eq!(s, [3.0, 2.0]);
+        assert!(
+            (&u * Mat::from_fn(2, 2, |i, j| if i == j { s[i] } else { 0.0 }) * v.transpose()
+                - mat![[3.0, 0.0], [0.0, -2.0]])
+            .norm_l2()
+                < 1e-12
+        );
+
+        // Left inverse of a full column rank matrix.
+        assert!((pinv(m.as_ref(), 1e-12) * &m - Mat::<f64>::identity(2, 2)).norm_l2() < 1e-12);
+        assert_eq!(rank(m.as_ref(), 1e-12), 2);
+        assert_eq!(rank(mat![[1.0, 2.0], [2.0, 4.0]].as_ref(), 1e-12), 1);
+    }
+    #[test]
+    fn test_dense_expm_matches_fixed_size() {
+        let a = [[-1.0, 2.0, 0.0], [-2.0, -1.0, 1.0], [0.0, 0.0, -3.0]];
+        let b = [[0.0], [1.0], [2.0]];
+        let a_mat = Mat::from_fn(3, 3, |i, j| a[i][j]);
+        let b_mat = Mat::from_fn(3, 1, |i, j| b[i][j]);
+
+        let exp = expm((&a_mat * 1.5).as_ref());
+        let expected = crate::linalg::expm(&a.map(|row| row.map(|x| x * 1.5)));
+        assert!((exp - Mat::from_fn(3, 3, |i, j| expected[i][j])).norm_l2() < 1e-12);
+
+        let (ad, bd) = zoh(a_mat.as_ref(), b_mat.as_ref(), 0.1);
+        let (ad_fixed, bd_fixed) = crate::linalg::zoh(&a, &b, 0.1);
+        assert!((&ad - Mat::from_fn(3, 3, |i, j| ad_fixed[i][j])).norm_l2() < 1e-12);
+        assert!((&bd - Mat::from_fn(3, 1, |i, j| bd_fixed[i][j])).norm_l2() < 1e-12);
+        assert!((expm_integral(a_mat.as_ref(), 0.1) * &b_mat - &bd).norm_l2() < 1e-12);
+
+        let q = [[1.0, 0.2, 0.0], [0.2, 0.5, 0.0], [0.0, 0.0, 2.0]];
+        let qd = discretize_noise(
+            a_mat.as_ref(),
+            Mat::from_fn(3, 3, |i, j| q[i][j]).as_ref(),
+            0.7,
+        );
+        let expected = crate::linalg::discretize_noise(&a, &q, 0.7);
+        assert!((qd - Mat::from_fn(3, 3, |i, j| expected[i][j])).norm_l2() < 1e-12);
+
+        // The discretized plant steps exactly like the continuous one sampled.
+        let mut plant = DSS::new(ad, bd, mat![[1.0, 0.0, 0.0]], 0.0);
+        let mut x = [[0.0], [0.0], [0.0]];
+        for sim_state in Simulation::new(0.1, 1000.0).take(10) {
+            let y = 1.0.as_signal(sim_state) >> plant.as_block();
+            assert!((y.value - x[0][0]).abs() < 1e-12);
+            let ax = crate::linalg::matmul(&ad_fixed, &x);
+            x = [0, 1, 2].map(|i| [ax[i][0] + bd_fixed[i][0]]);
+        }
+    }
+}
@@ -0,0 +1,674 @@
+//! Small dense linear algebra on fixed-size arrays, row major, usable
+//! without an allocator, and thin wrappers over faer for heap matrices in
+//! [`dense`]. These are the building blocks of root finding, subspace
+//! identification, model reduction and Riccati solvers.
+
+#[cfg(feature = "alloc")]
+pub mod dense;
+
+/// Rows by columns matrix, row major.
+pub type Matrix<const R: usize, const C: usize> = [[f64; C]; R];
+
+const MAX_SWEEPS: usize = 60;
+const MAX_QR_ITERATIONS: usize = 60;
+
+pub fn identity<const N: usize>() -> Matrix<N, N> {
+    let mut m = [[0.0; N]; N];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    m
+}
+
+pub fn transpose<const R: usize, const C: usize>(a: &Matrix<R, C>) -> Matrix<C, R> {
+    let mut t = [[0.0; R]; C];
+    for (i, row) in a.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            t[j][i] = *value;
+        }
+    }
+    t
+}
+
+pub fn matmul<const R: usize, const K: usize, const C: usize>(
+    a: &Matrix<R, K>,
+    b: &Matrix<K, C>,
+) -> Matrix<R, C> {
+    let mut m = [[0.0; C]; R];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..K).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+/// Householder QR decomposition `a = Q R`, with `Q` orthogonal and `R`
+/// upper triangular.
+#[allow(clippy::needless_range_loop)]
+pub fn qr<const R: usize, const C: usize>(a: &Matrix<R, C>) -> (Matrix<R, R>, Matrix<R, C>) {
+    let mut q = identity::<R>();
+    let mut r = *a;
+
+    for k in 0..C.min(R.saturating_sub(1)) {
+        let norm = libm::sqrt((k..R).map(|i| r[i][k] * r[i][k]).sum());
+        if norm == 0.0 {
+            continue;
+        }
+
+        let alpha = if r[k][k] > 0.0 { -norm } else { norm };
+        let mut v = [0.0; R];
+        for i in k..R {
+            v[i] = r[i][k];
+        }
+        v[k] -= alpha;
+        let v_norm2 = (k..R).map(|i| v[i] * v[i]).sum::<f64>();
+        if v_norm2 == 0.0 {
+            continue;
+        }
+
+        // R <- H R and Q <- Q H with H = I - 2 v v^T / (v^T v).
+        for j in 0..C {
+            let f = 2.0 * (k..R).map(|i| v[i] * r[i][j]).sum::<f64>() / v_norm2;
+            for i in k..R {
+                r[i][j] -= f * v[i];
+            }
+        }
+        for row in q.iter_mut() {
+            let f = 2.0 * (k..R).map(|i| row[i] * v[i]).sum::<f64>() / v_norm2;
+            for i in k..R {
+                row[i] -= f * v[i];
+            }
+        }
+        for row in r.iter_mut().skip(k + 1) {
+            row[k] = 0.0;
+        }
+    }
+
+    (q, r)
+}
+
+/// Thin singular value decomposition `a = U diag(s) V^T` by one-sided
+/// Jacobi rotations, singular values in decreasing order. Needs at least
+/// as many rows as columns; decompose the transpose of wide matrices.
+pub fn svd<const R: usize, const C: usize>(
+    a: &Matrix<R, C>,
+) -> (Matrix<R, C>, [f64; C], Matrix<C, C>) {
+    assert!(R >= C, "SVD needs at least as many rows as columns");
+
+    let mut u = *a;
+    let mut v = identity::<C>();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..C {
+            for q in p + 1..C {
+                let alpha = u.iter().map(|row| row[p] * row[p]).sum::<f64>();
+                let beta = u.iter().map(|row| row[q] * row[q]).sum::<f64>();
+                let gamma = u.iter().map(|row| row[p] * row[q]).sum::<f64>();
+                if gamma == 0.0 || gamma.abs() <= f64::EPSILON * libm::sqrt(alpha * beta) {
+                    continue;
+                }
+                rotated = true;
+
+                let (c, s) = jacobi_rotation(alpha, beta, gamma);
+                for row in u.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut s = [0.0; C];
+    for (j, s) in s.iter_mut().enumerate() {
+        *s = libm::sqrt(u.iter().map(|row| row[j] * row[j]).sum());
+        if *s > 0.0 {
+            for row in u.iter_mut() {
+                row[j] /= *s;
+            }
+        }
+    }
+
+    // Selection sort keeps the columns of U and V paired with s.
+    for j in 0..C {
+        let largest = (j..C).fold(j, |best, k| if s[k] > s[best] { k } else { best });
+        if largest != j {
+            s.swap(j, largest);
+            for row in u.iter_mut().chain(v.iter_mut()) {
+                row.swap(j, largest);
+            }
+        }
+    }
+
+    (u, s, v)
+}
+
+/// Eigenvalues and orthonormal eigenvectors, one per column, of a
+/// symmetric matrix by cyclic Jacobi rotations, in decreasing order.
+#[allow(clippy::needless_range_loop)]
+pub fn symmetric_eigen<const N: usize>(a: &Matrix<N, N>) -> ([f64; N], Matrix<N, N>) {
+    let mut a = *a;
+    let mut v = identity::<N>();
+
+    for _ in 0..MAX_SWEEPS {
+        let off = (0..N)
+            .flat_map(|i| (0..N).filter(move |j| *j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum::<f64>();
+        let scale = (0..N).map(|i| a[i][i] * a[i][i]).sum::<f64>();
+        if off <= f64::EPSILON * f64::EPSILON * scale || off == 0.0 {
+            break;
+        }
+
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+
+                let (c, s) = jacobi_rotation(a[p][p], a[q][q], a[p][q]);
+                for row in a.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                for j in 0..N {
+                    let (x, y) = (a[p][j], a[q][j]);
+                    a[p][j] = c * x - s * y;
+                    a[q][j] = s * x + c * y;
+                }
+                for row in v.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+    }
+
+    let mut values = [0.0; N];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = a[i][i];
+    }
+    for j in 0..N {
+        let largest = (j..N).fold(j, |best, k| if values[k] > values[best] { k } else { best });
+        if largest != j {
+            values.swap(j, largest);
+            for row in v.iter_mut() {
+                row.swap(j, largest);
+            }
+        }
+    }
+
+    (values, v)
+}
+
+/// Eigenvalues `(re, im)` of a general matrix, by reduction to Hessenberg
+/// form and shifted QR iterations. Complex pairs are adjacent; `None` if
+/// the iterations do not converge.
+pub fn eigenvalues<const N: usize>(a: &Matrix<N, N>) -> Option<[(f64, f64); N]> {
+    let mut h = *a;
+    hessenberg(&mut h);
+    hqr(&mut h)
+}
+
+/// Matrix exponential `e^A`, by Taylor series with scaling and squaring.
+pub fn expm<const N: usize>(a: &Matrix<N, N>) -> Matrix<N, N> {
+    exp_and_integral(a, 1.0).0
+}
+
+/// `∫₀ᵗ e^(Aτ) dτ`, which times `B` is the input matrix of the zero-order
+/// hold discretization.
+pub fn expm_integral<const N: usize>(a: &Matrix<N, N>, t: f64) -> Matrix<N, N> {
+    exp_and_integral(a, t).1
+}
+
+/// Zero-order hold discretization of `x' = A x + B u` over `dt`, returning
+/// `(e^(A dt), ∫₀^dt e^(Aτ) dτ B)`.
+pub fn zoh<const N: usize, const M: usize>(
+    a: &Matrix<N, N>,
+    b: &Matrix<N, M>,
+    dt: f64,
+) -> (Matrix<N, N>, Matrix<N, M>) {
+    let (phi, psi) = exp_and_integral(a, dt);
+    (phi, matmul(&psi, b))
+}
+
+/// Discrete process noise covariance `Qd = ∫₀^ts e^(Aτ) Q e^(Aᵀτ) dτ` of
+/// `x' = A x + w`, `E[w wᵀ] = Q δ(t)`, sampled every `ts`, as needed by a
+/// discrete Kalman filter. Using `Q ts` instead is only right for small
+/// `ts` and fast-enough sampling.
+pub fn discretize_noise<const N: usize>(
+    a: &Matrix<N, N>,
+    q: &Matrix<N, N>,
+    ts: f64,
+) -> Matrix<N, N> {
+    let norm = a
+        .iter()
+        .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let mut doublings = 0;
+    let mut h = ts;
+    while norm * h.abs() > 0.5 && doublings < 64 {
+        h *= 0.5;
+        doublings += 1;
+    }
+
+    // Qd(h) = Σ h^(k+1) / (k+1)! M_k, with M_0 = Q and
+    // M_k = A M_(k-1) + M_(k-1) Aᵀ.
+    let a_t = transpose(a);
+    let mut qd = q.map(|row| row.map(|x| x * h));
+    let mut m = *q;
+    let mut factor = h;
+    for k in 1..=20 {
+        let am = matmul(a, &m);
+        let ma = matmul(&m, &a_t);
+        factor *= h / (k + 1) as f64;
+        for i in 0..N {
+            for j in 0..N {
+                m[i][j] = am[i][j] + ma[i][j];
+                qd[i][j] += factor * m[i][j];
+            }
+        }
+    }
+
+    // Qd(2h) = Qd(h) + Φ(h) Qd(h) Φ(h)ᵀ.
+    let mut phi = exp_and_integral(a, h).0;
+    for _ in 0..doublings {
+        let propagated = matmul(&matmul(&phi, &qd), &transpose(&phi));
+        for i in 0..N {
+            for j in 0..N {
+                qd[i][j] += propagated[i][j];
+            }
+        }
+        phi = matmul(&phi, &phi);
+    }
+
+    qd
+}
+
+/// `(e^(At), ∫₀ᵗ e^(Aτ) dτ)`: the series are summed for `t / 2^s` small
+/// enough, then doubled `s` times with `Φ(2t) = Φ(t)²` and
+/// `Ψ(2t) = Ψ(t) (I + Φ(t))`.
+fn exp_and_integral<const N: usize>(a: &Matrix<N, N>, t: f64) -> (Matrix<N, N>, Matrix<N, N>) {
+    let norm = a
+        .iter()
+        .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let mut squarings = 0;
+    let mut h = t;
+    while norm * h.abs() > 0.5 && squarings < 64 {
+        h *= 0.5;
+        squarings += 1;
+    }
+
+    // Φ = Σ (Ah)^k / k!, Ψ = h Σ (Ah)^k / (k + 1)!.
+    let mut phi = identity::<N>();
+    let mut psi = identity::<N>().map(|row| row.map(|x| x * h));
+    let mut term = identity::<N>();
+    for k in 1..=20 {
+        term = matmul(&term, a).map(|row| row.map(|x| x * h / k as f64));
+        for i in 0..N {
+            for j in 0..N {
+                phi[i][j] += term[i][j];
+                psi[i][j] += term[i][j] * h / (k + 1) as f64;
+            }
+        }
+    }
+
+    for _ in 0..squarings {
+        let mut i_plus_phi = phi;
+        for (i, row) in i_plus_phi.iter_mut().enumerate() {
+            row[i] += 1.0;
+        }
+        psi = matmul(&psi, &i_plus_phi);
+        phi = matmul(&phi, &phi);
+    }
+
+    (phi, psi)
+}
+
+/// Rotation `(c, s)` zeroing the off-diagonal of the 2x2 symmetric matrix
+/// `[[alpha, gamma], [gamma, beta]]`.
+fn jacobi_rotation(alpha: f64, beta: f64, gamma: f64) -> (f64, f64) {
+    let zeta = (beta - alpha) / (2.0 * gamma);
+    let t = zeta.signum() / (zeta.abs() + libm::sqrt(1.0 + zeta * zeta));
+    let c = 1.0 / libm::sqrt(1.0 + t * t);
+    (c, c * t)
+}
+
+/// Reduces `a` to upper Hessenberg form by similarity transforms with
+/// Gaussian elimination and partial pivoting.
+#[allow(clippy::needless_range_loop)]
+fn hessenberg<const N: usize>(a: &mut Matrix<N, N>) {
+    for m in 1..N.saturating_sub(1) {
+        let pivot = (m..N).fold(m, |best, j| {
+            if a[j][m - 1].abs() > a[best][m - 1].abs() {
+                j
+            } else {
+                best
+            }
+        });
+        let x = a[pivot][m - 1];
+        if pivot != m {
+            a.swap(pivot, m);
+            for row in a.iter_mut() {
+                row.swap(pivot, m);
+            }
+        }
+        if x == 0.0 {
+            continue;
+        }
+
+        for i in m + 1..N {
+            let y = a[i][m - 1] / x;
+            if y == 0.0 {
+                continue;
+            }
+            for j in m - 1..N {
+                a[i][j] -= y * a[m][j];
+            }
+            for j in 0..N {
+                a[j][m] += y * a[j][i];
+            }
+        }
+    }
+
+    for (i, row) in a.iter_mut().enumerate() {
+        for value in row.iter_mut().take(i.saturating_sub(1)) {
+            *value = 0.0;
+        }
+    }
+}
+
+/// Eigenvalues of an upper Hessenberg matrix by Francis double shift QR
+/// iterations, deflating one real root or a pair at a time.
+#[allow(clippy::needless_range_loop)]
+fn hqr<const N: usize>(a: &mut Matrix<N, N>) -> Option<[(f64, f64); N]> {
+    let mut roots = [(0.0, 0.0); N];
+    let norm = (0..N)
+        .flat_map(|i| (i.saturating_sub(1)..N).map(move |j| (i, j)))
+        .map(|(i, j)| a[i][j].abs())
+        .sum::<f64>();
+    let mut shift = 0.0;
+    let mut active = N;
+
+    while active > 0 {
+        let nn = active - 1;
+        let mut iterations = 0;
+        loop {
+            // Smallest l whose subdiagonal entry is negligible.
+            let mut l = nn;
+            while l >= 1 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[nn][nn];
+            if l == nn {
+                roots[nn] = (x + shift, 0.0);
+                active -= 1;
+                break;
+            }
+
+            let mut y = a[nn - 1][nn - 1];
+            let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+            if l == nn - 1 {
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = libm::sqrt(q.abs());
+                x += shift;
+                if q >= 0.0 {
+                    let z = p + if p >= 0.0 { z } else { -z };
+                    let second = if z != 0.0 { x - w / z } else { x + z };
+                    roots[nn - 1] = (x + z, 0.0);
+                    roots[nn] = (second, 0.0);
+                } else {
+                    roots[nn - 1] = (x + p, -z);
+                    roots[nn] = (x + p, z);
+                }
+                active -= 2;
+                break;
+            }
+
+            if iterations == MAX_QR_ITERATIONS {
+                return None;
+            }
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift to break cycles.
+                shift += x;
+                for i in 0..=nn {
+                    a[i][i] -= x;
+                }
+                let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            iterations += 1;
+
+            // Look for two consecutive small subdiagonal entries.
+            let mut m = nn - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[m][m];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - rr - ss;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=nn {
+                a[i][i - 2] = 0.0;
+                if i != m + 2 {
+                    a[i][i - 3] = 0.0;
+                }
+            }
+
+            // Double shift QR step on rows l..=nn and columns m..=nn.
+            for k in m..nn {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != nn - 1 { a[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+
+                let norm = libm::sqrt(p * p + q * q + r * r);
+                let s = if p >= 0.0 { norm } else { -norm };
+                if s == 0.0 {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..=nn {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+                    if k != nn - 1 {
+                        p += r * a[k + 2][j];
+                        a[k + 2][j] -= p * z;
+                    }
+                    a[k + 1][j] -= p * y;
+                    a[k][j] -= p * x;
+                }
+                for i in l..=nn.min(k + 3) {
+                    let mut p = x * a[i][k] + y * a[i][k + 1];
+                    if k != nn - 1 {
+                        p += z * a[i][k + 2];
+                        a[i][k + 2] -= p * r;
+                    }
+                    a[i][k + 1] -= p * q;
+                    a[i][k] -= p;
+                }
+            }
+        }
+    }
+
+    Some(roots)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::linalg::{
+        discretize_noise, eigenvalues, expm, expm_integral, matmul, qr, svd, symmetric_eigen,
+        transpose, zoh,
+    };
+
+    fn close<const R: usize, const C: usize>(a: &[[f64; C]; R], b: &[[f64; C]; R]) -> bool {
+        a.iter()
+            .flatten()
+            .zip(b.iter().flatten())
+            .all(|(x, y)| (x - y).abs() < 1e-10)
+    }
+
+    #[test]
+    fn test_fixed_size_decompositions() {
+        let a = [
+            [4.0, 1.0, -2.0],
+            [1.0, 2.0, 0.5],
+            [-2.0, 0.5, 3.0],
+            [1.0, 1.0, 1.0],
+        ];
+
+        let (q, r) = qr(&a);
+        assert!(close(&matmul(&q, &r), &a));
+        assert!(close(
+            &matmul(&transpose(&q), &q),
+            &crate::linalg::identity()
+        ));
+        assert!(r[1][0] == 0.0 && r[2][0] == 0.0 && r[2][1] == 0.0 && r[3][2] == 0.0);
+
+        let (u, s, v) = svd(&a);
+        assert!(s[0] >= s[1] && s[1] >= s[2] && s[2] > 0.0);
+        let mut us = u;
+        for row in us.iter_mut() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value *= s[j];
+            }
+        }
+        assert!(close(&matmul(&us, &transpose(&v)), &a));
+
+        let symmetric = [[4.0, 1.0, -2.0], [1.0, 2.0, 0.5], [-2.0, 0.5, 3.0]];
+        let (values, vectors) = symmetric_eigen(&symmetric);
+        for (j, value) in values.iter().enumerate() {
+            let x = [[vectors[0][j]], [vectors[1][j]], [vectors[2][j]]];
+            let ax = matmul(&symmetric, &x);
+            assert!(close(&ax, &x.map(|[x]| [x * value])));
+        }
+        // Singular values of A are the square roots of the eigenvalues of A^T A.
+        let (gram, _) = symmetric_eigen(&matmul(&transpose(&a), &a));
+        for (g, s) in gram.iter().zip(&s) {
+            assert!((g.sqrt() - s).abs() < 1e-10);
+        }
+
+        // Rotation by 0.3 rad scaled by 0.9, and a real pole at 0.5.
+        let (c, sn) = (0.9 * 0.3f64.cos(), 0.9 * 0.3f64.sin());
+        let m = [[c, -sn, 1.0], [sn, c, 2.0], [0.0, 0.0, 0.5]];
+        let t = [[1.0, 2.0, 0.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0]];
+        let t_inv =
+            [[1.0, -2.0, 2.0], [1.0, 1.0, -1.0], [-1.0, 2.0, 1.0]].map(|row| row.map(|x| x / 3.0));
+        let mut roots = eigenvalues(&matmul(&matmul(&t, &m), &t_inv)).unwrap();
+        roots.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        assert!((roots[0].0 - c).abs() < 1e-10 && (roots[0].1 + sn).abs() < 1e-10);
+        assert!((roots[1].0 - 0.5).abs() < 1e-10 && roots[1].1 == 0.0);
+        assert!((roots[2].0 - c).abs() < 1e-10 && (roots[2].1 - sn).abs() < 1e-10);
+
+        // Companion matrix of (x - 1)(x - 2)(x - 3)(x + 4).
+        let companion = [
+            [2.0, 13.0, -38.0, 24.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ];
+        let mut roots = eigenvalues(&companion).unwrap().map(|(re, im)| {
+            assert!(im.abs() < 1e-10);
+            re
+        });
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (root, expected) in roots.iter().zip([-4.0, 1.0, 2.0, 3.0]) {
+            assert!((root - expected).abs() < 1e-9);
+        }
+    }
+    #[test]
+    fn test_fixed_size_expm_and_zoh() {
+        // Harmonic oscillator: e^(At) is a rotation.
+        let a = [[0.0, 1.0], [-1.0, 0.0]];
+        let phi = expm(&a.map(|row| row.map(|x| x * 2.0)));
+        let expected = [[2f64.cos(), 2f64.sin()], [-2f64.sin(), 2f64.cos()]];
+        assert!(close(&phi, &expected));
+
+        let psi = expm_integral(&a, 2.0);
+        let expected = [
+            [2f64.sin(), 1.0 - 2f64.cos()],
+            [2f64.cos() - 1.0, 2f64.sin()],
+        ];
+        assert!(close(&psi, &expected));
+
+        // First order lag x' = -2x + 2u held over 0.1 s.
+        let (ad, bd) = zoh(&[[-2.0]], &[[2.0]], 0.1);
+        assert!((ad[0][0] - (-0.2f64).exp()).abs() < 1e-14);
+        assert!((bd[0][0] - (1.0 - (-0.2f64).exp())).abs() < 1e-14);
+
+        // Singular A integrates: e^0 = I and the integral is t.
+        let (ad, bd) = zoh(&[[0.0, 1.0], [0.0, 0.0]], &[[0.0], [1.0]], 0.5);
+        assert!(close(&ad, &[[1.0, 0.5], [0.0, 1.0]]));
+        assert!(close(&bd, &[[0.125], [0.5]]));
+    }
+    #[test]
+    fn test_fixed_size_discretize_noise() {
+        // First order lag: Qd = q (1 - e^(-2 lambda T)) / (2 lambda).
+        let qd = discretize_noise(&[[-3.0]], &[[2.0]], 0.4);
+        assert!((qd[0][0] - 2.0 * (1.0 - (-2.4f64).exp()) / 6.0).abs() < 1e-14);
+
+        // Double integrator driven by white acceleration.
+        let (q, t) = (0.7, 2.5);
+        let qd = discretize_noise(&[[0.0, 1.0], [0.0, 0.0]], &[[0.0, 0.0], [0.0, q]], t);
+        let expected = [
+            [q * t * t * t / 3.0, q * t * t / 2.0],
+            [q * t * t / 2.0, q * t],
+        ];
+        assert!(close(&qd, &expected));
+    }
+}
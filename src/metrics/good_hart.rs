@@ -1,4 +1,4 @@
-use crate::{block::Block, prelude::SimulationState};
+use crate::{block::Block, metrics::Metric, prelude::SimulationState};
 use alloc::vec::Vec;
 use core::{
     iter::Sum,
@@ -6,7 +6,23 @@ use core::{
 };
 use num_traits::{Signed, Zero};
 
-#[derive(Debug, Clone, PartialEq)]
+/// Term of the Goodhart criterion, computed from the recorded samples.
+pub type GoodHartTerm<T> = fn(&[T]) -> T;
+
+/// Unweighted terms of a [`GoodHart`] score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoodHartTerms<T> {
+    /// Control effort, by default the mean of the control signal.
+    pub effort: T,
+    /// Control variation, by default the variance of the control signal.
+    pub variation: T,
+    /// Tracking error, by default the mean absolute error.
+    pub error: T,
+}
+
+/// Goodhart criterion `alpha1 * effort + alpha2 * variation + alpha3 * error`,
+/// fed with `(error, control_signal)` pairs.
+#[derive(Debug, Clone)]
 pub struct GoodHart<T>
 where
     T: Zero
@@ -14,12 +30,16 @@ where
         + Copy
         + Div<f64, Output = T>
         + Sub<Output = T>
+        + Mul<Output = T>
         + Mul<f64, Output = T>
         + Sum<T>,
 {
     error: Vec<T>,
     control_signal: Vec<T>,
     alphas: (f64, f64, f64),
+    effort: GoodHartTerm<T>,
+    variation: GoodHartTerm<T>,
+    error_term: GoodHartTerm<T>,
 }
 
 impl<T> GoodHart<T>
@@ -29,6 +49,7 @@ where
         + Copy
         + Div<f64, Output = T>
         + Sub<Output = T>
+        + Mul<Output = T>
         + Mul<f64, Output = T>
         + Sum<T>,
 {
@@ -37,24 +58,84 @@ where
             error: Vec::new(),
             control_signal: Vec::new(),
             alphas: (alpha1, alpha2, alpha3),
+            effort: mean,
+            variation: variance,
+            error_term: mean_abs,
         }
     }
 
-    pub fn value(&self) -> T {
+    /// Replaces the effort term, computed from the control signal.
+    pub fn with_effort(mut self, effort: GoodHartTerm<T>) -> Self {
+        self.effort = effort;
+        self
+    }
+
+    /// Replaces the variation term, computed from the control signal.
+    pub fn with_variation(mut self, variation: GoodHartTerm<T>) -> Self {
+        self.variation = variation;
+        self
+    }
+
+    /// Replaces the error term, computed from the error signal.
+    pub fn with_error(mut self, error: GoodHartTerm<T>) -> Self {
+        self.error_term = error;
+        self
+    }
+
+    pub fn terms(&self) -> GoodHartTerms<T> {
         if self.error.is_empty() || self.control_signal.is_empty() {
-            return T::zero();
+            return GoodHartTerms {
+                effort: T::zero(),
+                variation: T::zero(),
+                error: T::zero(),
+            };
         }
 
-        let n = self.error.len() as f64;
+        GoodHartTerms {
+            effort: (self.effort)(&self.control_signal),
+            variation: (self.variation)(&self.control_signal),
+            error: (self.error_term)(&self.error),
+        }
+    }
 
-        let e1 = self.control_signal.iter().cloned().sum::<T>() / n;
-        let e2 = self.control_signal.iter().map(|u| *u - e1).sum::<T>() / n;
-        let e3 = self.error.iter().map(|e| e.abs()).sum::<T>() / n;
+    /// Terms scaled by their weights, so they add up to [`GoodHart::value`].
+    pub fn weighted_terms(&self) -> GoodHartTerms<T> {
+        let terms = self.terms();
+        GoodHartTerms {
+            effort: terms.effort * self.alphas.0,
+            variation: terms.variation * self.alphas.1,
+            error: terms.error * self.alphas.2,
+        }
+    }
 
-        e1 * self.alphas.0 + e2 * self.alphas.1 + e3 * self.alphas.2
+    pub fn value(&self) -> T {
+        let terms = self.weighted_terms();
+        terms.effort + terms.variation + terms.error
     }
 }
 
+fn mean<T>(samples: &[T]) -> T
+where
+    T: Copy + Div<f64, Output = T> + Sum<T>,
+{
+    samples.iter().copied().sum::<T>() / samples.len() as f64
+}
+
+fn variance<T>(samples: &[T]) -> T
+where
+    T: Copy + Div<f64, Output = T> + Sub<Output = T> + Mul<Output = T> + Sum<T>,
+{
+    let mean = mean(samples);
+    samples.iter().map(|&u| (u - mean) * (u - mean)).sum::<T>() / samples.len() as f64
+}
+
+fn mean_abs<T>(samples: &[T]) -> T
+where
+    T: Copy + Signed + Div<f64, Output = T> + Sum<T>,
+{
+    samples.iter().map(|e| e.abs()).sum::<T>() / samples.len() as f64
+}
+
 impl<T> Block for GoodHart<T>
 where
     T: Zero
@@ -62,6 +143,7 @@ where
         + Copy
         + Div<f64, Output = T>
         + Sub<Output = T>
+        + Mul<Output = T>
         + Mul<f64, Output = T>
         + Sum<T>,
 {
@@ -82,3 +164,42 @@ where
         self.control_signal.clear();
     }
 }
+
+impl<T> Metric<T> for GoodHart<T>
+where
+    T: Zero
+        + Signed
+        + Copy
+        + Div<f64, Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Mul<f64, Output = T>
+        + Sum<T>,
+{
+    fn value(&self) -> T {
+        GoodHart::value(self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_good_hart_breakdown() {
+        let mut good_hart = GoodHart::new(0.5, 1.0, 2.0);
+        for (k, sim_state) in Simulation::new(1.0, 10.0).take(4).enumerate() {
+            let u = if k % 2 == 0 { 1.0 } else { 3.0 };
+            let _ = (-1.0, u).as_signal(sim_state) >> good_hart.as_block();
+        }
+
+        let terms = good_hart.terms();
+        assert_signal_eq!(terms.effort, 2.0, 1e-12, 0.0);
+        assert_signal_eq!(terms.variation, 1.0, 1e-12, 0.0);
+        assert_signal_eq!(terms.error, 1.0, 1e-12, 0.0);
+        assert_signal_eq!(good_hart.value(), 0.5 * 2.0 + 1.0 + 2.0, 1e-12, 0.0);
+
+        let good_hart = good_hart.with_effort(|u| u.iter().map(|u| u * u).sum());
+        assert_signal_eq!(good_hart.weighted_terms().effort, 0.5 * 20.0, 1e-12, 0.0);
+    }
+}
@@ -0,0 +1,163 @@
+use crate::signal::{from_f64, to_f64};
+use crate::{block::Block, prelude::SimulationState};
+use core::{f64::consts::PI, time::Duration};
+use num_traits::Float;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Harmonics<const N: usize, T = f64>
+where
+    T: Float,
+{
+    fundamental_freq: T,
+    periods: usize,
+    dt: Duration,
+    window_len: usize,
+    n: usize,
+    coeff: [T; N],
+    s1: [T; N],
+    s2: [T; N],
+    amplitudes: [T; N],
+}
+
+impl<const N: usize, T> Harmonics<N, T>
+where
+    T: Float,
+{
+    pub fn new(fundamental_freq: T) -> Self {
+        assert!(N > 0, "Harmonics must track at least the fundamental");
+        assert!(
+            fundamental_freq > T::zero(),
+            "Fundamental frequency must be greater than zero"
+        );
+
+        Self {
+            fundamental_freq,
+            periods: 1,
+            dt: Duration::ZERO,
+            window_len: 0,
+            n: 0,
+            coeff: [T::zero(); N],
+            s1: [T::zero(); N],
+            s2: [T::zero(); N],
+            amplitudes: [T::zero(); N],
+        }
+    }
+
+    pub fn with_periods(mut self, periods: usize) -> Self {
+        assert!(periods > 0, "Window must span at least one period");
+
+        self.periods = periods;
+        self
+    }
+
+    pub fn fundamental_freq(&self) -> T {
+        self.fundamental_freq
+    }
+
+    pub fn amplitudes(&self) -> &[T; N] {
+        &self.amplitudes
+    }
+
+    /// Amplitude of the `order`-th harmonic, where order 1 is the fundamental.
+    pub fn amplitude(&self, order: usize) -> Option<T> {
+        self.amplitudes.get(order.checked_sub(1)?).copied()
+    }
+
+    pub fn thd(&self) -> T {
+        let fundamental = self.amplitudes[0];
+        if fundamental == T::zero() {
+            return T::zero();
+        }
+
+        let harmonics = self.amplitudes[1..]
+            .iter()
+            .fold(T::zero(), |sum, a| sum + *a * *a);
+        harmonics.sqrt() / fundamental
+    }
+
+    /// The window length and Goertzel coefficients are computed in `f64`,
+    /// only the running sums use `T`.
+    fn restart_window(&mut self, dt: Duration) {
+        let ts = dt.as_secs_f64();
+        let fundamental_freq = to_f64(self.fundamental_freq);
+        let period = self.periods as f64 / fundamental_freq;
+
+        self.dt = dt;
+        self.window_len = libm::round(period / ts).max(1.0) as usize;
+        self.n = 0;
+        self.s1 = [T::zero(); N];
+        self.s2 = [T::zero(); N];
+        for (k, coeff) in self.coeff.iter_mut().enumerate() {
+            let omega = 2.0 * PI * (k + 1) as f64 * fundamental_freq * ts;
+            *coeff = from_f64(2.0 * libm::cos(omega));
+        }
+    }
+}
+
+impl<const N: usize, T> Block for Harmonics<N, T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if sim_state.dt() != self.dt {
+            self.restart_window(sim_state.dt());
+        }
+
+        let x = input;
+        for k in 0..N {
+            let s0 = x + self.coeff[k] * self.s1[k] - self.s2[k];
+            self.s2[k] = self.s1[k];
+            self.s1[k] = s0;
+        }
+        self.n += 1;
+
+        if self.n == self.window_len {
+            let len = from_f64::<T>(self.window_len as f64);
+            let two = T::one() + T::one();
+            for k in 0..N {
+                let (s1, s2) = (self.s1[k], self.s2[k]);
+                let power = s1 * s1 + s2 * s2 - self.coeff[k] * s1 * s2;
+                self.amplitudes[k] = two * power.max(T::zero()).sqrt() / len;
+            }
+
+            self.n = 0;
+            self.s1 = [T::zero(); N];
+            self.s2 = [T::zero(); N];
+        }
+
+        input
+    }
+
+    fn reset(&mut self) {
+        self.dt = Duration::ZERO;
+        self.n = 0;
+        self.s1 = [T::zero(); N];
+        self.s2 = [T::zero(); N];
+        self.amplitudes = [T::zero(); N];
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_harmonics_extracts_amplitudes_and_thd() {
+        let mut harmonics = Harmonics::<3>::new(50.0).with_periods(2);
+
+        for sim_state in Simulation::new(1e-4, 0.1) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let value = libm::sin(2.0 * PI * 50.0 * t) + 0.1 * libm::sin(2.0 * PI * 150.0 * t);
+            let _ = value.as_signal(sim_state) >> harmonics.as_block();
+        }
+
+        assert!((harmonics.amplitude(1).unwrap() - 1.0).abs() < 1e-2);
+        assert!(harmonics.amplitude(2).unwrap() < 1e-2);
+        assert!((harmonics.amplitude(3).unwrap() - 0.1).abs() < 1e-2);
+        assert!((harmonics.thd() - 0.1).abs() < 1e-2);
+    }
+}
@@ -1,38 +1,52 @@
-use crate::{block::Block, prelude::SimulationState};
-use core::ops::{AddAssign, Div};
-use num_traits::{Signed, Zero};
+use crate::{
+    block::Block,
+    metrics::{Accumulation, Metric},
+    prelude::SimulationState,
+};
+use core::ops::AddAssign;
+use num_traits::Float;
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct ISE<T>
 where
-    T: Zero + Copy + Signed + Div<f64, Output = T> + AddAssign<T>,
+    T: Float + AddAssign<T>,
 {
     acc: T,
     n: usize,
+    accumulation: Accumulation,
 }
 
 impl<T> ISE<T>
 where
-    T: Zero + Copy + Signed + Div<f64, Output = T> + AddAssign<T>,
+    T: Float + AddAssign<T>,
 {
+    pub fn with_accumulation(mut self, accumulation: Accumulation) -> Self {
+        self.accumulation = accumulation;
+        self
+    }
+
     pub fn value(&self) -> T {
-        if self.n == 0 {
-            T::zero()
-        } else {
-            self.acc / self.n as f64
+        match self.accumulation {
+            Accumulation::Mean if self.n == 0 => T::zero(),
+            Accumulation::Mean => self.acc / T::from(self.n).unwrap(),
+            Accumulation::Integral | Accumulation::Sum => self.acc,
         }
     }
 }
 
 impl<T> Block for ISE<T>
 where
-    T: Zero + Copy + Signed + Div<f64, Output = T> + AddAssign<T>,
+    T: Float + AddAssign<T>,
 {
     type Input = T;
     type Output = T;
 
-    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        self.acc += input * input;
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let weight = match self.accumulation {
+            Accumulation::Integral => T::from(sim_state.dt().as_secs_f64()).unwrap(),
+            Accumulation::Mean | Accumulation::Sum => T::one(),
+        };
+        self.acc += input * input * weight;
         self.n += 1;
         input
     }
@@ -42,3 +56,12 @@ where
         self.n = 0;
     }
 }
+
+impl<T> Metric<T> for ISE<T>
+where
+    T: Float + AddAssign<T>,
+{
+    fn value(&self) -> T {
+        ISE::value(self)
+    }
+}
@@ -1,39 +1,55 @@
-use crate::{block::Block, prelude::SimulationState};
-use core::ops::{AddAssign, Div, Mul};
-use num_traits::{Signed, Zero};
+use crate::{
+    block::Block,
+    metrics::{Accumulation, Metric},
+    prelude::SimulationState,
+};
+use core::ops::AddAssign;
+use num_traits::Float;
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct ITAE<T>
 where
-    T: Zero + Copy + Signed + Div<f64, Output = T> + AddAssign<T>,
+    T: Float + AddAssign<T>,
 {
     acc: T,
     n: usize,
+    accumulation: Accumulation,
 }
 
 impl<T> ITAE<T>
 where
-    T: Zero + Copy + Signed + Div<f64, Output = T> + AddAssign<T>,
+    T: Float + AddAssign<T>,
 {
+    pub fn with_accumulation(mut self, accumulation: Accumulation) -> Self {
+        self.accumulation = accumulation;
+        self
+    }
+
     pub fn value(&self) -> T {
-        if self.n == 0 {
-            T::zero()
-        } else {
-            self.acc / self.n as f64
+        match self.accumulation {
+            Accumulation::Mean if self.n == 0 => T::zero(),
+            Accumulation::Mean => self.acc / T::from(self.n).unwrap(),
+            Accumulation::Integral | Accumulation::Sum => self.acc,
         }
     }
 }
 
 impl<T> Block for ITAE<T>
 where
-    T: Zero + Copy + Signed + Div<f64, Output = T> + AddAssign<T> + Mul<f64, Output = T>,
+    T: Float + AddAssign<T>,
 {
     type Input = T;
     type Output = T;
 
-    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
         self.n += 1;
-        self.acc += input.abs() * self.n as f64;
+        let weight = match self.accumulation {
+            Accumulation::Integral => {
+                sim_state.sim_time().as_secs_f64() * sim_state.dt().as_secs_f64()
+            }
+            Accumulation::Mean | Accumulation::Sum => self.n as f64,
+        };
+        self.acc += input.abs() * T::from(weight).unwrap();
         input
     }
 
@@ -42,3 +58,46 @@ where
         self.n = 0;
     }
 }
+
+impl<T> Metric<T> for ITAE<T>
+where
+    T: Float + AddAssign<T>,
+{
+    fn value(&self) -> T {
+        ITAE::value(self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_metrics_accumulation() {
+        let mut mean = IAE::<f64>::default();
+        let mut integral = IAE::<f64>::default().with_accumulation(Accumulation::Integral);
+        let mut sum = ISE::<f64>::default().with_accumulation(Accumulation::Sum);
+        let mut itae = ITAE::<f64>::default().with_accumulation(Accumulation::Integral);
+
+        for _ in 0..2 {
+            let mut samples = 0.0;
+            for sim_state in Simulation::new(0.01, 2.0) {
+                let error = -2.0.as_signal(sim_state);
+                let _ = error >> mean.as_block() >> integral.as_block() >> sum.as_block();
+                let _ = error >> itae.as_block();
+                samples += 1.0;
+            }
+
+            assert!((Metric::value(&mean) - 2.0).abs() < 1e-12);
+            assert!((integral.value() - 4.0).abs() < 1e-3);
+            assert!((sum.value() - 4.0 * samples).abs() < 1e-9);
+            // Integral of 2 * t over [0, 2].
+            assert!((itae.value() - 4.0).abs() < 0.05);
+
+            mean.reset();
+            integral.reset();
+            sum.reset();
+            itae.reset();
+        }
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, string::ToString, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
+use core::time::Duration;
+
+/// Time a block spent against its limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LimitStats {
+    pub saturated_time: Duration,
+    pub total_time: Duration,
+    /// Number of times the block entered saturation.
+    pub events: u32,
+    saturated: bool,
+}
+
+impl LimitStats {
+    pub fn record(&mut self, saturated: bool, dt: Duration) {
+        self.total_time += dt;
+        if saturated {
+            self.saturated_time += dt;
+            if !self.saturated {
+                self.events += 1;
+            }
+        }
+        self.saturated = saturated;
+    }
+
+    pub fn is_saturated(&self) -> bool {
+        self.saturated
+    }
+
+    /// Fraction of the run spent saturated, in [0, 1].
+    pub fn fraction(&self) -> f64 {
+        if self.total_time.is_zero() {
+            0.0
+        } else {
+            self.saturated_time.as_secs_f64() / self.total_time.as_secs_f64()
+        }
+    }
+}
+
+/// Blocks that clamp their output or state and keep track of it.
+pub trait Limited {
+    fn limit_stats(&self) -> LimitStats;
+}
+
+/// End of run summary of the time each actuator or limiter spent saturated.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LimitsReport {
+    entries: Vec<(String, LimitStats)>,
+}
+
+#[cfg(feature = "alloc")]
+impl LimitsReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: &str, block: &impl Limited) -> Self {
+        self.add(name, block);
+        self
+    }
+
+    pub fn add(&mut self, name: &str, block: &impl Limited) {
+        self.entries.push((name.to_string(), block.limit_stats()));
+    }
+
+    pub fn entries(&self) -> &[(String, LimitStats)] {
+        &self.entries
+    }
+
+    pub fn get(&self, name: &str) -> Option<&LimitStats> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, stats)| stats)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Display for LimitsReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{:<16} {:>10} {:>8} {:>7}",
+            "block", "saturated", "%", "events"
+        )?;
+        for (name, stats) in &self.entries {
+            writeln!(
+                f,
+                "{:<16} {:>9.3}s {:>7.2}% {:>7}",
+                name,
+                stats.saturated_time.as_secs_f64(),
+                stats.fraction() * 100.0,
+                stats.events
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_limits_report_aggregates_saturated_time() {
+        let mut saturation = Saturation::new(-1.0, 1.0);
+        let mut pid = PID::new(10.0, 0.0, 0.0).with_anti_windup(-5.0, 5.0);
+
+        for sim_state in Simulation::new(0.1, 2.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let input = if t < 0.95 { 2.0 } else { 0.1 };
+            let u = input.as_signal(sim_state) >> pid.as_block();
+            let _ = u >> saturation.as_block();
+        }
+
+        let report = LimitsReport::new()
+            .with("controller", &pid)
+            .with("actuator", &saturation);
+        let actuator = report.get("actuator").unwrap();
+
+        assert_eq!(actuator.events, 1);
+        // The first simulation state has a zero dt.
+        assert!((actuator.saturated_time.as_secs_f64() - 0.9).abs() < 1e-3);
+        assert_eq!(
+            report.get("controller").unwrap().saturated_time,
+            actuator.saturated_time
+        );
+        assert!(report.to_string().contains("actuator"));
+    }
+}
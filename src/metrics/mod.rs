@@ -1,5 +1,28 @@
 #[cfg(feature = "alloc")]
 pub mod good_hart;
+pub mod harmonics;
 pub mod iae;
 pub mod ise;
 pub mod itae;
+pub mod limits;
+
+use crate::block::Block;
+
+/// How an error metric folds its samples into a single value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Accumulation {
+    /// Average over the number of samples.
+    #[default]
+    Mean,
+    /// Time-weighted integral, each sample scaled by the step `dt`.
+    Integral,
+    /// Plain sum over samples, for discrete-time loops.
+    Sum,
+}
+
+/// Performance index computed from the signals fed through it as a block.
+///
+/// Metrics are reused across runs or sweeps by calling [`Block::reset`].
+pub trait Metric<T>: Block {
+    fn value(&self) -> T;
+}
@@ -0,0 +1,259 @@
+use crate::block::Block;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Position of the node in the order it was added.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node {
+    label: String,
+    input: String,
+    output: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Edge {
+    from: NodeId,
+    to: NodeId,
+    label: String,
+}
+
+/// Connection graph of a block diagram, built alongside the operator chains
+/// and rendered to Graphviz or Mermaid for documentation and debugging.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Diagram {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+impl Diagram {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add<B>(&mut self, block: &B) -> NodeId
+    where
+        B: Block + ?Sized,
+    {
+        self.add_named(short_type_name(block.name()), block)
+    }
+
+    pub fn add_named<B>(&mut self, label: impl AsRef<str>, block: &B) -> NodeId
+    where
+        B: Block + ?Sized,
+    {
+        let ports = block.ports();
+        self.nodes.push(Node {
+            label: label.as_ref().to_string(),
+            input: short_type_name(ports.input),
+            output: short_type_name(ports.output),
+        });
+
+        NodeId(self.nodes.len() - 1)
+    }
+
+    pub fn connect(&mut self, from: NodeId, to: NodeId) -> &mut Self {
+        assert!(
+            from.0 < self.nodes.len() && to.0 < self.nodes.len(),
+            "Both nodes must belong to this diagram"
+        );
+
+        let label = self.nodes[from.0].output.clone();
+        self.edges.push(Edge { from, to, label });
+        self
+    }
+
+    pub fn chain(&mut self, nodes: &[NodeId]) -> &mut Self {
+        for pair in nodes.windows(2) {
+            self.connect(pair[0], pair[1]);
+        }
+        self
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            dot += &format!("    n{} [label=\"{}\"];\n", i, escape_dot(&node.label));
+        }
+        for edge in &self.edges {
+            dot += &format!(
+                "    n{} -> n{} [label=\"{}\"];\n",
+                edge.from.0,
+                edge.to.0,
+                escape_dot(&edge.label)
+            );
+        }
+
+        dot + "}\n"
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            mermaid += &format!("    n{}[\"{}\"]\n", i, escape_mermaid(&node.label));
+        }
+        for edge in &self.edges {
+            mermaid += &format!(
+                "    n{} -->|\"{}\"| n{}\n",
+                edge.from.0,
+                escape_mermaid(&edge.label),
+                edge.to.0
+            );
+        }
+
+        mermaid
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Nodes connected into `node`, in the order they were connected.
+    pub fn predecessors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.to == node)
+            .map(|edge| edge.from)
+    }
+
+    /// Groups the nodes in stages that only depend on earlier stages, so the
+    /// blocks inside a stage can run in parallel. Returns `None` when the
+    /// diagram has a cycle; leave feedback connections out of the diagram
+    /// to analyze a loop.
+    pub fn stages(&self) -> Option<Vec<Vec<NodeId>>> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for edge in &self.edges {
+            in_degree[edge.to.0] += 1;
+        }
+
+        let mut stages = Vec::new();
+        let mut current = (0..self.nodes.len())
+            .filter(|i| in_degree[*i] == 0)
+            .collect::<Vec<_>>();
+        let mut visited = 0;
+
+        while !current.is_empty() {
+            visited += current.len();
+
+            let mut next = Vec::new();
+            for edge in self.edges.iter().filter(|e| current.contains(&e.from.0)) {
+                in_degree[edge.to.0] -= 1;
+                if in_degree[edge.to.0] == 0 {
+                    next.push(edge.to.0);
+                }
+            }
+
+            stages.push(current.into_iter().map(NodeId).collect());
+            current = next;
+        }
+
+        (visited == self.nodes.len()).then_some(stages)
+    }
+
+    /// Connections whose source output type differs from the destination
+    /// input type, as `(from, to)` labels.
+    pub fn mismatched_connections(&self) -> Vec<(String, String)> {
+        self.edges
+            .iter()
+            .filter(|edge| self.nodes[edge.to.0].input != edge.label)
+            .map(|edge| {
+                (
+                    self.nodes[edge.from.0].label.clone(),
+                    self.nodes[edge.to.0].label.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Drops module paths from every type inside a type name, e.g.
+/// `aule::tier1::pid::PID<f64>` becomes `PID<f64>`.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut segment_start = 0;
+
+    for (i, c) in name.char_indices() {
+        if matches!(c, '<' | '>' | ',' | '(' | ')' | '[' | ']' | ';' | ' ' | '&') {
+            short += last_path_segment(&name[segment_start..i]);
+            short.push(c);
+            segment_start = i + c.len_utf8();
+        }
+    }
+    short += last_path_segment(&name[segment_start..]);
+
+    short
+}
+
+fn last_path_segment(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('"', "\\\"")
+}
+
+/// Mermaid labels take entity codes, a backslash does not escape a quote.
+fn escape_mermaid(label: &str) -> String {
+    label.replace('"', "#quot;")
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::short_type_name;
+    use crate::prelude::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_diagram_renders_dot_and_mermaid() {
+        let step = Step::new(1.0f64);
+        let pid = PID::new(1.0f64, 0.1, 0.0);
+        let pwm = Pwm::new(1e3);
+        let terminator = Terminator::<(f64, f64)>::new();
+
+        let mut diagram = Diagram::new();
+        let step = diagram.add(&step);
+        let pid = diagram.add(&pid);
+        let pwm = diagram.add(&pwm);
+        let sink = diagram.add_named("sink", &terminator);
+        diagram.chain(&[step, pid, pwm, sink]);
+
+        assert_eq!(
+            diagram.to_dot(),
+            "digraph {\n    rankdir=LR;\n    node [shape=box];\n\
+             \x20   n0 [label=\"Step<f64>\"];\n    n1 [label=\"PID<f64>\"];\n\
+             \x20   n2 [label=\"PWM\"];\n    n3 [label=\"sink\"];\n\
+             \x20   n0 -> n1 [label=\"f64\"];\n    n1 -> n2 [label=\"f64\"];\n\
+             \x20   n2 -> n3 [label=\"f64\"];\n}\n"
+        );
+        assert!(diagram.to_mermaid().contains("n1 -->|\"f64\"| n2\n"));
+
+        let mut quoted = Diagram::new();
+        quoted.add_named("say \"hi\"", &terminator);
+        assert!(quoted.to_mermaid().contains("n0[\"say #quot;hi#quot;\"]\n"));
+        assert!(quoted.to_dot().contains("n0 [label=\"say \\\"hi\\\"\"];\n"));
+        assert_eq!(
+            diagram.mismatched_connections(),
+            [("PWM".to_string(), "sink".to_string())]
+        );
+        assert_eq!(
+            short_type_name("[alloc::vec::Vec<f64>; 2]"),
+            "[Vec<f64>; 2]"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Info,
+    ModeChange,
+    Fault,
+    ConstraintHit,
+}
+
+impl Display for EventKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                EventKind::Info => "info",
+                EventKind::ModeChange => "mode_change",
+                EventKind::Fault => "fault",
+                EventKind::ConstraintHit => "constraint_hit",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub time: Duration,
+    pub kind: EventKind,
+    pub message: String,
+}
+
+/// Timestamped log of discrete, non-numeric events such as mode changes,
+/// faults and constraint hits. As a block it records every `Some` event it
+/// receives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventLog {
+    events: Vec<Event>,
+}
+
+impl EventLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, sim_state: SimulationState, kind: EventKind, message: impl AsRef<str>) {
+        self.events.push(Event {
+            time: sim_state.sim_time(),
+            kind,
+            message: message.as_ref().to_string(),
+        });
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn of_kind(&self, kind: EventKind) -> impl Iterator<Item = &Event> {
+        self.events.iter().filter(move |event| event.kind == kind)
+    }
+
+    /// Number of events recorded at or before `time`.
+    pub fn count_until(&self, time: Duration) -> usize {
+        self.events.partition_point(|event| event.time <= time)
+    }
+
+    /// Events as CSV lines with a `t,kind,message` header.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("t,kind,message\n");
+        for event in &self.events {
+            csv += &format!(
+                "{},{},\"{}\"\n",
+                event.time.as_secs_f64(),
+                event.kind,
+                event.message.replace('"', "\"\"")
+            );
+        }
+        csv
+    }
+}
+
+impl Block for EventLog {
+    type Input = Option<(EventKind, String)>;
+    type Output = ();
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if let Some((kind, message)) = input {
+            self.push(sim_state, kind, message);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.events.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::ToString;
+    use core::time::Duration;
+
+    #[test]
+    fn test_event_log_records_events_as_csv() {
+        let mut log = EventLog::new();
+        let mut saturation = Saturation::new(-1.0, 1.0);
+
+        for sim_state in Simulation::new(0.5, 2.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            saturation.block(t, sim_state);
+
+            let event = (saturation.limit_stats().events == 1
+                && saturation.limit_stats().is_saturated())
+            .then(|| {
+                (
+                    EventKind::ConstraintHit,
+                    "actuator \"u\" saturated".to_string(),
+                )
+            });
+            let _ = event.as_signal(sim_state) >> log.as_block();
+        }
+
+        assert_eq!(log.of_kind(EventKind::ConstraintHit).count(), 2);
+        assert_eq!(log.count_until(Duration::from_millis(1400)), 0);
+        assert_eq!(log.count_until(Duration::from_millis(1500)), 1);
+        assert_eq!(log.count_until(Duration::from_secs(2)), 2);
+        assert_eq!(
+            log.to_csv().lines().nth(1),
+            Some("1.5,constraint_hit,\"actuator \"\"u\"\" saturated\"")
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use crate::Error;
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+const MAGIC: &[u8] = b"\x89MCAP0\r\n";
+
+const OP_HEADER: u8 = 0x01;
+const OP_FOOTER: u8 = 0x02;
+const OP_SCHEMA: u8 = 0x03;
+const OP_CHANNEL: u8 = 0x04;
+const OP_MESSAGE: u8 = 0x05;
+const OP_DATA_END: u8 = 0x0f;
+
+#[derive(Debug)]
+struct Channel {
+    names: Vec<String>,
+    sequence: u32,
+}
+
+/// Writes signals to an MCAP file that Foxglove and the ROS 2 tooling can
+/// replay next to real robot logs.
+///
+/// Each channel is a topic of JSON messages `{"field": value, ...}` with a
+/// matching JSON schema, stamped with the simulation time. As a block, it
+/// logs its input on the topic given to [`McapWriter::new`]; more topics can
+/// be added with [`McapWriter::add_channel`] and fed with
+/// [`McapWriter::write`]. The file is finished on [`McapWriter::finish`] or
+/// on drop; once finished, the block passes its input through unlogged.
+#[derive(Debug)]
+pub struct McapWriter<const N: usize> {
+    filename: String,
+    topic: String,
+    variable_names: [String; N],
+    file: Option<BufWriter<File>>,
+    channels: Vec<Channel>,
+    /// Schemas written so far; MCAP reserves schema id `0` for "no schema".
+    schemas: u16,
+    error: Option<Error>,
+}
+
+impl<const N: usize> McapWriter<N> {
+    /// Writer of `filename`, see [`try_new`](Self::try_new). As with
+    /// [`Writter::new`](crate::prelude::Writter::new), a file that cannot be
+    /// created panics under `strict-panic`, and otherwise is logged and
+    /// leaves a block that drops its input and keeps the cause in
+    /// [`error`](Self::error). Use `try_new` to handle it.
+    #[track_caller]
+    pub fn new(filename: &str, topic: &str, variable_names: [&str; N]) -> Self {
+        crate::error::or_fallback(Self::try_new(filename, topic, variable_names), |error| {
+            Self {
+                filename: filename.to_string(),
+                topic: topic.to_string(),
+                variable_names: variable_names.map(|s| s.to_string()),
+                file: None,
+                channels: Vec::new(),
+                schemas: 0,
+                error: Some(error),
+            }
+        })
+    }
+
+    /// Creates `filename`, and its directory, with the block's own channel
+    /// on `topic`.
+    pub fn try_new(filename: &str, topic: &str, variable_names: [&str; N]) -> Result<Self, Error> {
+        let mut writer = Self {
+            filename: filename.to_string(),
+            topic: topic.to_string(),
+            variable_names: variable_names.map(|s| s.to_string()),
+            file: None,
+            channels: Vec::new(),
+            schemas: 0,
+            error: None,
+        };
+
+        writer.create()?;
+        Ok(writer)
+    }
+
+    /// Why the file could not be created, for a writer built by
+    /// [`new`](Self::new) without `strict-panic`.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Adds a topic and returns its channel id, `0` being the block's own.
+    pub fn add_channel(&mut self, topic: &str, variable_names: &[&str]) -> io::Result<u16> {
+        let id = self.channels.len() as u16;
+        let schema_id = self.schemas + 1;
+        let names = variable_names
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+
+        let properties = names
+            .iter()
+            .map(|name| format!("\"{}\":{{\"type\":\"number\"}}", escape(name)))
+            .collect::<Vec<_>>()
+            .join(",");
+        let schema = format!("{{\"type\":\"object\",\"properties\":{{{}}}}}", properties);
+
+        let mut content = Vec::new();
+        content.extend_from_slice(&schema_id.to_le_bytes());
+        put_str(&mut content, topic);
+        put_str(&mut content, "jsonschema");
+        put_bytes(&mut content, schema.as_bytes());
+        self.record(OP_SCHEMA, &content)?;
+        self.schemas = schema_id;
+
+        let mut content = Vec::new();
+        content.extend_from_slice(&id.to_le_bytes());
+        content.extend_from_slice(&schema_id.to_le_bytes());
+        put_str(&mut content, topic);
+        put_str(&mut content, "json");
+        content.extend_from_slice(&0u32.to_le_bytes());
+        self.record(OP_CHANNEL, &content)?;
+
+        self.channels.push(Channel { names, sequence: 0 });
+        Ok(id)
+    }
+
+    /// Logs `values` on `channel` at the time of `sim_state`.
+    pub fn write(
+        &mut self,
+        channel: u16,
+        sim_state: SimulationState,
+        values: &[f64],
+    ) -> io::Result<()> {
+        let entry = self
+            .channels
+            .get_mut(channel as usize)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Unknown MCAP channel"))?;
+        assert_eq!(
+            values.len(),
+            entry.names.len(),
+            "Channel {} must have {} values",
+            channel,
+            entry.names.len()
+        );
+
+        let fields = entry
+            .names
+            .iter()
+            .zip(values)
+            .map(|(name, value)| format!("\"{}\":{}", escape(name), json_number(*value)))
+            .collect::<Vec<_>>()
+            .join(",");
+        let message = format!("{{{}}}", fields);
+        let time = sim_state.sim_time().as_nanos() as u64;
+
+        let mut content = Vec::new();
+        content.extend_from_slice(&channel.to_le_bytes());
+        content.extend_from_slice(&entry.sequence.to_le_bytes());
+        content.extend_from_slice(&time.to_le_bytes());
+        content.extend_from_slice(&time.to_le_bytes());
+        content.extend_from_slice(message.as_bytes());
+        entry.sequence = entry.sequence.wrapping_add(1);
+
+        self.record(OP_MESSAGE, &content)
+    }
+
+    /// Writes the data end, footer and closing magic, and flushes the file.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.file.is_none() {
+            return Ok(());
+        }
+
+        self.record(OP_DATA_END, &0u32.to_le_bytes())?;
+
+        let mut footer = Vec::new();
+        footer.extend_from_slice(&0u64.to_le_bytes());
+        footer.extend_from_slice(&0u64.to_le_bytes());
+        footer.extend_from_slice(&0u32.to_le_bytes());
+        self.record(OP_FOOTER, &footer)?;
+
+        let mut file = self.file.take().expect("BUG: file checked above");
+        file.write_all(MAGIC)?;
+        file.flush()
+    }
+
+    fn create(&mut self) -> io::Result<()> {
+        let path = Path::new(&self.filename);
+        fs::create_dir_all(path.parent().unwrap_or(Path::new(""))).ok();
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(MAGIC)?;
+        self.file = Some(file);
+        self.channels.clear();
+        self.schemas = 0;
+
+        let mut header = Vec::new();
+        put_str(&mut header, "");
+        put_str(&mut header, "aule");
+        self.record(OP_HEADER, &header)?;
+
+        let topic = self.topic.clone();
+        let names = self.variable_names.clone();
+        let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+        self.add_channel(&topic, &names)?;
+        Ok(())
+    }
+
+    fn record(&mut self, opcode: u8, content: &[u8]) -> io::Result<()> {
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| io::Error::other("MCAP file already finished"))?;
+        file.write_all(&[opcode])?;
+        file.write_all(&(content.len() as u64).to_le_bytes())?;
+        file.write_all(content)
+    }
+}
+
+impl<const N: usize> Block for McapWriter<N> {
+    type Input = [f64; N];
+    type Output = [f64; N];
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if self.error.is_some() || self.file.is_none() {
+            return input;
+        }
+
+        self.write(0, sim_state, &input)
+            .expect("Failed to write MCAP message");
+        input
+    }
+
+    fn reset(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+
+        self.file = None;
+        self.create().expect("Failed to reset MCAP writer");
+    }
+}
+
+impl<const N: usize> Drop for McapWriter<N> {
+    fn drop(&mut self) {
+        self.finish().ok();
+    }
+}
+
+fn put_str(buffer: &mut Vec<u8>, value: &str) {
+    put_bytes(buffer, value.as_bytes());
+}
+
+fn put_bytes(buffer: &mut Vec<u8>, value: &[u8]) {
+    buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(value);
+}
+
+fn escape(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// JSON has no NaN or infinity; they are written as `null`.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+
+    fn records(bytes: &[u8]) -> Vec<(u8, &[u8])> {
+        let mut records = Vec::new();
+        let mut rest = &bytes[8..bytes.len() - 8];
+        while !rest.is_empty() {
+            let length = u64::from_le_bytes(rest[1..9].try_into().unwrap()) as usize;
+            records.push((rest[0], &rest[9..9 + length]));
+            rest = &rest[9 + length..];
+        }
+        records
+    }
+
+    #[test]
+    fn test_mcap_writer_layout() {
+        let path = std::env::temp_dir().join("aule_mcap_writer_layout.mcap");
+        let path = path.to_str().unwrap();
+
+        let mut writer = McapWriter::new(path, "/plant/state", ["position", "velocity"]);
+        let error = writer.add_channel("/controller/error", &["e"]).unwrap();
+        for sim_state in Simulation::new(0.5, 1.0) {
+            let _ = [1.0, f64::NAN].as_signal(sim_state) >> writer.as_block();
+            writer.write(error, sim_state, &[0.25]).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let bytes = std::fs::read(path).unwrap();
+        assert_eq!(&bytes[..8], b"\x89MCAP0\r\n");
+        assert_eq!(&bytes[bytes.len() - 8..], b"\x89MCAP0\r\n");
+
+        let records = records(&bytes);
+        let opcodes = records.iter().map(|(op, _)| *op).collect::<Vec<_>>();
+        assert_eq!(opcodes, [1, 3, 4, 3, 4, 5, 5, 5, 5, 0x0f, 2]);
+
+        // Schema ids start at 1, channel ids at 0, each channel pointing at
+        // its own schema.
+        let id = |record: &[u8], at: usize| u16::from_le_bytes([record[at], record[at + 1]]);
+        assert_eq!((id(records[1].1, 0), id(records[3].1, 0)), (1, 2));
+        assert_eq!((id(records[2].1, 0), id(records[2].1, 2)), (0, 1));
+        assert_eq!((id(records[4].1, 0), id(records[4].1, 2)), (1, 2));
+
+        let (_, last) = records[8];
+        assert_eq!(u16::from_le_bytes([last[0], last[1]]), 1);
+        assert_eq!(
+            u64::from_le_bytes(last[6..14].try_into().unwrap()),
+            1_000_000_000
+        );
+        assert_eq!(&last[22..], b"{\"e\":0.25}");
+        assert_eq!(&records[7].1[22..], b"{\"position\":1,\"velocity\":null}");
+    }
+
+    #[test]
+    fn test_mcap_writer_passes_through_after_finish() {
+        let path = std::env::temp_dir().join("aule_mcap_writer_finished.mcap");
+        let path = path.to_str().unwrap();
+
+        let mut writer = McapWriter::new(path, "/plant/state", ["position"]);
+        writer.finish().unwrap();
+        let finished = std::fs::read(path).unwrap();
+
+        for sim_state in Simulation::new(0.5, 1.0) {
+            assert_eq!(writer.block([2.0], sim_state), [2.0]);
+        }
+        drop(writer);
+
+        assert_eq!(std::fs::read(path).unwrap(), finished);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::prelude::SimulationState;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::time::Duration;
+use std::{fs, io, path::Path};
+
+/// How a channel without a sample at a row's time is filled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Fill {
+    /// Repeats the channel's last sample, NaN before its first one.
+    #[default]
+    Forward,
+    Nan,
+}
+
+/// Collects channels logged at different rates and writes them as one wide
+/// CSV, with a row per distinct sample time and a `t` column as
+/// [`Writter`](crate::prelude::Writter) uses.
+///
+/// Times closer than the tolerance, 1 us by default, share a row, which
+/// absorbs the rounding of independently accumulated `dt`s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogMerger {
+    names: Vec<String>,
+    samples: Vec<Vec<(Duration, f64)>>,
+    fill: Fill,
+    tolerance: Duration,
+}
+
+impl LogMerger {
+    pub fn new(names: &[&str]) -> Self {
+        Self {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            samples: names.iter().map(|_| Vec::new()).collect(),
+            fill: Fill::default(),
+            tolerance: Duration::from_micros(1),
+        }
+    }
+
+    pub fn with_fill(mut self, fill: Fill) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Adds a channel and returns its index.
+    pub fn add_channel(&mut self, name: &str) -> usize {
+        self.names.push(name.to_string());
+        self.samples.push(Vec::new());
+        self.names.len() - 1
+    }
+
+    pub fn channel(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    /// Records `value` of `channel` at the time of `sim_state`.
+    pub fn record(&mut self, channel: usize, sim_state: SimulationState, value: f64) {
+        self.record_at(channel, sim_state.sim_time(), value);
+    }
+
+    pub fn record_at(&mut self, channel: usize, time: Duration, value: f64) {
+        assert!(
+            channel < self.samples.len(),
+            "Channel {} does not exist",
+            channel
+        );
+        self.samples[channel].push((time, value));
+    }
+
+    /// Imports every column of a CSV written by `Writter` as a channel.
+    pub fn add_csv(&mut self, path: impl AsRef<Path>) -> Result<(), csv::Error> {
+        let mut reader = csv::Reader::from_path(path)?;
+        let headers = reader.headers()?.clone();
+        let channels = headers
+            .iter()
+            .skip(1)
+            .map(|name| self.add_channel(name))
+            .collect::<Vec<_>>();
+
+        for record in reader.records() {
+            let record = record?;
+            let Some(time) = record.get(0).and_then(|t| t.parse::<f64>().ok()) else {
+                continue;
+            };
+            for (column, channel) in channels.iter().enumerate() {
+                if let Some(value) = record.get(column + 1).and_then(|v| v.parse::<f64>().ok()) {
+                    self.record_at(*channel, Duration::from_secs_f64(time), value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Time-aligned rows: each time and the value of every channel.
+    pub fn rows(&self) -> Vec<(Duration, Vec<f64>)> {
+        let mut all = self
+            .samples
+            .iter()
+            .enumerate()
+            .flat_map(|(channel, samples)| {
+                samples
+                    .iter()
+                    .map(move |&(time, value)| (time, channel, value))
+            })
+            .collect::<Vec<_>>();
+        all.sort_by_key(|&(time, channel, _)| (time, channel));
+
+        let mut rows: Vec<(Duration, Vec<f64>)> = Vec::new();
+        let mut last = alloc::vec![f64::NAN; self.names.len()];
+        let mut index = 0;
+        while index < all.len() {
+            let row_time = all[index].0;
+            let mut row = match self.fill {
+                Fill::Forward => last.clone(),
+                Fill::Nan => alloc::vec![f64::NAN; self.names.len()],
+            };
+
+            while index < all.len() && all[index].0 - row_time <= self.tolerance {
+                let (_, channel, value) = all[index];
+                row[channel] = value;
+                last[channel] = value;
+                index += 1;
+            }
+
+            rows.push((row_time, row));
+        }
+
+        rows
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("t,{}\n", self.names.join(","));
+        for (time, values) in self.rows() {
+            let values = values.iter().map(f64::to_string).collect::<Vec<_>>();
+            csv += &format!("{},{}\n", time.as_secs_f32(), values.join(","));
+        }
+        csv
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        let path = path.as_ref();
+        fs::create_dir_all(path.parent().unwrap_or(Path::new(""))).ok();
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_log_merger_aligns_rates() {
+        let mut merger = LogMerger::new(&["fast", "slow"]);
+        for (k, sim_state) in Simulation::new(0.1, 0.6).enumerate() {
+            merger.record(0, sim_state, k as f64);
+        }
+        for sim_state in Simulation::new(0.3, 0.6) {
+            merger.record(1, sim_state, 10.0 * sim_state.sim_time().as_secs_f64());
+        }
+
+        let rows = merger.rows();
+        let slow = rows.iter().map(|(_, values)| values[1]).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 6);
+        assert!(slow[0].is_nan() && slow[1].is_nan());
+        assert!((slow[2] - 3.0).abs() < 1e-6 && (slow[4] - 3.0).abs() < 1e-6);
+        assert!((slow[5] - 6.0).abs() < 1e-6);
+
+        let csv = merger.with_fill(Fill::Nan).to_csv();
+        assert_eq!(csv.lines().next(), Some("t,fast,slow"));
+        assert!(csv.lines().nth(4).unwrap().ends_with(",3,NaN"));
+    }
+}
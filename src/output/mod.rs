@@ -1,4 +1,10 @@
+pub mod diagram;
+pub mod event_log;
 pub(crate) mod magmar;
+pub mod mcap;
+pub mod merger;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
 pub mod plotter;
 pub mod printer;
 pub mod writer;
@@ -0,0 +1,166 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::string::{String, ToString};
+use core::time::Duration;
+use rumqttc::{Client, Connection};
+pub use rumqttc::{MqttOptions, QoS};
+
+/// Delivery counters of an [`MqttMonitor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MqttStats {
+    /// Messages queued for the broker.
+    pub published: u64,
+    /// Messages lost because the outgoing queue was full.
+    pub dropped: u64,
+}
+
+/// Pass-through monitor publishing each channel of its input to its own MQTT
+/// topic, as a plain number payload, e.g. for Telegraf's `mqtt_consumer`
+/// with `data_format = "value"`.
+///
+/// Publishing never blocks the loop: messages go to a bounded queue drained
+/// by a background thread that also keeps reconnecting to the broker, and
+/// are dropped when the queue is full. Decimation publishes one step in
+/// every `n`, keeping multi-hour runs within the dashboard's budget.
+pub struct MqttMonitor<const N: usize> {
+    client: Client,
+    topics: [String; N],
+    qos: QoS,
+    decimation: u32,
+    steps: u32,
+    stats: MqttStats,
+}
+
+impl<const N: usize> MqttMonitor<N> {
+    pub fn new(options: MqttOptions, topics: [&str; N]) -> Self {
+        let (client, connection) = Client::new(options, 64 * N.max(1));
+        std::thread::spawn(move || Self::drive(connection));
+
+        Self::from_client(client, topics)
+    }
+
+    /// Monitor publishing through `client`, whose connection is driven
+    /// elsewhere.
+    fn from_client(client: Client, topics: [&str; N]) -> Self {
+        Self {
+            client,
+            topics: topics.map(|topic| topic.to_string()),
+            qos: QoS::AtMostOnce,
+            decimation: 1,
+            steps: 0,
+            stats: MqttStats::default(),
+        }
+    }
+
+    /// Monitor connecting to `host:port` as `client_id`.
+    pub fn connect(host: &str, port: u16, client_id: &str, topics: [&str; N]) -> Self {
+        Self::new(MqttOptions::new(client_id, host, port), topics)
+    }
+
+    pub fn with_qos(mut self, qos: QoS) -> Self {
+        self.qos = qos;
+        self
+    }
+
+    /// Publishes one step in every `decimation`.
+    pub fn with_decimation(mut self, decimation: u32) -> Self {
+        assert!(decimation > 0, "Decimation must be greater than zero");
+        self.decimation = decimation;
+        self
+    }
+
+    pub fn stats(&self) -> MqttStats {
+        self.stats
+    }
+
+    fn drive(mut connection: Connection) {
+        for event in connection.iter() {
+            if event.is_err() {
+                std::thread::sleep(Duration::from_secs(1));
+            }
+        }
+    }
+}
+
+impl<const N: usize> Block for MqttMonitor<N> {
+    type Input = [f64; N];
+    type Output = [f64; N];
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        if self.steps.is_multiple_of(self.decimation) {
+            for (topic, value) in self.topics.iter().zip(input.iter()) {
+                match self
+                    .client
+                    .try_publish(topic.as_str(), self.qos, false, value.to_string())
+                {
+                    Ok(()) => self.stats.published += 1,
+                    Err(_) => self.stats.dropped += 1,
+                }
+            }
+        }
+        self.steps = self.steps.wrapping_add(1);
+
+        input
+    }
+
+    fn reset(&mut self) {
+        self.steps = 0;
+        self.stats = MqttStats::default();
+    }
+}
+
+impl<const N: usize> Drop for MqttMonitor<N> {
+    fn drop(&mut self) {
+        self.client.try_disconnect().ok();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+    use rumqttc::{Client, Request};
+
+    #[test]
+    fn test_mqtt_monitor_queues_decimated_payloads() {
+        // The connection is never polled, so no socket is opened and the
+        // queue of 4 requests fills up.
+        let options = MqttOptions::new("aule-test", "127.0.0.1", 1883);
+        let (client, mut connection) = Client::new(options, 4);
+        let mut monitor = MqttMonitor::from_client(client, ["rig/y", "rig/u"])
+            .with_qos(QoS::AtLeastOnce)
+            .with_decimation(4);
+
+        for sim_state in Simulation::new(0.01, 10.0).take(12) {
+            let _ = [1.5, 2.0].as_signal(sim_state) >> monitor.as_block();
+        }
+
+        assert_eq!(
+            monitor.stats(),
+            MqttStats {
+                published: 4,
+                dropped: 2
+            }
+        );
+
+        connection.eventloop.clean();
+        let queued = connection
+            .eventloop
+            .pending
+            .iter()
+            .map(|request| match request {
+                Request::Publish(publish) => (publish.topic.as_str(), &publish.payload[..]),
+                other => panic!("Unexpected request {:?}", other),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            queued,
+            [
+                ("rig/y", &b"1.5"[..]),
+                ("rig/u", b"2"),
+                ("rig/y", b"1.5"),
+                ("rig/u", b"2"),
+            ]
+        );
+    }
+}
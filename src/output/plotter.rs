@@ -1,4 +1,5 @@
 use crate::block::Block;
+use crate::output::event_log::EventLog;
 use crate::output::magmar::Magmar;
 use crate::prelude::SimulationState;
 use crate::signal::AsSignal;
@@ -118,6 +119,16 @@ where
     }
 
     pub fn display(&mut self) {
+        self.show(None);
+    }
+
+    /// Displays the data with an extra `Events` line counting the events of
+    /// `log`, so each event is marked by a unit step at its time.
+    pub fn display_with_events(&mut self, log: &EventLog) {
+        self.show(Some(log));
+    }
+
+    fn show(&mut self, events: Option<&EventLog>) {
         self.magmar = Some(Magmar::new(&self.title, self.is_light));
 
         if let Some(magmar) = &mut self.magmar {
@@ -126,6 +137,7 @@ where
                 self.variable_names
                     .iter()
                     .map(|s| s.to_string())
+                    .chain(events.map(|_| "Events".to_string()))
                     .collect::<Vec<_>>()
                     .join(",")
             ));
@@ -135,13 +147,14 @@ where
             }
 
             for signals in &self.data {
-                let time = &signals[0].sim_state.sim_time().as_secs_f32();
-                let mut data = vec![*time as f64];
+                let time = signals[0].sim_state.sim_time();
+                let mut data = vec![time.as_secs_f64()];
                 data.extend(
                     signals
                         .iter()
                         .map(|s| s.value.to_string().parse::<f64>().unwrap_or(0.0)),
                 );
+                data.extend(events.map(|log| log.count_until(time) as f64));
 
                 magmar.send_data(&data);
             }
@@ -178,6 +191,16 @@ where
     }
 
     pub fn display(&mut self) {
+        self.show(None);
+    }
+
+    /// Displays the data with an extra `Events` line counting the events of
+    /// `log`, so each event is marked by a unit step at its time.
+    pub fn display_with_events(&mut self, log: &EventLog) {
+        self.show(Some(log));
+    }
+
+    fn show(&mut self, events: Option<&EventLog>) {
         self.magmar = Some(Magmar::new(&self.title, self.is_light));
 
         if let Some(magmar) = &mut self.magmar {
@@ -186,6 +209,7 @@ where
                 self.variable_names
                     .iter()
                     .map(|s| s.to_string())
+                    .chain(events.map(|_| "Events".to_string()))
                     .collect::<Vec<_>>()
                     .join(",")
             ));
@@ -195,13 +219,14 @@ where
             }
 
             for signals in &self.data {
-                let time = &signals[0].sim_state.sim_time().as_secs_f32();
-                let mut data = vec![*time as f64];
+                let time = signals[0].sim_state.sim_time();
+                let mut data = vec![time.as_secs_f64()];
                 data.extend(
                     signals
                         .iter()
                         .map(|s| s.value.to_string().parse::<f64>().unwrap_or(0.0)),
                 );
+                data.extend(events.map(|log| log.count_until(time) as f64));
 
                 magmar.send_data(&data);
             }
@@ -1,5 +1,6 @@
+use crate::Error;
 use crate::block::Block;
-use crate::prelude::SimulationState;
+use crate::prelude::{EventLog, SimulationState};
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
@@ -20,6 +21,7 @@ where
 {
     filename: String,
     variable_names: [String; N],
+    error: Option<Error>,
     _marker: PhantomData<T>,
 }
 
@@ -27,18 +29,31 @@ impl<const N: usize, T> Writter<N, T>
 where
     T: Display,
 {
+    /// Writer of `filename`, see [`try_new`](Self::try_new). A file that
+    /// cannot be created panics under `strict-panic`; otherwise it is logged
+    /// and the writer drops its input, keeping the cause in
+    /// [`error`](Self::error). Use `try_new` to handle it.
+    #[track_caller]
     pub fn new(filename: &str, variable_names: [&str; N]) -> Self {
+        crate::error::or_fallback(Self::try_new(filename, variable_names), |error| Self {
+            filename: filename.to_string(),
+            variable_names: variable_names.map(|s| s.to_string()),
+            error: Some(error),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Creates `filename`, and its directory, with the CSV header.
+    pub fn try_new(filename: &str, variable_names: [&str; N]) -> Result<Self, Error> {
         let writer = Self {
             filename: filename.to_string(),
             variable_names: variable_names.map(|s| s.to_string()),
+            error: None,
             _marker: PhantomData,
         };
 
-        writer
-            .write_header(&variable_names)
-            .expect("Failed to write header");
-
-        writer
+        writer.write_header(&variable_names)?;
+        Ok(writer)
     }
 
     fn write_header(&self, variable_names: &[&str]) -> Result<(), io::Error> {
@@ -52,6 +67,26 @@ where
             .write_all(("t,".to_string() + &variable_names.join(",") + "\n").as_bytes())
     }
 
+    /// Why the file could not be created, for a writer built by
+    /// [`new`](Self::new) without `strict-panic`.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Writes `events` next to the data file, as `<name>_events.csv`, and
+    /// returns its path.
+    pub fn write_events(&self, events: &EventLog) -> Result<String, io::Error> {
+        let path = Path::new(&self.filename);
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("data");
+        let events_path = path
+            .with_file_name(format!("{}_events.csv", stem))
+            .to_string_lossy()
+            .to_string();
+
+        fs::write(&events_path, events.to_csv())?;
+        Ok(events_path)
+    }
+
     fn append_line(&self, content: String) -> Result<(), io::Error> {
         OpenOptions::new()
             .append(true)
@@ -68,6 +103,10 @@ where
     type Output = [T; N];
 
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if self.error.is_some() {
+            return input;
+        }
+
         let values: Vec<String> = input.iter().map(|v| v.to_string()).collect();
         let line = format!(
             "{},{}\n",
@@ -80,6 +119,10 @@ where
     }
 
     fn reset(&mut self) {
+        if self.error.is_some() {
+            return;
+        }
+
         std::fs::remove_file(&self.filename).ok();
 
         let variable_names = self
@@ -0,0 +1,234 @@
+use crate::prelude::PID;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::{fs, io, path::Path, sync::Mutex};
+
+/// Parameter shared between a running loop and whoever tunes it. Reads and
+/// writes are lock-free, so the loop can poll it every step.
+#[derive(Debug, Clone)]
+pub struct TunableParam {
+    value: Arc<AtomicU64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamError {
+    Unknown(String),
+    InvalidValue(String),
+    /// Line of a config file that is not `name = value`.
+    Syntax(usize),
+}
+
+/// Blocks whose parameters can be read and written by name.
+pub trait Tunable {
+    /// Names and current values of the parameters.
+    fn params(&self) -> Vec<(&'static str, f64)>;
+
+    /// Sets `name` to `value`, returning `false` if there is no such
+    /// parameter.
+    fn set_param(&mut self, name: &str, value: f64) -> bool;
+}
+
+/// Registry of named parameters, e.g. `"pid1.kp"`, giving sweeps, tuning
+/// UIs and config files uniform access to them. Clones share the same
+/// registry, so it can be handed to other threads.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    entries: Arc<Mutex<Vec<(String, TunableParam)>>>,
+}
+
+impl TunableParam {
+    pub fn new(value: f64) -> Self {
+        Self {
+            value: Arc::new(AtomicU64::new(value.to_bits())),
+        }
+    }
+
+    pub fn get(&self) -> f64 {
+        f64::from_bits(self.value.load(Ordering::Relaxed))
+    }
+
+    pub fn set(&self, value: f64) {
+        self.value.store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Copies the current value into `target`, e.g. `kp.apply(pid.kp_mut())`.
+    pub fn apply(&self, target: &mut f64) {
+        *target = self.get();
+    }
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `name` with its initial value and returns the handle the
+    /// loop reads it through. Registering a name again hands back the
+    /// existing handle with its current value, ignoring `initial`, so every
+    /// handle of a name stays connected.
+    pub fn register(&self, name: &str, initial: f64) -> TunableParam {
+        assert!(
+            !name.is_empty() && !name.contains(char::is_whitespace) && !name.contains('='),
+            "Parameter names must be non-empty, without whitespace or '='"
+        );
+
+        let mut entries = self.lock();
+        if let Some((_, existing)) = entries.iter().find(|(n, _)| n == name) {
+            return existing.clone();
+        }
+
+        let param = TunableParam::new(initial);
+        entries.push((name.to_string(), param.clone()));
+        param
+    }
+
+    /// Registers every parameter of `block` as `<prefix>.<name>`.
+    pub fn register_block(&self, prefix: &str, block: &impl Tunable) {
+        for (name, value) in block.params() {
+            self.register(&format!("{}.{}", prefix, name), value);
+        }
+    }
+
+    /// Writes the registered `<prefix>.<name>` values into `block`.
+    pub fn apply_block(&self, prefix: &str, block: &mut impl Tunable) {
+        let entries = self.lock();
+        for (name, _) in block.params() {
+            let full_name = format!("{}.{}", prefix, name);
+            if let Some((_, param)) = entries.iter().find(|(n, _)| *n == full_name) {
+                block.set_param(name, param.get());
+            }
+        }
+    }
+
+    pub fn handle(&self, name: &str) -> Option<TunableParam> {
+        self.lock()
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, param)| param.clone())
+    }
+
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.handle(name).map(|param| param.get())
+    }
+
+    pub fn set(&self, name: &str, value: f64) -> Result<(), ParamError> {
+        if !value.is_finite() {
+            return Err(ParamError::InvalidValue(value.to_string()));
+        }
+        let param = self
+            .handle(name)
+            .ok_or_else(|| ParamError::Unknown(name.to_string()))?;
+        param.set(value);
+        Ok(())
+    }
+
+    /// Names and values, in registration order.
+    pub fn entries(&self) -> Vec<(String, f64)> {
+        self.lock()
+            .iter()
+            .map(|(name, param)| (name.clone(), param.get()))
+            .collect()
+    }
+
+    /// `name = value` lines.
+    pub fn to_config(&self) -> String {
+        self.entries()
+            .iter()
+            .map(|(name, value)| format!("{} = {}\n", name, value))
+            .collect()
+    }
+
+    /// Sets every parameter listed in `config`, written as by
+    /// [`Params::to_config`]. Blank lines and `#` comments are skipped.
+    pub fn load_config(&self, config: &str) -> Result<(), ParamError> {
+        for (index, line) in config.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (name, value) = line.split_once('=').ok_or(ParamError::Syntax(index + 1))?;
+            let value = value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| ParamError::InvalidValue(value.trim().to_string()))?;
+            self.set(name.trim(), value)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        fs::write(path, self.to_config())
+    }
+
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        let config = fs::read_to_string(path)?;
+        self.load_config(&config)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", e)))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(String, TunableParam)>> {
+        self.entries.lock().expect("Parameter registry poisoned")
+    }
+}
+
+impl Tunable for PID<f64> {
+    fn params(&self) -> Vec<(&'static str, f64)> {
+        let (kp, ki, kd) = self.gains();
+        alloc::vec![("kp", kp), ("ki", ki), ("kd", kd)]
+    }
+
+    fn set_param(&mut self, name: &str, value: f64) -> bool {
+        match name {
+            "kp" => *self.kp_mut() = value,
+            "ki" => *self.ki_mut() = value,
+            "kd" => *self.kd_mut() = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_params_round_trip_through_config() {
+        let params = Params::new();
+        let mut pid = PID::new(2.0, 0.5, 0.0);
+        params.register_block("pid1", &pid);
+        let gain = params.register("plant.gain", 3.0);
+
+        assert_eq!(params.get("pid1.kp"), Some(2.0));
+        params.set("pid1.kp", 4.0).unwrap();
+        params.apply_block("pid1", &mut pid);
+        assert_eq!(pid.params()[0], ("kp", 4.0));
+
+        let config = params.to_config();
+        assert_eq!(
+            config,
+            "pid1.kp = 4\npid1.ki = 0.5\npid1.kd = 0\nplant.gain = 3\n"
+        );
+
+        params
+            .load_config("# tuned\npid1.kd = 0.1\nplant.gain = 2.5\n")
+            .unwrap();
+        assert_eq!(gain.get(), 2.5);
+        let again = params.register("plant.gain", 0.0);
+        assert_eq!(again.get(), 2.5);
+        again.set(1.5);
+        assert_eq!(gain.get(), 1.5);
+        assert_eq!(params.get("pid1.kd"), Some(0.1));
+        assert_eq!(
+            params.load_config("pid2.kp = 1"),
+            Err(ParamError::Unknown("pid2.kp".to_string()))
+        );
+        assert_eq!(params.load_config("pid1.kp"), Err(ParamError::Syntax(1)));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::block::Block;
+use crate::prelude::{SimulationState, Solver, StateEstimation};
+use core::{fmt::Debug, marker::PhantomData};
+use faer::{Mat, mat};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConverterModel {
+    /// Input is the duty cycle in [0, 1] and the state follows the
+    /// state-space averaged equations in continuous conduction mode.
+    #[default]
+    Averaged,
+    /// Input is the switch state (0 or 1), usually driven by a `Pwm` block.
+    /// The freewheeling diode blocks reverse inductor current, so
+    /// discontinuous conduction is reproduced.
+    Switched,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Topology {
+    Buck,
+    Boost,
+}
+
+#[derive(Debug, Clone)]
+pub struct Converter<I>
+where
+    I: Solver<f64> + Debug,
+{
+    topology: Topology,
+    model: ConverterModel,
+    vin: f64,
+    l: f64,
+    c: f64,
+    r: f64,
+    state: Mat<f64>,
+    current_input: f64,
+    last_output: Option<(f64, f64)>,
+    _marker: PhantomData<I>,
+}
+
+impl<I> Converter<I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn new(topology: Topology, vin: f64, l: f64, c: f64, r: f64) -> Self {
+        assert!(l > 0.0, "Inductance must be greater than zero");
+        assert!(c > 0.0, "Capacitance must be greater than zero");
+        assert!(r > 0.0, "Load resistance must be greater than zero");
+
+        Self {
+            topology,
+            model: ConverterModel::default(),
+            vin,
+            l,
+            c,
+            r,
+            state: Mat::zeros(2, 1),
+            current_input: 0.0,
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn buck(vin: f64, l: f64, c: f64, r: f64, _integrator: I) -> Self {
+        Self::new(Topology::Buck, vin, l, c, r)
+    }
+
+    pub fn boost(vin: f64, l: f64, c: f64, r: f64, _integrator: I) -> Self {
+        Self::new(Topology::Boost, vin, l, c, r)
+    }
+
+    pub fn with_model(mut self, model: ConverterModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    pub fn set_load(&mut self, r: f64) {
+        assert!(r > 0.0, "Load resistance must be greater than zero");
+        self.r = r;
+    }
+
+    pub fn inductor_current(&self) -> f64 {
+        self.state[(0, 0)]
+    }
+
+    pub fn capacitor_voltage(&self) -> f64 {
+        self.state[(1, 0)]
+    }
+}
+
+impl<I> StateEstimation<f64> for Converter<I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn estimate(&self, state: Mat<f64>) -> Mat<f64> {
+        let il = state[(0, 0)];
+        let vc = state[(1, 0)];
+        let u = self.current_input;
+
+        let (dil, dvc) = match self.topology {
+            Topology::Buck => ((u * self.vin - vc) / self.l, (il - vc / self.r) / self.c),
+            Topology::Boost => (
+                (self.vin - (1.0 - u) * vc) / self.l,
+                ((1.0 - u) * il - vc / self.r) / self.c,
+            ),
+        };
+
+        let diode_blocking = self.model == ConverterModel::Switched && il <= 0.0 && dil < 0.0;
+        let dil = if diode_blocking { 0.0 } else { dil };
+
+        mat![[dil], [dvc]]
+    }
+}
+
+impl<I> Block for Converter<I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = f64;
+    type Output = (f64, f64);
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.current_input = match self.model {
+            ConverterModel::Averaged => input.clamp(0.0, 1.0),
+            ConverterModel::Switched if input >= 0.5 => 1.0,
+            ConverterModel::Switched => 0.0,
+        };
+        let state = core::mem::replace(&mut self.state, Mat::new());
+        self.state = I::integrate(state, sim_state.dt(), self);
+
+        if self.model == ConverterModel::Switched && self.state[(0, 0)] < 0.0 {
+            self.state[(0, 0)] = 0.0;
+        }
+
+        let output = (self.inductor_current(), self.capacitor_voltage());
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state.fill(0.0);
+        self.current_input = 0.0;
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_buck_averaged_settles_at_duty_times_vin() {
+        let mut buck = Converter::buck(12.0, 100e-6, 100e-6, 5.0, RK4);
+
+        let (_, vc) = Simulation::new(1e-6, 0.02)
+            .map(|sim_state| buck.block(0.5, sim_state))
+            .last()
+            .unwrap();
+
+        assert!((vc - 6.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_boost_switched_tracks_averaged_model() {
+        let mut pwm = Pwm::new(20e3);
+        let mut averaged = Converter::boost(12.0, 100e-6, 220e-6, 10.0, RK4);
+        let mut switched =
+            Converter::boost(12.0, 100e-6, 220e-6, 10.0, RK4).with_model(ConverterModel::Switched);
+
+        let mut outputs = ((0.0, 0.0), (0.0, 0.0));
+        for sim_state in Simulation::new(5e-7, 0.02) {
+            let duty = 0.5.as_signal(sim_state);
+            let gate = duty >> pwm.as_block();
+
+            outputs = (
+                averaged.block(duty.value, sim_state),
+                switched.block(gate.value, sim_state),
+            );
+        }
+
+        let (averaged, switched) = outputs;
+        assert!((averaged.1 - 24.0).abs() < 0.5);
+        assert!((switched.1 - averaged.1).abs() < 0.5);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::block::Block;
+use crate::prelude::{SimulationState, Solver, StateEstimation};
+use core::{fmt::Debug, marker::PhantomData};
+use faer::Mat;
+
+/// Exothermic continuous stirred-tank reactor with a first-order reaction
+/// `A -> B` and Arrhenius kinetics `k(T) = k0 exp(-E / (R T))`:
+///
+/// `dCa/dt = q / V (Caf - Ca) - k(T) Ca`
+///
+/// `dT/dt = q / V (Tf - T) + (-dH) / (rho Cp) k(T) Ca + UA / (V rho Cp) (Tc - T)`
+///
+/// The input is the coolant temperature `Tc` and the output `[Ca, T]`. With
+/// a cooling jacket, `Tc` becomes a third state driven by the coolant inlet
+/// temperature instead. Time is in the unit of the rate parameters.
+#[derive(Debug, Clone)]
+pub struct CSTR<I>
+where
+    I: Solver<f64> + Debug,
+{
+    params: CstrParams,
+    jacket: Option<(f64, f64)>,
+    initial_state: Mat<f64>,
+    state: Mat<f64>,
+    coolant: f64,
+    last_output: Option<[f64; 2]>,
+    _marker: PhantomData<I>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CstrParams {
+    /// Feed flow `q`.
+    pub flow: f64,
+    /// Reactor volume `V`.
+    pub volume: f64,
+    /// Feed concentration `Caf`.
+    pub feed_concentration: f64,
+    /// Feed temperature `Tf`.
+    pub feed_temperature: f64,
+    /// Density times heat capacity, `rho Cp`.
+    pub heat_capacity: f64,
+    /// Heat of reaction `dH`, negative for an exothermic reaction.
+    pub reaction_enthalpy: f64,
+    /// Activation temperature `E / R`.
+    pub activation_temperature: f64,
+    /// Pre-exponential factor `k0`.
+    pub rate_constant: f64,
+    /// Heat transfer coefficient times area, `UA`.
+    pub heat_transfer: f64,
+}
+
+impl CstrParams {
+    /// Classic benchmark (Seborg et al.), in L, mol, K, J and minutes. At
+    /// `Tc = 300 K` it has an open-loop unstable steady state at
+    /// `Ca = 0.5 mol/L`, `T = 350 K`.
+    pub fn benchmark() -> Self {
+        Self {
+            flow: 100.0,
+            volume: 100.0,
+            feed_concentration: 1.0,
+            feed_temperature: 350.0,
+            heat_capacity: 1000.0 * 0.239,
+            reaction_enthalpy: -5e4,
+            activation_temperature: 8750.0,
+            rate_constant: 7.2e10,
+            heat_transfer: 5e4,
+        }
+    }
+}
+
+impl<I> CSTR<I>
+where
+    I: Solver<f64> + Debug,
+{
+    pub fn new(params: CstrParams, concentration: f64, temperature: f64, _integrator: I) -> Self {
+        assert!(
+            params.flow > 0.0 && params.volume > 0.0 && params.heat_capacity > 0.0,
+            "Flow, volume and heat capacity must be greater than zero"
+        );
+        assert!(temperature > 0.0, "Temperature must be absolute");
+
+        let initial_state = Mat::from_fn(2, 1, |i, _| [concentration, temperature][i]);
+        Self {
+            params,
+            jacket: None,
+            state: initial_state.clone(),
+            initial_state,
+            coolant: temperature,
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Adds a cooling jacket of heat capacity `rho_j Cp_j V_j` fed with
+    /// `flow_ratio = Fj / Vj` per time unit, starting at `temperature`. The
+    /// input becomes the coolant inlet temperature.
+    pub fn with_jacket(mut self, heat_capacity: f64, flow_ratio: f64, temperature: f64) -> Self {
+        assert!(
+            heat_capacity > 0.0 && flow_ratio > 0.0,
+            "Jacket heat capacity and flow must be greater than zero"
+        );
+        self.jacket = Some((heat_capacity, flow_ratio));
+
+        let (ca, t) = (self.initial_state[(0, 0)], self.initial_state[(1, 0)]);
+        self.initial_state = Mat::from_fn(3, 1, |i, _| [ca, t, temperature][i]);
+        self.state = self.initial_state.clone();
+        self
+    }
+
+    pub fn params(&self) -> &CstrParams {
+        &self.params
+    }
+
+    pub fn concentration(&self) -> f64 {
+        self.state[(0, 0)]
+    }
+
+    pub fn temperature(&self) -> f64 {
+        self.state[(1, 0)]
+    }
+
+    /// Jacket temperature, if the reactor has a jacket.
+    pub fn jacket_temperature(&self) -> Option<f64> {
+        self.jacket.map(|_| self.state[(2, 0)])
+    }
+
+    /// Reaction rate constant `k(T)`.
+    pub fn rate(&self, temperature: f64) -> f64 {
+        self.params.rate_constant * libm::exp(-self.params.activation_temperature / temperature)
+    }
+}
+
+impl<I> StateEstimation<f64> for CSTR<I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn estimate(&self, state: Mat<f64>) -> Mat<f64> {
+        let p = &self.params;
+        let (ca, t) = (state[(0, 0)], state[(1, 0)]);
+        let tc = match self.jacket {
+            Some(_) => state[(2, 0)],
+            None => self.coolant,
+        };
+
+        let dilution = p.flow / p.volume;
+        let reaction = self.rate(t) * ca;
+        let cooling = p.heat_transfer * (tc - t);
+
+        let mut derivative = Mat::zeros(state.nrows(), 1);
+        derivative[(0, 0)] = dilution * (p.feed_concentration - ca) - reaction;
+        derivative[(1, 0)] = dilution * (p.feed_temperature - t)
+            + (-p.reaction_enthalpy) / p.heat_capacity * reaction
+            + cooling / (p.volume * p.heat_capacity);
+        if let Some((heat_capacity, flow_ratio)) = self.jacket {
+            derivative[(2, 0)] = flow_ratio * (self.coolant - tc) - cooling / heat_capacity;
+        }
+        derivative
+    }
+}
+
+impl<I> Block for CSTR<I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = f64;
+    type Output = [f64; 2];
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.coolant = input;
+        let state = core::mem::replace(&mut self.state, Mat::new());
+        self.state = I::integrate(state, sim_state.dt(), self);
+
+        let output = [self.concentration(), self.temperature()];
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    fn settle(coolant: f64, temperature: f64) -> (f64, f64) {
+        let mut reactor = CSTR::new(CstrParams::benchmark(), 0.5, temperature, RK4);
+        for sim_state in Simulation::new(0.001, 20.0) {
+            let _ = coolant.as_signal(sim_state) >> reactor.as_block();
+        }
+        (reactor.concentration(), reactor.temperature())
+    }
+
+    #[test]
+    fn test_cstr_benchmark_operating_points() {
+        let mut reactor = CSTR::new(CstrParams::benchmark(), 0.5, 350.0, RK4);
+        let sim_state = Simulation::new(0.01, 1.0).next().unwrap();
+        let [ca, t] = (300.0.as_signal(sim_state) >> reactor.as_block()).value;
+        assert!((ca - 0.5).abs() < 1e-4 && (t - 350.0).abs() < 1e-2);
+
+        // The nominal point is open-loop unstable: a 1 K upset drifts to the
+        // low-conversion steady state.
+        let (ca, t) = settle(300.0, 351.0);
+        assert!((ca - 0.877).abs() < 1e-2 && (t - 324.5).abs() < 0.5);
+
+        // Warmer coolant ignites the reaction.
+        let (ca, t) = settle(310.0, 350.0);
+        assert!(ca < 0.1 && t > 380.0, "ca = {}, t = {}", ca, t);
+    }
+
+    #[test]
+    fn test_cstr_jacket_lags_coolant_inlet() {
+        let mut reactor =
+            CSTR::new(CstrParams::benchmark(), 0.5, 350.0, RK4).with_jacket(1e5, 10.0, 300.0);
+
+        for sim_state in Simulation::new(0.001, 0.05) {
+            let _ = 290.0.as_signal(sim_state) >> reactor.as_block();
+        }
+        let jacket = reactor.jacket_temperature().unwrap();
+        assert!(jacket < 300.0 && jacket > 290.0);
+        assert!(reactor.temperature() < 350.0);
+    }
+}
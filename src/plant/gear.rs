@@ -0,0 +1,218 @@
+use crate::block::Block;
+use crate::prelude::{SimulationState, Solver, StateEstimation};
+use core::{fmt::Debug, marker::PhantomData};
+use faer::Mat;
+
+/// Two motors driving one load through a gear mesh with backlash, the plant
+/// of dual-motor anti-backlash control. Everything is reflected to the load
+/// side.
+///
+/// Each mesh transmits `k dz(theta_m - theta_l) + c (w_m - w_l)` only while
+/// its teeth are in contact, `dz` being a dead zone of half-width
+/// `backlash`. The inputs are the two motor torques and the output is the
+/// load position.
+#[derive(Debug, Clone)]
+pub struct DualMotorGear<I>
+where
+    I: Solver<f64> + Debug,
+{
+    motor_inertia: f64,
+    motor_damping: f64,
+    load_inertia: f64,
+    load_damping: f64,
+    stiffness: f64,
+    mesh_damping: f64,
+    backlash: f64,
+    state: Mat<f64>,
+    torque: [f64; 2],
+    last_output: Option<f64>,
+    _marker: PhantomData<I>,
+}
+
+impl<I> DualMotorGear<I>
+where
+    I: Solver<f64> + Debug,
+{
+    pub fn new(
+        motor_inertia: f64,
+        load_inertia: f64,
+        stiffness: f64,
+        backlash: f64,
+        _integrator: I,
+    ) -> Self {
+        assert!(
+            motor_inertia > 0.0 && load_inertia > 0.0,
+            "Inertias must be greater than zero"
+        );
+        assert!(stiffness > 0.0, "Mesh stiffness must be greater than zero");
+        assert!(backlash >= 0.0, "Backlash must not be negative");
+
+        Self {
+            motor_inertia,
+            motor_damping: 0.0,
+            load_inertia,
+            load_damping: 0.0,
+            stiffness,
+            mesh_damping: 0.0,
+            backlash,
+            state: Mat::zeros(6, 1),
+            torque: [0.0; 2],
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Viscous friction of each motor and of the load.
+    pub fn with_damping(mut self, motor_damping: f64, load_damping: f64) -> Self {
+        self.motor_damping = motor_damping;
+        self.load_damping = load_damping;
+        self
+    }
+
+    pub fn with_mesh_damping(mut self, mesh_damping: f64) -> Self {
+        self.mesh_damping = mesh_damping;
+        self
+    }
+
+    pub fn backlash(&self) -> f64 {
+        self.backlash
+    }
+
+    pub fn motor_positions(&self) -> [f64; 2] {
+        [self.state[(0, 0)], self.state[(2, 0)]]
+    }
+
+    pub fn motor_velocities(&self) -> [f64; 2] {
+        [self.state[(1, 0)], self.state[(3, 0)]]
+    }
+
+    pub fn load_position(&self) -> f64 {
+        self.state[(4, 0)]
+    }
+
+    pub fn load_velocity(&self) -> f64 {
+        self.state[(5, 0)]
+    }
+
+    /// Torques the two meshes currently transmit to the load.
+    pub fn mesh_torques(&self) -> [f64; 2] {
+        [0, 1].map(|i| self.mesh_torque(&self.state, i))
+    }
+
+    fn mesh_torque(&self, state: &Mat<f64>, motor: usize) -> f64 {
+        let twist = state[(2 * motor, 0)] - state[(4, 0)];
+        let slip = state[(2 * motor + 1, 0)] - state[(5, 0)];
+
+        let compression = if twist > self.backlash {
+            twist - self.backlash
+        } else if twist < -self.backlash {
+            twist + self.backlash
+        } else {
+            return 0.0;
+        };
+        self.stiffness * compression + self.mesh_damping * slip
+    }
+}
+
+impl<I> StateEstimation<f64> for DualMotorGear<I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn estimate(&self, state: Mat<f64>) -> Mat<f64> {
+        let mesh = [0, 1].map(|i| self.mesh_torque(&state, i));
+        let motor_acc = |i: usize| {
+            (self.torque[i] - self.motor_damping * state[(2 * i + 1, 0)] - mesh[i])
+                / self.motor_inertia
+        };
+        let load_acc = (mesh[0] + mesh[1] - self.load_damping * state[(5, 0)]) / self.load_inertia;
+
+        let derivative = [
+            state[(1, 0)],
+            motor_acc(0),
+            state[(3, 0)],
+            motor_acc(1),
+            state[(5, 0)],
+            load_acc,
+        ];
+        Mat::from_fn(6, 1, |i, _| derivative[i])
+    }
+}
+
+impl<I> Block for DualMotorGear<I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = [f64; 2];
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.torque = input;
+        let state = core::mem::replace(&mut self.state, Mat::new());
+        self.state = I::integrate(state, sim_state.dt(), self);
+
+        let output = self.load_position();
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state.fill(0.0);
+        self.torque = [0.0; 2];
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    fn gear() -> DualMotorGear<RK4> {
+        DualMotorGear::new(0.01, 0.1, 100.0, 0.05, RK4)
+            .with_damping(0.01, 0.05)
+            .with_mesh_damping(0.5)
+    }
+
+    #[test]
+    fn test_backlash_holds_load_until_play_is_taken_up() {
+        let mut plant = gear();
+
+        let mut contact = None;
+        for sim_state in Simulation::new(0.001, 1.0) {
+            let _ = [0.05, 0.0].as_signal(sim_state) >> plant.as_block();
+            let [theta_1, _] = plant.motor_positions();
+            if theta_1 < plant.backlash() {
+                assert_eq!(plant.load_position(), 0.0);
+            } else if contact.is_none() {
+                contact = Some(sim_state.sim_time());
+            }
+        }
+
+        assert!(contact.is_some());
+        assert!(plant.load_position() > 0.0);
+    }
+
+    #[test]
+    fn test_torque_bias_preloads_both_flanks() {
+        let mut plant = gear();
+        let mut bias = TorqueBias::new(0.5).with_release(2.0);
+
+        for sim_state in Simulation::new(0.001, 10.0) {
+            let torques = 0.0.as_signal(sim_state) >> bias.as_block();
+            let _ = torques >> plant.as_block();
+        }
+
+        // Each motor sits on its own flank, twisted by bias / stiffness.
+        let [theta_1, theta_2] = plant.motor_positions();
+        let load = plant.load_position();
+        assert!((theta_1 - load - (0.05 + 0.005)).abs() < 1e-4);
+        assert!((theta_2 - load + (0.05 + 0.005)).abs() < 1e-4);
+        assert!(plant.load_velocity().abs() < 1e-6);
+
+        assert_eq!(bias.bias(1.0), 0.25);
+        assert_eq!(bias.bias(3.0), 0.0);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::block::Block;
+use crate::prelude::{SimulationState, Solver, StateEstimation};
+use core::{fmt::Debug, marker::PhantomData};
+use faer::Mat;
+
+/// Double-acting cylinder driven by a four-way critically lapped valve.
+///
+/// The states are the piston position and velocity and the two chamber
+/// pressures. The valve flows follow the orifice equation
+/// `Q = Kv u sqrt(dp)`, and each chamber pressure obeys
+/// `dp/dt = beta / V (Q -/+ A v)`, `V` growing with the stroke. The fluid
+/// stiffness makes the model stiff: the hydraulic natural frequency
+/// `sqrt(beta A^2 / (V m))` bounds the usable `dt` of explicit solvers.
+///
+/// The input is the normalized spool position in `[-1, 1]` and the output
+/// the piston position, held within `[0, stroke]` by end stops.
+#[derive(Debug, Clone)]
+pub struct HydraulicCylinder<I>
+where
+    I: Solver<f64> + Debug,
+{
+    mass: f64,
+    area_a: f64,
+    area_b: f64,
+    stroke: f64,
+    bulk_modulus: f64,
+    valve_gain: f64,
+    supply_pressure: f64,
+    tank_pressure: f64,
+    dead_volumes: (f64, f64),
+    damping: f64,
+    load_force: f64,
+    initial_state: Mat<f64>,
+    state: Mat<f64>,
+    spool: f64,
+    last_output: Option<f64>,
+    _marker: PhantomData<I>,
+}
+
+/// `sign(dp) sqrt(|dp|)`, so the flow reverses instead of going undefined
+/// when a chamber pressure crosses the supply or tank pressure.
+fn orifice(dp: f64) -> f64 {
+    libm::copysign(libm::sqrt(dp.abs()), dp)
+}
+
+impl<I> HydraulicCylinder<I>
+where
+    I: Solver<f64> + Debug,
+{
+    /// `area_a` is the piston side and `area_b` the rod side. `valve_gain`
+    /// is the flow per unit spool opening and square-root pressure drop.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mass: f64,
+        area_a: f64,
+        area_b: f64,
+        stroke: f64,
+        bulk_modulus: f64,
+        valve_gain: f64,
+        supply_pressure: f64,
+        tank_pressure: f64,
+        _integrator: I,
+    ) -> Self {
+        assert!(mass > 0.0, "Mass must be greater than zero");
+        assert!(
+            area_a > 0.0 && area_b > 0.0,
+            "Piston areas must be greater than zero"
+        );
+        assert!(stroke > 0.0, "Stroke must be greater than zero");
+        assert!(bulk_modulus > 0.0, "Bulk modulus must be greater than zero");
+        assert!(
+            supply_pressure > tank_pressure,
+            "Supply pressure must be greater than tank pressure"
+        );
+
+        let mid = (supply_pressure + tank_pressure) / 2.0;
+        let initial_state = Mat::from_fn(4, 1, |i, _| [0.0, 0.0, mid, mid][i]);
+
+        Self {
+            mass,
+            area_a,
+            area_b,
+            stroke,
+            bulk_modulus,
+            valve_gain,
+            supply_pressure,
+            tank_pressure,
+            // Hoses and valve ports, 10% of the swept volume by default.
+            dead_volumes: (0.1 * area_a * stroke, 0.1 * area_b * stroke),
+            damping: 0.0,
+            load_force: 0.0,
+            state: initial_state.clone(),
+            initial_state,
+            spool: 0.0,
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_dead_volumes(mut self, volume_a: f64, volume_b: f64) -> Self {
+        assert!(
+            volume_a > 0.0 && volume_b > 0.0,
+            "Dead volumes must be greater than zero"
+        );
+        self.dead_volumes = (volume_a, volume_b);
+        self
+    }
+
+    /// Viscous friction of the piston.
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Constant external force opposing extension.
+    pub fn with_load_force(mut self, force: f64) -> Self {
+        self.load_force = force;
+        self
+    }
+
+    pub fn with_initial_state(mut self, position: f64, pressure_a: f64, pressure_b: f64) -> Self {
+        assert!(
+            (0.0..=self.stroke).contains(&position),
+            "Initial position must be within the stroke"
+        );
+        self.initial_state = Mat::from_fn(4, 1, |i, _| [position, 0.0, pressure_a, pressure_b][i]);
+        self.state = self.initial_state.clone();
+        self
+    }
+
+    pub fn position(&self) -> f64 {
+        self.state[(0, 0)]
+    }
+
+    pub fn velocity(&self) -> f64 {
+        self.state[(1, 0)]
+    }
+
+    /// Chamber pressures `(p_a, p_b)`.
+    pub fn pressures(&self) -> (f64, f64) {
+        (self.state[(2, 0)], self.state[(3, 0)])
+    }
+
+    /// Valve flows into chamber A and into chamber B.
+    fn flows(&self, p_a: f64, p_b: f64) -> (f64, f64) {
+        let u = self.spool;
+        let (ps, pt) = (self.supply_pressure, self.tank_pressure);
+        let k = self.valve_gain * u.abs();
+
+        if u >= 0.0 {
+            (k * orifice(ps - p_a), -k * orifice(p_b - pt))
+        } else {
+            (-k * orifice(p_a - pt), k * orifice(ps - p_b))
+        }
+    }
+}
+
+impl<I> StateEstimation<f64> for HydraulicCylinder<I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn estimate(&self, state: Mat<f64>) -> Mat<f64> {
+        let (x, v) = (state[(0, 0)], state[(1, 0)]);
+        let (p_a, p_b) = (state[(2, 0)], state[(3, 0)]);
+        let (q_a, q_b) = self.flows(p_a, p_b);
+
+        let volume_a = self.dead_volumes.0 + self.area_a * x;
+        let volume_b = self.dead_volumes.1 + self.area_b * (self.stroke - x);
+        let force = p_a * self.area_a - p_b * self.area_b - self.damping * v - self.load_force;
+
+        let derivative = [
+            v,
+            force / self.mass,
+            self.bulk_modulus / volume_a * (q_a - self.area_a * v),
+            self.bulk_modulus / volume_b * (q_b + self.area_b * v),
+        ];
+        Mat::from_fn(4, 1, |i, _| derivative[i])
+    }
+}
+
+impl<I> Block for HydraulicCylinder<I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = f64;
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.spool = input.clamp(-1.0, 1.0);
+        let state = core::mem::replace(&mut self.state, Mat::new());
+        self.state = I::integrate(state, sim_state.dt(), self);
+
+        // End stops: the piston stops dead against them.
+        let x = self.state[(0, 0)];
+        if !(0.0..=self.stroke).contains(&x) {
+            self.state[(0, 0)] = x.clamp(0.0, self.stroke);
+            self.state[(1, 0)] = 0.0;
+        }
+
+        let output = self.position();
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial_state.clone();
+        self.spool = 0.0;
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    fn cylinder() -> HydraulicCylinder<RK4> {
+        // 10 kg, 10 cm^2 symmetric piston, 0.5 m stroke, 200 bar supply.
+        HydraulicCylinder::new(10.0, 1e-3, 1e-3, 0.5, 1.4e9, 1e-6, 200e5, 0.0, RK4)
+            .with_initial_state(0.1, 100e5, 100e5)
+            .with_damping(100.0)
+    }
+
+    #[test]
+    fn test_hydraulic_cylinder_extends_at_valve_flow_speed() {
+        let mut cylinder = cylinder();
+
+        for sim_state in Simulation::new(1e-5, 0.1) {
+            let _ = 0.5.as_signal(sim_state) >> cylinder.as_block();
+        }
+
+        // Unloaded, each orifice drops half the supply pressure.
+        let expected = 1e-6 * 0.5 * libm::sqrt(100e5) / 1e-3;
+        assert!((cylinder.velocity() - expected).abs() < 0.05 * expected);
+        let (p_a, p_b) = cylinder.pressures();
+        assert!(p_a > p_b && p_a < 200e5 && p_b > 0.0);
+    }
+
+    #[test]
+    fn test_hydraulic_cylinder_holds_and_stops_at_end() {
+        let mut cylinder = cylinder();
+        for sim_state in Simulation::new(1e-5, 0.05) {
+            let _ = 0.0.as_signal(sim_state) >> cylinder.as_block();
+        }
+        assert!((cylinder.position() - 0.1).abs() < 1e-9);
+
+        let mut cylinder = cylinder.with_initial_state(0.49, 100e5, 100e5);
+        for sim_state in Simulation::new(1e-5, 0.2) {
+            let _ = 1.0.as_signal(sim_state) >> cylinder.as_block();
+        }
+        assert_eq!(cylinder.position(), 0.5);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod converter;
+pub mod cstr;
+pub mod gear;
+pub mod hydraulic;
+pub mod thermal;
@@ -0,0 +1,192 @@
+use crate::block::Block;
+use crate::prelude::{SimulationState, Solver, StateEstimation};
+use core::{fmt::Debug, marker::PhantomData};
+use faer::Mat;
+
+/// Chain of `N` thermal zones. Each zone exchanges heat with the ambient and
+/// with its neighbours, and is driven by a heater whose power is constrained
+/// to `[0, max_power]`.
+#[derive(Debug, Clone)]
+pub struct ThermalChamber<const N: usize, I>
+where
+    I: Solver<f64> + Debug,
+{
+    capacitance: [f64; N],
+    r_ambient: [f64; N],
+    r_coupling: f64,
+    max_power: [f64; N],
+    ambient: f64,
+    state: Mat<f64>,
+    power: [f64; N],
+    last_output: Option<[f64; N]>,
+    _marker: PhantomData<I>,
+}
+
+impl<const N: usize, I> ThermalChamber<N, I>
+where
+    I: Solver<f64> + Debug,
+{
+    pub fn new(
+        capacitance: f64,
+        r_ambient: f64,
+        r_coupling: f64,
+        max_power: f64,
+        ambient: f64,
+        _integrator: I,
+    ) -> Self {
+        assert!(N > 0, "Thermal chamber must have at least one zone");
+        assert!(capacitance > 0.0, "Capacitance must be greater than zero");
+        assert!(
+            r_ambient > 0.0 && r_coupling > 0.0,
+            "Thermal resistances must be greater than zero"
+        );
+        assert!(max_power >= 0.0, "Maximum power must not be negative");
+
+        Self {
+            capacitance: [capacitance; N],
+            r_ambient: [r_ambient; N],
+            r_coupling,
+            max_power: [max_power; N],
+            ambient,
+            state: Mat::from_fn(N, 1, |_, _| ambient),
+            power: [0.0; N],
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_zone(mut self, zone: usize, capacitance: f64, r_ambient: f64) -> Self {
+        assert!(zone < N, "Zone index must be lower than {}", N);
+        assert!(capacitance > 0.0, "Capacitance must be greater than zero");
+        assert!(
+            r_ambient > 0.0,
+            "Thermal resistance must be greater than zero"
+        );
+
+        self.capacitance[zone] = capacitance;
+        self.r_ambient[zone] = r_ambient;
+        self
+    }
+
+    pub fn with_max_power(mut self, zone: usize, max_power: f64) -> Self {
+        assert!(zone < N, "Zone index must be lower than {}", N);
+        assert!(max_power >= 0.0, "Maximum power must not be negative");
+
+        self.max_power[zone] = max_power;
+        self
+    }
+
+    pub fn power_limits(&self) -> [(f64, f64); N] {
+        self.max_power.map(|max| (0.0, max))
+    }
+
+    pub fn applied_power(&self) -> &[f64; N] {
+        &self.power
+    }
+
+    pub fn ambient(&self) -> f64 {
+        self.ambient
+    }
+
+    pub fn temperatures(&self) -> [f64; N] {
+        core::array::from_fn(|i| self.state[(i, 0)])
+    }
+
+    /// Continuous-time model `dx/dt = A x + B u + e * ambient`, returned as
+    /// `(A, B, e)`. The model is linear, so it is exact inside the power
+    /// limits.
+    pub fn linearized(&self) -> (Mat<f64>, Mat<f64>, Mat<f64>) {
+        let mut a = Mat::zeros(N, N);
+        for i in 0..N {
+            let c = self.capacitance[i];
+            a[(i, i)] = -1.0 / (self.r_ambient[i] * c);
+
+            for j in [i.wrapping_sub(1), i + 1] {
+                if j < N {
+                    a[(i, i)] -= 1.0 / (self.r_coupling * c);
+                    a[(i, j)] = 1.0 / (self.r_coupling * c);
+                }
+            }
+        }
+
+        let b = Mat::from_fn(N, N, |i, j| {
+            if i == j {
+                1.0 / self.capacitance[i]
+            } else {
+                0.0
+            }
+        });
+        let e = Mat::from_fn(N, 1, |i, _| 1.0 / (self.r_ambient[i] * self.capacitance[i]));
+
+        (a, b, e)
+    }
+}
+
+impl<const N: usize, I> StateEstimation<f64> for ThermalChamber<N, I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn estimate(&self, state: Mat<f64>) -> Mat<f64> {
+        Mat::from_fn(N, 1, |i, _| {
+            let t = state[(i, 0)];
+            let mut heat = self.power[i] - (t - self.ambient) / self.r_ambient[i];
+
+            if i > 0 {
+                heat -= (t - state[(i - 1, 0)]) / self.r_coupling;
+            }
+            if i + 1 < N {
+                heat -= (t - state[(i + 1, 0)]) / self.r_coupling;
+            }
+
+            heat / self.capacitance[i]
+        })
+    }
+}
+
+impl<const N: usize, I> Block for ThermalChamber<N, I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = [f64; N];
+    type Output = [f64; N];
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.power = core::array::from_fn(|i| input[i].clamp(0.0, self.max_power[i]));
+        let state = core::mem::replace(&mut self.state, Mat::new());
+        self.state = I::integrate(state, sim_state.dt(), self);
+
+        let output = self.temperatures();
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state.fill(self.ambient);
+        self.power = [0.0; N];
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_thermal_chamber_reaches_steady_state_and_clamps_power() {
+        let mut chamber = ThermalChamber::<2, RK4>::new(10.0, 2.0, 1.0, 5.0, 20.0, RK4);
+
+        for sim_state in Simulation::new(0.1, 400.0) {
+            let _ = [8.0, -1.0].as_signal(sim_state) >> chamber.as_block();
+        }
+
+        // Power clamps to [5, 0]: zone 2 only gains heat through the coupling.
+        let [t1, t2] = chamber.temperatures();
+        assert!((t1 - 26.0).abs() < 1e-3, "t1 = {}", t1);
+        assert!((t2 - 24.0).abs() < 1e-3, "t2 = {}", t2);
+        assert_eq!(chamber.applied_power(), &[5.0, 0.0]);
+    }
+}
@@ -6,20 +6,22 @@ use core::{
     fmt::Display,
     ops::{Add, AddAssign, Mul, Neg, Sub},
 };
-use faer::{Mat, traits::ComplexField};
+use faer::{Mat, c64, traits::ComplexField};
 use num_traits::Float;
 
+/// Dense polynomial shared by the `s`, `z` and `z^-1` polynomial wrappers,
+/// with coefficients stored highest degree first.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Polynomial<T>
 where
-    T: Float + Default + ComplexField,
+    T: Float,
 {
     coeff: Vec<T>,
 }
 
 impl<T> Polynomial<T>
 where
-    T: Float + Default + AddAssign<T> + ComplexField,
+    T: Float + AddAssign<T>,
 {
     pub fn new(coeff: &[T]) -> Self {
         let output = Polynomial {
@@ -33,6 +35,13 @@ where
         Polynomial::new(&[])
     }
 
+    /// Keeps leading zeros, which in powers of `z^-1` are pure delays.
+    pub(crate) fn from_coeffs(coeff: &[T]) -> Self {
+        Polynomial {
+            coeff: coeff.to_vec(),
+        }
+    }
+
     fn simplify(self) -> Self {
         let mut coeff = self.coeff;
         while !coeff.is_empty() && coeff.first() == Some(&T::zero()) {
@@ -42,17 +51,32 @@ where
     }
 
     pub fn pow(self, exp: usize) -> Self {
-        match exp {
-            0 => Polynomial::new(&[T::one()]),
-            1 => self,
-            _ => {
-                let mut result = self.clone();
-                for _ in 1..exp {
-                    result = result * self.clone();
-                }
-                result
+        let mut exp = exp;
+        let mut base = self;
+        let mut result = Polynomial::new(&[T::one()]);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.product(&base);
             }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.product(&base);
+            }
+        }
+
+        result
+    }
+
+    fn product(&self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        if self.degree() < 0 || rhs.degree() < 0 {
+            return Polynomial::empty();
+        }
+
+        Polynomial {
+            coeff: convolve(&self.coeff, &rhs.coeff),
         }
+        .simplify()
     }
 
     pub fn degree(&self) -> isize {
@@ -67,11 +91,27 @@ where
         &self.coeff
     }
 
+    /// Coefficients widened to `f64`, highest degree first, for analysis code
+    /// that works in double precision whatever `T` the model was built with.
+    pub fn real_coeffs(&self) -> Vec<f64> {
+        self.coeff.iter().map(|c| c.to_f64().unwrap()).collect()
+    }
+
+    /// Value at the complex point `x`, by Horner's rule in `f64`.
+    pub fn eval(&self, x: c64) -> c64 {
+        self.coeff.iter().fold(c64::new(0.0, 0.0), |acc, c| {
+            acc * x + c64::new(c.to_f64().unwrap(), 0.0)
+        })
+    }
+
     pub fn lead_coeff(&self) -> T {
         self.coeff.first().copied().unwrap_or(T::zero())
     }
 
-    pub fn transposed_companion_matrix(self) -> Mat<T> {
+    pub fn transposed_companion_matrix(self) -> Mat<T>
+    where
+        T: ComplexField,
+    {
         if self.degree() < 1 {
             return Mat::zeros(0, 0);
         }
@@ -110,14 +150,14 @@ where
             quotient.push(coeff);
 
             for (i, rem) in remainder.iter_mut().enumerate().take(other.coeff.len()) {
-                *rem -= coeff * other.coeff[i];
+                *rem = *rem - coeff * other.coeff[i];
             }
 
             remainder.remove(0);
         }
 
         if remainder.is_empty() {
-            remainder.push(T::default());
+            remainder.push(T::zero());
         }
 
         (
@@ -129,7 +169,7 @@ where
 
 impl<T> Add for Polynomial<T>
 where
-    T: Float + Default + AddAssign<T> + ComplexField,
+    T: Float + AddAssign<T>,
 {
     type Output = Polynomial<T>;
 
@@ -168,7 +208,7 @@ where
 
 impl<T> Sub for Polynomial<T>
 where
-    T: Float + Default + AddAssign<T> + ComplexField,
+    T: Float + AddAssign<T>,
 {
     type Output = Polynomial<T>;
 
@@ -207,32 +247,102 @@ where
 
 impl<T> Mul for Polynomial<T>
 where
-    T: Float + Default + AddAssign<T> + ComplexField,
+    T: Float + AddAssign<T>,
 {
     type Output = Polynomial<T>;
 
     fn mul(self, rhs: Polynomial<T>) -> Self::Output {
-        if self.degree() < 0 || rhs.degree() < 0 {
-            return Polynomial::empty();
-        }
+        self.product(&rhs)
+    }
+}
 
-        let mut coeff = vec![T::zero(); self.positive_degree() + rhs.positive_degree() + 1];
+/// Below this many coefficients the schoolbook product beats Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
 
-        for (i, &a) in self.coeff.iter().enumerate() {
-            for (j, &b) in rhs.coeff.iter().enumerate() {
-                coeff[i + j] += a * b;
-            }
+fn convolve<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Float + AddAssign<T>,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+
+    let n = a.len().max(b.len());
+    let mut a_padded = a.to_vec();
+    let mut b_padded = b.to_vec();
+    a_padded.resize(n, T::zero());
+    b_padded.resize(n, T::zero());
+
+    let mut coeff = karatsuba(&a_padded, &b_padded);
+    coeff.truncate(a.len() + b.len() - 1);
+    coeff
+}
+
+fn schoolbook<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Float + AddAssign<T>,
+{
+    let mut coeff = vec![T::zero(); a.len() + b.len() - 1];
+
+    for (i, &a) in a.iter().enumerate() {
+        for (j, &b) in b.iter().enumerate() {
+            coeff[i + j] += a * b;
         }
+    }
+
+    coeff
+}
 
-        let output = Polynomial { coeff };
+/// Product of two coefficient sequences of the same length.
+fn karatsuba<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Float + AddAssign<T>,
+{
+    let n = a.len();
+    let m = n / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let low = convolve(a0, b0);
+    let high = convolve(a1, b1);
+
+    let mut a_sum = a1.to_vec();
+    let mut b_sum = b1.to_vec();
+    for (sum, &c) in a_sum.iter_mut().zip(a0) {
+        *sum += c;
+    }
+    for (sum, &c) in b_sum.iter_mut().zip(b0) {
+        *sum += c;
+    }
+    let mut middle = convolve(&a_sum, &b_sum);
+    for (mid, &c) in middle.iter_mut().zip(&low) {
+        *mid += -c;
+    }
+    for (mid, &c) in middle.iter_mut().zip(&high) {
+        *mid += -c;
+    }
 
-        output.simplify()
+    let mut coeff = vec![T::zero(); 2 * n - 1];
+    for (i, &c) in low.iter().enumerate() {
+        coeff[i] += c;
     }
+    for (i, &c) in middle.iter().enumerate() {
+        coeff[i + m] += c;
+    }
+    for (i, &c) in high.iter().enumerate() {
+        coeff[i + 2 * m] += c;
+    }
+
+    coeff
 }
 
 impl<T> Neg for Polynomial<T>
 where
-    T: Float + Default + ComplexField,
+    T: Float,
 {
     type Output = Polynomial<T>;
 
@@ -245,7 +355,7 @@ where
 
 impl<T> Display for Polynomial<T>
 where
-    T: Float + Default + AddAssign<T> + Display + ComplexField,
+    T: Float + AddAssign<T> + Display,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let degree = self.degree();
@@ -269,3 +379,50 @@ where
         write!(f, "{}", string)
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow_matches_repeated_product() {
+        let p = Polynomial::new(&[1.0, -2.0, 0.5]);
+        let mut expected = Polynomial::new(&[1.0]);
+        for _ in 0..7 {
+            expected = expected * p.clone();
+        }
+
+        assert_eq!(p.clone().pow(0).coeff(), &[1.0]);
+        assert_eq!(p.clone().pow(1), p);
+        assert_eq!(p.pow(7), expected);
+    }
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let a = (0..90)
+            .map(|i| libm::sin(i as f64) + 1.5)
+            .collect::<Vec<_>>();
+        let b = (0..70)
+            .map(|i| libm::cos(0.3 * i as f64) + 1.5)
+            .collect::<Vec<_>>();
+
+        let fast = convolve(&a, &b);
+        let naive = schoolbook(&a, &b);
+
+        assert_eq!(fast.len(), naive.len());
+        for (fast, naive) in fast.iter().zip(&naive) {
+            assert!((fast - naive).abs() < 1e-9 * naive.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_real_coeffs_widens_f32() {
+        let p = Polynomial::new(&[0.0f32, 1.0, 0.1]).pow(2);
+
+        assert_eq!(p.degree(), 2);
+        assert_eq!(
+            p.real_coeffs(),
+            vec![1.0, 0.2f32 as f64, (0.1f32 * 0.1f32) as f64]
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::prelude::SimulationState;
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Instant;
+use std::vec::Vec;
+
+/// Steps kept for the percentiles and the histogram unless
+/// [`Profiler::with_window`] says otherwise.
+const DEFAULT_WINDOW: usize = 10_000;
+
+/// Wraps a simulation and measures the wall-clock time each step takes, i.e.
+/// the time between consecutive simulation states. In real-time mode the
+/// profiler also paces the loop to `dt` and records the steps that overrun it.
+///
+/// The mean, extremes and jitter cover every step through running
+/// statistics, while the percentiles and the histogram use the last
+/// `window` steps only, so long runs profile in constant memory.
+#[derive(Debug)]
+pub struct Profiler<S, C = fn() -> Duration>
+where
+    S: Iterator<Item = SimulationState>,
+    C: FnMut() -> Duration,
+{
+    simulation: S,
+    clock: C,
+    real_time: bool,
+    step_start: Option<(Duration, Duration)>,
+    window: usize,
+    durations: VecDeque<Duration>,
+    overruns: VecDeque<usize>,
+    stats: RunningStats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfilerReport {
+    pub steps: usize,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    /// Standard deviation of the step duration.
+    pub jitter: Duration,
+    pub p50: Duration,
+    pub p99: Duration,
+    pub overruns: usize,
+}
+
+/// Welford's running mean and variance of the step durations, in seconds.
+#[derive(Debug, Clone, Copy, Default)]
+struct RunningStats {
+    steps: usize,
+    mean: f64,
+    m2: f64,
+    min: Option<Duration>,
+    max: Option<Duration>,
+    overruns: usize,
+}
+
+/// Time elapsed since the first call, from the monotonic system clock.
+fn monotonic() -> Duration {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed()
+}
+
+impl<S> Profiler<S>
+where
+    S: Iterator<Item = SimulationState>,
+{
+    pub fn new(simulation: S) -> Self {
+        Self {
+            simulation,
+            clock: monotonic,
+            real_time: false,
+            step_start: None,
+            window: DEFAULT_WINDOW,
+            durations: VecDeque::new(),
+            overruns: VecDeque::new(),
+            stats: RunningStats::default(),
+        }
+    }
+}
+
+impl<S, C> Profiler<S, C>
+where
+    S: Iterator<Item = SimulationState>,
+    C: FnMut() -> Duration,
+{
+    /// Times the steps with `clock`, a monotonic time source, instead of the
+    /// system clock.
+    pub fn with_clock<K>(self, clock: K) -> Profiler<S, K>
+    where
+        K: FnMut() -> Duration,
+    {
+        Profiler {
+            simulation: self.simulation,
+            clock,
+            real_time: self.real_time,
+            step_start: None,
+            window: self.window,
+            durations: self.durations,
+            overruns: self.overruns,
+            stats: self.stats,
+        }
+    }
+
+    pub fn with_real_time(mut self) -> Self {
+        self.real_time = true;
+        self
+    }
+
+    /// Number of recent steps kept for the percentiles and the histogram.
+    pub fn with_window(mut self, window: usize) -> Self {
+        assert!(window > 0, "Window must hold at least one step");
+        self.window = window;
+        self
+    }
+
+    /// Durations of the last `window` steps, oldest first.
+    pub fn durations(&self) -> impl ExactSizeIterator<Item = Duration> + '_ {
+        self.durations.iter().copied()
+    }
+
+    /// Indices of the steps within the window whose execution took longer
+    /// than `dt`.
+    pub fn overruns(&self) -> Vec<usize> {
+        self.overruns.iter().copied().collect()
+    }
+
+    /// Step duration at percentile `p` in [0, 100] over the window, using
+    /// the nearest rank.
+    pub fn percentile(&self, p: f64) -> Duration {
+        assert!((0.0..=100.0).contains(&p), "Percentile must be in [0, 100]");
+
+        let mut sorted = self.durations().collect::<Vec<_>>();
+        sorted.sort();
+
+        match sorted.len() {
+            0 => Duration::ZERO,
+            n => {
+                let rank = libm::ceil(p / 100.0 * n as f64) as usize;
+                sorted[rank.clamp(1, n) - 1]
+            }
+        }
+    }
+
+    /// Counts of the step durations in the window in `bins` equal-width bins
+    /// between the shortest and the longest step.
+    pub fn histogram(&self, bins: usize) -> Vec<(Duration, usize)> {
+        assert!(bins > 0, "Histogram must have at least one bin");
+
+        let (Some(min), Some(max)) = (self.durations().min(), self.durations().max()) else {
+            return Vec::new();
+        };
+        let width = (max - min).as_secs_f64() / bins as f64;
+
+        let mut counts = std::vec![0; bins];
+        for duration in self.durations() {
+            let offset = (duration - min).as_secs_f64();
+            let bin = if width > 0.0 {
+                ((offset / width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            counts[bin] += 1;
+        }
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + Duration::from_secs_f64(width * i as f64), count))
+            .collect()
+    }
+
+    pub fn report(&self) -> ProfilerReport {
+        let stats = &self.stats;
+        let variance = if stats.steps > 0 {
+            stats.m2 / stats.steps as f64
+        } else {
+            0.0
+        };
+
+        ProfilerReport {
+            steps: stats.steps,
+            mean: Duration::from_secs_f64(stats.mean),
+            min: stats.min.unwrap_or_default(),
+            max: stats.max.unwrap_or_default(),
+            jitter: Duration::from_secs_f64(libm::sqrt(variance)),
+            p50: self.percentile(50.0),
+            p99: self.percentile(99.0),
+            overruns: stats.overruns,
+        }
+    }
+
+    fn finish_step(&mut self) {
+        let Some((start, dt)) = self.step_start.take() else {
+            return;
+        };
+
+        let elapsed = (self.clock)().saturating_sub(start);
+        let step = self.stats.steps;
+        if self.real_time {
+            if elapsed > dt {
+                self.overruns.push_back(step);
+                self.stats.overruns += 1;
+            } else {
+                thread::sleep(dt - elapsed);
+            }
+        }
+
+        self.stats.push(elapsed);
+        self.durations.push_back(elapsed);
+        if self.durations.len() > self.window {
+            self.durations.pop_front();
+        }
+        let oldest = self.stats.steps - self.durations.len();
+        while self.overruns.front().is_some_and(|i| *i < oldest) {
+            self.overruns.pop_front();
+        }
+    }
+}
+
+impl RunningStats {
+    fn push(&mut self, duration: Duration) {
+        let x = duration.as_secs_f64();
+        self.steps += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.steps as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = Some(self.min.map_or(duration, |min| min.min(duration)));
+        self.max = Some(self.max.map_or(duration, |max| max.max(duration)));
+    }
+}
+
+impl<S, C> Iterator for Profiler<S, C>
+where
+    S: Iterator<Item = SimulationState>,
+    C: FnMut() -> Duration,
+{
+    type Item = SimulationState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.finish_step();
+
+        let sim_state = self.simulation.next()?;
+        self.step_start = Some(((self.clock)(), sim_state.dt()));
+        Some(sim_state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use core::cell::Cell;
+    use core::time::Duration;
+
+    #[test]
+    fn test_profiler_flags_overruns_in_real_time_mode() {
+        // Each step takes exactly dt on the fake clock, except the fourth.
+        let now = Cell::new(Duration::ZERO);
+        let mut profiler = Profiler::new(Simulation::new(0.02, 0.2))
+            .with_clock(|| now.get())
+            .with_real_time()
+            .with_window(8);
+
+        for (i, _sim_state) in (&mut profiler).enumerate() {
+            let elapsed = if i == 3 { 30 } else { 20 };
+            now.set(now.get() + Duration::from_millis(elapsed));
+        }
+
+        let report = profiler.report();
+        assert_eq!(report.steps, 10);
+        assert_eq!(report.overruns, 1);
+        assert_eq!(report.max, Duration::from_millis(30));
+        assert_eq!(report.min, Duration::from_millis(20));
+        assert!((report.mean.as_secs_f64() - 0.021).abs() < 1e-9);
+        assert_eq!(profiler.overruns(), [3]);
+        assert_eq!(profiler.durations().len(), 8);
+        assert_eq!(
+            profiler.histogram(4).iter().map(|(_, n)| n).sum::<usize>(),
+            8
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::prelude::{Simulation, SimulationState};
+use core::time::Duration;
+
+/// State that can be saved and later restored, so iterative algorithms
+/// (predictive control, shooting methods, line searches) can simulate ahead
+/// from the current instant and then rewind.
+///
+/// Every `Clone` value is rewindable, snapshotting the whole value; that
+/// covers single blocks as well as tuples of blocks.
+pub trait Rewindable {
+    type Snapshot;
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    fn restore(&mut self, snapshot: Self::Snapshot);
+
+    /// Runs `f` on `self` and rewinds it afterwards, returning what `f` did.
+    fn lookahead<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R
+    where
+        Self: Sized,
+    {
+        let snapshot = self.snapshot();
+        let result = f(self);
+        self.restore(snapshot);
+        result
+    }
+}
+
+impl<B> Rewindable for B
+where
+    B: Clone,
+{
+    type Snapshot = B;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        *self = snapshot;
+    }
+}
+
+impl SimulationState {
+    /// Child time sequence starting at this instant, stepping by `dt` up to
+    /// `horizon` ahead of it.
+    pub fn branch(&self, dt: Duration, horizon: Duration) -> Simulation {
+        Simulation::starting_at(self.sim_time(), dt, self.sim_time() + horizon)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_lookahead_rewinds_blocks() {
+        let mut plant = (PID::new(2.0, 1.0, 0.0), Integrator::<f64>::new());
+        let mut simulation = Simulation::new(0.01, 1.0);
+        let step = |plant: &mut (PID<f64>, Integrator<f64>), sim_state: SimulationState| {
+            let y = plant.1.last_output().unwrap_or(0.0);
+            let u = plant.0.block(1.0 - y, sim_state);
+            plant.1.block(u, sim_state)
+        };
+
+        for sim_state in simulation.by_ref().take(10) {
+            step(&mut plant, sim_state);
+        }
+        let now = simulation.clone().next().unwrap();
+        let before = plant.clone();
+
+        let predicted = plant.lookahead(|plant| {
+            let mut y = 0.0;
+            for sim_state in now.branch(Duration::from_millis(10), Duration::from_millis(500)) {
+                y = step(plant, sim_state);
+            }
+            y
+        });
+
+        assert!(predicted > before.1.last_output().unwrap());
+        assert_eq!(plant.0, before.0);
+        assert_eq!(plant.1.last_output(), before.1.last_output());
+    }
+}
@@ -0,0 +1,366 @@
+use core::ops::{Mul, Neg};
+
+/// Hamilton quaternion `w + x i + y j + z k`. Unit quaternions are
+/// attitudes, taken as the rotation from the body to the world frame, and
+/// compose with `*` so a `Signal<Quaternion>` flows through a diagram like
+/// any scalar:
+///
+/// ```ignore
+/// let error = attitude.value.error(reference.value).to_rotation_vector();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// Rotation matrix taking body vectors to the world frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation {
+    matrix: [[f64; 3]; 3],
+}
+
+impl Quaternion {
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Rotation of `angle` radians about `axis`, which need not be unit.
+    pub fn from_axis_angle(axis: [f64; 3], angle: f64) -> Self {
+        let norm = norm3(axis);
+        if norm == 0.0 {
+            return Self::identity();
+        }
+        let s = libm::sin(angle / 2.0) / norm;
+        Self::new(
+            libm::cos(angle / 2.0),
+            axis[0] * s,
+            axis[1] * s,
+            axis[2] * s,
+        )
+    }
+
+    /// Rotation about the direction of `v` by its length.
+    pub fn from_rotation_vector(v: [f64; 3]) -> Self {
+        Self::from_axis_angle(v, norm3(v))
+    }
+
+    /// Roll, pitch and yaw, applied in the `z-y-x` order of aerospace
+    /// convention.
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Self {
+        let (sr, cr) = (libm::sin(roll / 2.0), libm::cos(roll / 2.0));
+        let (sp, cp) = (libm::sin(pitch / 2.0), libm::cos(pitch / 2.0));
+        let (sy, cy) = (libm::sin(yaw / 2.0), libm::cos(yaw / 2.0));
+        Self::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+        )
+    }
+
+    /// `[roll, pitch, yaw]` of a unit quaternion.
+    pub fn to_euler(&self) -> [f64; 3] {
+        let Self { w, x, y, z } = *self;
+        [
+            libm::atan2(2.0 * (w * x + y * z), 1.0 - 2.0 * (x * x + y * y)),
+            libm::asin((2.0 * (w * y - z * x)).clamp(-1.0, 1.0)),
+            libm::atan2(2.0 * (w * z + x * y), 1.0 - 2.0 * (y * y + z * z)),
+        ]
+    }
+
+    /// Axis times angle of a unit quaternion, the angle in `[0, pi]`.
+    pub fn to_rotation_vector(&self) -> [f64; 3] {
+        let q = self.canonical();
+        let sin = norm3(q.vector());
+        if sin == 0.0 {
+            return [0.0; 3];
+        }
+        let angle = 2.0 * libm::atan2(sin, q.w);
+        q.vector().map(|v| v * angle / sin)
+    }
+
+    pub fn vector(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn norm(&self) -> f64 {
+        libm::sqrt(self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z)
+    }
+
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Self::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn inverse(&self) -> Self {
+        let norm2 = self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z;
+        let c = self.conjugate();
+        Self::new(c.w / norm2, c.x / norm2, c.y / norm2, c.z / norm2)
+    }
+
+    /// Same rotation with a non-negative scalar part, i.e. the short way
+    /// round.
+    pub fn canonical(&self) -> Self {
+        if self.w < 0.0 { -*self } else { *self }
+    }
+
+    /// Rotation from `self` to `target` in the body frame,
+    /// `self⁻¹ ⊗ target`, taking the short way round. Its rotation vector is
+    /// the usual attitude error of a 3-axis controller.
+    pub fn error(&self, target: Quaternion) -> Self {
+        (self.conjugate() * target).canonical()
+    }
+
+    /// Rotates the body vector `v` into the world frame.
+    pub fn rotate(&self, v: [f64; 3]) -> [f64; 3] {
+        let u = self.vector();
+        let t = cross(u, v).map(|t| 2.0 * t);
+        let ut = cross(u, t);
+        core::array::from_fn(|i| v[i] + self.w * t[i] + ut[i])
+    }
+
+    /// Attitude after turning at the body `rate`, in rad/s, for `dt`
+    /// seconds.
+    pub fn integrate(&self, rate: [f64; 3], dt: f64) -> Self {
+        (*self * Self::from_rotation_vector(rate.map(|r| r * dt))).normalize()
+    }
+
+    /// Spherical linear interpolation from `self` at `t = 0` to `other` at
+    /// `t = 1`.
+    pub fn slerp(&self, other: Quaternion, t: f64) -> Self {
+        let delta = self.error(other).to_rotation_vector();
+        *self * Self::from_rotation_vector(delta.map(|d| d * t))
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<[f64; 4]> for Quaternion {
+    fn from([w, x, y, z]: [f64; 4]) -> Self {
+        Self::new(w, x, y, z)
+    }
+}
+
+impl From<Quaternion> for [f64; 4] {
+    fn from(q: Quaternion) -> Self {
+        [q.w, q.x, q.y, q.z]
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        )
+    }
+}
+
+impl Mul<f64> for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.w * rhs, self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl Rotation {
+    pub fn new(matrix: [[f64; 3]; 3]) -> Self {
+        Self { matrix }
+    }
+
+    pub fn identity() -> Self {
+        Self::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    pub fn matrix(&self) -> &[[f64; 3]; 3] {
+        &self.matrix
+    }
+
+    /// The inverse rotation, world to body.
+    pub fn transpose(&self) -> Self {
+        Self::new(core::array::from_fn(|i| {
+            core::array::from_fn(|j| self.matrix[j][i])
+        }))
+    }
+
+    pub fn rotate(&self, v: [f64; 3]) -> [f64; 3] {
+        core::array::from_fn(|i| (0..3).map(|j| self.matrix[i][j] * v[j]).sum())
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<Quaternion> for Rotation {
+    fn from(q: Quaternion) -> Self {
+        let Quaternion { w, x, y, z } = q.normalize();
+        Self::new([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ])
+    }
+}
+
+impl From<Rotation> for Quaternion {
+    /// Shepperd's method, pivoting on the largest of the scalar and vector
+    /// parts for accuracy.
+    fn from(r: Rotation) -> Self {
+        let m = r.matrix;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > m[0][0].max(m[1][1]).max(m[2][2]) {
+            let s = 2.0 * libm::sqrt(1.0 + trace);
+            Self::new(
+                s / 4.0,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let s = 2.0 * libm::sqrt(1.0 + m[0][0] - m[1][1] - m[2][2]);
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                s / 4.0,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] >= m[2][2] {
+            let s = 2.0 * libm::sqrt(1.0 - m[0][0] + m[1][1] - m[2][2]);
+            Self::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                s / 4.0,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = 2.0 * libm::sqrt(1.0 - m[0][0] - m[1][1] + m[2][2]);
+            Self::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                s / 4.0,
+            )
+        };
+        q.canonical()
+    }
+}
+
+impl Mul for Rotation {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(core::array::from_fn(|i| {
+            core::array::from_fn(|j| (0..3).map(|k| self.matrix[i][k] * rhs.matrix[k][j]).sum())
+        }))
+    }
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn norm3(v: [f64; 3]) -> f64 {
+    libm::sqrt(v[0] * v[0] + v[1] * v[1] + v[2] * v[2])
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::FRAC_PI_2;
+
+    fn close(a: &[f64], b: &[f64]) -> bool {
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    #[test]
+    fn test_quaternion_rotation_roundtrips() {
+        let q = Quaternion::from_euler(0.3, -0.2, 1.1);
+        assert!(close(&q.to_euler(), &[0.3, -0.2, 1.1]));
+        assert!((q.norm() - 1.0).abs() < 1e-12);
+
+        let v = [1.0, -2.0, 0.5];
+        let r = Rotation::from(q);
+        assert!(close(&q.rotate(v), &r.rotate(v)));
+        assert!(close(&r.transpose().rotate(r.rotate(v)), &v));
+        let back: [f64; 4] = Quaternion::from(r).into();
+        assert!(close(&back, &<[f64; 4]>::from(q.canonical())));
+
+        // A quarter turn about z takes x to y.
+        let yaw = Quaternion::from_axis_angle([0.0, 0.0, 2.0], FRAC_PI_2);
+        assert!(close(&yaw.rotate([1.0, 0.0, 0.0]), &[0.0, 1.0, 0.0]));
+        let composed = Rotation::from(q) * Rotation::from(yaw);
+        assert!(close(&composed.rotate(v), &(q * yaw).rotate(v)));
+    }
+
+    #[test]
+    fn test_quaternion_error_in_signals() {
+        let sim_state = Simulation::new(0.01, 0.01).next().unwrap();
+        let attitude = Quaternion::from_euler(0.1, 0.0, 0.0).as_signal(sim_state);
+        let step = Quaternion::from_rotation_vector([0.2, 0.0, 0.0]).as_signal(sim_state);
+
+        let reference = attitude * step;
+        let error = attitude.value.error(reference.value).to_rotation_vector();
+        assert!(close(&error, &[0.2, 0.0, 0.0]));
+        assert!(close(&reference.value.to_euler(), &[0.3, 0.0, 0.0]));
+
+        // The error takes the short way round even across the double cover.
+        let flipped = attitude.value.error(-reference.value).to_rotation_vector();
+        assert!(close(&flipped, &error));
+
+        let turned = attitude.value.integrate([0.0, 0.0, 1.0], 0.5);
+        assert!(close(
+            &attitude.value.error(turned).to_rotation_vector(),
+            &[0.0, 0.0, 0.5]
+        ));
+        let halfway = attitude.value.slerp(reference.value, 0.5);
+        assert!((halfway.to_euler()[0] - 0.2).abs() < 1e-12);
+    }
+}
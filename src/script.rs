@@ -0,0 +1,242 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+#[cfg(feature = "std")]
+use crate::prelude::{ParamError, Params};
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+type Action<S> = Box<dyn FnMut(&mut S)>;
+
+/// Timed actions on a scenario state `S`, e.g. setpoint changes,
+/// disturbances and injected faults, declared in one place instead of as
+/// `if` statements scattered through the loop.
+///
+/// As a block it runs every action due at the current step, in time order,
+/// and outputs the updated state for the rest of the loop to read. Resetting
+/// rewinds the schedule but leaves the state as is.
+pub struct Script<S> {
+    state: S,
+    actions: Vec<(Duration, Action<S>)>,
+    next: usize,
+}
+
+impl<S> Script<S> {
+    pub fn new(state: S) -> Self {
+        Self {
+            state,
+            actions: Vec::new(),
+            next: 0,
+        }
+    }
+
+    /// Runs `action` at `secs` seconds. Actions at the same time run in the
+    /// order they were added.
+    ///
+    /// Panics if `secs` is negative, NaN or too large for a [`Duration`].
+    pub fn at(self, secs: f64, action: impl FnMut(&mut S) + 'static) -> Self {
+        let time = Duration::try_from_secs_f64(secs).unwrap_or_else(|_| {
+            panic!(
+                "Action time must be a non-negative number of seconds, got {}",
+                secs
+            )
+        });
+        self.at_time(time, action)
+    }
+
+    fn at_time(mut self, time: Duration, action: impl FnMut(&mut S) + 'static) -> Self {
+        let index = self.actions.partition_point(|(at, _)| *at <= time);
+        self.actions.insert(index, (time, Box::new(action)));
+        self
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Number of actions run so far.
+    pub fn executed(&self) -> usize {
+        self.next
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next == self.actions.len()
+    }
+
+    /// Runs the actions due at `sim_state` and returns the state.
+    pub fn step(&mut self, sim_state: SimulationState) -> &S {
+        while let Some((at, action)) = self.actions.get_mut(self.next) {
+            if !sim_state.is_due(*at) {
+                break;
+            }
+            action(&mut self.state);
+            self.next += 1;
+        }
+
+        &self.state
+    }
+}
+
+#[cfg(feature = "std")]
+impl Script<Params> {
+    /// Parses a script acting on the parameters of `params`, one action per
+    /// line:
+    ///
+    /// ```text
+    /// # setpoint change, then a stuck sensor
+    /// at 5.0s: set step.amplitude 2.0
+    /// at 8s: set fault.stuck 1
+    /// at 8500ms: set fault.stuck 0
+    /// ```
+    ///
+    /// Every parameter must already be registered.
+    pub fn parse(params: Params, source: &str) -> Result<Self, ParamError> {
+        let mut script = Script::new(params);
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let syntax = ParamError::Syntax(index + 1);
+            let (time, action) = line
+                .strip_prefix("at ")
+                .and_then(|line| line.split_once(':'))
+                .ok_or(syntax.clone())?;
+            let time = time.trim();
+            let secs = match time.strip_suffix("ms") {
+                Some(ms) => ms.parse::<f64>().map(|ms| ms / 1000.0),
+                None => time.strip_suffix('s').unwrap_or(time).parse::<f64>(),
+            };
+            let time = secs
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or(syntax.clone())?;
+
+            let words = action.split_whitespace().collect::<Vec<_>>();
+            let ["set", name, value] = words.as_slice() else {
+                return Err(syntax);
+            };
+            let value = value
+                .parse::<f64>()
+                .map_err(|_| ParamError::InvalidValue(value.to_string()))?;
+            let param = script
+                .state
+                .handle(name)
+                .ok_or_else(|| ParamError::Unknown(name.to_string()))?;
+
+            script = script.at_time(time, move |_| param.set(value));
+        }
+
+        Ok(script)
+    }
+}
+
+impl<S> Block for Script<S>
+where
+    S: Clone,
+{
+    type Input = ();
+    type Output = S;
+
+    fn block(&mut self, _input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.step(sim_state).clone()
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        Some(self.state.clone())
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[derive(Debug, Clone, Default)]
+    struct Scenario {
+        setpoint: f64,
+        stuck: bool,
+    }
+
+    #[test]
+    fn test_script_runs_actions_in_time_order() {
+        let mut script = Script::new(Scenario::default())
+            .at(8.0, |s: &mut Scenario| s.stuck = true)
+            .at(5.0, |s: &mut Scenario| s.setpoint = 2.0)
+            .at(1.0, |s: &mut Scenario| s.setpoint = 1.0);
+        let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut sensor = 0.0;
+
+        for sim_state in Simulation::new(0.01, 10.0) {
+            let scenario = ().as_signal(sim_state) >> script.as_block();
+            let y = scenario.value.setpoint.as_signal(sim_state) >> plant.as_block();
+            if !scenario.value.stuck {
+                sensor = y.value;
+            }
+            if sim_state.sim_time().as_secs_f32() < 0.99 {
+                assert_eq!(scenario.value.setpoint, 0.0);
+            }
+        }
+
+        assert!(script.is_finished());
+        assert!((script.state().setpoint - 2.0).abs() < 1e-12);
+        assert!((sensor - 2.0).abs() > 0.01);
+        assert!((plant.last_output().unwrap() - 2.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_script_parses_parameter_actions() {
+        let params = Params::new();
+        let amplitude = params.register("step.amplitude", 1.0);
+        let stuck = params.register("fault.stuck", 0.0);
+        let mut script = Script::parse(
+            params.clone(),
+            "# scenario\nat 5.0s: set step.amplitude 2.0\n\nat 8s: set fault.stuck 1\nat 8500ms: set fault.stuck 0\n",
+        )
+        .unwrap();
+
+        let mut stuck_steps = 0;
+        for sim_state in Simulation::new(0.1, 20.0).take(100) {
+            script.step(sim_state);
+            if stuck.get() == 1.0 {
+                stuck_steps += 1;
+            }
+            if sim_state.sim_time().as_secs_f32() < 4.9 {
+                assert_eq!(amplitude.get(), 1.0);
+            }
+        }
+        assert_eq!(amplitude.get(), 2.0);
+        assert_eq!(stuck.get(), 0.0);
+        assert_eq!(stuck_steps, 5);
+        assert_eq!(script.executed(), 3);
+
+        assert_eq!(
+            Script::parse(params.clone(), "at 1s: set step.offset 1").err(),
+            Some(ParamError::Unknown("step.offset".into()))
+        );
+        assert_eq!(
+            Script::parse(params.clone(), "\nat 1s set step.amplitude 1").err(),
+            Some(ParamError::Syntax(2))
+        );
+        for time in ["-1s", "NaNs", "inf", "1e30ms"] {
+            let source = std::format!("at {}: set step.amplitude 1", time);
+            assert_eq!(
+                Script::parse(params.clone(), &source).err(),
+                Some(ParamError::Syntax(1)),
+                "{}",
+                time
+            );
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::block::Block;
 use crate::simulation::SimulationState;
-use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Neg, Shr, Sub};
+use num_traits::{Float, Zero};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Signal<T> {
@@ -205,24 +208,93 @@ where
     }
 }
 
-impl<I, O> Mul<&mut dyn Block<Input = I, Output = O>> for Signal<I> {
+/// Feeds the signal into `block`. Monitors, writers and metrics return their
+/// input, so a pipeline keeps the signal flowing past them, left to right:
+/// `(r - y) >> pid.as_block() >> plant.as_block() >> writer.as_block()`.
+///
+/// `>>` binds looser than the arithmetic operators, but clippy asks for the
+/// parentheses anyway when the two are mixed.
+impl<I, O> Shr<&mut dyn Block<Input = I, Output = O>> for Signal<I> {
     type Output = Signal<O>;
 
-    fn mul(self, block: &mut dyn Block<Input = I, Output = O>) -> Self::Output {
+    fn shr(self, block: &mut dyn Block<Input = I, Output = O>) -> Self::Output {
         block.output(self)
     }
 }
 
-impl<I, O> Mul<&mut dyn Block<Input = [I; 1], Output = [O; 1]>> for Signal<I>
-where
-    O: Clone,
-{
+/// Feeds a scalar signal through a single-element vector block, so scalar
+/// loops can log to writers and plotters sized for one variable. The value
+/// is wrapped and unwrapped with [`Cast`], as a
+/// [`Convert`](crate::prelude::Convert) block on each side would.
+impl<I, O> Shr<&mut dyn Block<Input = [I; 1], Output = [O; 1]>> for Signal<I> {
     type Output = Signal<O>;
 
-    fn mul(self, block: &mut dyn Block<Input = [I; 1], Output = [O; 1]>) -> Self::Output {
-        let output = block.block([self.value], self.sim_state);
+    fn shr(self, block: &mut dyn Block<Input = [I; 1], Output = [O; 1]>) -> Self::Output {
+        let output = crate::block::run(block, self.value.cast(), self.sim_state);
+        Signal {
+            value: output.cast(),
+            sim_state: self.sim_state,
+        }
+    }
+}
+
+/// Lossy or shape-changing conversion of a signal value, between `f32` and
+/// `f64` or between a scalar and a one-element vector.
+pub trait Cast<To> {
+    fn cast(self) -> To;
+}
+
+impl Cast<f64> for f32 {
+    fn cast(self) -> f64 {
+        self as f64
+    }
+}
+
+/// Rounds to the nearest `f32`.
+impl Cast<f32> for f64 {
+    fn cast(self) -> f32 {
+        self as f32
+    }
+}
+
+impl<T> Cast<[T; 1]> for T {
+    fn cast(self) -> [T; 1] {
+        [self]
+    }
+}
+
+impl<T> Cast<T> for [T; 1] {
+    fn cast(self) -> T {
+        let [value] = self;
+        value
+    }
+}
+
+/// `value` as a [`Float`]. `NumCast` from `f64` only fails for a type that
+/// cannot hold the magnitude, so that case saturates to an infinity of the
+/// same sign instead of unwrapping.
+pub(crate) fn from_f64<T: Float>(value: f64) -> T {
+    T::from(value).unwrap_or_else(|| match value {
+        v if v.is_nan() => T::nan(),
+        v if v > 0.0 => T::infinity(),
+        _ => T::neg_infinity(),
+    })
+}
+
+/// `value` as an `f64`, NaN for a [`Float`] without an `f64` form.
+pub(crate) fn to_f64<T: Float>(value: T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+impl<T> Signal<T> {
+    /// Converts the value, e.g. `y.cast::<f32>()` ahead of a single
+    /// precision controller.
+    pub fn cast<U>(self) -> Signal<U>
+    where
+        T: Cast<U>,
+    {
         Signal {
-            value: output[0].clone(),
+            value: self.value.cast(),
             sim_state: self.sim_state,
         }
     }
@@ -356,6 +428,151 @@ impl<T> Unpack<Option<Signal<T>>> for Signal<Option<T>> {
     }
 }
 
+/// Vector values of a [`Signal`], combined element by element.
+///
+/// Plain arrays and `Vec`s get the combinators below; wrap fixed-size ones
+/// in a [`Vector`](crate::prelude::Vector) to write a MIMO error as `r - y`.
+pub trait Elementwise: Sized {
+    type Scalar: Copy;
+
+    fn map_each(self, f: impl FnMut(Self::Scalar) -> Self::Scalar) -> Self;
+
+    /// Combines matching elements, which must be as many on both sides.
+    fn zip_each(self, rhs: Self, f: impl FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar)
+    -> Self;
+
+    fn fold_each<A>(&self, init: A, f: impl FnMut(A, Self::Scalar) -> A) -> A;
+}
+
+impl<T, const N: usize> Elementwise for [T; N]
+where
+    T: Copy,
+{
+    type Scalar = T;
+
+    fn map_each(self, f: impl FnMut(T) -> T) -> Self {
+        self.map(f)
+    }
+
+    fn zip_each(self, rhs: Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        core::array::from_fn(|i| f(self[i], rhs[i]))
+    }
+
+    fn fold_each<A>(&self, init: A, f: impl FnMut(A, T) -> A) -> A {
+        self.iter().copied().fold(init, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Elementwise for Vec<T>
+where
+    T: Copy,
+{
+    type Scalar = T;
+
+    fn map_each(self, f: impl FnMut(T) -> T) -> Self {
+        self.into_iter().map(f).collect()
+    }
+
+    fn zip_each(self, rhs: Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        assert_eq!(
+            self.len(),
+            rhs.len(),
+            "Element-wise operands must have the same length"
+        );
+        self.into_iter().zip(rhs).map(|(a, b)| f(a, b)).collect()
+    }
+
+    fn fold_each<A>(&self, init: A, f: impl FnMut(A, T) -> A) -> A {
+        self.iter().copied().fold(init, f)
+    }
+}
+
+impl<V> Signal<V>
+where
+    V: Elementwise,
+{
+    pub fn map_each(self, f: impl FnMut(V::Scalar) -> V::Scalar) -> Self {
+        Signal {
+            value: self.value.map_each(f),
+            sim_state: self.sim_state,
+        }
+    }
+
+    pub fn zip_each(self, rhs: Self, f: impl FnMut(V::Scalar, V::Scalar) -> V::Scalar) -> Self {
+        Signal {
+            value: self.value.zip_each(rhs.value, f),
+            sim_state: self.sim_state.merge(rhs.sim_state),
+        }
+    }
+
+    pub fn add_each(self, rhs: Self) -> Self
+    where
+        V::Scalar: Add<Output = V::Scalar>,
+    {
+        self.zip_each(rhs, |a, b| a + b)
+    }
+
+    pub fn sub_each(self, rhs: Self) -> Self
+    where
+        V::Scalar: Sub<Output = V::Scalar>,
+    {
+        self.zip_each(rhs, |a, b| a - b)
+    }
+
+    pub fn mul_each(self, rhs: Self) -> Self
+    where
+        V::Scalar: Mul<Output = V::Scalar>,
+    {
+        self.zip_each(rhs, |a, b| a * b)
+    }
+
+    /// Multiplies every element by `k`.
+    pub fn scale(self, k: V::Scalar) -> Self
+    where
+        V::Scalar: Mul<Output = V::Scalar>,
+    {
+        self.map_each(|a| a * k)
+    }
+
+    pub fn dot(self, rhs: Self) -> Signal<V::Scalar>
+    where
+        V::Scalar: Float,
+    {
+        let product = self.mul_each(rhs);
+        Signal {
+            value: product.value.fold_each(V::Scalar::zero(), |acc, a| acc + a),
+            sim_state: product.sim_state,
+        }
+    }
+
+    /// Euclidean norm.
+    pub fn norm(self) -> Signal<V::Scalar>
+    where
+        V::Scalar: Float,
+    {
+        let Signal { value, sim_state } = self;
+        Signal {
+            value: value
+                .fold_each(V::Scalar::zero(), |acc, a| acc + a * a)
+                .sqrt(),
+            sim_state,
+        }
+    }
+
+    /// Largest magnitude, the infinity norm.
+    pub fn max_abs(self) -> Signal<V::Scalar>
+    where
+        V::Scalar: Float,
+    {
+        let Signal { value, sim_state } = self;
+        Signal {
+            value: value.fold_each(V::Scalar::zero(), |acc, a| acc.max(a.abs())),
+            sim_state,
+        }
+    }
+}
+
 pub trait AsSignal {
     #[allow(clippy::wrong_self_convention)]
     fn as_signal(self, sim_state: SimulationState) -> Signal<Self>
@@ -370,3 +587,54 @@ pub trait AsSignal {
 }
 
 impl<T> AsSignal for T {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_pipeline_operator() {
+        let mut step = Step::default();
+        let mut pid = PID::new(2.0, 1.0, 0.0);
+        let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut iae = IAE::<f64>::default();
+        let mut twin_pid = PID::new(2.0, 1.0, 0.0);
+        let mut twin_plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        for sim_state in Simulation::new(0.01, 1.0) {
+            let error = (sim_state >> step.as_block()) - plant.last_output();
+            let y = error >> iae.as_block() >> pid.as_block() >> plant.as_block();
+
+            let twin_error = (sim_state >> step.as_block()) - twin_plant.last_output();
+            let twin_y = twin_error >> twin_pid.as_block() >> twin_plant.as_block();
+            assert_eq!(y, twin_y);
+        }
+        assert!(iae.value() > 0.0);
+    }
+
+    #[test]
+    fn test_vector_signal_elementwise() {
+        let sim_state = Simulation::new(0.01, 0.01).next().unwrap();
+        let r = [1.0, 2.0, 2.0].as_signal(sim_state);
+        let y = [0.5, 0.0, -1.0].as_signal(sim_state);
+
+        let error = r.sub_each(y);
+        assert_signal_eq!(error.value, [0.5, 2.0, 3.0], 1e-12, 0.0);
+        assert_signal_eq!(r.add_each(y).scale(2.0).value, [3.0, 4.0, 2.0], 1e-12, 0.0);
+        assert_signal_eq!(r.norm().value, 3.0, 1e-12, 0.0);
+        assert_signal_eq!(r.dot(y).value, -1.5, 1e-12, 0.0);
+        assert_signal_eq!(error.max_abs().value, 3.0, 1e-12, 0.0);
+        assert_signal_eq!(
+            r.map_each(|a: f64| a.powi(2)).value,
+            [1.0, 4.0, 4.0],
+            1e-12,
+            0.0
+        );
+
+        let r = vec![3.0, 4.0].as_signal(sim_state);
+        let y = vec![1.0, 1.0].as_signal(sim_state);
+        assert_signal_eq!(r.clone().sub_each(y).value, vec![2.0, 3.0], 1e-12, 0.0);
+        assert_signal_eq!(r.norm().value, 5.0, 1e-12, 0.0);
+    }
+}
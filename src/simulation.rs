@@ -1,6 +1,6 @@
 use core::{
     fmt::Debug,
-    ops::{Add, AddAssign, Mul},
+    ops::{Add, AddAssign, Shr},
     time::Duration,
 };
 
@@ -25,6 +25,18 @@ pub struct EndlessSimulation {
     sim_time: Duration,
 }
 
+/// Simulation paced by an external clock: each step takes its `dt` from
+/// `source`, e.g. elapsed ticks of a hardware timer or timestamps reported by
+/// the bridge, and ends when the source returns `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalSimulation<F>
+where
+    F: FnMut() -> Option<Duration>,
+{
+    source: F,
+    sim_time: Duration,
+}
+
 impl Simulation {
     pub fn new(dt: f32, max_time: f32) -> Self {
         Self {
@@ -34,6 +46,15 @@ impl Simulation {
         }
     }
 
+    /// Simulation resuming at `sim_time` rather than at zero.
+    pub fn starting_at(sim_time: Duration, dt: Duration, max_time: Duration) -> Self {
+        Self {
+            dt,
+            sim_time,
+            max_time,
+        }
+    }
+
     pub fn reset(&mut self) {
         self.sim_time = Duration::default();
     }
@@ -60,7 +81,42 @@ impl EndlessSimulation {
     }
 }
 
+impl<F> ExternalSimulation<F>
+where
+    F: FnMut() -> Option<Duration>,
+{
+    pub fn new(source: F) -> Self {
+        Self {
+            source,
+            sim_time: Duration::default(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.sim_time = Duration::default();
+    }
+}
+
+#[cfg(feature = "std")]
+impl ExternalSimulation<alloc::boxed::Box<dyn FnMut() -> Option<Duration>>> {
+    /// Endless simulation stepping by the wall-clock time elapsed between
+    /// iterations.
+    pub fn real_time() -> Self {
+        let mut last = std::time::Instant::now();
+        Self::new(alloc::boxed::Box::new(move || {
+            let now = std::time::Instant::now();
+            let dt = now - last;
+            last = now;
+            Some(dt)
+        }))
+    }
+}
+
 impl SimulationState {
+    pub(crate) fn new(dt: Duration, sim_time: Duration) -> Self {
+        Self { dt, sim_time }
+    }
+
     pub fn dt(&self) -> Duration {
         self.dt
     }
@@ -69,6 +125,13 @@ impl SimulationState {
         self.sim_time
     }
 
+    /// Whether something scheduled at `at` is due at this step. Half a step
+    /// of slack absorbs the rounding of accumulated times.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn is_due(&self, at: Duration) -> bool {
+        at <= self.sim_time + self.dt / 2
+    }
+
     pub fn merge(self, other: Self) -> Self {
         Self {
             dt: self.dt.min(other.dt),
@@ -85,11 +148,13 @@ impl SimulationState {
     }
 }
 
-impl<O> Mul<&mut dyn Block<Input = (), Output = O>> for SimulationState {
+/// Runs a source block at this step, starting a pipeline:
+/// `sim_state >> step.as_block() >> plant.as_block()`.
+impl<O> Shr<&mut dyn Block<Input = (), Output = O>> for SimulationState {
     type Output = Signal<O>;
 
-    fn mul(self, block: &mut dyn Block<Input = (), Output = O>) -> Self::Output {
-        let output = block.block((), self);
+    fn shr(self, block: &mut dyn Block<Input = (), Output = O>) -> Self::Output {
+        let output = crate::block::run(block, (), self);
         Signal {
             value: output,
             sim_state: self,
@@ -180,3 +245,20 @@ impl Iterator for EndlessSimulation {
         })
     }
 }
+
+impl<F> Iterator for ExternalSimulation<F>
+where
+    F: FnMut() -> Option<Duration>,
+{
+    type Item = SimulationState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dt = (self.source)()?;
+        self.sim_time += dt;
+
+        Some(SimulationState {
+            dt,
+            sim_time: self.sim_time,
+        })
+    }
+}
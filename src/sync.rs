@@ -0,0 +1,127 @@
+use crate::prelude::SimulationState;
+use core::time::Duration;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+/// Synchronization for co-simulating two loops on separate threads, each
+/// iterating its own `Simulation` with its own `dt`.
+///
+/// The loops meet at every multiple of `period`: each sends the value it
+/// publishes and blocks until the other's value for that same instant
+/// arrives, so neither can run ahead by more than one period.
+pub struct SyncBarrier;
+
+/// One side of a [`SyncBarrier`], sending `Tx` and receiving `Rx`.
+#[derive(Debug)]
+pub struct SyncPort<Tx, Rx> {
+    period: Duration,
+    next_sync: Duration,
+    tx: Sender<Tx>,
+    rx: Receiver<Rx>,
+    latest: Option<Rx>,
+    connected: bool,
+}
+
+impl SyncBarrier {
+    /// Both ends of a barrier meeting every `period`.
+    pub fn pair<A, B>(period: Duration) -> (SyncPort<A, B>, SyncPort<B, A>)
+    where
+        A: Clone,
+        B: Clone,
+    {
+        assert!(!period.is_zero(), "Sync period must be greater than zero");
+
+        let (tx_a, rx_a) = channel();
+        let (tx_b, rx_b) = channel();
+        (
+            SyncPort::new(period, tx_a, rx_b),
+            SyncPort::new(period, tx_b, rx_a),
+        )
+    }
+}
+
+impl<Tx, Rx> SyncPort<Tx, Rx>
+where
+    Tx: Clone,
+    Rx: Clone,
+{
+    fn new(period: Duration, tx: Sender<Tx>, rx: Receiver<Rx>) -> Self {
+        Self {
+            period,
+            next_sync: Duration::ZERO,
+            tx,
+            rx,
+            latest: None,
+            connected: true,
+        }
+    }
+
+    /// Call once per step of the owning loop. When this step reaches a sync
+    /// instant, sends `value` and waits for the peer's value, returning it;
+    /// otherwise returns the value received at the last sync instant.
+    ///
+    /// Once the peer has finished, the last received value is kept.
+    pub fn sync(&mut self, value: Tx, sim_state: SimulationState) -> Option<Rx> {
+        while self.connected && sim_state.is_due(self.next_sync) {
+            self.next_sync += self.period;
+            if self.tx.send(value.clone()).is_err() {
+                self.connected = false;
+                break;
+            }
+            match self.rx.recv() {
+                Ok(received) => self.latest = Some(received),
+                Err(_) => self.connected = false,
+            }
+        }
+
+        self.latest.clone()
+    }
+
+    /// Value received at the last sync instant.
+    pub fn latest(&self) -> Option<Rx> {
+        self.latest.clone()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_sync_barrier_couples_fast_plant_and_slow_controller() {
+        let (mut plant_port, mut controller_port) =
+            SyncBarrier::pair::<f64, f64>(Duration::from_millis(10));
+
+        let controller = std::thread::spawn(move || {
+            let mut pid = PID::new(2.0, 0.0, 0.0);
+            let mut exchanges = 0;
+            let mut u = 0.0;
+            for sim_state in Simulation::new(0.01, 3.0) {
+                let y = controller_port.sync(u, sim_state).unwrap();
+                exchanges += 1;
+                u = pid.block(1.0 - y, sim_state);
+            }
+            exchanges
+        });
+
+        let mut plant = Integrator::<f64>::new();
+        let mut u = 0.0;
+        for sim_state in Simulation::new(0.001, 3.0) {
+            let y = plant.last_output().unwrap_or(0.0);
+            u = plant_port.sync(y, sim_state).unwrap_or(u);
+            plant.block(u, sim_state);
+        }
+
+        let exchanges = controller.join().unwrap();
+        assert!(exchanges >= 299);
+        assert!((plant.last_output().unwrap() - 1.0).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,285 @@
+//! Approximate comparison of signals and recorded traces, for the crate's
+//! tests and for tests of diagrams built on it.
+//!
+//! Two scalars `a` and `b` match when `|a - b| <= atol + rtol * |b|`. Two
+//! NaNs match, so a guard that propagates NaN on purpose can be checked,
+//! but a NaN never matches a number. Infinities only match the same
+//! infinity.
+
+use crate::signal::Signal;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+/// First difference found between two values compared with [`ApproxEq`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mismatch {
+    /// Scalars out of tolerance at `index`, the position in the outermost
+    /// sequence, `0` for a scalar.
+    Value { index: usize, left: f64, right: f64 },
+    /// Sequences of different lengths. An `Option` is a sequence of zero or
+    /// one element.
+    Length { left: usize, right: usize },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Value { index, left, right } => write!(
+                f,
+                "at index {}: {} != {} (difference {})",
+                index,
+                left,
+                right,
+                libm::fabs(left - right)
+            ),
+            Mismatch::Length { left, right } => {
+                write!(f, "lengths differ: {} != {}", left, right)
+            }
+        }
+    }
+}
+
+/// Whether `left` is within `atol + rtol * |right|` of `right`, NaN
+/// matching NaN only.
+pub fn is_close(left: f64, right: f64, rtol: f64, atol: f64) -> bool {
+    if left.is_nan() || right.is_nan() {
+        return left.is_nan() && right.is_nan();
+    }
+    if left.is_infinite() || right.is_infinite() {
+        return left == right;
+    }
+    libm::fabs(left - right) <= atol + rtol * libm::fabs(right)
+}
+
+/// Values that can be compared element by element within a tolerance.
+pub trait ApproxEq {
+    /// The first element of `self` out of tolerance from `other`, if any.
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch>;
+}
+
+impl ApproxEq for f64 {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        (!is_close(*self, *other, rtol, atol)).then_some(Mismatch::Value {
+            index: 0,
+            left: *self,
+            right: *other,
+        })
+    }
+}
+
+impl ApproxEq for f32 {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        (*self as f64).mismatch(&(*other as f64), rtol, atol)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        if self.len() != other.len() {
+            return Some(Mismatch::Length {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+
+        self.iter()
+            .zip(other)
+            .enumerate()
+            .find_map(|(i, (left, right))| {
+                left.mismatch(right, rtol, atol)
+                    .map(|mismatch| match mismatch {
+                        Mismatch::Value { left, right, .. } => Mismatch::Value {
+                            index: i,
+                            left,
+                            right,
+                        },
+                        length => length,
+                    })
+            })
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        self.as_slice().mismatch(other.as_slice(), rtol, atol)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        self.as_slice().mismatch(other.as_slice(), rtol, atol)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        match (self, other) {
+            (Some(left), Some(right)) => left.mismatch(right, rtol, atol),
+            (None, None) => None,
+            _ => Some(Mismatch::Length {
+                left: self.is_some() as usize,
+                right: other.is_some() as usize,
+            }),
+        }
+    }
+}
+
+impl<A: ApproxEq, B: ApproxEq> ApproxEq for (A, B) {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        self.0
+            .mismatch(&other.0, rtol, atol)
+            .or_else(|| self.1.mismatch(&other.1, rtol, atol))
+    }
+}
+
+/// Compares the values only; the simulation states may differ.
+impl<T: ApproxEq> ApproxEq for Signal<T> {
+    fn mismatch(&self, other: &Self, rtol: f64, atol: f64) -> Option<Mismatch> {
+        self.value.mismatch(&other.value, rtol, atol)
+    }
+}
+
+/// Compares two recorded traces sample by sample, e.g. the output of a
+/// diagram against a reference run saved before a refactor.
+pub fn compare_traces<T: ApproxEq>(
+    left: &[T],
+    right: &[T],
+    rtol: f64,
+    atol: f64,
+) -> Result<(), Mismatch> {
+    match left.mismatch(right, rtol, atol) {
+        Some(mismatch) => Err(mismatch),
+        None => Ok(()),
+    }
+}
+
+/// Largest absolute difference between two scalar traces, over their
+/// common length. Samples where both are NaN count as equal; `NaN` if only
+/// one of them is.
+pub fn max_deviation(left: &[f64], right: &[f64]) -> f64 {
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| match (left.is_nan(), right.is_nan()) {
+            (true, true) => 0.0,
+            (false, false) if left == right => 0.0,
+            _ => libm::fabs(left - right),
+        })
+        .fold(0.0, |acc, deviation| {
+            if acc.is_nan() || deviation.is_nan() {
+                f64::NAN
+            } else {
+                acc.max(deviation)
+            }
+        })
+}
+
+/// Asserts that two signals, values or traces are equal within
+/// `atol + rtol * |right|`, element by element, with NaN equal to NaN.
+///
+/// ```
+/// use aule::assert_signal_eq;
+///
+/// assert_signal_eq!([1.0, f64::NAN], [1.0 + 1e-12, f64::NAN], 1e-9, 0.0);
+/// assert_signal_eq!(0.1_f32 + 0.2, 0.3, 0.0, 1e-6, "sum of {} terms", 2);
+/// ```
+#[macro_export]
+macro_rules! assert_signal_eq {
+    ($left:expr, $right:expr, $rtol:expr, $atol:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some(mismatch) =
+                    $crate::testing::ApproxEq::mismatch(left, right, $rtol, $atol)
+                {
+                    panic!(
+                        "assertion `left ≈ right` failed: {}\n  left: {:?}\n right: {:?}",
+                        mismatch, left, right
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $rtol:expr, $atol:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some(mismatch) =
+                    $crate::testing::ApproxEq::mismatch(left, right, $rtol, $atol)
+                {
+                    panic!(
+                        "assertion `left ≈ right` failed: {}: {}\n  left: {:?}\n right: {:?}",
+                        format_args!($($arg)+),
+                        mismatch,
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::testing::{Mismatch, compare_traces, is_close, max_deviation};
+    use alloc::vec;
+
+    #[test]
+    fn test_is_close_nan_and_infinity() {
+        assert!(is_close(f64::NAN, f64::NAN, 0.0, 0.0));
+        assert!(!is_close(f64::NAN, 1.0, 1.0, 1.0));
+        assert!(is_close(f64::INFINITY, f64::INFINITY, 0.0, 0.0));
+        assert!(!is_close(f64::INFINITY, f64::NEG_INFINITY, 1.0, 1.0));
+        assert!(is_close(100.0, 100.5, 1e-2, 0.0));
+        assert!(!is_close(100.0, 101.5, 1e-2, 0.0));
+    }
+
+    #[test]
+    fn test_assert_signal_eq() {
+        let sim_state = Simulation::new(0.01, 0.01).next().unwrap();
+        let y = [0.1_f32 + 0.2, f32::NAN].as_signal(sim_state);
+        assert_signal_eq!(y, [0.3, f32::NAN].as_signal(sim_state), 0.0, 1e-6);
+        assert_signal_eq!(
+            Some((1.0, vec![2.0])),
+            Some((1.0, vec![2.0 + 1e-12])),
+            1e-9,
+            0.0
+        );
+
+        let caught =
+            std::panic::catch_unwind(|| assert_signal_eq!(1.0, 1.1, 0.0, 1e-3, "step {}", 3));
+        let message = caught.unwrap_err();
+        let message = message.downcast_ref::<std::string::String>().unwrap();
+        assert!(message.contains("step 3"), "{}", message);
+    }
+
+    #[test]
+    fn test_compare_traces() {
+        let reference = [0.0, 0.5, 1.0, f64::NAN];
+        assert_eq!(
+            compare_traces(&reference, &[0.0, 0.5, 1.0 + 1e-10, f64::NAN], 0.0, 1e-9),
+            Ok(())
+        );
+        assert_eq!(
+            compare_traces(&reference, &[0.0, 0.6, 1.0, f64::NAN], 0.0, 1e-9),
+            Err(Mismatch::Value {
+                index: 1,
+                left: 0.5,
+                right: 0.6
+            })
+        );
+        assert_eq!(
+            compare_traces(&reference, &[0.0], 0.0, 1.0),
+            Err(Mismatch::Length { left: 4, right: 1 })
+        );
+
+        assert_signal_eq!(
+            max_deviation(&reference, &[0.0, 0.6, 1.0, f64::NAN]),
+            0.1,
+            0.0,
+            1e-12
+        );
+        assert!(max_deviation(&reference, &[0.0, 0.5, 1.0, 1.0]).is_nan());
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{block::Block, prelude::SimulationState, rotation::Quaternion};
+use num_traits::Float;
+
+/// Single-axis complementary filter fusing a rate gyro with an absolute but
+/// noisy angle, e.g. the tilt from an accelerometer. The gyro is trusted
+/// above `1 / tau` rad/s and the angle below it. Input is `(rate, angle)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplementaryFilter<T>
+where
+    T: Float,
+{
+    tau: T,
+    angle: Option<T>,
+}
+
+impl<T> ComplementaryFilter<T>
+where
+    T: Float,
+{
+    pub fn new(tau: T) -> Self {
+        assert!(
+            tau > T::zero(),
+            "Filter time constant must be greater than zero"
+        );
+        Self { tau, angle: None }
+    }
+
+    pub fn tau(&self) -> T {
+        self.tau
+    }
+}
+
+impl<T> Block for ComplementaryFilter<T>
+where
+    T: Float,
+{
+    type Input = (T, T);
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (rate, measured) = input;
+        let dt = T::from(sim_state.dt().as_secs_f64()).unwrap();
+        let alpha = self.tau / (self.tau + dt);
+        let angle = match self.angle {
+            Some(angle) => alpha * (angle + rate * dt) + (T::one() - alpha) * measured,
+            None => measured,
+        };
+        self.angle = Some(angle);
+        angle
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.angle
+    }
+
+    fn reset(&mut self) {
+        self.angle = None;
+    }
+}
+
+/// Mahony nonlinear complementary filter on the attitude quaternion, body
+/// to world, from the gyro and accelerometer readings
+/// of an [`ImuModel`](crate::prelude::ImuModel). The tilt error between the
+/// measured and the predicted gravity drives the gyro through a PI loop,
+/// whose integral estimates the gyro bias. Yaw is not observable and
+/// drifts with the gyro.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mahony {
+    kp: f64,
+    ki: f64,
+    initial: Quaternion,
+    quaternion: Quaternion,
+    bias: [f64; 3],
+    last_output: Option<Quaternion>,
+}
+
+impl Mahony {
+    pub fn new(kp: f64, ki: f64) -> Self {
+        Self {
+            kp,
+            ki,
+            initial: Quaternion::identity(),
+            quaternion: Quaternion::identity(),
+            bias: [0.0; 3],
+            last_output: None,
+        }
+    }
+
+    /// Attitude at the start of the run.
+    pub fn with_initial(mut self, quaternion: Quaternion) -> Self {
+        self.initial = quaternion.normalize();
+        self.quaternion = self.initial;
+        self
+    }
+
+    pub fn quaternion(&self) -> Quaternion {
+        self.quaternion
+    }
+
+    /// Gyro bias estimated by the integral term, with the opposite sign of
+    /// the correction it applies.
+    pub fn bias(&self) -> [f64; 3] {
+        self.bias.map(|b| -b)
+    }
+}
+
+impl Block for Mahony {
+    type Input = ([f64; 3], [f64; 3]);
+    type Output = Quaternion;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (gyro, accel) = input;
+        let dt = sim_state.dt().as_secs_f64();
+        let q: [f64; 4] = self.quaternion.into();
+
+        let mut rate = gyro;
+        if let Some(accel) = unit(accel) {
+            let error = cross(accel, gravity(q));
+            for i in 0..3 {
+                self.bias[i] += self.ki * error[i] * dt;
+                rate[i] += self.kp * error[i] + self.bias[i];
+            }
+        }
+
+        let q_dot = rate_derivative(q, rate);
+        self.quaternion =
+            Quaternion::from(core::array::from_fn(|i| q[i] + q_dot[i] * dt)).normalize();
+        self.last_output = Some(self.quaternion);
+        self.quaternion
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.quaternion = self.initial;
+        self.bias = [0.0; 3];
+        self.last_output = None;
+    }
+}
+
+/// Madgwick gradient-descent attitude filter on the quaternion, body to
+/// world, from gyro and accelerometer readings.
+/// Each step takes a gradient step of size `beta` rad/s towards the
+/// attitude that aligns the predicted gravity with the accelerometer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Madgwick {
+    beta: f64,
+    initial: Quaternion,
+    quaternion: Quaternion,
+    last_output: Option<Quaternion>,
+}
+
+impl Madgwick {
+    pub fn new(beta: f64) -> Self {
+        Self {
+            beta,
+            initial: Quaternion::identity(),
+            quaternion: Quaternion::identity(),
+            last_output: None,
+        }
+    }
+
+    /// Attitude at the start of the run.
+    pub fn with_initial(mut self, quaternion: Quaternion) -> Self {
+        self.initial = quaternion.normalize();
+        self.quaternion = self.initial;
+        self
+    }
+
+    pub fn quaternion(&self) -> Quaternion {
+        self.quaternion
+    }
+}
+
+impl Block for Madgwick {
+    type Input = ([f64; 3], [f64; 3]);
+    type Output = Quaternion;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (gyro, accel) = input;
+        let dt = sim_state.dt().as_secs_f64();
+        let q: [f64; 4] = self.quaternion.into();
+        let [w, x, y, z] = q;
+
+        let mut q_dot = rate_derivative(q, gyro);
+        if let Some(accel) = unit(accel) {
+            let predicted = gravity(q);
+            let f = core::array::from_fn::<_, 3, _>(|i| predicted[i] - accel[i]);
+            let jacobian = [
+                [-2.0 * y, 2.0 * z, -2.0 * w, 2.0 * x],
+                [2.0 * x, 2.0 * w, 2.0 * z, 2.0 * y],
+                [0.0, -4.0 * x, -4.0 * y, 0.0],
+            ];
+            let gradient = core::array::from_fn::<_, 4, _>(|j| {
+                (0..3).map(|i| jacobian[i][j] * f[i]).sum::<f64>()
+            });
+            let norm = libm::sqrt(gradient.iter().map(|g| g * g).sum::<f64>());
+            if norm > 0.0 {
+                for (q_dot, gradient) in q_dot.iter_mut().zip(gradient) {
+                    *q_dot -= self.beta * gradient / norm;
+                }
+            }
+        }
+
+        self.quaternion =
+            Quaternion::from(core::array::from_fn(|i| q[i] + q_dot[i] * dt)).normalize();
+        self.last_output = Some(self.quaternion);
+        self.quaternion
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.quaternion = self.initial;
+        self.last_output = None;
+    }
+}
+
+/// Direction of the accelerometer reading at rest, i.e. world up in the
+/// body frame.
+fn gravity([w, x, y, z]: [f64; 4]) -> [f64; 3] {
+    [
+        2.0 * (x * z - w * y),
+        2.0 * (w * x + y * z),
+        w * w - x * x - y * y + z * z,
+    ]
+}
+
+/// `q ⊗ [0, rate] / 2`.
+fn rate_derivative([w, x, y, z]: [f64; 4], [p, q, r]: [f64; 3]) -> [f64; 4] {
+    [
+        0.5 * (-x * p - y * q - z * r),
+        0.5 * (w * p + y * r - z * q),
+        0.5 * (w * q - x * r + z * p),
+        0.5 * (w * r + x * q - y * p),
+    ]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn unit(v: [f64; 3]) -> Option<[f64; 3]> {
+    let norm = libm::sqrt(v.iter().map(|v| v * v).sum::<f64>());
+    (norm > 0.0).then(|| v.map(|v| v / norm))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_complementary_filter_rejects_gyro_bias() {
+        let mut filter = ComplementaryFilter::new(0.5_f64);
+        for sim_state in Simulation::new(0.01, 10.0) {
+            let _ = (0.05, 0.3).as_signal(sim_state) >> filter.as_block();
+        }
+
+        // A constant gyro bias leaves a steady error of `bias * tau`.
+        let angle = filter.last_output().unwrap();
+        assert!(
+            (angle - (0.3 + 0.05 * 0.5)).abs() < 1e-3,
+            "angle = {}",
+            angle
+        );
+        filter.reset();
+        assert_eq!(filter.last_output(), None);
+    }
+
+    #[test]
+    fn test_mahony_and_madgwick_converge_to_tilt() {
+        let roll: f64 = 0.4;
+        let expected = Quaternion::from_euler(roll, 0.0, 0.0);
+        let mut imu = ImuModel::new(
+            ImuSensor::ideal().with_bias([0.02, 0.0, 0.0]),
+            ImuSensor::ideal().with_white_noise(0.002),
+        )
+        .with_seed(1);
+        let mut mahony = Mahony::new(2.0, 0.2);
+        let mut madgwick = Madgwick::new(0.1);
+
+        for sim_state in Simulation::new(0.01, 60.0) {
+            let truth = ([0.0; 3], [0.0, 9.81 * roll.sin(), 9.81 * roll.cos()]);
+            let measured = truth.as_signal(sim_state) >> imu.as_block();
+            let _ = measured >> mahony.as_block();
+            let _ = measured >> madgwick.as_block();
+        }
+
+        let angle = |q: Quaternion| {
+            let error = q.error(expected).to_rotation_vector();
+            error.iter().map(|e| e * e).sum::<f64>().sqrt()
+        };
+        assert!(angle(mahony.quaternion()) < 0.01);
+        assert!((mahony.bias()[0] - 0.02).abs() < 0.005);
+        assert!(angle(madgwick.quaternion()) < 0.01);
+    }
+}
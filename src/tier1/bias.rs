@@ -0,0 +1,110 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::ops::Add;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bias<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    offset: T,
+    last_output: Option<T>,
+}
+
+/// Element-wise offset applied to an `N` sized vector signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorBias<T, const N: usize>
+where
+    T: Copy + Add<Output = T>,
+{
+    offsets: [T; N],
+    last_output: Option<[T; N]>,
+}
+
+impl<T> Bias<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    pub fn new(offset: T) -> Self {
+        Self {
+            offset,
+            last_output: None,
+        }
+    }
+
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+
+    pub fn offset_mut(&mut self) -> &mut T {
+        &mut self.offset
+    }
+}
+
+impl<T, const N: usize> VectorBias<T, N>
+where
+    T: Copy + Add<Output = T>,
+{
+    pub fn new(offsets: [T; N]) -> Self {
+        Self {
+            offsets,
+            last_output: None,
+        }
+    }
+
+    pub fn uniform(offset: T) -> Self {
+        Self::new([offset; N])
+    }
+
+    pub fn offsets(&self) -> &[T; N] {
+        &self.offsets
+    }
+
+    pub fn offsets_mut(&mut self) -> &mut [T; N] {
+        &mut self.offsets
+    }
+}
+
+impl<T> Block for Bias<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = input + self.offset;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<T, const N: usize> Block for VectorBias<T, N>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Input = [T; N];
+    type Output = [T; N];
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = core::array::from_fn(|i| input[i] + self.offsets[i]);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
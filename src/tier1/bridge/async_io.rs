@@ -0,0 +1,191 @@
+use crate::{block::Block, prelude::SimulationState};
+use tokio::sync::mpsc::{self, Receiver, Sender, error::TrySendError};
+
+/// Back-pressure counters of an async adapter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelMetrics {
+    /// Values handed over to the other side.
+    pub transferred: u64,
+    /// Values lost because the channel was full (sink) or superseded before
+    /// the loop read them (source).
+    pub dropped: u64,
+    /// Largest number of values found waiting in the channel.
+    pub max_depth: usize,
+}
+
+/// Pass-through block that publishes each value to an async task through a
+/// bounded channel, e.g. a WebSocket streamer or a network bridge. It never
+/// blocks the control loop: when the consumer falls behind and the channel
+/// is full, the value is dropped and counted.
+#[derive(Debug)]
+pub struct AsyncSink<T>
+where
+    T: Clone,
+{
+    tx: Sender<T>,
+    metrics: ChannelMetrics,
+    last_output: Option<T>,
+}
+
+/// Block bringing values produced by an async task, e.g. serial or network
+/// reads, into the control loop. Each step it drains the channel without
+/// blocking and outputs the newest value received so far.
+#[derive(Debug)]
+pub struct AsyncSource<T>
+where
+    T: Clone,
+{
+    rx: Receiver<T>,
+    metrics: ChannelMetrics,
+    last_output: Option<T>,
+}
+
+impl<T> AsyncSink<T>
+where
+    T: Clone,
+{
+    /// Sink holding up to `capacity` values, and the receiver for the async
+    /// side.
+    pub fn bounded(capacity: usize) -> (Self, Receiver<T>) {
+        let (tx, rx) = mpsc::channel(capacity);
+        let sink = Self {
+            tx,
+            metrics: ChannelMetrics::default(),
+            last_output: None,
+        };
+        (sink, rx)
+    }
+
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.metrics
+    }
+
+    /// Whether the async side still holds its receiver.
+    pub fn is_connected(&self) -> bool {
+        !self.tx.is_closed()
+    }
+}
+
+impl<T> AsyncSource<T>
+where
+    T: Clone,
+{
+    /// Source holding up to `capacity` values, and the sender for the async
+    /// side.
+    pub fn bounded(capacity: usize) -> (Self, Sender<T>) {
+        let (tx, rx) = mpsc::channel(capacity);
+        let source = Self {
+            rx,
+            metrics: ChannelMetrics::default(),
+            last_output: None,
+        };
+        (source, tx)
+    }
+
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.metrics
+    }
+}
+
+impl<T> Block for AsyncSink<T>
+where
+    T: Clone,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let depth = self.tx.max_capacity() - self.tx.capacity();
+        self.metrics.max_depth = self.metrics.max_depth.max(depth);
+
+        match self.tx.try_send(input.clone()) {
+            Ok(()) => self.metrics.transferred += 1,
+            Err(TrySendError::Full(_)) | Err(TrySendError::Closed(_)) => self.metrics.dropped += 1,
+        }
+
+        self.last_output = Some(input.clone());
+        input
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<T> Block for AsyncSource<T>
+where
+    T: Clone,
+{
+    type Input = ();
+    type Output = Option<T>;
+
+    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let depth = self.rx.len();
+        self.metrics.max_depth = self.metrics.max_depth.max(depth);
+
+        let mut received = 0;
+        while let Ok(value) = self.rx.try_recv() {
+            received += 1;
+            self.last_output = Some(value);
+        }
+        if received > 0 {
+            self.metrics.transferred += 1;
+            self.metrics.dropped += received - 1;
+        }
+
+        self.last_output.clone()
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        Some(self.last_output.clone())
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_async_adapters_do_not_block_the_loop() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let (mut sink, mut rx) = AsyncSink::<f64>::bounded(4);
+        let (mut source, tx) = AsyncSource::<f64>::bounded(4);
+
+        // Nobody consumes yet: the sink fills up and drops the rest.
+        for sim_state in Simulation::new(0.1, 5.0).take(10) {
+            let _ = 1.0.as_signal(sim_state) >> sink.as_block();
+        }
+        assert_eq!(sink.metrics().transferred, 4);
+        assert_eq!(sink.metrics().dropped, 6);
+        assert_eq!(sink.metrics().max_depth, 4);
+
+        // The async side echoes what it reads back into the source.
+        let echoed = runtime.block_on(async move {
+            let mut echoed = 0;
+            while let Ok(value) = rx.try_recv() {
+                tx.send(value * 2.0).await.unwrap();
+                echoed += 1;
+            }
+            echoed
+        });
+        assert_eq!(echoed, 4);
+
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+        let value = (sim_state >> source.as_block()).value;
+        assert_eq!(value, Some(2.0));
+        assert_eq!(source.metrics().transferred, 1);
+        assert_eq!(source.metrics().dropped, 3);
+        assert!(!sink.is_connected());
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{block::Block, prelude::SimulationState};
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::{marker::PhantomData, time::Duration};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tonic::{
+    Request, Response, Status,
+    codegen::{Body, BoxFuture, Context, Poll, Service, StdError, http},
+    server::{Grpc as GrpcServer, NamedService, UnaryService},
+    transport::{Channel, Endpoint},
+};
+use tonic_prost::ProstCodec;
+
+/// Protocol of the remote block service, for generating servers or clients
+/// in other languages, e.g. a Python or Julia plant model.
+pub const REMOTE_BLOCK_PROTO: &str = include_str!("../../../proto/remote_block.proto");
+
+const STEP_PATH: &str = "/aule.RemoteBlock/Step";
+const RESET_PATH: &str = "/aule.RemoteBlock/Reset";
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StepRequest {
+    #[prost(double, repeated, tag = "1")]
+    pub input: Vec<f64>,
+    #[prost(uint64, tag = "2")]
+    pub dt_ns: u64,
+    #[prost(uint64, tag = "3")]
+    pub sim_time_ns: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StepReply {
+    #[prost(double, repeated, tag = "1")]
+    pub output: Vec<f64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Empty {}
+
+/// Values that travel as a flat list of doubles.
+pub trait RemoteValue: Sized {
+    fn to_wire(&self) -> Vec<f64>;
+    fn from_wire(values: &[f64]) -> Option<Self>;
+}
+
+impl RemoteValue for () {
+    fn to_wire(&self) -> Vec<f64> {
+        Vec::new()
+    }
+
+    fn from_wire(values: &[f64]) -> Option<Self> {
+        values.is_empty().then_some(())
+    }
+}
+
+impl RemoteValue for f64 {
+    fn to_wire(&self) -> Vec<f64> {
+        alloc::vec![*self]
+    }
+
+    fn from_wire(values: &[f64]) -> Option<Self> {
+        match values {
+            [value] => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl<const N: usize> RemoteValue for [f64; N] {
+    fn to_wire(&self) -> Vec<f64> {
+        self.to_vec()
+    }
+
+    fn from_wire(values: &[f64]) -> Option<Self> {
+        values.try_into().ok()
+    }
+}
+
+/// Serves a local block over gRPC, one `Step` RPC per sample.
+#[derive(Debug)]
+pub struct RemoteBlockServer<B> {
+    block: Arc<Mutex<B>>,
+}
+
+/// Block whose steps run on a [`RemoteBlockServer`], or any server
+/// implementing [`REMOTE_BLOCK_PROTO`]. Each step is a blocking round trip, so the
+/// remote model must answer within the loop period.
+#[derive(Debug)]
+pub struct RemoteBlock<I, O> {
+    runtime: tokio::runtime::Runtime,
+    client: tonic::client::Grpc<Channel>,
+    last_output: Option<O>,
+    _marker: PhantomData<I>,
+}
+
+impl<B> Clone for RemoteBlockServer<B> {
+    fn clone(&self) -> Self {
+        Self {
+            block: self.block.clone(),
+        }
+    }
+}
+
+impl<B> RemoteBlockServer<B>
+where
+    B: Block + Send + 'static,
+    B::Input: RemoteValue,
+    B::Output: RemoteValue,
+{
+    pub fn new(block: B) -> Self {
+        Self {
+            block: Arc::new(Mutex::new(block)),
+        }
+    }
+
+    /// Shared handle to the served block, e.g. to inspect its state.
+    pub fn block(&self) -> Arc<Mutex<B>> {
+        self.block.clone()
+    }
+
+    /// Serves on `addr` until the returned future is dropped or fails.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), tonic::transport::Error> {
+        tonic::transport::Server::builder()
+            .add_service(self)
+            .serve(addr)
+            .await
+    }
+
+    fn step(&self, request: StepRequest) -> Result<StepReply, Status> {
+        let input = B::Input::from_wire(&request.input)
+            .ok_or_else(|| Status::invalid_argument("Input has the wrong length"))?;
+        let sim_state = SimulationState::new(
+            Duration::from_nanos(request.dt_ns),
+            Duration::from_nanos(request.sim_time_ns),
+        );
+
+        let mut block = self
+            .block
+            .lock()
+            .map_err(|_| Status::internal("Block panicked in a previous step"))?;
+        let output = crate::block::run(&mut *block, input, sim_state);
+
+        Ok(StepReply {
+            output: output.to_wire(),
+        })
+    }
+
+    fn reset(&self) -> Result<Empty, Status> {
+        self.block
+            .lock()
+            .map_err(|_| Status::internal("Block panicked in a previous step"))?
+            .reset();
+        Ok(Empty {})
+    }
+}
+
+struct StepSvc<B>(RemoteBlockServer<B>);
+
+struct ResetSvc<B>(RemoteBlockServer<B>);
+
+impl<B> UnaryService<StepRequest> for StepSvc<B>
+where
+    B: Block + Send + 'static,
+    B::Input: RemoteValue,
+    B::Output: RemoteValue,
+{
+    type Response = StepReply;
+    type Future = BoxFuture<Response<StepReply>, Status>;
+
+    fn call(&mut self, request: Request<StepRequest>) -> Self::Future {
+        let reply = self.0.step(request.into_inner()).map(Response::new);
+        Box::pin(async move { reply })
+    }
+}
+
+impl<B> UnaryService<Empty> for ResetSvc<B>
+where
+    B: Block + Send + 'static,
+    B::Input: RemoteValue,
+    B::Output: RemoteValue,
+{
+    type Response = Empty;
+    type Future = BoxFuture<Response<Empty>, Status>;
+
+    fn call(&mut self, _request: Request<Empty>) -> Self::Future {
+        let reply = self.0.reset().map(Response::new);
+        Box::pin(async move { reply })
+    }
+}
+
+impl<B, Bd> Service<http::Request<Bd>> for RemoteBlockServer<B>
+where
+    B: Block + Send + 'static,
+    B::Input: RemoteValue,
+    B::Output: RemoteValue,
+    Bd: Body + Send + 'static,
+    Bd::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = core::convert::Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Bd>) -> Self::Future {
+        let server = self.clone();
+        match request.uri().path() {
+            STEP_PATH => Box::pin(async move {
+                let mut grpc = GrpcServer::new(ProstCodec::default());
+                Ok(grpc.unary(StepSvc(server), request).await)
+            }),
+            RESET_PATH => Box::pin(async move {
+                let mut grpc = GrpcServer::new(ProstCodec::default());
+                Ok(grpc.unary(ResetSvc(server), request).await)
+            }),
+            _ => Box::pin(async move { Ok(Status::unimplemented("Unknown method").into_http()) }),
+        }
+    }
+}
+
+impl<B> NamedService for RemoteBlockServer<B> {
+    const NAME: &'static str = "aule.RemoteBlock";
+}
+
+impl<I, O> RemoteBlock<I, O>
+where
+    I: RemoteValue,
+    O: RemoteValue + Clone,
+{
+    /// Connects to a server at `endpoint`, e.g. `"http://127.0.0.1:50051"`.
+    pub fn connect(endpoint: &str) -> Result<Self, tonic::transport::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to build the remote block runtime");
+        let channel = runtime.block_on(Endpoint::from_shared(endpoint.to_string())?.connect())?;
+
+        Ok(Self {
+            runtime,
+            client: tonic::client::Grpc::new(channel),
+            last_output: None,
+            _marker: PhantomData,
+        })
+    }
+
+    fn call<Req, Rep>(&mut self, path: &'static str, message: Req) -> Result<Rep, Status>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Rep: prost::Message + Default + Send + Sync + 'static,
+    {
+        let client = &mut self.client;
+        self.runtime.block_on(async move {
+            client
+                .ready()
+                .await
+                .map_err(|e| Status::unavailable(e.to_string()))?;
+            client
+                .unary(
+                    Request::new(message),
+                    http::uri::PathAndQuery::from_static(path),
+                    ProstCodec::default(),
+                )
+                .await
+                .map(Response::into_inner)
+        })
+    }
+}
+
+impl<I, O> Block for RemoteBlock<I, O>
+where
+    I: RemoteValue,
+    O: RemoteValue + Clone,
+{
+    type Input = I;
+    type Output = O;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let request = StepRequest {
+            input: input.to_wire(),
+            dt_ns: sim_state.dt().as_nanos() as u64,
+            sim_time_ns: sim_state.sim_time().as_nanos() as u64,
+        };
+        let reply: StepReply = self
+            .call(STEP_PATH, request)
+            .unwrap_or_else(|status| panic!("Remote block step failed: {}", status));
+        let output = O::from_wire(&reply.output).expect("Remote block output has the wrong length");
+
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        let _: Empty = self
+            .call(RESET_PATH, Empty {})
+            .unwrap_or_else(|status| panic!("Remote block reset failed: {}", status));
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{RemoteBlock, RemoteBlockServer};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_remote_block_matches_local() {
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let server = RemoteBlockServer::new(Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4));
+        std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(server.serve(addr))
+        });
+
+        let endpoint = std::format!("http://{}", addr);
+        let mut remote = (0..50)
+            .find_map(|_| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                RemoteBlock::<f64, f64>::connect(&endpoint).ok()
+            })
+            .unwrap();
+        let mut local = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        for sim_state in Simulation::new(0.01, 0.5) {
+            let y_remote = 1.0.as_signal(sim_state) >> remote.as_block();
+            let y_local = 1.0.as_signal(sim_state) >> local.as_block();
+            assert_eq!(y_remote.value, y_local.value);
+        }
+
+        remote.reset();
+        assert_eq!(remote.last_output(), None);
+    }
+}
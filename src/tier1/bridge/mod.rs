@@ -1,3 +1,9 @@
+#[cfg(feature = "tokio")]
+pub mod async_io;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "ros2")]
+pub mod ros2;
 pub mod swd;
 
 #[cfg(all(not(feature = "std"), feature = "swd"))]
@@ -0,0 +1,314 @@
+use crate::Error;
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::Mutex;
+
+/// Latest `data` received on each subscribed topic.
+type Inbox = Arc<Mutex<BTreeMap<String, f64>>>;
+
+/// Connection to a ROS 2 graph through a `rosbridge_server` TCP endpoint
+/// (`ros2 launch rosbridge_server rosbridge_tcp_launch.xml`), speaking the
+/// rosbridge v2 JSON protocol. Signals travel as `std_msgs/msg/Float64`.
+///
+/// Going through rosbridge keeps the build free of a sourced ROS 2
+/// installation, so the same binary drives a software-in-the-loop test
+/// against any ROS 2 stack reachable over the network.
+#[derive(Debug)]
+pub struct Ros2Bridge {
+    stream: TcpStream,
+    inbox: Inbox,
+}
+
+/// Pass-through block publishing its input to a ROS 2 topic every step.
+#[derive(Debug)]
+pub struct Ros2Publisher {
+    stream: TcpStream,
+    topic: String,
+    failed: u64,
+    last_output: Option<f64>,
+}
+
+/// Source block holding the last value received on a ROS 2 topic.
+#[derive(Debug)]
+pub struct Ros2Subscriber {
+    inbox: Inbox,
+    topic: String,
+    initial: f64,
+    last_output: Option<f64>,
+}
+
+impl Ros2Bridge {
+    pub fn connect(addr: impl ToSocketAddrs) -> Result<Self, Error> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+
+        let inbox = Inbox::default();
+        let mut reader = stream.try_clone()?;
+        let messages = inbox.clone();
+        std::thread::spawn(move || {
+            let mut pending = Vec::new();
+            let mut buffer = [0u8; 4096];
+            while let Ok(n) = reader.read(&mut buffer) {
+                if n == 0 {
+                    break;
+                }
+                pending.extend_from_slice(&buffer[..n]);
+                while let Some(end) = object_end(&pending) {
+                    let object = pending.drain(..end).collect::<Vec<_>>();
+                    if let Some((topic, data)) = parse_publish(&String::from_utf8_lossy(&object)) {
+                        messages.lock().unwrap().insert(topic, data);
+                    }
+                }
+            }
+        });
+
+        Ok(Self { stream, inbox })
+    }
+
+    /// Advertises `topic` and returns the block publishing to it.
+    pub fn publisher(&self, topic: &str) -> Result<Ros2Publisher, Error> {
+        check_topic(topic)?;
+        let mut stream = self.stream.try_clone()?;
+        stream.write_all(
+            format!(
+                r#"{{"op":"advertise","topic":"{}","type":"std_msgs/msg/Float64"}}"#,
+                topic
+            )
+            .as_bytes(),
+        )?;
+
+        Ok(Ros2Publisher {
+            stream,
+            topic: topic.to_string(),
+            failed: 0,
+            last_output: None,
+        })
+    }
+
+    /// Subscribes to `topic` and returns the block reading it.
+    pub fn subscriber(&self, topic: &str) -> Result<Ros2Subscriber, Error> {
+        check_topic(topic)?;
+        let mut stream = self.stream.try_clone()?;
+        stream.write_all(
+            format!(
+                r#"{{"op":"subscribe","topic":"{}","type":"std_msgs/msg/Float64"}}"#,
+                topic
+            )
+            .as_bytes(),
+        )?;
+
+        Ok(Ros2Subscriber {
+            inbox: self.inbox.clone(),
+            topic: topic.to_string(),
+            initial: 0.0,
+            last_output: None,
+        })
+    }
+}
+
+/// Closes the connection, for the blocks created from the bridge too.
+impl Drop for Ros2Bridge {
+    fn drop(&mut self) {
+        self.stream.shutdown(std::net::Shutdown::Both).ok();
+    }
+}
+
+impl Ros2Publisher {
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Messages that could not be written to the bridge.
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+}
+
+impl Ros2Subscriber {
+    /// Value output until the first message arrives, `0` by default.
+    pub fn with_initial(mut self, initial: f64) -> Self {
+        self.initial = initial;
+        self
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+}
+
+impl Block for Ros2Publisher {
+    type Input = f64;
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        // JSON has no NaN nor infinities, rosbridge reads `null` as NaN.
+        let data = if input.is_finite() {
+            input.to_string()
+        } else {
+            String::from("null")
+        };
+        let message = format!(
+            r#"{{"op":"publish","topic":"{}","msg":{{"data":{}}}}}"#,
+            self.topic, data
+        );
+        if self.stream.write_all(message.as_bytes()).is_err() {
+            self.failed += 1;
+        }
+
+        self.last_output = Some(input);
+        input
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.failed = 0;
+        self.last_output = None;
+    }
+}
+
+impl Block for Ros2Subscriber {
+    type Input = ();
+    type Output = f64;
+
+    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let received = self.inbox.lock().unwrap().get(&self.topic).copied();
+        let output = received.or(self.last_output).unwrap_or(self.initial);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+/// ROS 2 topic names only use alphanumerics, `_`, `/` and `~`, which also
+/// keeps them safe to embed in JSON unescaped.
+fn check_topic(topic: &str) -> Result<(), Error> {
+    let valid = !topic.is_empty()
+        && topic
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '/' | '~'));
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Connection(format!(
+            "Invalid ROS 2 topic {:?}",
+            topic
+        )))
+    }
+}
+
+/// Length of the first complete JSON object in `bytes`, skipping anything
+/// before its opening brace.
+fn object_end(bytes: &[u8]) -> Option<usize> {
+    let start = bytes.iter().position(|b| *b == b'{')?;
+    let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+
+    for (i, b) in bytes.iter().enumerate().skip(start) {
+        match (*b, in_string, escaped) {
+            (_, true, true) => escaped = false,
+            (b'\\', true, false) => escaped = true,
+            (b'"', _, _) => in_string = !in_string,
+            (b'{', false, _) => depth += 1,
+            (b'}', false, _) => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Topic and `data` of a rosbridge `publish` operation.
+fn parse_publish(object: &str) -> Option<(String, f64)> {
+    if string_field(object, "op")? != "publish" {
+        return None;
+    }
+    let topic = string_field(object, "topic")?;
+
+    let msg = &object[object.find(r#""msg""#)?..];
+    let value = value_of(msg, "data")?;
+    let end = value.find([',', '}', ' ', '\n']).unwrap_or(value.len());
+    let data = match &value[..end] {
+        "null" => f64::NAN,
+        number => number.parse().ok()?,
+    };
+
+    Some((topic.to_string(), data))
+}
+
+fn string_field<'a>(object: &'a str, key: &str) -> Option<&'a str> {
+    let value = value_of(object, key)?.strip_prefix('"')?;
+    Some(&value[..value.find('"')?])
+}
+
+/// Text following `"key":`, with the whitespace around the colon skipped.
+fn value_of<'a>(object: &'a str, key: &str) -> Option<&'a str> {
+    let after = &object[object.find(&format!("\"{}\"", key))? + key.len() + 2..];
+    after.trim_start().strip_prefix(':').map(str::trim_start)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::string::String;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_ros2_bridge_publishes_and_subscribes() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        // Stand-in for rosbridge: echoes a command on /cmd and collects
+        // everything the bridge sends.
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream
+                .write_all(br#"{"op": "publish", "topic": "/cmd", "msg": {"data": 2.5}}"#)
+                .unwrap();
+            let mut received = String::new();
+            stream.read_to_string(&mut received).unwrap();
+            received
+        });
+
+        let bridge = Ros2Bridge::connect(addr).unwrap();
+        let mut command = bridge.subscriber("/cmd").unwrap().with_initial(-1.0);
+        let mut output = bridge.publisher("/plant/y").unwrap();
+        assert!(bridge.publisher("not a topic").is_err());
+
+        let sim_state = Simulation::new(0.01, 1.0).next().unwrap();
+        let start = Instant::now();
+        while command.block((), sim_state) != 2.5 {
+            assert!(start.elapsed() < Duration::from_secs(5), "No message");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let y = ((sim_state >> command.as_block()) * 2.0) >> output.as_block();
+        assert_eq!(y.value, 5.0);
+        assert_eq!(output.failed(), 0);
+
+        drop((command, output, bridge));
+        let received = server.join().unwrap();
+        assert!(received.contains(r#""op":"subscribe","topic":"/cmd""#));
+        assert!(received.contains(r#""op":"advertise","topic":"/plant/y""#));
+        assert!(received.contains(r#"{"op":"publish","topic":"/plant/y","msg":{"data":5}}"#));
+    }
+}
@@ -3,6 +3,7 @@ type BridgeId = [u8; 6];
 
 #[cfg(all(feature = "std", feature = "swd"))]
 pub mod std {
+    use crate::Error;
     use crate::block::Block;
     use crate::prelude::SimulationState;
     use crate::tier1::bridge::swd::BridgeId;
@@ -14,7 +15,7 @@ pub mod std {
     use std::{
         collections::HashMap,
         eprintln,
-        string::{String, ToString},
+        string::ToString,
         sync::mpsc::{Receiver, Sender, channel},
         thread::{self},
         vec::Vec,
@@ -38,7 +39,20 @@ pub mod std {
     }
 
     impl SwdConnection {
+        /// Attaches like [`try_new`](Self::try_new), panicking when no probe
+        /// reaches the target: there is no connection to fall back to.
         pub fn new(chip_name: &str, core: usize, ram_offset: u64, ram_size: u64) -> Self {
+            Self::try_new(chip_name, core, ram_offset, ram_size)
+                .unwrap_or_else(|error| panic!("{}", error))
+        }
+
+        /// Attaches to `chip_name` through the first debug probe found.
+        pub fn try_new(
+            chip_name: &str,
+            core: usize,
+            ram_offset: u64,
+            ram_size: u64,
+        ) -> Result<Self, Error> {
             let (req_sender, req_recv) = channel();
             let (rsp_sender_sender, rsp_sender_recv) = channel();
             let cfg = SessionConfig {
@@ -46,7 +60,8 @@ pub mod std {
                 protocol: Some(WireProtocol::Swd),
                 ..Default::default()
             };
-            let session = Session::auto_attach(chip_name, cfg).unwrap();
+            let session = Session::auto_attach(chip_name, cfg)
+                .map_err(|error| Error::Connection(error.to_string()))?;
 
             thread::spawn(move || {
                 Self::task(
@@ -59,11 +74,11 @@ pub mod std {
                 )
             });
 
-            Self {
+            Ok(Self {
                 req_sender,
                 rsp_sender_sender,
                 bridges: Vec::new(),
-            }
+            })
         }
 
         fn task(
@@ -146,14 +161,14 @@ pub mod std {
         pub fn new_bridge_down<T, const N: usize>(
             &mut self,
             name: &str,
-        ) -> Result<BridgeSwdDown<T, N>, String>
+        ) -> Result<BridgeSwdDown<T, N>, Error>
         where
             T: Clone + ToBytes<Bytes = [u8; N]>,
         {
             let real_name = Self::build_real_name(name, true);
 
             if self.bridges.contains(&real_name) {
-                return Err("A bridge with this name has already taken".to_string());
+                return Err(Error::BridgeTaken);
             }
 
             self.rsp_sender_sender
@@ -169,14 +184,14 @@ pub mod std {
         pub fn new_bridge_up<T, const N: usize>(
             &mut self,
             name: &str,
-        ) -> Result<BridgeSwdUp<T, N>, String>
+        ) -> Result<BridgeSwdUp<T, N>, Error>
         where
             T: Clone + FromBytes<Bytes = [u8; N]>,
         {
             let real_name = Self::build_real_name(name, false);
 
             if self.bridges.contains(&real_name) {
-                return Err("A bridge with this name has already taken".to_string());
+                return Err(Error::BridgeTaken);
             }
 
             let (req, rsp) = channel();
@@ -193,7 +208,7 @@ pub mod std {
         pub fn new_remote_block<T, const N: usize>(
             &mut self,
             name: &str,
-        ) -> Result<RemoteSwd<T, N>, String>
+        ) -> Result<RemoteSwd<T, N>, Error>
         where
             T: Clone + ToBytes<Bytes = [u8; N]> + FromBytes<Bytes = [u8; N]>,
         {
@@ -355,7 +370,7 @@ pub mod std {
 
 #[cfg(all(not(feature = "std"), feature = "swd"))]
 pub mod no_std {
-    use crate::{block::Block, prelude::SimulationState, tier1::bridge::swd::BridgeId};
+    use crate::{Error, block::Block, prelude::SimulationState, tier1::bridge::swd::BridgeId};
     use alloc::vec::Vec;
     use core::ptr;
 
@@ -364,11 +379,6 @@ pub mod no_std {
         bridges: Vec<BridgeId>,
     }
 
-    #[derive(Debug)]
-    pub enum SwdError {
-        BridgeHasAlreadyTaken,
-    }
-
     impl SwdConnection {
         fn build_real_name(name: &str, is_down: bool) -> [u8; 6] {
             let mut real_name = [0u8; 6];
@@ -382,33 +392,33 @@ pub mod no_std {
             real_name
         }
 
-        pub fn new_bridge_down<T>(&mut self, name: &str) -> Result<BridgeSwdDown<T>, SwdError>
+        pub fn new_bridge_down<T>(&mut self, name: &str) -> Result<BridgeSwdDown<T>, Error>
         where
             T: Default,
         {
             let real_name = Self::build_real_name(name, true);
 
             if self.bridges.contains(&real_name) {
-                return Err(SwdError::BridgeHasAlreadyTaken);
+                return Err(Error::BridgeTaken);
             }
 
             Ok(BridgeSwdDown::new(real_name))
         }
 
-        pub fn new_bridge_up<T>(&mut self, name: &str) -> Result<BridgeSwdUp<T>, SwdError>
+        pub fn new_bridge_up<T>(&mut self, name: &str) -> Result<BridgeSwdUp<T>, Error>
         where
             T: Default,
         {
             let real_name = Self::build_real_name(name, false);
 
             if self.bridges.contains(&real_name) {
-                return Err(SwdError::BridgeHasAlreadyTaken);
+                return Err(Error::BridgeTaken);
             }
 
             Ok(BridgeSwdUp::new(real_name))
         }
 
-        pub fn new_remote_block<T>(&mut self, name: &str) -> Result<RemoteSwd<T>, SwdError>
+        pub fn new_remote_block<T>(&mut self, name: &str) -> Result<RemoteSwd<T>, Error>
         where
             T: Default,
         {
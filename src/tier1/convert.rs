@@ -0,0 +1,86 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use crate::signal::Cast;
+use core::marker::PhantomData;
+
+/// Converts signals between blocks of different value types, e.g. an `f64`
+/// plant driving an `f32` controller or a scalar source feeding a
+/// one-element vector block. See [`Cast`] for the supported pairs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Convert<From, To> {
+    last_output: Option<To>,
+    _marker: PhantomData<From>,
+}
+
+impl<From, To> Convert<From, To>
+where
+    From: Cast<To>,
+{
+    pub fn new() -> Self {
+        Self {
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<From, To> Default for Convert<From, To>
+where
+    From: Cast<To>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<From, To> Block for Convert<From, To>
+where
+    From: Cast<To>,
+    To: Clone,
+{
+    type Input = From;
+    type Output = To;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = input.cast();
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_mixed_precision_diagram() {
+        let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut to_single = Convert::<f64, f32>::new();
+        let mut controller = Gain::new(0.5f32);
+        let mut to_double = Convert::<f32, f64>::default();
+        let mut to_vector = Convert::<f64, [f64; 1]>::new();
+        let mut vector_gain = VectorGain::new([2.0]);
+
+        for sim_state in Simulation::new(0.01, 1.0) {
+            let error = 1.0.as_signal(sim_state) - plant.last_output();
+            let u = error >> to_single.as_block() >> controller.as_block() >> to_double.as_block();
+            let y = u >> plant.as_block();
+
+            let doubled = y >> to_vector.as_block() >> vector_gain.as_block();
+            assert_signal_eq!(doubled.cast::<f64>(), y * 2.0, 1e-12, 0.0);
+            assert_eq!(u.cast::<f32>().value, controller.last_output().unwrap());
+            assert_eq!(to_vector.last_output(), Some([y.value]));
+        }
+
+        to_vector.reset();
+        assert_eq!(to_vector.last_output(), None);
+    }
+}
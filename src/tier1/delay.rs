@@ -1,26 +1,25 @@
 use crate::block::Block;
 use crate::prelude::SimulationState;
-use crate::signal::Signal;
-use alloc::vec;
-use alloc::vec::Vec;
-use core::ops::Mul;
+use crate::signal::{Signal, from_f64};
+use alloc::collections::VecDeque;
 use core::time::Duration;
-use num_traits::Zero;
+use num_traits::Float;
 
 #[derive(Clone, Debug)]
 pub struct Delay<T>
 where
-    T: Zero + Copy + Mul<f64, Output = T>,
+    T: Float,
 {
     delay: Duration,
     initial_value: T,
-    input_buffer: Vec<Signal<T>>,
+    input_buffer: VecDeque<Signal<T>>,
+    max_capacity: Option<usize>,
     last_output: Option<T>,
 }
 
 impl<T> Delay<T>
 where
-    T: Zero + Copy + Mul<f64, Output = T>,
+    T: Float,
 {
     pub fn new(delay: Duration) -> Self {
         assert!(
@@ -31,16 +30,45 @@ where
         Delay {
             delay,
             initial_value: T::zero(),
-            input_buffer: vec![],
+            input_buffer: VecDeque::new(),
+            max_capacity: None,
             last_output: None,
         }
     }
 
+    /// Pre-sizes the buffer for a simulation stepping at `dt`, so it doesn't
+    /// reallocate while the delay line fills up.
+    pub fn with_sample_time(mut self, dt: Duration) -> Self {
+        if !dt.is_zero() {
+            let samples = libm::ceil(self.delay.as_secs_f64() / dt.as_secs_f64()) as usize + 2;
+            self.input_buffer.reserve(samples);
+        }
+        self
+    }
+
+    /// Bounds the number of buffered samples. Samples arrive in time order, so
+    /// once full the oldest one is evicted. A capacity below
+    /// `delay / dt + 2` samples loses the sample due at the current time, and
+    /// the output holds the oldest one kept, shortening the delay.
+    pub fn with_max_capacity(mut self, max_capacity: usize) -> Self {
+        assert!(max_capacity >= 2, "Delay capacity must be at least 2");
+        self.max_capacity = Some(max_capacity);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.input_buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.input_buffer.is_empty()
+    }
+
     pub fn with_initial_signal(mut self, initial_signal: Signal<T>) -> Self {
         self.initial_value = initial_signal.value;
 
         if self.input_buffer.is_empty() {
-            self.input_buffer.push(initial_signal);
+            self.input_buffer.push_back(initial_signal);
         } else {
             self.input_buffer[0] = initial_signal;
         }
@@ -55,14 +83,24 @@ where
                 break;
             }
 
-            self.input_buffer.remove(0);
+            self.input_buffer.pop_front();
+        }
+    }
+
+    fn evict(&mut self) {
+        let Some(max_capacity) = self.max_capacity else {
+            return;
+        };
+
+        while self.input_buffer.len() > max_capacity {
+            self.input_buffer.pop_front();
         }
     }
 }
 
 impl<T> Block for Delay<T>
 where
-    T: Zero + Copy + Mul<f64, Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
@@ -76,7 +114,7 @@ where
             initial_sim_state.reset_sim_time();
             initial_sim_state += (self.delay, self.delay);
 
-            self.input_buffer.push(Signal {
+            self.input_buffer.push_back(Signal {
                 value: T::zero(),
                 sim_state: initial_sim_state,
             });
@@ -87,7 +125,8 @@ where
             sim_state,
         };
         input_delayed.sim_state += self.delay;
-        self.input_buffer.push(input_delayed);
+        self.input_buffer.push_back(input_delayed);
+        self.evict();
 
         /* # Current time before delay */
         if current_time < self.delay {
@@ -99,12 +138,14 @@ where
         /* # Current time after delay */
         self.drop_invalid_inputs(current_time);
 
-        let mut first_input = &self.input_buffer[0];
-        let mut second_input = self.input_buffer.get(1).unwrap_or(&input_delayed);
+        let first_input = &self.input_buffer[0];
+        let second_input = self.input_buffer.get(1).unwrap_or(&input_delayed);
 
         if current_time < first_input.sim_state.sim_time() {
-            second_input = first_input;
-            first_input = &input_delayed;
+            // The sample due now was evicted.
+            let output = first_input.value;
+            self.last_output = Some(output);
+            return output;
         }
 
         let gama = if first_input.sim_state.sim_time().as_secs_f64()
@@ -123,7 +164,8 @@ where
             gama
         );
 
-        let output = first_input.value * (1.0 - gama) + second_input.value * gama;
+        let gama = from_f64::<T>(gama);
+        let output = first_input.value * (T::one() - gama) + second_input.value * gama;
         self.last_output = Some(output);
         output
     }
@@ -278,12 +320,12 @@ mod tests {
         let mut output_signals = Vec::new();
         for i in 0..3 {
             let input = input_signal(i + 1, &mut simulation);
-            output_signals.push(input * delay.as_block());
+            output_signals.push(input >> delay.as_block());
         }
         output_signals.push(delay.output(large_input_signal(4, &mut simulation)));
         for i in 0..6 {
             let input = half_signal(5 + i, &mut simulation);
-            output_signals.push(input * delay.as_block());
+            output_signals.push(input >> delay.as_block());
         }
         // Output signals will be:
         // 1st input (t=1s): output = 0.0 (initial signal)
@@ -297,4 +339,45 @@ mod tests {
         // 9th input (t=10.5s): output = 5.0 (fifth input delayed by 2s)
         // 10th input (t=11s): output = 6.0 (sixth input delayed by 2s)
     }
+
+    #[test]
+    fn test_delay_bounded_capacity() {
+        // 1 s at 10 ms needs 102 samples, the delay stays exact.
+        let mut sized = Delay::<f64>::new(Duration::from_secs(1)).with_max_capacity(102);
+        // 20 samples only keep the last 0.2 s of input.
+        let mut small = Delay::<f64>::new(Duration::from_secs(1)).with_max_capacity(20);
+
+        for sim_state in Simulation::new(0.01, 3.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let output = sized.block(t, sim_state);
+            let held = small.block(t, sim_state);
+            assert!(sized.len() <= 102 && small.len() <= 20);
+
+            let expected = (t - 1.0).max(0.0);
+            assert!((output - expected).abs() < 1e-9, "t = {t}: {output}");
+            for at in [0.5, 1.5, 2.0, 2.5, 3.0] {
+                if (t - at).abs() < 1e-9 {
+                    assert!((output - (at - 1.0).max(0.0)).abs() < 1e-9, "t = {t}");
+                }
+            }
+            if t > 1.0 + 1e-9 {
+                assert!((held - (t - 0.19)).abs() < 1e-9, "t = {t}: {held}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_delay_and_pid_in_single_precision() {
+        let mut delay = Delay::<f32>::new(Duration::from_millis(500));
+        let mut pid = PID::new(2.0f32, 1.0, 0.0);
+
+        let mut output = 0.0;
+        for sim_state in Simulation::new(0.25, 2.0) {
+            let delayed = delay.block(1.0, sim_state);
+            output = pid.block(delayed, sim_state);
+        }
+
+        // The step reaches the PID at 0.75 s, six samples before the end.
+        assert_eq!(output, 2.0 + 6.0 * 0.25);
+    }
 }
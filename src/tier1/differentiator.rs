@@ -0,0 +1,121 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use crate::signal::from_f64;
+use num_traits::Float;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Differentiator<T>
+where
+    T: Float,
+{
+    tau: T,
+    last_input: Option<T>,
+    last_output: Option<T>,
+}
+
+impl<T> Differentiator<T>
+where
+    T: Float,
+{
+    pub fn new(tau: T) -> Self {
+        assert!(
+            tau >= T::zero(),
+            "Differentiator filter time constant must not be negative"
+        );
+
+        Self {
+            tau,
+            last_input: None,
+            last_output: None,
+        }
+    }
+
+    pub fn tau(&self) -> T {
+        self.tau
+    }
+}
+
+impl<T> Default for Differentiator<T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        Self::new(T::zero())
+    }
+}
+
+impl<T> Block for Differentiator<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let dt = from_f64::<T>(sim_state.dt().as_secs_f64());
+        let last_input = self.last_input.unwrap_or(input);
+        let last_output = self.last_output.unwrap_or(T::zero());
+
+        /* # Backward Euler discretization of s / (tau * s + 1) */
+        let output = (last_output * self.tau + (input - last_input)) / (self.tau + dt);
+
+        self.last_input = Some(input);
+        self.last_output = Some(output);
+
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_input = None;
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_differentiator_of_ramp_is_slope() {
+        let mut differentiator = Differentiator::<f64>::default();
+        let mut ramp = Ramp::new(3.0);
+
+        let outputs = Simulation::new(0.25, 1.0)
+            .map(|sim_state| (sim_state >> ramp.as_block()) >> differentiator.as_block())
+            .map(|signal| signal.value)
+            .collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(outputs[0], 0.0);
+        for output in &outputs[1..] {
+            assert!((output - 3.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_filtered_differentiator_smooths_step() {
+        let mut differentiator = Differentiator::new(0.1f64);
+        let mut simulation = Simulation::new(0.1, 1.0);
+
+        differentiator.block(0.0, simulation.next().unwrap());
+        let peak = differentiator.block(1.0, simulation.next().unwrap());
+        let decayed = differentiator.block(1.0, simulation.next().unwrap());
+
+        assert!((peak - 5.0).abs() < 1e-6);
+        assert!((decayed - 2.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_differentiator_f32() {
+        let mut differentiator = Differentiator::new(0.1f32);
+        let mut simulation = Simulation::new(0.1, 1.0);
+
+        differentiator.block(0.0, simulation.next().unwrap());
+        let peak = differentiator.block(1.0, simulation.next().unwrap());
+
+        assert!((peak - 5.0).abs() < 1e-5);
+    }
+}
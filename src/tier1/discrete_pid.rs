@@ -0,0 +1,316 @@
+use crate::{
+    block::{Block, Discrete},
+    prelude::{LimitStats, Limited, SimulationState},
+};
+use core::{
+    ops::{Add, Mul, Sub},
+    time::Duration,
+};
+use num_traits::{Zero, clamp};
+
+/// PID in sample units, `u[k] = kp e[k] + ki sum(e) + kd (e[k] - e[k-1])`,
+/// for loops designed in discrete time.
+///
+/// Unlike [`PID`](crate::prelude::PID) it never scales by `dt`: each step is
+/// one sample. In a loop stepping faster than the design, give it its sample
+/// time with [`DiscretePID::with_sample_time`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscretePID<T>
+where
+    T: Zero + Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    kp: T,
+    ki: T,
+    kd: T,
+    last_input: T,
+    sum: T,
+    last_output: Option<T>,
+    anti_windup: Option<(T, T)>,
+    limit_stats: LimitStats,
+}
+
+impl<T> DiscretePID<T>
+where
+    T: Zero + Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    pub fn new(kp: T, ki: T, kd: T) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            last_input: T::zero(),
+            sum: T::zero(),
+            last_output: None,
+            anti_windup: None,
+            limit_stats: LimitStats::default(),
+        }
+    }
+
+    /// Runs the PID once per `sample_time`, holding its output in between.
+    pub fn with_sample_time(self, sample_time: Duration) -> Sampled<Self> {
+        Sampled::new(self, sample_time)
+    }
+
+    pub fn with_anti_windup(mut self, min: T, max: T) -> Self {
+        self.anti_windup = Some((min, max));
+        self
+    }
+
+    pub fn sum(&self) -> &T {
+        &self.sum
+    }
+}
+
+impl<T> Block for DiscretePID<T>
+where
+    T: Zero + Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let sum = self.sum + input;
+        let output = self.kp * input + self.ki * sum + self.kd * (input - self.last_input);
+        let (output, sum, saturated) = match self.anti_windup {
+            Some((min, max)) if output < min || output > max => {
+                (clamp(output, min, max), self.sum, true)
+            }
+            _ => (output, sum, false),
+        };
+        self.limit_stats.record(saturated, sim_state.dt());
+
+        self.last_output = Some(output);
+        self.last_input = input;
+        self.sum = sum;
+
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_input = T::zero();
+        self.sum = T::zero();
+        self.last_output = None;
+        self.limit_stats = LimitStats::default();
+    }
+}
+
+impl<T> Discrete for DiscretePID<T> where
+    T: Zero + Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + PartialOrd
+{
+}
+
+impl<T> Limited for DiscretePID<T>
+where
+    T: Zero + Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + PartialOrd,
+{
+    fn limit_stats(&self) -> LimitStats {
+        self.limit_stats
+    }
+}
+
+/// Delay of exactly `N` samples, `y[k] = u[k - N]`, whatever the step size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscreteDelay<T, const N: usize>
+where
+    T: Zero + Copy,
+{
+    buffer: [T; N],
+    head: usize,
+    initial_value: T,
+    last_output: Option<T>,
+}
+
+impl<T, const N: usize> DiscreteDelay<T, N>
+where
+    T: Zero + Copy,
+{
+    pub fn new() -> Self {
+        assert!(N > 0, "DiscreteDelay must delay by at least one sample");
+
+        Self {
+            buffer: [T::zero(); N],
+            head: 0,
+            initial_value: T::zero(),
+            last_output: None,
+        }
+    }
+
+    /// Value output during the first `N` samples.
+    pub fn with_initial_value(mut self, initial_value: T) -> Self {
+        self.initial_value = initial_value;
+        self.buffer = [initial_value; N];
+        self
+    }
+}
+
+impl<T, const N: usize> Default for DiscreteDelay<T, N>
+where
+    T: Zero + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Block for DiscreteDelay<T, N>
+where
+    T: Zero + Copy,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = core::mem::replace(&mut self.buffer[self.head], input);
+        self.head = (self.head + 1) % N;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.buffer = [self.initial_value; N];
+        self.head = 0;
+        self.last_output = None;
+    }
+}
+
+impl<T, const N: usize> Discrete for DiscreteDelay<T, N> where T: Zero + Copy {}
+
+/// Runs a [`Discrete`] block once per `sample_time` and holds its output in
+/// between, so a block designed in sample units keeps its meaning inside a
+/// loop stepping faster. A loop slower than the sample time runs it once
+/// per step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sampled<B>
+where
+    B: Discrete,
+{
+    block: B,
+    sample_time: Duration,
+    next_sample: Duration,
+    held: Option<B::Output>,
+}
+
+impl<B> Sampled<B>
+where
+    B: Discrete,
+{
+    pub fn new(block: B, sample_time: Duration) -> Self {
+        assert!(
+            !sample_time.is_zero(),
+            "Sample time must be greater than zero"
+        );
+
+        Self {
+            block,
+            sample_time,
+            next_sample: Duration::ZERO,
+            held: None,
+        }
+    }
+
+    pub fn sample_time(&self) -> Duration {
+        self.sample_time
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.block
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.block
+    }
+}
+
+impl<B> Block for Sampled<B>
+where
+    B: Discrete,
+    B::Output: Clone,
+{
+    type Input = B::Input;
+    type Output = B::Output;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let now = sim_state.sim_time();
+        let held = match self.held.take() {
+            Some(held) if now < self.next_sample => held,
+            _ => {
+                while self.next_sample <= now {
+                    self.next_sample += self.sample_time;
+                }
+                let sample = SimulationState::new(self.sample_time, now);
+                self.block.block(input, sample)
+            }
+        };
+
+        self.held = Some(held.clone());
+        held
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.held.clone()
+    }
+
+    fn reset(&mut self) {
+        self.block.reset();
+        self.next_sample = Duration::ZERO;
+        self.held = None;
+    }
+
+    fn name(&self) -> &str {
+        self.block.name()
+    }
+
+    fn preferred_sample_time(&self) -> Option<Duration> {
+        Some(self.sample_time)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_discrete_pid_and_delay_count_samples() {
+        let mut pid = DiscretePID::new(1.0, 0.5, 2.0).with_sample_time(Duration::from_millis(100));
+        let mut delay = DiscreteDelay::<f64, 2>::new().with_initial_value(-1.0);
+        let mut outputs = [0.0; 4];
+
+        for (k, sim_state) in Simulation::new(0.1, 1.0).take(4).enumerate() {
+            let u = (1.0).as_signal(sim_state) >> pid.as_block();
+            outputs[k] = (u >> delay.as_block()).value;
+        }
+
+        // u = [1 + 0.5 + 2, 1 + 1 + 0, 1 + 1.5 + 0, ...] delayed by two samples.
+        assert_eq!(outputs, [-1.0, -1.0, 3.5, 2.0]);
+    }
+
+    #[test]
+    fn test_sampled_discrete_pid_in_faster_loop() {
+        let mut pid = DiscretePID::new(0.0, 1.0, 0.0).with_sample_time(Duration::from_millis(100));
+        let mut outputs = std::vec::Vec::new();
+
+        for sim_state in Simulation::new(0.01, 1.0) {
+            outputs.push((1.0.as_signal(sim_state) >> pid.as_block()).value);
+        }
+
+        // A sample on the first step, then one every 100 ms up to 1 s, each
+        // held until the next.
+        assert_eq!(pid.inner().sum(), &11.0);
+        assert_eq!(outputs[..9], [1.0; 9]);
+        assert_eq!(outputs[9..19], [2.0; 10]);
+        assert_eq!(outputs[19], 3.0);
+        assert_eq!(
+            pid.preferred_sample_time(),
+            Some(Duration::from_millis(100))
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::marker::PhantomData;
+use core::ops::Add;
+
+/// Disturbance summed into the wrapped block's input, e.g. a load torque
+/// on a motor or a heat loss on a thermal plant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtInput;
+
+/// Disturbance summed into the wrapped block's output, e.g. sensor offset
+/// or an output load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtOutput;
+
+/// Adds an external disturbance port to a block. The wrapper takes
+/// `(input, disturbance)` and sums the disturbance at the point `P`, either
+/// [`AtInput`] or [`AtOutput`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithDisturbance<B, P>
+where
+    B: Block,
+{
+    block: B,
+    /// Disturbed output at [`AtOutput`], which the wrapped block never sees.
+    last_output: Option<B::Output>,
+    _point: PhantomData<P>,
+}
+
+impl<B> WithDisturbance<B, AtInput>
+where
+    B: Block,
+{
+    pub fn at_input(block: B) -> Self {
+        Self {
+            block,
+            last_output: None,
+            _point: PhantomData,
+        }
+    }
+}
+
+impl<B> WithDisturbance<B, AtOutput>
+where
+    B: Block,
+{
+    pub fn at_output(block: B) -> Self {
+        Self {
+            block,
+            last_output: None,
+            _point: PhantomData,
+        }
+    }
+}
+
+impl<B, P> WithDisturbance<B, P>
+where
+    B: Block,
+{
+    pub fn inner(&self) -> &B {
+        &self.block
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.block
+    }
+
+    pub fn into_inner(self) -> B {
+        self.block
+    }
+}
+
+impl<B> Block for WithDisturbance<B, AtInput>
+where
+    B: Block,
+    B::Input: Add<Output = B::Input>,
+{
+    type Input = (B::Input, B::Input);
+    type Output = B::Output;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (input, disturbance) = input;
+        crate::block::run(&mut self.block, input + disturbance, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.block.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.block.reset();
+    }
+
+    fn name(&self) -> &str {
+        self.block.name()
+    }
+}
+
+impl<B> Block for WithDisturbance<B, AtOutput>
+where
+    B: Block,
+    B::Output: Add<Output = B::Output> + Clone,
+{
+    type Input = (B::Input, B::Output);
+    type Output = B::Output;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (input, disturbance) = input;
+        let output = crate::block::run(&mut self.block, input, sim_state) + disturbance;
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.block.reset();
+        self.last_output = None;
+    }
+
+    fn name(&self) -> &str {
+        self.block.name()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_with_disturbance_sums_at_the_chosen_point() {
+        let mut at_input = WithDisturbance::at_input(Gain::new(2.0));
+        let mut at_output = WithDisturbance::at_output(Gain::new(2.0));
+
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+        let (u, d) = (1.0.as_signal(sim_state), 0.5.as_signal(sim_state));
+        assert_eq!(((u, d).pack() >> at_input.as_block()).value, 3.0);
+        assert_eq!(((u, d).pack() >> at_output.as_block()).value, 2.5);
+        assert_eq!(at_input.last_output(), Some(3.0));
+        assert_eq!(at_output.last_output(), Some(2.5));
+
+        at_output.reset();
+        assert_eq!(at_output.last_output(), None);
+    }
+}
@@ -1,15 +1,14 @@
+use crate::signal::from_f64;
 use crate::{block::Block, prelude::SimulationState, tier1::filter::Filter};
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct HighPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    cutoff_freq: f64,
-    alpha: f64,
+    cutoff_freq: T,
+    alpha: T,
     prev_input: Option<T>,
     prev_output: Option<T>,
     dt: Duration,
@@ -17,11 +16,11 @@ where
 
 impl<T> HighPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    pub fn new(cutoff_freq: f64, dt: Duration) -> Self {
-        let ts = dt.as_secs_f64();
-        let tau = 1.0 / (2.0 * core::f64::consts::PI * cutoff_freq);
+    pub fn new(cutoff_freq: T, dt: Duration) -> Self {
+        let ts = from_f64::<T>(dt.as_secs_f64());
+        let tau = T::one() / (from_f64::<T>(core::f64::consts::TAU) * cutoff_freq);
 
         #[cfg(feature = "std")]
         let alpha = (-ts / tau).exp();
@@ -37,39 +36,33 @@ where
         }
     }
 
-    pub fn cutoff_freq(&self) -> f64 {
+    pub fn cutoff_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn alpha(&self) -> f64 {
+    pub fn alpha(&self) -> T {
         self.alpha
     }
 }
 
 impl<T> Block for HighPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
 
     fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        let Some(prev_in) = self.prev_input.as_ref() else {
-            let filtered = input.clone() - input.clone();
+        let Some(prev_in) = self.prev_input else {
             self.prev_input = Some(input);
-            self.prev_output = Some(filtered.clone());
-            return filtered;
+            self.prev_output = Some(T::zero());
+            return T::zero();
         };
 
-        let prev_out_value = self.prev_output.as_ref().map_or_else(
-            || input.clone() - input.clone(),
-            |prev_out| prev_out.clone(),
-        );
-
-        let input_clone = input.clone();
-        let filtered = (prev_out_value.clone() + input - prev_in.clone()) * self.alpha;
-        self.prev_input = Some(input_clone);
-        self.prev_output = Some(filtered.clone());
+        let prev_out_value = self.prev_output.unwrap_or_else(T::zero);
+        let filtered = (prev_out_value + input - prev_in) * self.alpha;
+        self.prev_input = Some(input);
+        self.prev_output = Some(filtered);
 
         filtered
     }
@@ -80,13 +73,13 @@ where
     }
 
     fn last_output(&self) -> Option<Self::Output> {
-        self.prev_output.clone()
+        self.prev_output
     }
 }
 
 impl<T> Filter for HighPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -127,7 +120,7 @@ mod tests {
     #[test]
     fn test_high_pass_uses_null_initial_condition() {
         let sim_state = Simulation::new(0.1, 0.1).next().unwrap();
-        let mut filter = HighPass::new(1.0, Duration::from_secs_f32(0.1));
+        let mut filter = HighPass::new(1.0f64, Duration::from_secs_f32(0.1));
         let expected = 0.0;
 
         let output = filter.block(1.0, sim_state);
@@ -177,4 +170,13 @@ mod tests {
 
         assert!(high_rms > low_rms * 2.0);
     }
+    #[test]
+    fn test_high_pass_f32() {
+        let mut filter = HighPass::new(1.0f32, Duration::from_secs_f32(0.1));
+        let alpha = (-0.1f32 * 2.0 * core::f32::consts::PI).exp();
+        let mut simulation = Simulation::new(0.1, 1.0);
+
+        assert_eq!(filter.block(0.0, simulation.next().unwrap()), 0.0);
+        assert!((filter.block(1.0, simulation.next().unwrap()) - alpha).abs() < 1e-6);
+    }
 }
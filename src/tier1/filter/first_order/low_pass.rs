@@ -1,29 +1,28 @@
+use crate::signal::from_f64;
 use crate::{block::Block, prelude::SimulationState, tier1::filter::Filter};
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct LowPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    cutoff_freq: f64,
-    alpha: f64,
+    cutoff_freq: T,
+    alpha: T,
     prev_output: Option<T>,
     dt: Duration,
 }
 
 impl<T> LowPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    pub fn new(cutoff_freq: f64, dt: Duration) -> Self {
-        let ts = dt.as_secs_f64();
-        let tau = 1.0 / (2.0 * core::f64::consts::PI * cutoff_freq);
+    pub fn new(cutoff_freq: T, dt: Duration) -> Self {
+        let ts = from_f64::<T>(dt.as_secs_f64());
+        let tau = T::one() / (from_f64::<T>(core::f64::consts::TAU) * cutoff_freq);
 
         #[cfg(feature = "std")]
-        let alpha = 1.0 - (-ts / tau).exp();
+        let alpha = T::one() - (-ts / tau).exp();
         #[cfg(not(feature = "std"))]
         let alpha = ts / (tau + ts);
 
@@ -35,30 +34,27 @@ where
         }
     }
 
-    pub fn cutoff_freq(&self) -> f64 {
+    pub fn cutoff_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn alpha(&self) -> f64 {
+    pub fn alpha(&self) -> T {
         self.alpha
     }
 }
 
 impl<T> Block for LowPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
 
     fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        let prev_value = self
-            .prev_output
-            .as_ref()
-            .map_or_else(|| input.clone() - input.clone(), |prev| prev.clone());
+        let prev_value = self.prev_output.unwrap_or_else(T::zero);
 
-        let filtered = prev_value.clone() + (input - prev_value.clone()) * self.alpha;
-        self.prev_output = Some(filtered.clone());
+        let filtered = prev_value + (input - prev_value) * self.alpha;
+        self.prev_output = Some(filtered);
         filtered
     }
 
@@ -67,13 +63,13 @@ where
     }
 
     fn last_output(&self) -> Option<Self::Output> {
-        self.prev_output.clone()
+        self.prev_output
     }
 }
 
 impl<T> Filter for LowPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -172,4 +168,13 @@ mod tests {
 
         assert!(low_rms > high_rms * 2.0);
     }
+
+    #[test]
+    fn test_low_pass_f32() {
+        let sim_state = Simulation::new(0.1, 0.1).next().unwrap();
+        let mut filter = LowPass::new(1.0f32, Duration::from_secs_f32(0.1));
+        let alpha = 1.0 - (-0.1f32 * 2.0 * core::f32::consts::PI).exp();
+
+        assert!((filter.block(1.0, sim_state) - alpha).abs() < 1e-6);
+    }
 }
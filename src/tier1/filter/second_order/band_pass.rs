@@ -1,40 +1,38 @@
+use crate::signal::from_f64;
 use crate::{
     block::Block,
     prelude::{Biquad, Filter, SimulationState},
 };
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct BandPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    center_freq: f64,
-    q_factor: f64,
+    center_freq: T,
+    q_factor: T,
     biquad: Biquad<T>,
     dt: Duration,
 }
 
 impl<T> BandPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    pub fn new(center_freq: f64, q_factor: f64, dt: Duration) -> Self {
-        let ts = dt.as_secs_f64();
+    pub fn new(center_freq: T, q_factor: T, dt: Duration) -> Self {
+        let ts = from_f64::<T>(dt.as_secs_f64());
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
 
-        #[cfg(feature = "std")]
-        let k = (core::f64::consts::PI * center_freq * ts).tan();
-        #[cfg(not(feature = "std"))]
-        let k = libm::tan(core::f64::consts::PI * center_freq * ts);
-        let a0 = 1.0 + k / q_factor + k * k;
+        let k = (from_f64::<T>(core::f64::consts::PI) * center_freq * ts).tan();
+        let a0 = one + k / q_factor + k * k;
 
         let b0 = k / q_factor / a0;
-        let b1 = 0.0;
+        let b1 = T::zero();
         let b2 = -b0;
-        let a1 = 2.0 * (k * k - 1.0) / a0;
-        let a2 = (1.0 - k / q_factor + k * k) / a0;
+        let a1 = two * (k * k - one) / a0;
+        let a2 = (one - k / q_factor + k * k) / a0;
 
         Self {
             center_freq,
@@ -44,22 +42,22 @@ where
         }
     }
 
-    pub fn center_freq(&self) -> f64 {
+    pub fn center_freq(&self) -> T {
         self.center_freq
     }
 
-    pub fn q_factor(&self) -> f64 {
+    pub fn q_factor(&self) -> T {
         self.q_factor
     }
 
-    pub fn biquad_coefficients(&self) -> (f64, f64, f64, f64, f64) {
+    pub fn biquad_coefficients(&self) -> (T, T, T, T, T) {
         self.biquad.coefficients()
     }
 }
 
 impl<T> Block for BandPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
@@ -79,7 +77,7 @@ where
 
 impl<T> Filter for BandPass<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -87,3 +85,24 @@ where
         self.dt
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+    use core::time::Duration;
+
+    #[test]
+    fn test_band_pass_f32_matches_f64() {
+        let dt = Duration::from_millis(1);
+        let mut filter = BandPass::new(50.0, 2.0, dt);
+        let mut filter32 = BandPass::new(50.0f32, 2.0, dt);
+
+        for sim_state in Simulation::new(1e-3, 0.2) {
+            let x = libm::sin(2.0 * PI * 50.0 * sim_state.sim_time().as_secs_f64());
+            let y = filter.block(x, sim_state);
+            let y32 = filter32.block(x as f32, sim_state);
+            assert!((y - y32 as f64).abs() < 1e-4);
+        }
+    }
+}
@@ -2,20 +2,18 @@ use crate::{
     block::Block,
     prelude::{Filter, SimulationState},
 };
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct Biquad<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    b0: f64,
-    b1: f64,
-    b2: f64,
-    a1: f64,
-    a2: f64,
+    b0: T,
+    b1: T,
+    b2: T,
+    a1: T,
+    a2: T,
     prev_input: [Option<T>; 2],
     prev_output: [Option<T>; 2],
     dt: Duration,
@@ -23,9 +21,9 @@ where
 
 impl<T> Biquad<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    pub fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64, dt: Duration) -> Self {
+    pub fn new(b0: T, b1: T, b2: T, a1: T, a2: T, dt: Duration) -> Self {
         Self {
             b0,
             b1,
@@ -38,53 +36,36 @@ where
         }
     }
 
-    pub fn coefficients(&self) -> (f64, f64, f64, f64, f64) {
+    pub fn coefficients(&self) -> (T, T, T, T, T) {
         (self.b0, self.b1, self.b2, self.a1, self.a2)
     }
 }
 
 impl<T> Block for Biquad<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
 
     fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
-        let prev_in_1 = self.prev_input[0].as_ref();
-        let prev_in_2 = self.prev_input[1].as_ref();
-        let prev_out_1 = self.prev_output[0].as_ref();
-        let prev_out_2 = self.prev_output[1].as_ref();
-
-        let prev_in_value_1 = prev_in_1
-            .cloned()
-            .unwrap_or_else(|| input.clone() - input.clone());
-        let prev_in_value_2 = prev_in_2
-            .cloned()
-            .unwrap_or_else(|| input.clone() - input.clone());
-        let prev_out_value_1 = prev_out_1
-            .cloned()
-            .unwrap_or_else(|| input.clone() - input.clone());
-        let prev_out_value_2 = prev_out_2
-            .cloned()
-            .unwrap_or_else(|| input.clone() - input.clone());
-
-        let input_clone = input.clone();
-        let filtered =
-            input * self.b0 + prev_in_value_1.clone() * self.b1 + prev_in_value_2.clone() * self.b2
-                - prev_out_value_1.clone() * self.a1
-                - prev_out_value_2.clone() * self.a2;
+        let [prev_in_1, prev_in_2] = self.prev_input.map(|value| value.unwrap_or_else(T::zero));
+        let [prev_out_1, prev_out_2] = self.prev_output.map(|value| value.unwrap_or_else(T::zero));
+
+        let filtered = input * self.b0 + prev_in_1 * self.b1 + prev_in_2 * self.b2
+            - prev_out_1 * self.a1
+            - prev_out_2 * self.a2;
 
         self.prev_input[1] = self.prev_input[0].take();
-        self.prev_input[0] = Some(input_clone);
+        self.prev_input[0] = Some(input);
         self.prev_output[1] = self.prev_output[0].take();
-        self.prev_output[0] = Some(filtered.clone());
+        self.prev_output[0] = Some(filtered);
 
         filtered
     }
 
     fn last_output(&self) -> Option<Self::Output> {
-        self.prev_output[0].clone()
+        self.prev_output[0]
     }
 
     fn reset(&mut self) {
@@ -95,7 +76,7 @@ where
 
 impl<T> Filter for Biquad<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -152,7 +133,7 @@ mod tests {
     #[test]
     fn test_biquad_uses_null_initial_condition() {
         let dt = 0.01_f64;
-        let mut filter = Biquad::new(0.0, 1.0, 0.0, 0.0, 0.0, Duration::from_secs_f64(dt));
+        let mut filter = Biquad::new(0.0f64, 1.0, 0.0, 0.0, 0.0, Duration::from_secs_f64(dt));
         let sim_state = Simulation::new(dt as f32, dt as f32).next().unwrap();
 
         let output = filter.block(1.0, sim_state);
@@ -189,4 +170,15 @@ mod tests {
 
         assert!(low_rms > high_rms * 2.0);
     }
+
+    #[test]
+    fn test_biquad_f32() {
+        let dt = 0.01_f32;
+        let mut filter = Biquad::new(0.5f32, 0.5, 0.0, 0.0, 0.0, Duration::from_secs_f32(dt));
+        let mut sim = Simulation::new(dt, 2.0 * dt);
+
+        assert_eq!(filter.block(2.0, sim.next().unwrap()), 1.0);
+        assert_eq!(filter.block(2.0, sim.next().unwrap()), 2.0);
+        assert_eq!(filter.coefficients(), (0.5, 0.5, 0.0, 0.0, 0.0));
+    }
 }
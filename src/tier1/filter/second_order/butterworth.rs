@@ -1,54 +1,43 @@
+use crate::signal::from_f64;
 use crate::{
     block::Block,
     prelude::{Biquad, Filter, SimulationState},
 };
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct Butterworth<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    cutoff_freq: f64,
+    cutoff_freq: T,
     biquad: Biquad<T>,
     dt: Duration,
 }
 
 impl<T> Butterworth<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    fn base_parameters(cutoff_freq: f64, dt: Duration) -> (f64, f64, f64) {
-        let ts = dt.as_secs_f64();
-        #[cfg(feature = "std")]
-        let (k, d) = {
-            let k = (core::f64::consts::PI * cutoff_freq * ts).tan();
-            let d = 2f64.sqrt();
-
-            (k, d)
-        };
-        #[cfg(not(feature = "std"))]
-        let (k, d) = {
-            let k = libm::tan(core::f64::consts::PI * cutoff_freq * ts);
-            let d = libm::sqrt(2.0);
-
-            (k, d)
-        };
-        let a0 = 1.0 + d * k + k * k;
+    fn base_parameters(cutoff_freq: T, dt: Duration) -> (T, T, T) {
+        let ts = from_f64::<T>(dt.as_secs_f64());
+        let k = (from_f64::<T>(core::f64::consts::PI) * cutoff_freq * ts).tan();
+        let d = from_f64::<T>(core::f64::consts::SQRT_2);
+        let a0 = T::one() + d * k + k * k;
 
         (k, d, a0)
     }
 
-    pub fn low_pass(cutoff_freq: f64, dt: Duration) -> Self {
+    pub fn low_pass(cutoff_freq: T, dt: Duration) -> Self {
         let (k, d, a0) = Self::base_parameters(cutoff_freq, dt);
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
 
         let b0 = k * k / a0;
-        let b1 = 2.0 * b0;
+        let b1 = two * b0;
         let b2 = b0;
-        let a1 = 2.0 * (k * k - 1.0) / a0;
-        let a2 = (1.0 - d * k + k * k) / a0;
+        let a1 = two * (k * k - one) / a0;
+        let a2 = (one - d * k + k * k) / a0;
 
         Self {
             cutoff_freq,
@@ -57,14 +46,16 @@ where
         }
     }
 
-    pub fn high_pass(cutoff_freq: f64, dt: Duration) -> Self {
+    pub fn high_pass(cutoff_freq: T, dt: Duration) -> Self {
         let (k, d, a0) = Self::base_parameters(cutoff_freq, dt);
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
 
-        let b0 = 1.0 / a0;
-        let b1 = -2.0 * b0;
+        let b0 = one / a0;
+        let b1 = -two * b0;
         let b2 = b0;
-        let a1 = 2.0 * (k * k - 1.0) / a0;
-        let a2 = (1.0 - d * k + k * k) / a0;
+        let a1 = two * (k * k - one) / a0;
+        let a2 = (one - d * k + k * k) / a0;
 
         Self {
             cutoff_freq,
@@ -73,18 +64,18 @@ where
         }
     }
 
-    pub fn cutoff_freq(&self) -> f64 {
+    pub fn cutoff_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn biquad_coefficients(&self) -> (f64, f64, f64, f64, f64) {
+    pub fn biquad_coefficients(&self) -> (T, T, T, T, T) {
         self.biquad.coefficients()
     }
 }
 
 impl<T> Block for Butterworth<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
@@ -104,7 +95,7 @@ where
 
 impl<T> Filter for Butterworth<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -112,3 +103,24 @@ where
         self.dt
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+    use core::time::Duration;
+
+    #[test]
+    fn test_butterworth_f32_matches_f64() {
+        let dt = Duration::from_millis(1);
+        let mut filter = Butterworth::low_pass(50.0, dt);
+        let mut filter32 = Butterworth::low_pass(50.0f32, dt);
+
+        for sim_state in Simulation::new(1e-3, 0.2) {
+            let x = libm::sin(2.0 * PI * 50.0 * sim_state.sim_time().as_secs_f64());
+            let y = filter.block(x, sim_state);
+            let y32 = filter32.block(x as f32, sim_state);
+            assert!((y - y32 as f64).abs() < 1e-4);
+        }
+    }
+}
@@ -1,69 +1,54 @@
+use crate::signal::from_f64;
 use crate::{
     block::Block,
     prelude::{Biquad, Filter, SimulationState},
 };
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct Chebyshev1<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    cutoff_freq: f64,
-    ripple_db: f64,
+    cutoff_freq: T,
+    ripple_db: T,
     biquad: Biquad<T>,
     dt: Duration,
 }
 
 impl<T> Chebyshev1<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    fn base_parameters(cutoff_freq: f64, ripple_db: f64, dt: Duration) -> (f64, f64, f64) {
-        let ts = dt.as_secs_f64();
-
-        #[cfg(feature = "std")]
-        let epsilon = (10f64.powf(ripple_db / 10.0) - 1.0).sqrt();
-        #[cfg(not(feature = "std"))]
-        let epsilon = libm::sqrt(libm::pow(10.0, ripple_db / 10.0) - 1.0);
-
-        #[cfg(feature = "std")]
-        let gamma = 0.5 * (1.0 / epsilon).asinh();
-        #[cfg(not(feature = "std"))]
-        let gamma = 0.5 * libm::asinh(1.0 / epsilon);
-
-        #[cfg(feature = "std")]
+    fn base_parameters(cutoff_freq: T, ripple_db: T, dt: Duration) -> (T, T, T) {
+        let ts = from_f64::<T>(dt.as_secs_f64());
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
+        let ten = from_f64::<T>(10.0);
+
+        let epsilon = (ten.powf(ripple_db / ten) - one).sqrt();
+        let gamma = (one / epsilon).asinh() / two;
         let (sinh_g, cosh_g) = (gamma.sinh(), gamma.cosh());
-        #[cfg(not(feature = "std"))]
-        let (sinh_g, cosh_g) = (libm::sinh(gamma), libm::cosh(gamma));
-
-        #[cfg(feature = "std")]
-        let wn = ((sinh_g * sinh_g + cosh_g * cosh_g) / 2.0).sqrt();
-        #[cfg(not(feature = "std"))]
-        let wn = libm::sqrt((sinh_g * sinh_g + cosh_g * cosh_g) / 2.0);
+        let wn = ((sinh_g * sinh_g + cosh_g * cosh_g) / two).sqrt();
 
-        let d = core::f64::consts::SQRT_2 * sinh_g / wn;
+        let d = from_f64::<T>(core::f64::consts::SQRT_2) * sinh_g / wn;
+        let k = (from_f64::<T>(core::f64::consts::PI) * cutoff_freq * ts).tan() * wn;
 
-        #[cfg(feature = "std")]
-        let k = (core::f64::consts::PI * cutoff_freq * ts).tan() * wn;
-        #[cfg(not(feature = "std"))]
-        let k = libm::tan(core::f64::consts::PI * cutoff_freq * ts) * wn;
-
-        let a0 = k * k + d * k + 1.0;
+        let a0 = k * k + d * k + one;
 
         (d, k, a0)
     }
 
-    pub fn low_pass(cutoff_freq: f64, ripple_db: f64, dt: Duration) -> Self {
+    pub fn low_pass(cutoff_freq: T, ripple_db: T, dt: Duration) -> Self {
         let (d, k, a0) = Self::base_parameters(cutoff_freq, ripple_db, dt);
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
 
         let b0 = k * k / a0;
-        let b1 = 2.0 * b0;
+        let b1 = two * b0;
         let b2 = b0;
-        let a1 = 2.0 * (k * k - 1.0) / a0;
-        let a2 = (1.0 - d * k + k * k) / a0;
+        let a1 = two * (k * k - one) / a0;
+        let a2 = (one - d * k + k * k) / a0;
 
         Self {
             cutoff_freq,
@@ -73,14 +58,16 @@ where
         }
     }
 
-    pub fn high_pass(cutoff_freq: f64, ripple_db: f64, dt: Duration) -> Self {
+    pub fn high_pass(cutoff_freq: T, ripple_db: T, dt: Duration) -> Self {
         let (d, k, a0) = Self::base_parameters(cutoff_freq, ripple_db, dt);
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
 
-        let b0 = 1.0 / a0;
-        let b1 = -2.0 * b0;
+        let b0 = one / a0;
+        let b1 = -two * b0;
         let b2 = b0;
-        let a1 = 2.0 * (k * k - 1.0) / a0;
-        let a2 = (1.0 - d * k + k * k) / a0;
+        let a1 = two * (k * k - one) / a0;
+        let a2 = (one - d * k + k * k) / a0;
 
         Self {
             cutoff_freq,
@@ -90,26 +77,26 @@ where
         }
     }
 
-    pub fn cutoff_freq(&self) -> f64 {
+    pub fn cutoff_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn center_freq(&self) -> f64 {
+    pub fn center_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn ripple_db(&self) -> f64 {
+    pub fn ripple_db(&self) -> T {
         self.ripple_db
     }
 
-    pub fn biquad_coefficients(&self) -> (f64, f64, f64, f64, f64) {
+    pub fn biquad_coefficients(&self) -> (T, T, T, T, T) {
         self.biquad.coefficients()
     }
 }
 
 impl<T> Block for Chebyshev1<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
@@ -129,7 +116,7 @@ where
 
 impl<T> Filter for Chebyshev1<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -137,3 +124,24 @@ where
         self.dt
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+    use core::time::Duration;
+
+    #[test]
+    fn test_chebyshev1_f32_matches_f64() {
+        let dt = Duration::from_millis(1);
+        let mut filter = Chebyshev1::low_pass(50.0, 1.0, dt);
+        let mut filter32 = Chebyshev1::low_pass(50.0f32, 1.0, dt);
+
+        for sim_state in Simulation::new(1e-3, 0.2) {
+            let x = libm::sin(2.0 * PI * 50.0 * sim_state.sim_time().as_secs_f64());
+            let y = filter.block(x, sim_state);
+            let y32 = filter32.block(x as f32, sim_state);
+            assert!((y - y32 as f64).abs() < 1e-4);
+        }
+    }
+}
@@ -1,81 +1,72 @@
+use crate::signal::from_f64;
 use crate::{
     block::Block,
     prelude::{Biquad, Filter, SimulationState},
 };
-use core::{
-    ops::{Add, Mul, Sub},
-    time::Duration,
-};
+use core::time::Duration;
+use num_traits::Float;
 
 pub struct Chebyshev2<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    cutoff_freq: f64,
-    ripple_db: f64,
+    cutoff_freq: T,
+    ripple_db: T,
     biquad: Biquad<T>,
     dt: Duration,
 }
 
 impl<T> Chebyshev2<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
-    fn base_parameters(cutoff_freq: f64, ripple_db: f64, dt: Duration) -> (f64, f64, f64, f64) {
-        let ts = dt.as_secs_f64();
-
-        #[cfg(feature = "std")]
-        let epsilon = (10f64.powf(ripple_db / 10.0) - 1.0).sqrt();
-        #[cfg(not(feature = "std"))]
-        let epsilon = libm::sqrt(libm::pow(10.0, ripple_db / 10.0) - 1.0);
-
-        #[cfg(feature = "std")]
-        let gamma = 0.5 * (1.0 / epsilon).asinh();
-        #[cfg(not(feature = "std"))]
-        let gamma = 0.5 * libm::asinh(1.0 / epsilon);
-
-        #[cfg(feature = "std")]
+    fn base_parameters(cutoff_freq: T, ripple_db: T, dt: Duration) -> (T, T, T, T) {
+        let ts = from_f64::<T>(dt.as_secs_f64());
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
+        let ten = from_f64::<T>(10.0);
+        let sqrt_2 = from_f64::<T>(core::f64::consts::SQRT_2);
+
+        let epsilon = (ten.powf(ripple_db / ten) - one).sqrt();
+        let gamma = (one / epsilon).asinh() / two;
         let (sinh_g, cosh_g) = (gamma.sinh(), gamma.cosh());
-        #[cfg(not(feature = "std"))]
-        let (sinh_g, cosh_g) = (libm::sinh(gamma), libm::cosh(gamma));
 
         // Type I pole locations (angle theta = pi/4)
-        let sigma_1 = sinh_g / core::f64::consts::SQRT_2;
-        let omega_1 = cosh_g / core::f64::consts::SQRT_2;
+        let sigma_1 = sinh_g / sqrt_2;
+        let omega_1 = cosh_g / sqrt_2;
         let r1_sq = sigma_1 * sigma_1 + omega_1 * omega_1;
 
         // Type II: invert poles
         let sigma_2 = sigma_1 / r1_sq;
-        let r2_sq = 1.0 / r1_sq;
+        let r2_sq = one / r1_sq;
 
         // Zeros at ±j/cos(π/4) = ±j√2 (normalized)
-        let omega_z_sq = 2.0;
+        let omega_z_sq = two;
 
-        #[cfg(feature = "std")]
-        let k = (core::f64::consts::PI * cutoff_freq * ts).tan();
-        #[cfg(not(feature = "std"))]
-        let k = libm::tan(core::f64::consts::PI * cutoff_freq * ts);
+        let k = (from_f64::<T>(core::f64::consts::PI) * cutoff_freq * ts).tan();
 
         (sigma_2, r2_sq, omega_z_sq, k)
     }
 
-    pub fn low_pass(cutoff_freq: f64, ripple_db: f64, dt: Duration) -> Self {
+    pub fn low_pass(cutoff_freq: T, ripple_db: T, dt: Duration) -> Self {
         let (sigma_2, r2_sq, omega_z_sq, k) = Self::base_parameters(cutoff_freq, ripple_db, dt);
+        let one = T::one();
+        let two = from_f64::<T>(2.0);
 
         // Denominator: H(s) denominator s² + 2σ₂s + r₂²
-        let a0 = r2_sq * k * k + 2.0 * sigma_2 * k + 1.0;
+        let a0 = r2_sq * k * k + two * sigma_2 * k + one;
 
         // Numerator with zeros: s² + ω_z²
         // Gain normalized for unity DC gain: multiply by r₂²/ω_z²
         let gain = r2_sq / omega_z_sq;
-        let n0 = 1.0 + omega_z_sq * k * k;
-        let n1 = 2.0 * (omega_z_sq * k * k - 1.0);
+        let n0 = one + omega_z_sq * k * k;
+        let n1 = two * (omega_z_sq * k * k - one);
 
         let b0 = gain * n0 / a0;
         let b1 = gain * n1 / a0;
         let b2 = b0;
-        let a1 = 2.0 * (r2_sq * k * k - 1.0) / a0;
-        let a2 = (r2_sq * k * k - 2.0 * sigma_2 * k + 1.0) / a0;
+        let a1 = two * (r2_sq * k * k - one) / a0;
+        let a2 = (r2_sq * k * k - two * sigma_2 * k + one) / a0;
 
         Self {
             cutoff_freq,
@@ -85,22 +76,23 @@ where
         }
     }
 
-    pub fn high_pass(cutoff_freq: f64, ripple_db: f64, dt: Duration) -> Self {
+    pub fn high_pass(cutoff_freq: T, ripple_db: T, dt: Duration) -> Self {
         let (sigma_2, r2_sq, omega_z_sq, k) = Self::base_parameters(cutoff_freq, ripple_db, dt);
+        let two = from_f64::<T>(2.0);
 
         // HP denominator (LP→HP: s → 1/s)
-        let a0 = r2_sq + 2.0 * sigma_2 * k + k * k;
+        let a0 = r2_sq + two * sigma_2 * k + k * k;
 
         // HP numerator with zeros
         let gain = r2_sq / omega_z_sq;
         let n0 = omega_z_sq + k * k;
-        let n1 = 2.0 * (k * k - omega_z_sq);
+        let n1 = two * (k * k - omega_z_sq);
 
         let b0 = gain * n0 / a0;
         let b1 = gain * n1 / a0;
         let b2 = b0;
-        let a1 = 2.0 * (k * k - r2_sq) / a0;
-        let a2 = (r2_sq - 2.0 * sigma_2 * k + k * k) / a0;
+        let a1 = two * (k * k - r2_sq) / a0;
+        let a2 = (r2_sq - two * sigma_2 * k + k * k) / a0;
 
         Self {
             cutoff_freq,
@@ -110,26 +102,26 @@ where
         }
     }
 
-    pub fn cutoff_freq(&self) -> f64 {
+    pub fn cutoff_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn center_freq(&self) -> f64 {
+    pub fn center_freq(&self) -> T {
         self.cutoff_freq
     }
 
-    pub fn ripple_db(&self) -> f64 {
+    pub fn ripple_db(&self) -> T {
         self.ripple_db
     }
 
-    pub fn biquad_coefficients(&self) -> (f64, f64, f64, f64, f64) {
+    pub fn biquad_coefficients(&self) -> (T, T, T, T, T) {
         self.biquad.coefficients()
     }
 }
 
 impl<T> Block for Chebyshev2<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type Input = T;
     type Output = T;
@@ -149,7 +141,7 @@ where
 
 impl<T> Filter for Chebyshev2<T>
 where
-    T: Clone + Mul<f64, Output = T> + Add<Output = T> + Sub<Output = T>,
+    T: Float,
 {
     type SignalValue = T;
 
@@ -157,3 +149,24 @@ where
         self.dt
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+    use core::time::Duration;
+
+    #[test]
+    fn test_chebyshev2_f32_matches_f64() {
+        let dt = Duration::from_millis(1);
+        let mut filter = Chebyshev2::low_pass(50.0, 20.0, dt);
+        let mut filter32 = Chebyshev2::low_pass(50.0f32, 20.0, dt);
+
+        for sim_state in Simulation::new(1e-3, 0.2) {
+            let x = libm::sin(2.0 * PI * 50.0 * sim_state.sim_time().as_secs_f64());
+            let y = filter.block(x, sim_state);
+            let y32 = filter32.block(x as f32, sim_state);
+            assert!((y - y32 as f64).abs() < 1e-4);
+        }
+    }
+}
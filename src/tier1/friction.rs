@@ -0,0 +1,274 @@
+use crate::{
+    block::Block,
+    prelude::{FixedSolver, FixedStateEstimation, SimulationState},
+};
+use core::{fmt::Debug, marker::PhantomData};
+use num_traits::Float;
+
+/// Static friction `(Fc + (Fs - Fc) exp(-(v / vs)^2)) sign(v) + Fv v`, with
+/// the Stribeck term only when a breakaway force is set. Input is the
+/// relative velocity and output the friction force opposing it, to be
+/// subtracted from the driving force.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoulombViscous<T>
+where
+    T: Float,
+{
+    coulomb: T,
+    viscous: T,
+    stribeck: Option<(T, T)>,
+    smoothing: T,
+    last_output: Option<T>,
+}
+
+/// LuGre dynamic friction. The bristle deflection `z` follows
+/// `dz/dt = v - sigma0 |v| z / g(v)` and the force is
+/// `sigma0 z + sigma1 dz/dt + sigma2 v`, where `g(v)` is the Stribeck curve.
+/// It captures presliding displacement, the Stribeck effect and stick-slip.
+/// The bristle dynamics are stiff, so `dt` must stay small compared with
+/// the bristle time constant `g(v) / (sigma0 |v|)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuGre<I, T = f64>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    sigma0: T,
+    sigma1: T,
+    sigma2: T,
+    coulomb: T,
+    breakaway: T,
+    stribeck_velocity: T,
+    velocity: T,
+    bristle: [T; 1],
+    last_output: Option<T>,
+    _marker: PhantomData<I>,
+}
+
+fn stribeck<T: Float>(coulomb: T, breakaway: T, stribeck_velocity: T, v: T) -> T {
+    let ratio = v / stribeck_velocity;
+    coulomb + (breakaway - coulomb) * (-ratio * ratio).exp()
+}
+
+impl<T> CoulombViscous<T>
+where
+    T: Float,
+{
+    pub fn new(coulomb: T, viscous: T) -> Self {
+        assert!(
+            coulomb >= T::zero() && viscous >= T::zero(),
+            "Friction coefficients must not be negative"
+        );
+
+        Self {
+            coulomb,
+            viscous,
+            stribeck: None,
+            smoothing: T::zero(),
+            last_output: None,
+        }
+    }
+
+    /// Breakaway force `Fs` reached at rest, decaying to the Coulomb level
+    /// over the Stribeck velocity `vs`.
+    pub fn with_stribeck(mut self, breakaway: T, stribeck_velocity: T) -> Self {
+        assert!(
+            breakaway >= self.coulomb,
+            "Breakaway force must not be lower than the Coulomb force"
+        );
+        assert!(
+            stribeck_velocity > T::zero(),
+            "Stribeck velocity must be greater than zero"
+        );
+        self.stribeck = Some((breakaway, stribeck_velocity));
+        self
+    }
+
+    /// Replaces `sign(v)` by `tanh(v / velocity)`, which avoids chattering
+    /// around zero velocity with explicit solvers.
+    pub fn with_smoothing(mut self, velocity: T) -> Self {
+        assert!(
+            velocity > T::zero(),
+            "Smoothing velocity must be greater than zero"
+        );
+        self.smoothing = velocity;
+        self
+    }
+
+    /// Friction force at velocity `v`.
+    pub fn force(&self, v: T) -> T {
+        let level = match self.stribeck {
+            Some((breakaway, vs)) => stribeck(self.coulomb, breakaway, vs, v),
+            None => self.coulomb,
+        };
+        let sign = if self.smoothing > T::zero() {
+            (v / self.smoothing).tanh()
+        } else if v.is_zero() {
+            T::zero()
+        } else {
+            v.signum()
+        };
+        level * sign + self.viscous * v
+    }
+}
+
+impl<T> Block for CoulombViscous<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = self.force(input);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<I, T> LuGre<I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    /// Bristle stiffness `sigma0`, bristle damping `sigma1` and viscous
+    /// coefficient `sigma2`, with a Coulomb level `coulomb`, a breakaway
+    /// force `breakaway` and a Stribeck velocity `stribeck_velocity`.
+    pub fn new(
+        sigma0: T,
+        sigma1: T,
+        sigma2: T,
+        coulomb: T,
+        breakaway: T,
+        stribeck_velocity: T,
+    ) -> Self {
+        assert!(
+            sigma0 > T::zero(),
+            "Bristle stiffness must be greater than zero"
+        );
+        assert!(
+            coulomb > T::zero() && breakaway >= coulomb,
+            "Breakaway force must not be lower than a positive Coulomb force"
+        );
+        assert!(
+            stribeck_velocity > T::zero(),
+            "Stribeck velocity must be greater than zero"
+        );
+
+        Self {
+            sigma0,
+            sigma1,
+            sigma2,
+            coulomb,
+            breakaway,
+            stribeck_velocity,
+            velocity: T::zero(),
+            bristle: [T::zero()],
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_integrator(self, _integrator: I) -> Self {
+        self
+    }
+
+    /// Average bristle deflection `z`.
+    pub fn bristle(&self) -> T {
+        self.bristle[0]
+    }
+
+    fn bristle_rate(&self, z: T) -> T {
+        let v = self.velocity;
+        let g = stribeck(self.coulomb, self.breakaway, self.stribeck_velocity, v);
+        v - self.sigma0 * v.abs() * z / g
+    }
+}
+
+impl<I, T> FixedStateEstimation<T, 1> for LuGre<I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    fn estimate(&self, state: &[T; 1]) -> [T; 1] {
+        [self.bristle_rate(state[0])]
+    }
+}
+
+impl<I, T> Block for LuGre<I, T>
+where
+    T: Float,
+    I: FixedSolver<T> + Debug,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.velocity = input;
+        self.bristle = I::integrate(self.bristle, sim_state.dt(), self);
+
+        let z = self.bristle[0];
+        let output = self.sigma0 * z + self.sigma1 * self.bristle_rate(z) + self.sigma2 * input;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.velocity = T::zero();
+        self.bristle = [T::zero()];
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_coulomb_viscous_force() {
+        let friction = CoulombViscous::new(1.0, 0.1);
+        assert_eq!(friction.force(0.0), 0.0);
+        assert_eq!(friction.force(2.0), 1.2);
+        assert_eq!(friction.force(-2.0), -1.2);
+
+        let stribeck = CoulombViscous::new(1.0_f64, 0.0).with_stribeck(1.5, 0.01);
+        assert!((stribeck.force(1e-6) - 1.5).abs() < 1e-6);
+        assert!((stribeck.force(1.0) - 1.0).abs() < 1e-9);
+
+        let smooth = CoulombViscous::new(1.0, 0.0).with_smoothing(1e-3);
+        assert!(smooth.force(1e-4) < 0.1);
+    }
+
+    #[test]
+    fn test_lugre_steady_sliding_and_presliding() {
+        let mut friction = LuGre::<RK4>::new(1e5, 300.0, 0.4, 1.0, 1.5, 0.01);
+
+        // Sliding at constant speed settles on the Stribeck curve.
+        for sim_state in Simulation::new(1e-5, 0.05) {
+            let _ = 0.1.as_signal(sim_state) >> friction.as_block();
+        }
+        let expected = 1.0 + 0.5 * libm::exp(-100.0) + 0.4 * 0.1;
+        assert!((friction.last_output().unwrap() - expected).abs() < 1e-6);
+
+        // A tiny displacement from rest only deflects the bristles, like a
+        // spring of stiffness sigma0.
+        friction.reset();
+        for sim_state in Simulation::new(1e-5, 0.001) {
+            let _ = 1e-3.as_signal(sim_state) >> friction.as_block();
+        }
+        let displacement = 1e-6;
+        assert!((friction.bristle() - displacement).abs() < 0.05 * displacement);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::ops::Mul;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gain<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    gain: T,
+    last_output: Option<T>,
+}
+
+/// Element-wise gain applied to an `N` sized vector signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorGain<T, const N: usize>
+where
+    T: Copy + Mul<Output = T>,
+{
+    gains: [T; N],
+    last_output: Option<[T; N]>,
+}
+
+impl<T> Gain<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    pub fn new(gain: T) -> Self {
+        Self {
+            gain,
+            last_output: None,
+        }
+    }
+
+    pub fn gain(&self) -> T {
+        self.gain
+    }
+
+    pub fn gain_mut(&mut self) -> &mut T {
+        &mut self.gain
+    }
+}
+
+impl<T, const N: usize> VectorGain<T, N>
+where
+    T: Copy + Mul<Output = T>,
+{
+    pub fn new(gains: [T; N]) -> Self {
+        Self {
+            gains,
+            last_output: None,
+        }
+    }
+
+    pub fn uniform(gain: T) -> Self {
+        Self::new([gain; N])
+    }
+
+    pub fn gains(&self) -> &[T; N] {
+        &self.gains
+    }
+
+    pub fn gains_mut(&mut self) -> &mut [T; N] {
+        &mut self.gains
+    }
+}
+
+impl<T> Block for Gain<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = input * self.gain;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<T, const N: usize> Block for VectorGain<T, N>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Input = [T; N];
+    type Output = [T; N];
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = core::array::from_fn(|i| input[i] * self.gains[i]);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_gain_and_bias_chain() {
+        let mut gain = Gain::new(2.0);
+        let mut bias = Bias::new(-1.0);
+        let mut vector_gain = VectorGain::new([1.0, -3.0]);
+        let mut vector_bias = VectorBias::uniform(0.5);
+
+        let sim_state = Simulation::new(0.1, 0.1).next().unwrap();
+
+        let y = (3.0.as_signal(sim_state) >> gain.as_block()) >> bias.as_block();
+        assert_eq!(y.value, 5.0);
+
+        let y =
+            ([1.0, 2.0].as_signal(sim_state) >> vector_gain.as_block()) >> vector_bias.as_block();
+        assert_eq!(y.value, [1.5, -5.5]);
+        assert_eq!(vector_bias.last_output(), Some([1.5, -5.5]));
+    }
+}
@@ -0,0 +1,423 @@
+use crate::signal::from_f64;
+use crate::tier1::scale::Scale;
+use crate::{block::Block, prelude::SimulationState};
+use embedded_hal::{
+    digital::{InputPin, OutputPin, PinState},
+    pwm::SetDutyCycle,
+};
+use num_traits::Float;
+
+/// One-shot ADC conversion. `embedded-hal` 1.0 has no ADC trait, so HAL
+/// drivers are adapted through this one; any `FnMut() -> Result<u16, E>`
+/// already implements it.
+pub trait AdcChannel {
+    type Error;
+
+    fn read(&mut self) -> Result<u16, Self::Error>;
+}
+
+impl<F, E> AdcChannel for F
+where
+    F: FnMut() -> Result<u16, E>,
+{
+    type Error = E;
+
+    fn read(&mut self) -> Result<u16, Self::Error> {
+        self()
+    }
+}
+
+/// Source block sampling an ADC channel once per step and converting the
+/// raw counts to engineering units through a [`Scale`]. A failed
+/// conversion repeats the last good value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdcInput<A, T = f64>
+where
+    A: AdcChannel,
+    T: Float,
+{
+    adc: A,
+    scale: Scale<T>,
+    errors: u32,
+    last_output: Option<T>,
+}
+
+/// Sink block driving a PWM channel with a duty cycle in `[0, 1]`. Values
+/// outside the range are clamped and the applied duty is the output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PwmOutput<P, T = f64>
+where
+    P: SetDutyCycle,
+    T: Float,
+{
+    pwm: P,
+    errors: u32,
+    last_output: Option<T>,
+}
+
+/// Source block reading a GPIO input pin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitalInput<P>
+where
+    P: InputPin,
+{
+    pin: P,
+    errors: u32,
+    last_output: Option<bool>,
+}
+
+/// Sink block writing a GPIO output pin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitalOutput<P>
+where
+    P: OutputPin,
+{
+    pin: P,
+    errors: u32,
+    last_output: Option<bool>,
+}
+
+impl<A, T> AdcInput<A, T>
+where
+    A: AdcChannel,
+    T: Float,
+{
+    /// Raw counts, without conversion.
+    pub fn new(adc: A) -> Self {
+        Self {
+            adc,
+            scale: Scale::from_range((T::zero(), T::one()), (T::zero(), T::one())),
+            errors: 0,
+            last_output: None,
+        }
+    }
+
+    /// Converts the counts with `scale`, e.g. [`Scale::from_bits`].
+    pub fn with_scale(mut self, scale: Scale<T>) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Maps the `bits`-wide code range onto `[0, reference]`, e.g. volts.
+    pub fn with_reference(self, reference: T, bits: u8) -> Self {
+        assert!(
+            (1..=16).contains(&bits),
+            "ADC resolution must be between 1 and 16 bits"
+        );
+
+        self.with_scale(Scale::from_bits(bits, (T::zero(), reference)))
+    }
+
+    /// Number of failed conversions.
+    pub fn errors(&self) -> u32 {
+        self.errors
+    }
+
+    pub fn inner(&self) -> &A {
+        &self.adc
+    }
+
+    pub fn inner_mut(&mut self) -> &mut A {
+        &mut self.adc
+    }
+}
+
+impl<P, T> PwmOutput<P, T>
+where
+    P: SetDutyCycle,
+    T: Float,
+{
+    pub fn new(pwm: P) -> Self {
+        Self {
+            pwm,
+            errors: 0,
+            last_output: None,
+        }
+    }
+
+    /// Number of rejected duty-cycle writes.
+    pub fn errors(&self) -> u32 {
+        self.errors
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.pwm
+    }
+
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.pwm
+    }
+}
+
+impl<P> DigitalInput<P>
+where
+    P: InputPin,
+{
+    pub fn new(pin: P) -> Self {
+        Self {
+            pin,
+            errors: 0,
+            last_output: None,
+        }
+    }
+
+    /// Number of failed reads.
+    pub fn errors(&self) -> u32 {
+        self.errors
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.pin
+    }
+
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.pin
+    }
+}
+
+impl<P> DigitalOutput<P>
+where
+    P: OutputPin,
+{
+    pub fn new(pin: P) -> Self {
+        Self {
+            pin,
+            errors: 0,
+            last_output: None,
+        }
+    }
+
+    /// Number of failed writes.
+    pub fn errors(&self) -> u32 {
+        self.errors
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.pin
+    }
+
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.pin
+    }
+}
+
+impl<A, T> Block for AdcInput<A, T>
+where
+    A: AdcChannel,
+    T: Float,
+{
+    type Input = ();
+    type Output = T;
+
+    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = match self.adc.read() {
+            Ok(counts) => self.scale.apply(from_f64(counts as f64)),
+            Err(_) => {
+                self.errors += 1;
+                self.last_output
+                    .unwrap_or_else(|| self.scale.apply(T::zero()))
+            }
+        };
+
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.errors = 0;
+        self.last_output = None;
+    }
+}
+
+impl<P, T> Block for PwmOutput<P, T>
+where
+    P: SetDutyCycle,
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let duty = if input.is_nan() {
+            T::zero()
+        } else {
+            input.max(T::zero()).min(T::one())
+        };
+        let max = self.pwm.max_duty_cycle();
+        let counts = (duty * from_f64(max as f64))
+            .round()
+            .to_u16()
+            .unwrap_or(max);
+
+        if self.pwm.set_duty_cycle(counts).is_err() {
+            self.errors += 1;
+        }
+
+        let output = from_f64::<T>(counts as f64 / max as f64);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        if self.pwm.set_duty_cycle_fully_off().is_err() {
+            self.errors += 1;
+        }
+        self.last_output = None;
+    }
+}
+
+impl<P> Block for DigitalInput<P>
+where
+    P: InputPin,
+{
+    type Input = ();
+    type Output = bool;
+
+    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = match self.pin.is_high() {
+            Ok(level) => level,
+            Err(_) => {
+                self.errors += 1;
+                self.last_output.unwrap_or(false)
+            }
+        };
+
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.errors = 0;
+        self.last_output = None;
+    }
+}
+
+impl<P> Block for DigitalOutput<P>
+where
+    P: OutputPin,
+{
+    type Input = bool;
+    type Output = bool;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        if self.pin.set_state(PinState::from(input)).is_err() {
+            self.errors += 1;
+        }
+
+        self.last_output = Some(input);
+        input
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        if self.pin.set_low().is_err() {
+            self.errors += 1;
+        }
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::{cell::Cell, convert::Infallible};
+    use embedded_hal::{digital, pwm};
+    use std::rc::Rc;
+
+    /// Timer channel with an 8-bit compare register.
+    struct Timer {
+        duty: u16,
+    }
+
+    impl pwm::ErrorType for Timer {
+        type Error = Infallible;
+    }
+
+    impl pwm::SetDutyCycle for Timer {
+        fn max_duty_cycle(&self) -> u16 {
+            255
+        }
+
+        fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Self::Error> {
+            self.duty = duty;
+            Ok(())
+        }
+    }
+
+    struct Pin {
+        high: bool,
+    }
+
+    impl digital::ErrorType for Pin {
+        type Error = Infallible;
+    }
+
+    impl digital::OutputPin for Pin {
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.high = false;
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.high = true;
+            Ok(())
+        }
+    }
+
+    impl digital::InputPin for Pin {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.high)
+        }
+
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            Ok(!self.high)
+        }
+    }
+
+    #[test]
+    fn test_hal_blocks_close_the_loop() {
+        // RC plant read by a 12-bit ADC and driven by the PWM average.
+        let voltage = Rc::new(Cell::new(0.0));
+        let sensed = voltage.clone();
+        let mut adc = AdcInput::new(move || {
+            Ok::<_, Infallible>(libm::round(sensed.get() / 3.3 * 4095.0) as u16)
+        })
+        .with_reference(3.3f64, 12);
+        let mut pwm = PwmOutput::<_, f64>::new(Timer { duty: 0 });
+        let mut pid = PID::new(2.0, 20.0, 0.0);
+        let mut enable = DigitalOutput::new(Pin { high: false });
+
+        for sim_state in Simulation::new(0.001, 2.0) {
+            let measured = sim_state >> adc.as_block();
+            let error = 1.65.as_signal(sim_state) - measured;
+            let duty = pid.output(error / 3.3) >> pwm.as_block();
+            let _ = (duty.value > 0.0).as_signal(sim_state) >> enable.as_block();
+
+            let applied = pwm.inner().duty as f64 / 255.0 * 3.3;
+            voltage.set(voltage.get() + (applied - voltage.get()) / 0.05 * 0.001);
+        }
+
+        assert!((adc.last_output().unwrap() - 1.65).abs() < 0.02);
+        assert_eq!(pwm.errors(), 0);
+        assert!(enable.inner().high);
+
+        let mut button = DigitalInput::new(Pin { high: true });
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+        assert!((sim_state >> button.as_block()).value);
+    }
+}
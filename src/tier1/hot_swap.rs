@@ -0,0 +1,199 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+/// What the outgoing block last saw and produced, handed to the incoming
+/// block so it can start where the other left off (bumpless transfer).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Handover<I, O> {
+    pub sim_time: Duration,
+    pub last_input: Option<I>,
+    pub last_output: Option<O>,
+}
+
+type Pending<I, O> = (
+    Duration,
+    Box<dyn FnOnce(&Handover<I, O>) -> Box<dyn Block<Input = I, Output = O>>>,
+);
+
+/// Slot running one block at a time that can be replaced mid-run, e.g. to
+/// switch from a PID to an MPC or to compare two controllers A/B.
+///
+/// Replacements are scheduled and installed at the start of the first step
+/// at or after their time, between two steps of the outgoing block, after
+/// an initialization hook that receives the [`Handover`]. Resetting keeps
+/// the active block and drops the scheduled replacements and swap times.
+pub struct HotSwap<I, O>
+where
+    I: Clone,
+    O: Clone,
+{
+    active: Box<dyn Block<Input = I, Output = O>>,
+    pending: Vec<Pending<I, O>>,
+    last_input: Option<I>,
+    swap_times: Vec<Duration>,
+}
+
+impl<I, O> HotSwap<I, O>
+where
+    I: Clone + 'static,
+    O: Clone + 'static,
+{
+    pub fn new(block: impl Block<Input = I, Output = O> + 'static) -> Self {
+        Self {
+            active: Box::new(block),
+            pending: Vec::new(),
+            last_input: None,
+            swap_times: Vec::new(),
+        }
+    }
+
+    /// Replaces the active block at the next step, without initialization.
+    pub fn swap<B>(&mut self, block: B)
+    where
+        B: Block<Input = I, Output = O> + 'static,
+    {
+        self.swap_at(Duration::ZERO, block, |_, _| {});
+    }
+
+    /// Replaces the active block at the first step at or after `at`, calling
+    /// `init` on the incoming block first.
+    pub fn swap_at<B>(
+        &mut self,
+        at: Duration,
+        block: B,
+        init: impl FnOnce(&mut B, &Handover<I, O>) + 'static,
+    ) where
+        B: Block<Input = I, Output = O> + 'static,
+    {
+        self.pending.push((
+            at,
+            Box::new(move |handover| {
+                let mut block = block;
+                init(&mut block, handover);
+                Box::new(block)
+            }),
+        ));
+        self.pending.sort_by_key(|(at, _)| *at);
+    }
+
+    /// Times at which a replacement was installed.
+    pub fn swap_times(&self) -> &[Duration] {
+        &self.swap_times
+    }
+
+    pub fn active(&self) -> &dyn Block<Input = I, Output = O> {
+        self.active.as_ref()
+    }
+}
+
+impl<I, O> Block for HotSwap<I, O>
+where
+    I: Clone + 'static,
+    O: Clone + 'static,
+{
+    type Input = I;
+    type Output = O;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        while self
+            .pending
+            .first()
+            .is_some_and(|(at, _)| sim_state.is_due(*at))
+        {
+            let (_, install) = self.pending.remove(0);
+            let handover = Handover {
+                sim_time: sim_state.sim_time(),
+                last_input: self.last_input.clone(),
+                last_output: self.active.last_output(),
+            };
+            self.active = install(&handover);
+            self.swap_times.push(sim_state.sim_time());
+        }
+
+        self.last_input = Some(input.clone());
+        crate::block::run(self.active.as_mut(), input, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.active.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.active.reset();
+        self.pending.clear();
+        self.last_input = None;
+        self.swap_times.clear();
+    }
+
+    fn name(&self) -> &str {
+        self.active.name()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    fn run_switchover(bumpless: bool) -> f64 {
+        let mut controller = HotSwap::new(PID::new(1.0, 1.0, 0.0));
+        controller.swap_at(
+            Duration::from_secs(3),
+            PID::new(4.0, 2.0, 0.0),
+            move |pid, handover| {
+                if bumpless {
+                    pid.bumpless(handover.last_output.unwrap(), handover.last_input.unwrap());
+                }
+            },
+        );
+        let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        let mut largest_jump = 0.0f64;
+        let mut last_control: Option<f64> = None;
+        for sim_state in Simulation::new(0.01, 6.0) {
+            let reference: f64 = if sim_state.sim_time() < Duration::from_secs(1) {
+                0.0
+            } else {
+                1.0
+            };
+            let error = reference.as_signal(sim_state) - plant.last_output();
+            let control = error >> controller.as_block();
+            plant.output(control);
+
+            if sim_state.sim_time() > Duration::from_secs(2) {
+                if let Some(last) = last_control {
+                    largest_jump = largest_jump.max((control.value - last).abs());
+                }
+                last_control = Some(control.value);
+            }
+        }
+
+        assert_eq!(controller.swap_times().len(), 1);
+        largest_jump
+    }
+
+    #[test]
+    fn test_hot_swap_bumpless_handover() {
+        assert!(run_switchover(false) > 0.3);
+        assert!(run_switchover(true) < 0.01);
+    }
+
+    #[test]
+    fn test_hot_swap_reset_drops_scheduled_swaps() {
+        let mut controller = HotSwap::new(PID::new(1.0, 0.0, 0.0));
+        let mut simulation = Simulation::new(0.1, 1.0);
+
+        controller.swap(PID::new(2.0, 0.0, 0.0));
+        assert_eq!(controller.block(1.0, simulation.next().unwrap()), 2.0);
+        assert_eq!(controller.swap_times().len(), 1);
+
+        controller.swap(PID::new(3.0, 0.0, 0.0));
+        controller.reset();
+        assert!(controller.swap_times().is_empty());
+        assert_eq!(controller.block(1.0, simulation.next().unwrap()), 2.0);
+        assert!(controller.swap_times().is_empty());
+    }
+}
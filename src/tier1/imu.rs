@@ -0,0 +1,238 @@
+use crate::{block::Block, prelude::SimulationState, rng::SplitMix64, tier3::AllanNoise};
+
+/// Error terms of one triad of an [`ImuModel`], e.g. the gyros, in the
+/// sensor units.
+///
+/// The measurement of a true vector `x` is `(I + S) M x + b + n`, with `S`
+/// the diagonal scale-factor errors, `M` the misalignment, `b` a bias that
+/// random walks and `n` white noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuSensor {
+    white_noise: f64,
+    bias: [f64; 3],
+    bias_walk: f64,
+    scale_factor: [f64; 3],
+    misalignment: [[f64; 3]; 3],
+}
+
+impl ImuSensor {
+    /// Sensor that measures the true vector exactly.
+    pub fn ideal() -> Self {
+        Self {
+            white_noise: 0.0,
+            bias: [0.0; 3],
+            bias_walk: 0.0,
+            scale_factor: [0.0; 3],
+            misalignment: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// White noise and bias random walk of an Allan variance fit.
+    pub fn from_allan(noise: AllanNoise) -> Self {
+        Self::ideal()
+            .with_white_noise(noise.white_noise)
+            .with_bias_walk(noise.random_walk.unwrap_or(0.0))
+    }
+
+    /// White noise density `N`, in units per `sqrt(Hz)`.
+    pub fn with_white_noise(mut self, density: f64) -> Self {
+        assert!(density >= 0.0, "White noise density must be non-negative");
+        self.white_noise = density;
+        self
+    }
+
+    /// Bias at the start of the run.
+    pub fn with_bias(mut self, bias: [f64; 3]) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    /// Random walk `K` of the bias, in units per `sqrt(s)`.
+    pub fn with_bias_walk(mut self, walk: f64) -> Self {
+        assert!(walk >= 0.0, "Bias random walk must be non-negative");
+        self.bias_walk = walk;
+        self
+    }
+
+    /// Relative scale-factor error of each axis, e.g. `0.01` reads 1% high.
+    pub fn with_scale_factor(mut self, scale_factor: [f64; 3]) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Small-angle misalignment of the sensing axes, in radians: axis `i`
+    /// picks up `angles[i][j]` of the true axis `j` for `i != j`. The
+    /// diagonal is ignored.
+    pub fn with_misalignment(mut self, angles: [[f64; 3]; 3]) -> Self {
+        for (i, row) in angles.iter().enumerate() {
+            for (j, angle) in row.iter().enumerate() {
+                self.misalignment[i][j] = if i == j { 1.0 } else { *angle };
+            }
+        }
+        self
+    }
+
+    fn measure(&self, truth: [f64; 3], bias: [f64; 3], rng: &mut SplitMix64, dt: f64) -> [f64; 3] {
+        let sigma = if dt > 0.0 {
+            self.white_noise / libm::sqrt(dt)
+        } else {
+            0.0
+        };
+        core::array::from_fn(|i| {
+            let aligned = (0..3)
+                .map(|j| self.misalignment[i][j] * truth[j])
+                .sum::<f64>();
+            (1.0 + self.scale_factor[i]) * aligned + bias[i] + sigma * rng.next_gaussian()
+        })
+    }
+}
+
+impl Default for ImuSensor {
+    fn default() -> Self {
+        Self::ideal()
+    }
+}
+
+/// Strapdown IMU turning the true angular rate and specific force, both
+/// 3-axis, into gyro and accelerometer readings with the error terms of an
+/// [`ImuSensor`] each. Input and output are `(rate, acceleration)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImuModel {
+    gyro: ImuSensor,
+    accel: ImuSensor,
+    gyro_bias: [f64; 3],
+    accel_bias: [f64; 3],
+    seed: u64,
+    rng: SplitMix64,
+    last_output: Option<([f64; 3], [f64; 3])>,
+}
+
+impl ImuModel {
+    pub fn new(gyro: ImuSensor, accel: ImuSensor) -> Self {
+        Self {
+            gyro,
+            accel,
+            gyro_bias: gyro.bias,
+            accel_bias: accel.bias,
+            seed: 0,
+            rng: SplitMix64::new(0),
+            last_output: None,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = SplitMix64::new(seed);
+        self
+    }
+
+    pub fn gyro(&self) -> &ImuSensor {
+        &self.gyro
+    }
+
+    pub fn accel(&self) -> &ImuSensor {
+        &self.accel
+    }
+
+    /// Gyro bias at the last step.
+    pub fn gyro_bias(&self) -> [f64; 3] {
+        self.gyro_bias
+    }
+
+    /// Accelerometer bias at the last step.
+    pub fn accel_bias(&self) -> [f64; 3] {
+        self.accel_bias
+    }
+}
+
+impl Block for ImuModel {
+    type Input = ([f64; 3], [f64; 3]);
+    type Output = ([f64; 3], [f64; 3]);
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let dt = sim_state.dt().as_secs_f64();
+        let sqrt_dt = libm::sqrt(dt);
+        for i in 0..3 {
+            self.gyro_bias[i] += self.gyro.bias_walk * sqrt_dt * self.rng.next_gaussian();
+            self.accel_bias[i] += self.accel.bias_walk * sqrt_dt * self.rng.next_gaussian();
+        }
+
+        let rate = self
+            .gyro
+            .measure(input.0, self.gyro_bias, &mut self.rng, dt);
+        let acceleration = self
+            .accel
+            .measure(input.1, self.accel_bias, &mut self.rng, dt);
+        let output = (rate, acceleration);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.gyro_bias = self.gyro.bias;
+        self.accel_bias = self.accel.bias;
+        self.rng = SplitMix64::new(self.seed);
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::AllanVariance;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_imu_model_deterministic_errors() {
+        let gyro = ImuSensor::ideal()
+            .with_bias([0.1, 0.0, -0.2])
+            .with_scale_factor([0.01, 0.0, 0.0])
+            .with_misalignment([[0.0, 0.002, 0.0], [0.0; 3], [0.0; 3]]);
+        let accel = ImuSensor::ideal().with_bias([0.0, 0.0, 0.05]);
+        let mut imu = ImuModel::new(gyro, accel);
+
+        let sim_state = Simulation::new(0.01, 0.01).next().unwrap();
+        let truth = ([1.0, 2.0, 3.0], [0.0, 0.0, 9.81]);
+        let (rate, acceleration) = (truth.as_signal(sim_state) >> imu.as_block()).value;
+
+        assert!((rate[0] - (1.01 * (1.0 + 0.004) + 0.1)).abs() < 1e-12);
+        assert_eq!(rate[1], 2.0);
+        assert!((rate[2] - 2.8).abs() < 1e-12);
+        assert!((acceleration[2] - 9.86).abs() < 1e-12);
+        assert_eq!(imu.gyro_bias(), [0.1, 0.0, -0.2]);
+    }
+
+    #[test]
+    fn test_imu_model_noise_matches_allan_fit() {
+        let (dt, n_density, k_walk): (f64, f64, f64) = (0.01, 0.02, 0.01);
+        let gyro = ImuSensor::ideal()
+            .with_white_noise(n_density)
+            .with_bias_walk(k_walk);
+        let mut imu = ImuModel::new(gyro, ImuSensor::ideal()).with_seed(5);
+
+        let mut samples = Vec::new();
+        for sim_state in Simulation::new(0.01, 2000.0) {
+            let (rate, _) = (([0.0; 3], [0.0; 3]).as_signal(sim_state) >> imu.as_block()).value;
+            samples.push(rate[0]);
+        }
+        let noise = AllanVariance::from_samples(&samples, dt).noise().unwrap();
+        assert!((noise.white_noise - n_density).abs() < 0.1 * n_density);
+        let refit = ImuSensor::from_allan(noise);
+        assert_eq!(
+            refit.with_bias_walk(k_walk),
+            gyro.with_white_noise(noise.white_noise)
+        );
+
+        let first = imu.last_output();
+        imu.reset();
+        assert_eq!(imu.gyro_bias(), [0.0; 3]);
+        for sim_state in Simulation::new(0.01, 2000.0) {
+            let _ = (([0.0; 3], [0.0; 3]).as_signal(sim_state) >> imu.as_block()).value;
+        }
+        assert_eq!(imu.last_output(), first);
+    }
+}
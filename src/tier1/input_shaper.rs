@@ -0,0 +1,280 @@
+use crate::block::Block;
+use crate::prelude::{Delay, SimulationState};
+use crate::signal::from_f64;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use core::time::Duration;
+use num_traits::Float;
+
+/// Convolves its input with a sequence of impulses `(amplitude, time)`,
+/// built on delay lines. The amplitudes sum to one, so the shaped reference
+/// settles to the same value as the original.
+#[derive(Debug, Clone)]
+pub struct InputShaper<T>
+where
+    T: Float,
+{
+    impulses: Vec<(f64, Duration)>,
+    delays: Vec<Option<Delay<T>>>,
+    last_output: Option<T>,
+}
+
+impl<T> InputShaper<T>
+where
+    T: Float,
+{
+    pub fn new(impulses: &[(f64, Duration)]) -> Self {
+        assert!(
+            !impulses.is_empty(),
+            "Input shaper needs at least one impulse"
+        );
+        let sum = impulses.iter().map(|(amplitude, _)| amplitude).sum::<f64>();
+        assert!(
+            (sum - 1.0).abs() < 1e-9,
+            "Impulse amplitudes must sum to 1, got {}",
+            sum
+        );
+
+        Self {
+            impulses: impulses.to_vec(),
+            delays: impulses
+                .iter()
+                .map(|(_, time)| (!time.is_zero()).then(|| Delay::new(*time)))
+                .collect(),
+            last_output: None,
+        }
+    }
+
+    pub fn impulses(&self) -> &[(f64, Duration)] {
+        &self.impulses
+    }
+
+    /// Time from the first to the last impulse, i.e. the lag the shaper adds.
+    pub fn duration(&self) -> Duration {
+        self.impulses
+            .iter()
+            .map(|(_, time)| *time)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl<T> Block for InputShaper<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let output = self.impulses.iter().zip(&mut self.delays).fold(
+            T::zero(),
+            |acc, ((amplitude, _), delay)| {
+                let delayed = match delay {
+                    Some(delay) => delay.block(input, sim_state),
+                    None => input,
+                };
+                acc + delayed * from_f64(*amplitude)
+            },
+        );
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.delays.iter_mut().flatten().for_each(Delay::reset);
+        self.last_output = None;
+    }
+}
+
+/// Damped half period and the amplitude ratio `K` between successive
+/// half-period impulses of a mode with natural frequency `omega` (rad/s) and
+/// damping ratio `damping`.
+fn mode(omega: f64, damping: f64) -> (Duration, f64) {
+    assert!(omega > 0.0, "Mode frequency must be positive");
+    assert!(
+        (0.0..1.0).contains(&damping),
+        "Mode damping must be in [0, 1)"
+    );
+
+    let root = libm::sqrt(1.0 - damping * damping);
+    let half_period = PI / (omega * root);
+    let k = libm::exp(-damping * PI / root);
+    (Duration::from_secs_f64(half_period), k)
+}
+
+/// Impulses weighted by `K^i` at multiples of the half period, normalized.
+fn shaper<T>(weights: &[f64], omega: f64, damping: f64) -> InputShaper<T>
+where
+    T: Float,
+{
+    let (half_period, k) = mode(omega, damping);
+    let mut impulses = weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| (weight * libm::pow(k, i as f64), half_period * i as u32))
+        .collect::<Vec<_>>();
+    let sum = impulses.iter().map(|(amplitude, _)| amplitude).sum::<f64>();
+    impulses
+        .iter_mut()
+        .for_each(|(amplitude, _)| *amplitude /= sum);
+
+    InputShaper::new(&impulses)
+}
+
+macro_rules! impl_shaper_block {
+    ($name:ident) => {
+        impl<T> $name<T>
+        where
+            T: Float,
+        {
+            pub fn impulses(&self) -> &[(f64, Duration)] {
+                self.0.impulses()
+            }
+
+            pub fn duration(&self) -> Duration {
+                self.0.duration()
+            }
+        }
+
+        impl<T> Block for $name<T>
+        where
+            T: Float,
+        {
+            type Input = T;
+            type Output = T;
+
+            fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+                self.0.block(input, sim_state)
+            }
+
+            fn last_output(&self) -> Option<Self::Output> {
+                self.0.last_output()
+            }
+
+            fn reset(&mut self) {
+                self.0.reset();
+            }
+        }
+    };
+}
+
+/// Zero-vibration shaper: two impulses half a damped period apart. Cancels
+/// the mode exactly but is sensitive to frequency errors.
+#[derive(Debug, Clone)]
+pub struct ZV<T>(InputShaper<T>)
+where
+    T: Float;
+
+/// Zero-vibration-derivative shaper: three impulses over a damped period,
+/// also zeroing the sensitivity to frequency errors at the mode.
+#[derive(Debug, Clone)]
+pub struct ZVD<T>(InputShaper<T>)
+where
+    T: Float;
+
+/// Extra-insensitive shaper: three impulses over a damped period that allow
+/// a residual vibration of `tolerance` at the mode in exchange for a wider
+/// insensitive band than ZVD.
+#[derive(Debug, Clone)]
+pub struct EI<T>(InputShaper<T>)
+where
+    T: Float;
+
+impl<T> ZV<T>
+where
+    T: Float,
+{
+    pub fn new(omega: f64, damping: f64) -> Self {
+        Self(shaper(&[1.0, 1.0], omega, damping))
+    }
+}
+
+impl<T> ZVD<T>
+where
+    T: Float,
+{
+    pub fn new(omega: f64, damping: f64) -> Self {
+        Self(shaper(&[1.0, 2.0, 1.0], omega, damping))
+    }
+}
+
+impl<T> EI<T>
+where
+    T: Float,
+{
+    /// The amplitudes are the closed-form undamped ones; damping is taken
+    /// into account with the same `K^i` weighting as ZV and ZVD, which is
+    /// exact for an undamped mode and close for lightly damped ones.
+    pub fn new(omega: f64, damping: f64, tolerance: f64) -> Self {
+        assert!(
+            (0.0..1.0).contains(&tolerance),
+            "Vibration tolerance must be in [0, 1)"
+        );
+        let weights = [
+            (1.0 + tolerance) / 4.0,
+            (1.0 - tolerance) / 2.0,
+            (1.0 + tolerance) / 4.0,
+        ];
+        Self(shaper(&weights, omega, damping))
+    }
+}
+
+impl_shaper_block!(ZV);
+impl_shaper_block!(ZVD);
+impl_shaper_block!(EI);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+
+    /// Peak-to-peak output of a lightly damped 1 Hz mode after the shaped
+    /// reference has finished.
+    fn residual_vibration(shaper: &mut dyn Block<Input = f64, Output = f64>) -> f64 {
+        let omega = 2.0 * PI;
+        let mut mode = SsFixed::<2, RK4>::new(
+            [[0.0, 1.0], [-omega * omega, -2.0 * 0.05 * omega]],
+            [0.0, omega * omega],
+            [1.0, 0.0],
+            0.0,
+        );
+
+        let (mut low, mut high) = (f64::MAX, f64::MIN);
+        for sim_state in Simulation::new(0.001, 4.0) {
+            let r = shaper.output(1.0.as_signal(sim_state)).value;
+            let y = (r.as_signal(sim_state) >> mode.as_block()).value;
+            if sim_state.sim_time().as_secs_f64() > 2.0 {
+                low = low.min(y);
+                high = high.max(y);
+            }
+        }
+        high - low
+    }
+
+    #[test]
+    fn test_input_shapers_suppress_vibration() {
+        let omega = 2.0 * PI;
+        let mut unshaped = Gain::new(1.0);
+        let mut zv = ZV::new(omega, 0.05);
+        let mut zvd = ZVD::new(omega, 0.05);
+        let mut ei = EI::new(omega, 0.05, 0.05);
+
+        assert_eq!(zv.impulses().len(), 2);
+        assert!((zv.duration().as_secs_f64() - 0.5 / (1.0 - 0.05f64.powi(2)).sqrt()).abs() < 1e-9);
+        assert_eq!(zvd.impulses().len(), 3);
+        for shaper in [zvd.impulses(), ei.impulses()] {
+            let sum = shaper.iter().map(|(a, _)| a).sum::<f64>();
+            assert!((sum - 1.0).abs() < 1e-12);
+        }
+
+        let reference = residual_vibration(&mut unshaped);
+        assert!(residual_vibration(&mut zv) < 0.02 * reference);
+        assert!(residual_vibration(&mut zvd) < 0.02 * reference);
+        assert!(residual_vibration(&mut ei) < 0.1 * reference);
+    }
+}
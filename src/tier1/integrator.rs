@@ -0,0 +1,239 @@
+use crate::block::Block;
+use crate::prelude::{LimitStats, Limited, SimulationState};
+use crate::signal::from_f64;
+use num_traits::{Float, clamp};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Integrator<T>
+where
+    T: Float,
+{
+    initial_condition: T,
+    state: T,
+    limits: Option<(T, T)>,
+    limit_stats: LimitStats,
+    last_output: Option<T>,
+}
+
+impl<T> Integrator<T>
+where
+    T: Float,
+{
+    pub fn new() -> Self {
+        Self {
+            initial_condition: T::zero(),
+            state: T::zero(),
+            limits: None,
+            limit_stats: LimitStats::default(),
+            last_output: None,
+        }
+    }
+
+    pub fn with_initial_condition(mut self, initial_condition: T) -> Self {
+        self.initial_condition = initial_condition;
+        self.state = initial_condition;
+        self
+    }
+
+    pub fn with_limits(mut self, min: T, max: T) -> Self {
+        assert!(min <= max, "Integrator min limit must not exceed max limit");
+
+        self.limits = Some((min, max));
+        self.state = clamp(self.state, min, max);
+        self
+    }
+
+    pub fn state(&self) -> &T {
+        &self.state
+    }
+
+    pub fn is_saturated(&self) -> bool {
+        self.limits
+            .is_some_and(|(min, max)| self.state <= min || self.state >= max)
+    }
+
+    pub fn reset_to(&mut self, value: T) {
+        self.state = match self.limits {
+            Some((min, max)) => clamp(value, min, max),
+            None => value,
+        };
+    }
+
+    /// Adds an external reset input, see [`ResettableIntegrator`].
+    pub fn with_reset_input(self) -> ResettableIntegrator<T> {
+        ResettableIntegrator { integrator: self }
+    }
+}
+
+/// [`Integrator`] taking `(input, reset)`. While `reset` is true the state
+/// holds the initial condition, and integration resumes from it on the
+/// first step it is false.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResettableIntegrator<T>
+where
+    T: Float,
+{
+    integrator: Integrator<T>,
+}
+
+impl<T> ResettableIntegrator<T>
+where
+    T: Float,
+{
+    pub fn integrator(&self) -> &Integrator<T> {
+        &self.integrator
+    }
+
+    pub fn integrator_mut(&mut self) -> &mut Integrator<T> {
+        &mut self.integrator
+    }
+}
+
+impl<T> Default for Integrator<T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Block for Integrator<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let dt = from_f64::<T>(sim_state.dt().as_secs_f64());
+        let state = self.state + input * dt;
+
+        self.state = match self.limits {
+            Some((min, max)) => clamp(state, min, max),
+            None => state,
+        };
+        self.limit_stats.record(self.is_saturated(), sim_state.dt());
+        self.last_output = Some(self.state);
+
+        self.state
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial_condition;
+        self.limit_stats = LimitStats::default();
+        self.last_output = None;
+    }
+}
+
+impl<T> Limited for Integrator<T>
+where
+    T: Float,
+{
+    fn limit_stats(&self) -> LimitStats {
+        self.limit_stats
+    }
+}
+
+impl<T> Block for ResettableIntegrator<T>
+where
+    T: Float,
+{
+    type Input = (T, bool);
+    type Output = T;
+
+    fn block(&mut self, (input, reset): Self::Input, sim_state: SimulationState) -> Self::Output {
+        if !reset {
+            return self.integrator.block(input, sim_state);
+        }
+
+        let integrator = &mut self.integrator;
+        integrator.reset_to(integrator.initial_condition);
+        integrator.last_output = Some(integrator.state);
+        integrator.state
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.integrator.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.integrator.reset();
+    }
+}
+
+impl<T> Limited for ResettableIntegrator<T>
+where
+    T: Float,
+{
+    fn limit_stats(&self) -> LimitStats {
+        self.integrator.limit_stats()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_integrator_accumulates_input_by_dt() {
+        let mut integrator = Integrator::new().with_initial_condition(1.0);
+
+        let output = Simulation::new(0.5, 2.0)
+            .map(|sim_state| integrator.block(2.0, sim_state))
+            .last()
+            .unwrap();
+
+        assert_eq!(output, 5.0);
+    }
+
+    #[test]
+    fn test_integrator_holds_at_limits() {
+        let mut integrator = Integrator::new().with_limits(-1.0, 1.0);
+
+        for sim_state in Simulation::new(0.1, 5.0) {
+            integrator.block(1.0, sim_state);
+        }
+
+        assert_eq!(*integrator.state(), 1.0);
+        assert!(integrator.is_saturated());
+
+        integrator.reset_to(0.25);
+        assert_eq!(*integrator.state(), 0.25);
+        assert!(!integrator.is_saturated());
+    }
+
+    #[test]
+    fn test_integrator_reset_input() {
+        let mut integrator = Integrator::new()
+            .with_initial_condition(1.0)
+            .with_reset_input();
+
+        let outputs = Simulation::new(0.5, 3.0)
+            .enumerate()
+            .map(|(i, sim_state)| {
+                let reset = i == 2 || i == 3;
+                ((2.0, reset).as_signal(sim_state) >> integrator.as_block()).value
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(outputs, [2.0, 3.0, 1.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_integrator_f32() {
+        let mut integrator = Integrator::new().with_initial_condition(1.0f32);
+
+        let output = Simulation::new(0.5, 2.0)
+            .map(|sim_state| integrator.block(2.0f32, sim_state))
+            .last()
+            .unwrap();
+
+        assert_eq!(output, 5.0f32);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::block::Block;
+use crate::prelude::{SS, SimulationState, Solver, Tf};
+use core::fmt::Debug;
+use core::ops::AddAssign;
+use faer::traits::ComplexField;
+use num_traits::Float;
+
+#[derive(Debug, Clone)]
+pub struct LeadLag<I, T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+    I: Solver<T> + Debug,
+{
+    t_lead: T,
+    t_lag: T,
+    ss: SS<I, T>,
+}
+
+impl<I, T> LeadLag<I, T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+    I: Solver<T> + Debug,
+{
+    pub fn new(t_lead: T, t_lag: T, integrator: I) -> Self {
+        assert!(
+            t_lead >= T::zero(),
+            "Lead time constant must not be negative"
+        );
+        assert!(
+            t_lag > T::zero(),
+            "Lag time constant must be greater than zero"
+        );
+
+        let tf = Tf::new_unchecked(&[t_lead, T::one()], &[t_lag, T::one()]);
+
+        Self {
+            t_lead,
+            t_lag,
+            ss: tf.to_ss_controllable(integrator),
+        }
+    }
+
+    pub fn t_lead(&self) -> T {
+        self.t_lead
+    }
+
+    pub fn t_lag(&self) -> T {
+        self.t_lag
+    }
+}
+
+impl<I, T> Block for LeadLag<I, T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+    I: Solver<T> + Debug,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.ss.block(input, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.ss.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.ss.reset();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_lead_lag_step_jumps_by_ratio_and_settles_at_unity() {
+        let mut lead_lag = LeadLag::new(2.0, 0.5, RK4);
+        let mut simulation = Simulation::new(1e-3, 10.0);
+
+        let first = lead_lag.block(1.0, simulation.next().unwrap());
+        let last = simulation
+            .map(|sim_state| lead_lag.block(1.0, sim_state))
+            .last()
+            .unwrap();
+
+        assert!((first - 4.0).abs() < 1e-2);
+        assert!((last - 1.0).abs() < 1e-6);
+    }
+}
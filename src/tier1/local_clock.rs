@@ -0,0 +1,220 @@
+use crate::{block::Block, prelude::SimulationState, rng::SplitMix64};
+use core::time::Duration;
+
+/// Runs the wrapped block on its own clock, e.g. a controller whose crystal
+/// drifts relative to the plant, or a sensor sampled with jitter.
+///
+/// The local time is `(1 + skew) t + offset + j`, with `j` a fresh jitter
+/// draw at every step, and the block sees the difference between
+/// consecutive local times as its `dt`. That `dt` never drops below
+/// [`MIN_DT_FRACTION`](Self::MIN_DT_FRACTION) of the nominal step, so jitter
+/// of half a step or more delays samples instead of stacking them at the
+/// same instant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalClock<B>
+where
+    B: Block,
+{
+    inner: B,
+    rate: f64,
+    offset: Duration,
+    jitter: Jitter,
+    seed: u64,
+    rng: SplitMix64,
+    local_time: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Jitter {
+    /// Uniform in `[-bound, bound]`.
+    Uniform(Duration),
+    /// Normal with this standard deviation.
+    Gaussian(Duration),
+}
+
+impl<B> LocalClock<B>
+where
+    B: Block,
+{
+    /// Smallest local `dt`, as a fraction of the nominal step.
+    pub const MIN_DT_FRACTION: f64 = 0.1;
+
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            rate: 1.0,
+            offset: Duration::ZERO,
+            jitter: Jitter::Uniform(Duration::ZERO),
+            seed: 0,
+            rng: SplitMix64::new(0),
+            local_time: None,
+        }
+    }
+
+    /// Clock drift in parts per million; positive runs fast.
+    pub fn with_skew(mut self, ppm: f64) -> Self {
+        assert!(ppm > -1e6, "Clock skew must keep the clock running forward");
+        self.rate = 1.0 + ppm * 1e-6;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Duration) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Jitter drawn uniformly in `[-jitter, jitter]`.
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = Jitter::Uniform(jitter);
+        self
+    }
+
+    /// Normally distributed jitter of standard deviation `sigma`, the usual
+    /// model of oscillator phase noise.
+    pub fn with_gaussian_jitter(mut self, sigma: Duration) -> Self {
+        self.jitter = Jitter::Gaussian(sigma);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = SplitMix64::new(seed);
+        self
+    }
+
+    /// Local time at the last step.
+    pub fn local_time(&self) -> Option<Duration> {
+        self.local_time
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    fn local_state(&mut self, sim_state: SimulationState) -> SimulationState {
+        let jitter = match self.jitter {
+            Jitter::Uniform(bound) => (2.0 * self.rng.next_f64() - 1.0) * bound.as_secs_f64(),
+            Jitter::Gaussian(sigma) => self.rng.next_gaussian() * sigma.as_secs_f64(),
+        };
+        let secs = self.rate * sim_state.sim_time().as_secs_f64() + jitter;
+        let sim_time = Duration::from_secs_f64(secs.max(0.0)) + self.offset;
+
+        // A late previous sample and an early current one must neither make
+        // the local time run backwards nor give the block a zero `dt`.
+        let nominal = sim_state.dt().mul_f64(self.rate);
+        let last = self.local_time.unwrap_or(sim_time.saturating_sub(nominal));
+        let sim_time = sim_time.max(last + nominal.mul_f64(Self::MIN_DT_FRACTION));
+        self.local_time = Some(sim_time);
+
+        SimulationState::new(sim_time - last, sim_time)
+    }
+}
+
+impl<B> Block for LocalClock<B>
+where
+    B: Block,
+{
+    type Input = B::Input;
+    type Output = B::Output;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let local_state = self.local_state(sim_state);
+        crate::block::run(&mut self.inner, input, local_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.inner.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.rng = SplitMix64::new(self.seed);
+        self.local_time = None;
+    }
+
+    fn preferred_sample_time(&self) -> Option<Duration> {
+        self.inner.preferred_sample_time()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    #[test]
+    fn test_local_clock_skew_and_offset() {
+        let mut reference = Integrator::<f64>::new();
+        let mut skewed = LocalClock::new(Integrator::<f64>::new())
+            .with_skew(1e5)
+            .with_offset(Duration::from_secs(2));
+
+        for sim_state in Simulation::new(0.001, 1.0) {
+            let _ = 1.0.as_signal(sim_state) >> reference.as_block();
+            let _ = 1.0.as_signal(sim_state) >> skewed.as_block();
+        }
+
+        // A clock 10% fast integrates 10% more.
+        let ratio = skewed.last_output().unwrap() / reference.last_output().unwrap();
+        assert!((ratio - 1.1).abs() < 1e-6, "ratio = {}", ratio);
+        let local = skewed.local_time().unwrap().as_secs_f64();
+        assert!((local - 3.1).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_local_clock_jitter_is_reproducible_and_monotonic() {
+        let mut clock = LocalClock::new(Integrator::<f64>::new())
+            .with_jitter(Duration::from_micros(300))
+            .with_seed(3);
+        let run = |clock: &mut LocalClock<Integrator<f64>>| {
+            let mut times = Vec::new();
+            for sim_state in Simulation::new(0.001, 0.5) {
+                let _ = 1.0.as_signal(sim_state) >> clock.as_block();
+                times.push(clock.local_time().unwrap());
+            }
+            times
+        };
+
+        let first = run(&mut clock);
+        assert!(first.windows(2).all(|w| w[1] >= w[0]));
+        assert!(
+            first
+                .windows(2)
+                .any(|w| w[1] - w[0] != Duration::from_millis(1))
+        );
+        let integral = clock.last_output().unwrap();
+        assert!((integral - 0.5).abs() < 1e-3);
+
+        clock.reset();
+        assert_eq!(run(&mut clock), first);
+    }
+
+    #[test]
+    fn test_local_clock_large_jitter_keeps_dt_positive() {
+        let mut uniform = LocalClock::new(Integrator::<f64>::new())
+            .with_jitter(Duration::from_millis(2))
+            .with_seed(5);
+        let mut gaussian = LocalClock::new(Integrator::<f64>::new())
+            .with_gaussian_jitter(Duration::from_millis(1))
+            .with_seed(5);
+
+        let (mut last_uniform, mut last_gaussian) = (Duration::ZERO, Duration::ZERO);
+        for sim_state in Simulation::new(0.001, 0.5) {
+            let _ = 1.0.as_signal(sim_state) >> uniform.as_block();
+            let _ = 1.0.as_signal(sim_state) >> gaussian.as_block();
+
+            let (now_uniform, now_gaussian) = (
+                uniform.local_time().unwrap(),
+                gaussian.local_time().unwrap(),
+            );
+            assert!(now_uniform - last_uniform >= Duration::from_micros(100));
+            assert!(now_gaussian - last_gaussian >= Duration::from_micros(100));
+            (last_uniform, last_gaussian) = (now_uniform, now_gaussian);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LutBoundary {
+    #[default]
+    Clamp,
+    Extrapolate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut1D<T>
+where
+    T: Float,
+{
+    breakpoints: Vec<T>,
+    values: Vec<T>,
+    boundary: LutBoundary,
+    last_output: Option<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut2D<T>
+where
+    T: Float,
+{
+    row_breakpoints: Vec<T>,
+    col_breakpoints: Vec<T>,
+    values: Vec<Vec<T>>,
+    boundary: LutBoundary,
+    last_output: Option<T>,
+}
+
+fn check_breakpoints<T>(breakpoints: &[T], name: &str)
+where
+    T: Float,
+{
+    assert!(
+        breakpoints.len() >= 2,
+        "{} must have at least 2 breakpoints",
+        name
+    );
+    assert!(
+        breakpoints.windows(2).all(|w| w[0] < w[1]),
+        "{} breakpoints must be strictly increasing",
+        name
+    );
+}
+
+/// Returns the index of the segment that contains `x` and the interpolation
+/// factor inside that segment. The factor leaves [0, 1] only when
+/// extrapolating.
+fn segment<T>(breakpoints: &[T], x: T, boundary: LutBoundary) -> (usize, T)
+where
+    T: Float,
+{
+    let last = breakpoints.len() - 1;
+    let x = match boundary {
+        LutBoundary::Clamp => x.max(breakpoints[0]).min(breakpoints[last]),
+        LutBoundary::Extrapolate => x,
+    };
+
+    let index = breakpoints[1..last]
+        .iter()
+        .position(|bp| x < *bp)
+        .unwrap_or(last - 1);
+    let (x0, x1) = (breakpoints[index], breakpoints[index + 1]);
+
+    (index, (x - x0) / (x1 - x0))
+}
+
+impl<T> Lut1D<T>
+where
+    T: Float,
+{
+    pub fn new(breakpoints: &[T], values: &[T]) -> Self {
+        check_breakpoints(breakpoints, "Lut1D");
+        assert_eq!(
+            breakpoints.len(),
+            values.len(),
+            "Lut1D must have one value per breakpoint"
+        );
+
+        Self {
+            breakpoints: breakpoints.to_vec(),
+            values: values.to_vec(),
+            boundary: LutBoundary::default(),
+            last_output: None,
+        }
+    }
+
+    pub fn with_boundary(mut self, boundary: LutBoundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn lookup(&self, x: T) -> T {
+        let (i, alpha) = segment(&self.breakpoints, x, self.boundary);
+        self.values[i] + (self.values[i + 1] - self.values[i]) * alpha
+    }
+}
+
+impl<T> Lut2D<T>
+where
+    T: Float,
+{
+    pub fn new(row_breakpoints: &[T], col_breakpoints: &[T], values: Vec<Vec<T>>) -> Self {
+        check_breakpoints(row_breakpoints, "Lut2D rows");
+        check_breakpoints(col_breakpoints, "Lut2D columns");
+        assert_eq!(
+            values.len(),
+            row_breakpoints.len(),
+            "Lut2D must have one row of values per row breakpoint"
+        );
+        assert!(
+            values.iter().all(|row| row.len() == col_breakpoints.len()),
+            "Lut2D must have one column of values per column breakpoint"
+        );
+
+        Self {
+            row_breakpoints: row_breakpoints.to_vec(),
+            col_breakpoints: col_breakpoints.to_vec(),
+            values,
+            boundary: LutBoundary::default(),
+            last_output: None,
+        }
+    }
+
+    pub fn with_boundary(mut self, boundary: LutBoundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn lookup(&self, row: T, col: T) -> T {
+        let (i, alpha) = segment(&self.row_breakpoints, row, self.boundary);
+        let (j, beta) = segment(&self.col_breakpoints, col, self.boundary);
+
+        let v00 = self.values[i][j];
+        let v01 = self.values[i][j + 1];
+        let v10 = self.values[i + 1][j];
+        let v11 = self.values[i + 1][j + 1];
+
+        let top = v00 + (v01 - v00) * beta;
+        let bottom = v10 + (v11 - v10) * beta;
+
+        top + (bottom - top) * alpha
+    }
+}
+
+impl<T> Block for Lut1D<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = self.lookup(input);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<T> Block for Lut2D<T>
+where
+    T: Float,
+{
+    type Input = (T, T);
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = self.lookup(input.0, input.1);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_lut1d_interpolates_between_breakpoints() {
+        let lut = Lut1D::new(&[0.0, 1.0, 3.0], &[0.0, 10.0, 30.0]);
+
+        assert_eq!(lut.lookup(0.5), 5.0);
+        assert_eq!(lut.lookup(2.0), 20.0);
+        assert_eq!(lut.lookup(3.0), 30.0);
+    }
+
+    #[test]
+    fn test_lut1d_clamps_by_default() {
+        let lut = Lut1D::new(&[0.0, 1.0], &[1.0, 2.0]);
+
+        assert_eq!(lut.lookup(-1.0), 1.0);
+        assert_eq!(lut.lookup(5.0), 2.0);
+    }
+
+    #[test]
+    fn test_lut1d_extrapolates_from_edge_segments() {
+        let lut =
+            Lut1D::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 3.0]).with_boundary(LutBoundary::Extrapolate);
+
+        assert_eq!(lut.lookup(-1.0), -1.0);
+        assert_eq!(lut.lookup(3.0), 5.0);
+    }
+
+    #[test]
+    fn test_lut2d_bilinear_interpolation() {
+        let mut lut = Lut2D::new(
+            &[0.0, 1.0],
+            &[0.0, 2.0],
+            vec![vec![0.0, 2.0], vec![1.0, 3.0]],
+        );
+        let sim_state = Simulation::new(0.1, 0.1).next().unwrap();
+
+        assert_eq!(lut.block((0.5, 1.0), sim_state), 1.5);
+        assert_eq!(lut.last_output(), Some(1.5));
+        assert_eq!(lut.lookup(2.0, -1.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Lut1D breakpoints must be strictly increasing")]
+    fn test_lut1d_rejects_unsorted_breakpoints() {
+        let _lut = Lut1D::new(&[1.0, 0.0], &[0.0, 1.0]);
+    }
+}
@@ -1,8 +1,51 @@
+pub mod attitude;
+pub mod bias;
 pub mod bridge;
+pub mod convert;
 #[cfg(feature = "alloc")]
 pub mod delay;
+pub mod differentiator;
+pub mod discrete_pid;
+pub mod disturbance;
 pub mod filter;
+pub mod friction;
+pub mod gain;
+#[cfg(feature = "hal")]
+pub mod hal;
+#[cfg(feature = "alloc")]
+pub mod hot_swap;
+#[cfg(feature = "alloc")]
+pub mod imu;
+#[cfg(feature = "alloc")]
+pub mod input_shaper;
+pub mod integrator;
+#[cfg(feature = "alloc")]
+pub mod lead_lag;
+pub mod local_clock;
+#[cfg(feature = "alloc")]
+pub mod lookup_table;
+pub mod nan_guard;
+#[cfg(feature = "alloc")]
+pub mod network;
+#[cfg(feature = "onnx")]
+pub mod nn_controller;
 #[cfg(feature = "alloc")]
 pub mod observer;
+#[cfg(feature = "std")]
+pub mod parallel;
 pub mod pid;
+pub mod pr;
+pub mod pwm;
+#[cfg(feature = "alloc")]
+pub mod repetitive;
 pub mod saturation;
+pub mod scale;
+pub mod state_output;
+pub mod static_fn;
+#[cfg(feature = "nalgebra")]
+pub mod static_observer;
+pub mod terminator;
+pub mod torque_bias;
+#[cfg(feature = "alloc")]
+pub mod washout;
+pub mod watchdog;
@@ -0,0 +1,201 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::time::Duration;
+
+/// Values that can be checked for NaN or infinite components.
+pub trait Finite {
+    fn is_finite(&self) -> bool;
+}
+
+impl Finite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl Finite for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+impl<T, const N: usize> Finite for [T; N]
+where
+    T: Finite,
+{
+    fn is_finite(&self) -> bool {
+        self.iter().all(Finite::is_finite)
+    }
+}
+
+impl<A, B> Finite for (A, B)
+where
+    A: Finite,
+    B: Finite,
+{
+    fn is_finite(&self) -> bool {
+        self.0.is_finite() && self.1.is_finite()
+    }
+}
+
+impl<A, B, C> Finite for (A, B, C)
+where
+    A: Finite,
+    B: Finite,
+    C: Finite,
+{
+    fn is_finite(&self) -> bool {
+        self.0.is_finite() && self.1.is_finite() && self.2.is_finite()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NanGuardAction {
+    /// Panics naming the guarded block and the simulation time.
+    #[default]
+    Panic,
+    /// Records the first fault and lets the value through.
+    Record,
+    /// Records the first fault and repeats the last finite value.
+    HoldLast,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NanFault {
+    pub label: &'static str,
+    pub sim_time: Duration,
+}
+
+/// Pass-through block placed after `label` that detects NaN/Inf values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NanGuard<T>
+where
+    T: Clone,
+{
+    label: &'static str,
+    action: NanGuardAction,
+    fault: Option<NanFault>,
+    last_finite: Option<T>,
+    last_output: Option<T>,
+}
+
+impl<T> NanGuard<T>
+where
+    T: Clone,
+{
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            action: NanGuardAction::default(),
+            fault: None,
+            last_finite: None,
+            last_output: None,
+        }
+    }
+
+    pub fn with_action(mut self, action: NanGuardAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// First non-finite value seen since the last reset.
+    pub fn fault(&self) -> Option<NanFault> {
+        self.fault
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.fault.is_none()
+    }
+}
+
+impl<T> Block for NanGuard<T>
+where
+    T: Finite + Clone,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let finite = input.is_finite();
+        let output = self.check(input, finite, sim_state);
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> NanGuard<T>
+where
+    T: Clone,
+{
+    pub(crate) fn clear(&mut self) {
+        self.fault = None;
+        self.last_finite = None;
+        self.last_output = None;
+    }
+
+    /// Applies the action to `input`, already known to be `finite` or not.
+    pub(crate) fn check(&mut self, input: T, finite: bool, sim_state: SimulationState) -> T {
+        if finite {
+            self.last_finite = Some(input.clone());
+            input
+        } else {
+            let fault = NanFault {
+                label: self.label,
+                sim_time: sim_state.sim_time(),
+            };
+
+            match self.action {
+                NanGuardAction::Panic => panic!(
+                    "Non-finite value from '{}' at t = {:?}",
+                    fault.label, fault.sim_time
+                ),
+                NanGuardAction::Record => {
+                    self.fault.get_or_insert(fault);
+                    input
+                }
+                NanGuardAction::HoldLast => {
+                    self.fault.get_or_insert(fault);
+                    self.last_finite.clone().unwrap_or(input)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_nan_guard_holds_last_finite_value() {
+        let mut guard = NanGuard::<[f64; 2]>::new("divider").with_action(NanGuardAction::HoldLast);
+
+        for sim_state in Simulation::new(0.1, 1.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let value = if t >= 0.45 { [f64::NAN, 1.0] } else { [t, 1.0] };
+            let output = value.as_signal(sim_state) >> guard.as_block();
+            assert!(output.value[0] <= 0.45);
+        }
+
+        let fault = guard.fault().unwrap();
+        assert_eq!(fault.label, "divider");
+        assert!((fault.sim_time.as_secs_f64() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Non-finite value from 'plant'")]
+    fn test_nan_guard_panics_by_default() {
+        let mut guard = NanGuard::new("plant");
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+
+        guard.block(f64::INFINITY, sim_state);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{block::Block, prelude::SimulationState, rng::SplitMix64};
+use alloc::collections::VecDeque;
+use core::time::Duration;
+use num_traits::Float;
+
+/// What the receiver outputs on a step in which no packet arrives.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// Keeps the last received value, like a zero-order hold.
+    #[default]
+    HoldLast,
+    /// Outputs zero until the next packet arrives.
+    Zero,
+}
+
+/// Packet counters of a [`NetworkChannel`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChannelStats {
+    pub sent: u32,
+    pub dropped: u32,
+    pub delivered: u32,
+    /// Packets that arrived after a newer one and were discarded.
+    pub out_of_order: u32,
+}
+
+/// Network link between two blocks. Each step sends one packet carrying the
+/// input, quantized if asked, that is lost with probability `drop_rate` or
+/// else arrives after `delay` plus a random jitter uniform in `[0, jitter]`.
+/// The receiver outputs the newest packet that has arrived and discards
+/// older ones that arrive late.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkChannel<T>
+where
+    T: Float,
+{
+    delay: Duration,
+    jitter: Duration,
+    drop_rate: f64,
+    drop_policy: DropPolicy,
+    quantization: Option<T>,
+    seed: u64,
+    rng: SplitMix64,
+    in_flight: VecDeque<(Duration, Duration, T)>,
+    newest: Option<(Duration, T)>,
+    stats: ChannelStats,
+    last_output: Option<T>,
+}
+
+impl<T> NetworkChannel<T>
+where
+    T: Float,
+{
+    pub fn new() -> Self {
+        Self {
+            delay: Duration::ZERO,
+            jitter: Duration::ZERO,
+            drop_rate: 0.0,
+            drop_policy: DropPolicy::default(),
+            quantization: None,
+            seed: 0,
+            rng: SplitMix64::new(0),
+            in_flight: VecDeque::new(),
+            newest: None,
+            stats: ChannelStats::default(),
+            last_output: None,
+        }
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_drop_rate(mut self, drop_rate: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&drop_rate),
+            "Drop rate must be in [0, 1]"
+        );
+        self.drop_rate = drop_rate;
+        self
+    }
+
+    pub fn with_drop_policy(mut self, drop_policy: DropPolicy) -> Self {
+        self.drop_policy = drop_policy;
+        self
+    }
+
+    /// Rounds the transmitted values to multiples of `step`.
+    pub fn with_quantization(mut self, step: T) -> Self {
+        assert!(
+            step > T::zero(),
+            "Quantization step must be greater than zero"
+        );
+        self.quantization = Some(step);
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = SplitMix64::new(seed);
+        self
+    }
+
+    pub fn stats(&self) -> ChannelStats {
+        self.stats
+    }
+
+    fn quantize(&self, value: T) -> T {
+        match self.quantization {
+            Some(step) => (value / step).round() * step,
+            None => value,
+        }
+    }
+}
+
+impl<T> Default for NetworkChannel<T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Block for NetworkChannel<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let now = sim_state.sim_time();
+
+        self.stats.sent += 1;
+        if self.rng.next_f64() < self.drop_rate {
+            self.stats.dropped += 1;
+        } else {
+            let jitter = self.jitter.mul_f64(self.rng.next_f64());
+            let value = self.quantize(input);
+            self.in_flight
+                .push_back((now + self.delay + jitter, now, value));
+        }
+
+        let mut received = false;
+        let newest = &mut self.newest;
+        let stats = &mut self.stats;
+        self.in_flight.retain(|&(arrival, sent, value)| {
+            if arrival > now {
+                return true;
+            }
+            if newest.is_some_and(|(newest_sent, _)| sent < newest_sent) {
+                stats.out_of_order += 1;
+            } else {
+                *newest = Some((sent, value));
+                stats.delivered += 1;
+                received = true;
+            }
+            false
+        });
+
+        let output = match (received, self.drop_policy, self.newest) {
+            (true, _, Some((_, value))) | (false, DropPolicy::HoldLast, Some((_, value))) => value,
+            _ => T::zero(),
+        };
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.rng = SplitMix64::new(self.seed);
+        self.in_flight.clear();
+        self.newest = None;
+        self.stats = ChannelStats::default();
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    #[test]
+    fn test_network_channel_delays_and_quantizes() {
+        let mut channel = NetworkChannel::new()
+            .with_delay(Duration::from_millis(30))
+            .with_quantization(0.5);
+
+        let outputs = Simulation::new(0.01, 0.1)
+            .map(|sim_state| {
+                let t = sim_state.sim_time().as_secs_f64();
+                ((t.as_signal(sim_state) * 10.0) >> channel.as_block()).value
+            })
+            .collect::<Vec<_>>();
+
+        // Nothing arrives for the first 3 steps, then the samples sent 30 ms
+        // earlier: 0.1, 0.2 and 0.3 round to 0, 0 and 0.5.
+        assert_eq!(&outputs[..5], &[0.0; 5]);
+        assert_eq!(outputs[5], 0.5);
+        assert_eq!(channel.stats().delivered as usize, outputs.len() - 3);
+    }
+
+    #[test]
+    fn test_network_channel_drops_jitters_and_resets() {
+        let run = |channel: &mut NetworkChannel<f64>| {
+            Simulation::new(0.001, 1.0)
+                .map(|sim_state| (1.0.as_signal(sim_state) >> channel.as_block()).value)
+                .collect::<Vec<_>>()
+        };
+        let mut channel = NetworkChannel::new()
+            .with_delay(Duration::from_millis(5))
+            .with_jitter(Duration::from_millis(4))
+            .with_drop_rate(0.2)
+            .with_drop_policy(DropPolicy::Zero)
+            .with_seed(7);
+
+        let first = run(&mut channel);
+        let stats = channel.stats();
+        let rate = stats.dropped as f64 / stats.sent as f64;
+        assert!((rate - 0.2).abs() < 0.05, "drop rate = {}", rate);
+        assert!(stats.out_of_order > 0);
+        assert!(first.iter().skip(10).any(|y| *y == 0.0));
+
+        channel.reset();
+        assert_eq!(run(&mut channel), first);
+
+        let mut hold = channel.clone().with_drop_policy(DropPolicy::HoldLast);
+        hold.reset();
+        assert!(run(&mut hold).iter().skip(10).all(|y| *y == 1.0));
+    }
+}
@@ -0,0 +1,648 @@
+use crate::{block::Block, prelude::SimulationState};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::{io, path::Path};
+
+const FLOAT: i32 = 1;
+const DOUBLE: i32 = 11;
+
+/// Subset of the ONNX protobuf schema needed to load feed-forward networks.
+mod proto {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ModelProto {
+        #[prost(message, optional, tag = "7")]
+        pub graph: Option<GraphProto>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct GraphProto {
+        #[prost(message, repeated, tag = "1")]
+        pub node: Vec<NodeProto>,
+        #[prost(string, tag = "2")]
+        pub name: String,
+        #[prost(message, repeated, tag = "5")]
+        pub initializer: Vec<TensorProto>,
+        #[prost(message, repeated, tag = "11")]
+        pub input: Vec<ValueInfoProto>,
+        #[prost(message, repeated, tag = "12")]
+        pub output: Vec<ValueInfoProto>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct NodeProto {
+        #[prost(string, repeated, tag = "1")]
+        pub input: Vec<String>,
+        #[prost(string, repeated, tag = "2")]
+        pub output: Vec<String>,
+        #[prost(string, tag = "3")]
+        pub name: String,
+        #[prost(string, tag = "4")]
+        pub op_type: String,
+        #[prost(message, repeated, tag = "5")]
+        pub attribute: Vec<AttributeProto>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct AttributeProto {
+        #[prost(string, tag = "1")]
+        pub name: String,
+        #[prost(float, tag = "2")]
+        pub f: f32,
+        #[prost(int64, tag = "3")]
+        pub i: i64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct TensorProto {
+        #[prost(int64, repeated, tag = "1")]
+        pub dims: Vec<i64>,
+        #[prost(int32, tag = "2")]
+        pub data_type: i32,
+        #[prost(float, repeated, tag = "4")]
+        pub float_data: Vec<f32>,
+        #[prost(string, tag = "8")]
+        pub name: String,
+        #[prost(bytes = "vec", tag = "9")]
+        pub raw_data: Vec<u8>,
+        #[prost(double, repeated, tag = "10")]
+        pub double_data: Vec<f64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ValueInfoProto {
+        #[prost(string, tag = "1")]
+        pub name: String,
+    }
+}
+
+#[derive(Debug)]
+pub enum OnnxError {
+    Io(io::Error),
+    Decode(prost::DecodeError),
+    /// Operator or tensor type outside the supported subset.
+    Unsupported(String),
+    /// Graph that does not fit the block, e.g. a dangling input or an
+    /// output of the wrong size.
+    Graph(String),
+}
+
+/// Row-major tensor of rank at most two.
+#[derive(Debug, Clone, PartialEq)]
+struct Tensor {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Op {
+    Gemm {
+        alpha: f64,
+        beta: f64,
+        trans_a: bool,
+        trans_b: bool,
+    },
+    MatMul,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Relu,
+    LeakyRelu(f64),
+    Tanh,
+    Sigmoid,
+    Identity,
+    Clip {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node {
+    op: Op,
+    /// Slots read by the operator; `None` for omitted optional inputs.
+    inputs: Vec<Option<usize>>,
+    output: usize,
+}
+
+/// Controller running a small feed-forward network exported to ONNX, e.g.
+/// a policy trained against [`ControlEnv`](crate::tier3::env::ControlEnv)
+/// or a learned compensator, on the packed observation each step.
+///
+/// Supports the operators of multilayer perceptrons: `Gemm`, `MatMul`,
+/// `Add`, `Sub`, `Mul`, `Div`, `Relu`, `LeakyRelu`, `Tanh`, `Sigmoid`,
+/// `Clip` and `Identity`, with float or double weights. The network takes a
+/// `[1, I]` input and produces `O` values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NnController<const I: usize, const O: usize> {
+    nodes: Vec<Node>,
+    constants: Vec<Option<Tensor>>,
+    input: usize,
+    output: usize,
+    last_output: Option<[f64; O]>,
+}
+
+impl<const I: usize, const O: usize> NnController<I, O> {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, OnnxError> {
+        let bytes = std::fs::read(path).map_err(OnnxError::Io)?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OnnxError> {
+        let model =
+            <proto::ModelProto as prost::Message>::decode(bytes).map_err(OnnxError::Decode)?;
+        let graph = model
+            .graph
+            .ok_or_else(|| OnnxError::Graph("Model has no graph".to_string()))?;
+
+        let mut names = Vec::<String>::new();
+        let mut constants = Vec::new();
+        for initializer in &graph.initializer {
+            let index = slot(&mut names, &initializer.name);
+            constants.resize(names.len(), None);
+            constants[index] = Some(tensor(initializer)?);
+        }
+
+        let input_name = graph
+            .input
+            .iter()
+            .map(|input| &input.name)
+            .find(|name| !graph.initializer.iter().any(|i| &i.name == *name))
+            .ok_or_else(|| OnnxError::Graph("Graph has no input".to_string()))?;
+        let input = slot(&mut names, input_name);
+
+        let mut nodes = Vec::new();
+        for node in &graph.node {
+            let op = op(node)?;
+            let inputs = node
+                .input
+                .iter()
+                .map(|name| {
+                    if name.is_empty() {
+                        return Ok(None);
+                    }
+                    names
+                        .iter()
+                        .position(|n| n == name)
+                        .map(Some)
+                        .ok_or_else(|| {
+                            OnnxError::Graph(format!(
+                                "Input {} of {} is undefined",
+                                name, node.name
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let [output] = node.output.as_slice() else {
+                return Err(OnnxError::Unsupported(format!(
+                    "{} has {} outputs",
+                    node.op_type,
+                    node.output.len()
+                )));
+            };
+            let output = slot(&mut names, output);
+            nodes.push(Node { op, inputs, output });
+        }
+
+        let output_name = &graph
+            .output
+            .first()
+            .ok_or_else(|| OnnxError::Graph("Graph has no output".to_string()))?
+            .name;
+        let output = names
+            .iter()
+            .position(|n| n == output_name)
+            .ok_or_else(|| OnnxError::Graph(format!("Output {} is never computed", output_name)))?;
+        constants.resize(names.len(), None);
+
+        let controller = Self {
+            nodes,
+            constants,
+            input,
+            output,
+            last_output: None,
+        };
+        // The probe also rejects shape mismatches and inverted `Clip` bounds
+        // coming from the weights.
+        let probe = controller.evaluate(&[0.0; I])?;
+        if probe.data.len() != O {
+            return Err(OnnxError::Graph(format!(
+                "Network produces {} values, expected {}",
+                probe.data.len(),
+                O
+            )));
+        }
+
+        Ok(controller)
+    }
+
+    /// Runs the network on one observation. Fails when the observation makes
+    /// an operator invalid, e.g. `Clip` bounds computed from it that cross.
+    pub fn infer(&self, observation: &[f64; I]) -> Result<[f64; O], OnnxError> {
+        let output = self.evaluate(observation)?;
+        Ok(core::array::from_fn(|i| output.data[i]))
+    }
+
+    fn evaluate(&self, observation: &[f64; I]) -> Result<Tensor, OnnxError> {
+        // Only computed values live here, the weights are read in place.
+        let mut values = alloc::vec![None; self.constants.len()];
+        values[self.input] = Some(Tensor {
+            rows: 1,
+            cols: I,
+            data: observation.to_vec(),
+        });
+
+        for node in &self.nodes {
+            let inputs = node
+                .inputs
+                .iter()
+                .map(|slot| {
+                    slot.and_then(|slot| values[slot].as_ref().or(self.constants[slot].as_ref()))
+                })
+                .collect::<Vec<_>>();
+            let result = apply(&node.op, &inputs)?;
+            values[node.output] = Some(result);
+        }
+
+        values[self.output]
+            .take()
+            .or_else(|| self.constants[self.output].clone())
+            .ok_or_else(|| OnnxError::Graph("Output was not computed".to_string()))
+    }
+}
+
+impl<const I: usize, const O: usize> Block for NnController<I, O> {
+    type Input = [f64; I];
+    type Output = [f64; O];
+
+    /// Outputs NaN when the network fails on `input`.
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = self.infer(&input).unwrap_or([f64::NAN; O]);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+/// Index of the value called `name`, allocating one for new names.
+fn slot(names: &mut Vec<String>, name: &str) -> usize {
+    match names.iter().position(|n| n == name) {
+        Some(index) => index,
+        None => {
+            names.push(name.to_string());
+            names.len() - 1
+        }
+    }
+}
+
+fn tensor(proto: &proto::TensorProto) -> Result<Tensor, OnnxError> {
+    let data = match proto.data_type {
+        FLOAT if !proto.raw_data.is_empty() => proto
+            .raw_data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64)
+            .collect(),
+        FLOAT => proto.float_data.iter().map(|v| *v as f64).collect(),
+        DOUBLE if !proto.raw_data.is_empty() => proto
+            .raw_data
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+            .collect(),
+        DOUBLE => proto.double_data.clone(),
+        other => {
+            return Err(OnnxError::Unsupported(format!(
+                "Tensor {} has data type {}",
+                proto.name, other
+            )));
+        }
+    };
+
+    let (rows, cols) = match proto.dims.as_slice() {
+        [] => (1, 1),
+        [n] => (1, *n as usize),
+        [rows, cols] => (*rows as usize, *cols as usize),
+        _ => {
+            return Err(OnnxError::Unsupported(format!(
+                "Tensor {} has rank {}",
+                proto.name,
+                proto.dims.len()
+            )));
+        }
+    };
+    if rows * cols != data.len() {
+        return Err(OnnxError::Graph(format!(
+            "Tensor {} has {} values for shape {:?}",
+            proto.name,
+            data.len(),
+            proto.dims
+        )));
+    }
+
+    Ok(Tensor { rows, cols, data })
+}
+
+fn op(node: &proto::NodeProto) -> Result<Op, OnnxError> {
+    let attribute = |name: &str| node.attribute.iter().find(|a| a.name == name);
+    let float = |name: &str, default: f64| attribute(name).map(|a| a.f as f64).unwrap_or(default);
+    let flag = |name: &str| attribute(name).is_some_and(|a| a.i != 0);
+
+    Ok(match node.op_type.as_str() {
+        "Gemm" => Op::Gemm {
+            alpha: float("alpha", 1.0),
+            beta: float("beta", 1.0),
+            trans_a: flag("transA"),
+            trans_b: flag("transB"),
+        },
+        "MatMul" => Op::MatMul,
+        "Add" => Op::Add,
+        "Sub" => Op::Sub,
+        "Mul" => Op::Mul,
+        "Div" => Op::Div,
+        "Relu" => Op::Relu,
+        "LeakyRelu" => Op::LeakyRelu(float("alpha", 0.01)),
+        "Tanh" => Op::Tanh,
+        "Sigmoid" => Op::Sigmoid,
+        "Identity" => Op::Identity,
+        "Clip" => Op::Clip {
+            min: attribute("min").map(|a| a.f as f64),
+            max: attribute("max").map(|a| a.f as f64),
+        },
+        other => return Err(OnnxError::Unsupported(format!("Operator {}", other))),
+    })
+}
+
+fn apply(op: &Op, inputs: &[Option<&Tensor>]) -> Result<Tensor, OnnxError> {
+    let input = |index: usize| {
+        inputs
+            .get(index)
+            .copied()
+            .flatten()
+            .ok_or_else(|| OnnxError::Graph(format!("{:?} is missing input {}", op, index)))
+    };
+    let map = |f: &dyn Fn(f64) -> f64| -> Result<Tensor, OnnxError> {
+        let x = input(0)?;
+        Ok(Tensor {
+            rows: x.rows,
+            cols: x.cols,
+            data: x.data.iter().map(|v| f(*v)).collect(),
+        })
+    };
+
+    match op {
+        Op::Gemm {
+            alpha,
+            beta,
+            trans_a,
+            trans_b,
+        } => {
+            let product = matmul(input(0)?, *trans_a, input(1)?, *trans_b)?;
+            let scaled = Tensor {
+                data: product.data.iter().map(|v| v * alpha).collect(),
+                ..product
+            };
+            match inputs.get(2).copied().flatten() {
+                Some(c) => broadcast(&scaled, c, |a, c| a + beta * c),
+                None => Ok(scaled),
+            }
+        }
+        Op::MatMul => matmul(input(0)?, false, input(1)?, false),
+        Op::Add => broadcast(input(0)?, input(1)?, |a, b| a + b),
+        Op::Sub => broadcast(input(0)?, input(1)?, |a, b| a - b),
+        Op::Mul => broadcast(input(0)?, input(1)?, |a, b| a * b),
+        Op::Div => broadcast(input(0)?, input(1)?, |a, b| a / b),
+        Op::Relu => map(&|v| v.max(0.0)),
+        Op::LeakyRelu(alpha) => map(&|v| if v < 0.0 { alpha * v } else { v }),
+        Op::Tanh => map(&libm::tanh),
+        Op::Sigmoid => map(&|v| 1.0 / (1.0 + libm::exp(-v))),
+        Op::Identity => map(&|v| v),
+        Op::Clip { min, max } => {
+            // Since opset 11 the bounds are optional inputs.
+            let bound = |index: usize, attribute: Option<f64>, default: f64| match inputs
+                .get(index)
+                .copied()
+                .flatten()
+            {
+                Some(t) => t
+                    .data
+                    .first()
+                    .copied()
+                    .ok_or_else(|| OnnxError::Graph(format!("Clip bound {} is empty", index))),
+                None => Ok(attribute.unwrap_or(default)),
+            };
+            let (low, high) = (
+                bound(1, *min, f64::NEG_INFINITY)?,
+                bound(2, *max, f64::INFINITY)?,
+            );
+            if low.is_nan() || high.is_nan() || low > high {
+                return Err(OnnxError::Graph(format!(
+                    "Clip bounds [{}, {}] are inverted",
+                    low, high
+                )));
+            }
+            map(&|v| v.clamp(low, high))
+        }
+    }
+}
+
+fn matmul(a: &Tensor, trans_a: bool, b: &Tensor, trans_b: bool) -> Result<Tensor, OnnxError> {
+    let (m, k) = if trans_a {
+        (a.cols, a.rows)
+    } else {
+        (a.rows, a.cols)
+    };
+    let (k2, n) = if trans_b {
+        (b.cols, b.rows)
+    } else {
+        (b.rows, b.cols)
+    };
+    if k != k2 {
+        return Err(OnnxError::Graph(format!(
+            "Cannot multiply {}x{} by {}x{}",
+            m, k, k2, n
+        )));
+    }
+
+    let at = |i: usize, j: usize| {
+        if trans_a {
+            a.data[j * a.cols + i]
+        } else {
+            a.data[i * a.cols + j]
+        }
+    };
+    let bt = |i: usize, j: usize| {
+        if trans_b {
+            b.data[j * b.cols + i]
+        } else {
+            b.data[i * b.cols + j]
+        }
+    };
+    let mut data = alloc::vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            data[i * n + j] = (0..k).map(|l| at(i, l) * bt(l, j)).sum();
+        }
+    }
+
+    Ok(Tensor {
+        rows: m,
+        cols: n,
+        data,
+    })
+}
+
+fn broadcast(a: &Tensor, b: &Tensor, f: impl Fn(f64, f64) -> f64) -> Result<Tensor, OnnxError> {
+    let dim = |x: usize, y: usize| match (x, y) {
+        (x, y) if x == y => Ok(x),
+        (1, y) => Ok(y),
+        (x, 1) => Ok(x),
+        _ => Err(OnnxError::Graph(format!(
+            "Cannot broadcast {}x{} with {}x{}",
+            a.rows, a.cols, b.rows, b.cols
+        ))),
+    };
+    let (rows, cols) = (dim(a.rows, b.rows)?, dim(a.cols, b.cols)?);
+    let at = |t: &Tensor, i: usize, j: usize| t.data[(i % t.rows) * t.cols + j % t.cols];
+
+    Ok(Tensor {
+        rows,
+        cols,
+        data: (0..rows * cols)
+            .map(|k| f(at(a, k / cols, k % cols), at(b, k / cols, k % cols)))
+            .collect(),
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::proto::*;
+    use crate::prelude::*;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use prost::Message;
+
+    fn weights(name: &str, dims: &[i64], values: &[f32]) -> TensorProto {
+        TensorProto {
+            dims: dims.to_vec(),
+            data_type: 1,
+            float_data: Vec::new(),
+            name: name.to_string(),
+            raw_data: values.iter().flat_map(|v| v.to_le_bytes()).collect(),
+            double_data: Vec::new(),
+        }
+    }
+
+    fn node(
+        op_type: &str,
+        inputs: &[&str],
+        output: &str,
+        attribute: Vec<AttributeProto>,
+    ) -> NodeProto {
+        NodeProto {
+            input: inputs.iter().map(|s| s.to_string()).collect(),
+            output: alloc::vec![output.to_string()],
+            name: output.to_string(),
+            op_type: op_type.to_string(),
+            attribute,
+        }
+    }
+
+    fn value(name: &str) -> ValueInfoProto {
+        ValueInfoProto {
+            name: name.to_string(),
+        }
+    }
+
+    /// 2-3-1 perceptron: `tanh(relu(x W1^T + b1) W2 + b2)`.
+    fn model() -> Vec<u8> {
+        let graph = GraphProto {
+            node: alloc::vec![
+                node(
+                    "Gemm",
+                    &["obs", "w1", "b1"],
+                    "h",
+                    alloc::vec![AttributeProto {
+                        name: "transB".to_string(),
+                        f: 0.0,
+                        i: 1,
+                    }],
+                ),
+                node("Relu", &["h"], "a", Vec::new()),
+                node("MatMul", &["a", "w2"], "z", Vec::new()),
+                node("Add", &["z", "b2"], "y", Vec::new()),
+                node("Tanh", &["y"], "action", Vec::new()),
+            ],
+            name: "policy".to_string(),
+            initializer: alloc::vec![
+                weights("w1", &[3, 2], &[1.0, 0.0, 0.0, 1.0, 1.0, -1.0]),
+                weights("b1", &[3], &[0.0, 0.5, 0.0]),
+                weights("w2", &[3, 1], &[0.5, -0.25, 1.0]),
+                weights("b2", &[1], &[0.1]),
+            ],
+            input: alloc::vec![value("obs")],
+            output: alloc::vec![value("action")],
+        };
+        ModelProto { graph: Some(graph) }.encode_to_vec()
+    }
+
+    #[test]
+    fn test_nn_controller_evaluates_perceptron() {
+        let mut controller = NnController::<2, 1>::from_bytes(&model()).unwrap();
+
+        let expected = |x: [f64; 2]| {
+            let h = [x[0].max(0.0), (x[1] + 0.5).max(0.0), (x[0] - x[1]).max(0.0)];
+            libm::tanh(0.5 * h[0] - 0.25 * h[1] + h[2] + 0.1)
+        };
+        for x in [[0.0, 0.0], [1.0, -2.0], [-0.5, 3.0]] {
+            assert!((controller.infer(&x).unwrap()[0] - expected(x)).abs() < 1e-6);
+        }
+
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+        let u = [1.0, -2.0].as_signal(sim_state) >> controller.as_block();
+        assert_eq!(controller.last_output(), Some(u.value));
+
+        assert!(matches!(
+            NnController::<2, 2>::from_bytes(&model()),
+            Err(OnnxError::Graph(_))
+        ));
+        assert!(matches!(
+            NnController::<2, 1>::from_bytes(&[0xff, 0xff]),
+            Err(OnnxError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_nn_controller_rejects_inverted_clip() {
+        let clip = |bounds: &[f32]| {
+            let graph = GraphProto {
+                node: alloc::vec![node("Clip", &["obs", "min", "max"], "action", Vec::new())],
+                name: "clip".to_string(),
+                initializer: alloc::vec![
+                    weights("min", &[], &bounds[..1]),
+                    weights("max", &[], &bounds[1..]),
+                ],
+                input: alloc::vec![value("obs")],
+                output: alloc::vec![value("action")],
+            };
+            ModelProto { graph: Some(graph) }.encode_to_vec()
+        };
+
+        let controller = NnController::<1, 1>::from_bytes(&clip(&[-1.0, 1.0])).unwrap();
+        assert_eq!(controller.infer(&[3.0]).unwrap(), [1.0]);
+        assert!(matches!(
+            NnController::<1, 1>::from_bytes(&clip(&[1.0, -1.0])),
+            Err(OnnxError::Graph(_))
+        ));
+    }
+}
@@ -1,11 +1,13 @@
+use crate::Error;
 use crate::block::Block;
-use crate::prelude::{SimulationState, Solver, StateEstimation};
+use crate::continuous::solver::SolverBuffers;
+use crate::prelude::{HasState, SimulationState, Solver, StateEstimation};
 use core::{
     fmt::{Debug, Display},
     marker::PhantomData,
 };
 use faer::traits::ComplexField;
-use faer::{Mat, mat};
+use faer::{Accum, Mat, Par, linalg::matmul::matmul, mat, unzip, zip};
 use num_traits::Zero;
 
 #[derive(Debug, Clone)]
@@ -22,6 +24,7 @@ where
     initial_state: Option<Mat<T>>,
     current_input: ObserverInput<T>,
     state: Mat<T>,
+    buffers: SolverBuffers<T>,
     last_output: Option<ObserverOutput<T>>,
     _marker: PhantomData<I>,
 }
@@ -31,32 +34,46 @@ where
     T: Zero + Copy + ComplexField,
     I: Solver<T> + Debug,
 {
+    /// Observer with the gain `l`, see [`try_new`](Self::try_new). Like
+    /// [`SS::new`](crate::prelude::SS::new), mismatched dimensions panic
+    /// under `strict-panic` and otherwise are logged and give a stateless
+    /// observer whose output is NaN. Use `try_new` to handle them.
+    #[track_caller]
     pub fn new(a: Mat<T>, b: Mat<T>, c: Mat<T>, d: T, l: Mat<T>) -> Self {
-        let n = a.shape().0;
-
-        assert_eq!(a.shape().0, a.shape().1, "A must be a square matrix");
-
-        assert_eq!(b.shape().0, n, "B must has {} rows", n);
-        assert_eq!(b.shape().1, 1, "B must be a column matrix");
-
-        assert_eq!(c.shape().0, 1, "C must be a row matrix");
-        assert_eq!(c.shape().1, n, "C must has {} columns", n);
+        crate::error::or_fallback(Self::try_new(a, b, c, d, l), |_| {
+            Self::try_new(
+                Mat::zeros(0, 0),
+                Mat::zeros(0, 1),
+                Mat::zeros(1, 0),
+                T::nan_impl(),
+                Mat::zeros(0, 1),
+            )
+            .expect("Empty matrices always match")
+        })
+    }
 
-        assert_eq!(l.shape().0, n, "L must has {} rows", n);
-        assert_eq!(l.shape().1, 1, "L must be a column matrix");
+    /// Luenberger observer of the model `(A, B, C, D)` with the column
+    /// gain `L`.
+    pub fn try_new(a: Mat<T>, b: Mat<T>, c: Mat<T>, d: T, l: Mat<T>) -> Result<Self, Error> {
+        let n = a.nrows();
+        Error::check_dimension("A", (n, n), a.shape())?;
+        Error::check_dimension("B", (n, 1), b.shape())?;
+        Error::check_dimension("C", (1, n), c.shape())?;
+        Error::check_dimension("L", (n, 1), l.shape())?;
 
-        Self {
+        Ok(Self {
             a,
             b,
             c,
             d: mat![[d]],
             l,
             state: Mat::zeros(n, 1),
+            buffers: SolverBuffers::new(),
             initial_state: None,
             last_output: None,
             current_input: ObserverInput::default(),
             _marker: PhantomData,
-        }
+        })
     }
 
     pub fn with_initial_state(mut self, initial_state: Mat<T>) -> Self {
@@ -89,12 +106,26 @@ where
     I: Solver<T> + Debug,
 {
     fn estimate(&self, state: Mat<T>) -> Mat<T> {
-        let input_matrix = mat![[self.current_input.control_input]];
-        let y_hat = &self.c * &state + &self.d + &input_matrix;
-        let y = mat![[self.current_input.measured_output]];
-        let y_err = y - y_hat;
+        let mut derivative = Mat::zeros(state.nrows(), 1);
+        self.estimate_into(&state, &mut derivative);
+        derivative
+    }
+
+    fn estimate_into(&self, state: &Mat<T>, derivative: &mut Mat<T>) {
+        let u = self.current_input.control_input;
+        let y_hat = self.c.row(0) * state.col(0) + self.d[(0, 0)] * u;
+        let y_err = self.current_input.measured_output - y_hat;
 
-        &self.a * &state + &self.b * &input_matrix + &self.l * &y_err
+        matmul(
+            &mut *derivative,
+            Accum::Replace,
+            &self.a,
+            state,
+            T::one_impl(),
+            Par::Seq,
+        );
+        zip!(derivative, &self.b, &self.l)
+            .for_each(|unzip!(dx, b, l)| *dx = *dx + *b * u + *l * y_err);
     }
 }
 
@@ -110,12 +141,14 @@ where
         let dt = sim_state.dt();
 
         self.current_input = input.clone();
-        self.state = I::integrate(self.state.clone(), dt, self);
+        let mut buffers = core::mem::take(&mut self.buffers);
+        let mut state = core::mem::replace(&mut self.state, Mat::new());
+        I::integrate_in(&mut state, dt, self, &mut buffers);
+        (self.state, self.buffers) = (state, buffers);
 
-        let u = mat![[input.control_input]];
-        let y = &self.c * &self.state + &self.d * &u;
+        let y = self.c.row(0) * self.state.col(0) + self.d[(0, 0)] * input.control_input;
 
-        let output = ObserverOutput::new(y[(0, 0)], self.state.clone());
+        let output = ObserverOutput::new(y, self.state.clone());
         self.last_output = Some(output.clone());
 
         output
@@ -136,6 +169,20 @@ where
     }
 }
 
+impl<I, T> HasState<T> for Observer<I, T>
+where
+    T: Zero + Copy + ComplexField,
+    I: Solver<T> + Debug,
+{
+    fn order(&self) -> usize {
+        self.state.nrows()
+    }
+
+    fn state_at(&self, index: usize) -> T {
+        self.state[(index, 0)]
+    }
+}
+
 impl<I, T> Display for Observer<I, T>
 where
     T: Zero + Copy + Display + ComplexField,
@@ -191,3 +238,40 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::ObserverInput;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_observer_tracks_model_with_feedthrough() {
+        let model = || {
+            (
+                mat![[0.0, 1.0], [-2.0, -3.0]],
+                mat![[0.0], [1.0]],
+                mat![[1.0, 0.0]],
+            )
+        };
+        let (a, b, c) = model();
+        let mut plant: SS<RK4, f64> = SS::new(a, b, c, 0.5).with_initial_state(mat![[1.0], [-1.0]]);
+        let (a, b, c) = model();
+        let mut observer: Observer<RK4, f64> = Observer::new(a, b, c, 0.5, mat![[10.0], [20.0]]);
+
+        let mut estimate = None;
+        for sim_state in Simulation::new(0.01, 10.0) {
+            let y = plant.block(1.0, sim_state);
+            let input = ObserverInput {
+                control_input: 1.0,
+                measured_output: y,
+            };
+            estimate = Some(observer.block(input, sim_state));
+        }
+
+        let estimate = estimate.unwrap();
+        assert!((estimate.measured_output - plant.last_output().unwrap()).abs() < 1e-6);
+        for i in 0..2 {
+            assert!((estimate.state_estimate[(i, 0)] - plant.state_at(i)).abs() < 1e-6);
+        }
+    }
+}
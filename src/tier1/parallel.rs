@@ -0,0 +1,565 @@
+use crate::block::Block;
+use crate::output::diagram::Diagram;
+use crate::prelude::{Finite, NanFault, NanGuard, NanGuardAction, SimulationState};
+use core::ops::Add;
+use std::boxed::Box;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::thread::{self, JoinHandle};
+use std::vec::Vec;
+
+type Branch<I, O> = Box<dyn Block<Input = I, Output = O> + Send>;
+type FiniteCheck<T> = fn(&T) -> bool;
+
+enum Job<I> {
+    Step(Vec<(usize, I)>, SimulationState),
+    Reset,
+    Stop,
+}
+
+struct Worker<I, O> {
+    jobs: Sender<Job<I>>,
+    outputs: Receiver<Vec<(usize, O)>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+/// Threads started once and kept for the whole run, each owning a share of
+/// the blocks. Block `i` lives on worker `i % workers`, so a step only sends
+/// the inputs over and the outputs back.
+struct Pool<I, O> {
+    workers: Vec<Worker<I, O>>,
+}
+
+impl<I, O> Pool<I, O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    fn new(blocks: Vec<Branch<I, O>>) -> Self {
+        let threads = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .clamp(1, blocks.len().max(1));
+
+        let mut shares = (0..threads).map(|_| Vec::new()).collect::<Vec<_>>();
+        for (i, block) in blocks.into_iter().enumerate() {
+            shares[i % threads].push(block);
+        }
+
+        let workers = shares
+            .into_iter()
+            .map(|mut blocks| {
+                let (jobs, job_receiver) = channel::<Job<I>>();
+                let (output_sender, outputs) = channel();
+                let handle = thread::spawn(move || {
+                    while let Ok(job) = job_receiver.recv() {
+                        match job {
+                            Job::Step(inputs, sim_state) => {
+                                let outputs = inputs
+                                    .into_iter()
+                                    .map(|(i, input)| {
+                                        (i, blocks[i / threads].block(input, sim_state))
+                                    })
+                                    .collect();
+                                if output_sender.send(outputs).is_err() {
+                                    return;
+                                }
+                            }
+                            Job::Reset => blocks.iter_mut().for_each(|block| block.reset()),
+                            Job::Stop => return,
+                        }
+                    }
+                });
+
+                Worker {
+                    jobs,
+                    outputs,
+                    handle: Some(handle),
+                }
+            })
+            .collect();
+
+        Self { workers }
+    }
+
+    /// Steps the blocks named in `inputs` concurrently, returning their
+    /// outputs in the same order.
+    fn run(&self, inputs: Vec<(usize, I)>, sim_state: SimulationState) -> Vec<(usize, O)> {
+        let threads = self.workers.len();
+        let order = inputs.iter().map(|(i, _)| *i).collect::<Vec<_>>();
+
+        let mut shares = (0..threads).map(|_| Vec::new()).collect::<Vec<_>>();
+        for (i, input) in inputs {
+            shares[i % threads].push((i, input));
+        }
+
+        let busy = shares
+            .into_iter()
+            .enumerate()
+            .filter(|(_, share)| !share.is_empty())
+            .map(|(w, share)| {
+                self.workers[w]
+                    .jobs
+                    .send(Job::Step(share, sim_state))
+                    .expect("Parallel branch panicked");
+                w
+            })
+            .collect::<Vec<_>>();
+
+        let mut outputs = busy
+            .into_iter()
+            .flat_map(|w| {
+                self.workers[w]
+                    .outputs
+                    .recv()
+                    .expect("Parallel branch panicked")
+            })
+            .collect::<Vec<_>>();
+        outputs.sort_by_key(|(i, _)| order.iter().position(|j| j == i));
+        outputs
+    }
+
+    fn reset(&self) {
+        for worker in &self.workers {
+            worker.jobs.send(Job::Reset).ok();
+        }
+    }
+}
+
+impl<I, O> Drop for Pool<I, O> {
+    fn drop(&mut self) {
+        for worker in &mut self.workers {
+            worker.jobs.send(Job::Stop).ok();
+            if let Some(handle) = worker.handle.take() {
+                handle.join().ok();
+            }
+        }
+    }
+}
+
+/// Evaluates independent branches with the same signature concurrently on a
+/// pool of threads started at the first step. Branch `i` receives
+/// `input[i]`.
+pub struct Parallel<I, O>
+where
+    I: Send + 'static,
+    O: Send + Clone + 'static,
+{
+    branches: Vec<Branch<I, O>>,
+    len: usize,
+    pool: Option<Pool<I, O>>,
+    last_output: Option<Vec<O>>,
+}
+
+/// Evaluates two independent branches concurrently, the first one on a
+/// thread of its own.
+pub struct ParallelPair<A, B>
+where
+    A: Block + Send + 'static,
+    B: Block,
+    A::Input: Send + 'static,
+    A::Output: Send + Clone + 'static,
+{
+    first: Pool<A::Input, A::Output>,
+    second: B,
+    last_first: Option<A::Output>,
+}
+
+/// Runs a diagram of blocks stage by stage, following
+/// [`Diagram::stages`], with the blocks of each stage stepped concurrently.
+///
+/// Block `i` is the node of index `i`. Nodes without predecessors receive
+/// the input of the diagram; the others the sum of the outputs connected
+/// into them. The output holds the value of every node.
+pub struct ParallelDiagram<T>
+where
+    T: Add<Output = T> + Clone + Send + 'static,
+{
+    stages: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    blocks: Vec<Branch<T, T>>,
+    labels: Vec<&'static str>,
+    nan_guard: Option<(NanGuardAction, FiniteCheck<T>)>,
+    guards: Vec<NanGuard<T>>,
+    pool: Option<Pool<T, T>>,
+    last_output: Option<Vec<T>>,
+}
+
+impl<I, O> Parallel<I, O>
+where
+    I: Send + 'static,
+    O: Send + Clone + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            branches: Vec::new(),
+            len: 0,
+            pool: None,
+            last_output: None,
+        }
+    }
+
+    pub fn with_branch(
+        mut self,
+        branch: impl Block<Input = I, Output = O> + Send + 'static,
+    ) -> Self {
+        assert!(
+            self.pool.is_none(),
+            "Branches must be added before the first step"
+        );
+        self.branches.push(Box::new(branch));
+        self.len += 1;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<I, O> Default for Parallel<I, O>
+where
+    I: Send + 'static,
+    O: Send + Clone + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, B> ParallelPair<A, B>
+where
+    A: Block + Send + 'static,
+    B: Block,
+    A::Input: Send + 'static,
+    A::Output: Send + Clone + 'static,
+{
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first: Pool::new(std::vec![Box::new(first)]),
+            second,
+            last_first: None,
+        }
+    }
+
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<T> ParallelDiagram<T>
+where
+    T: Add<Output = T> + Clone + Send + 'static,
+{
+    pub fn new(diagram: &Diagram) -> Self {
+        let stages = diagram
+            .stages()
+            .expect("Parallel diagrams must not have cycles");
+
+        let mut predecessors = std::vec![Vec::new(); diagram.len()];
+        for &node in stages.iter().flatten() {
+            predecessors[node.index()] = diagram.predecessors(node).map(|n| n.index()).collect();
+        }
+        let stages = stages
+            .into_iter()
+            .map(|stage| stage.iter().map(|node| node.index()).collect())
+            .collect();
+
+        Self {
+            stages,
+            predecessors,
+            blocks: Vec::new(),
+            labels: Vec::new(),
+            nan_guard: None,
+            guards: Vec::new(),
+            pool: None,
+            last_output: None,
+        }
+    }
+
+    /// Adds the block of the next node, in the order the nodes were added
+    /// to the diagram.
+    pub fn with_block(mut self, block: impl Block<Input = T, Output = T> + Send + 'static) -> Self {
+        assert!(
+            self.pool.is_none(),
+            "Blocks must be added before the first step"
+        );
+        self.labels.push(core::any::type_name_of_val(&block));
+        self.blocks.push(Box::new(block));
+        self
+    }
+
+    pub fn stages(&self) -> &[Vec<usize>] {
+        &self.stages
+    }
+
+    /// Earliest non-finite output of a guarded block since the last reset,
+    /// labelled with the block type.
+    pub fn fault(&self) -> Option<NanFault> {
+        self.guards
+            .iter()
+            .filter_map(NanGuard::fault)
+            .min_by_key(|fault| fault.sim_time)
+    }
+}
+
+impl<T> ParallelDiagram<T>
+where
+    T: Finite + Add<Output = T> + Clone + Send + 'static,
+{
+    /// Checks the output of every block as a [`NanGuard`] placed after it
+    /// would, applying `action` to non-finite values.
+    pub fn with_nan_guard(mut self, action: NanGuardAction) -> Self {
+        assert!(
+            self.pool.is_none(),
+            "The NaN guard must be set before the first step"
+        );
+        self.nan_guard = Some((action, T::is_finite));
+        self
+    }
+}
+
+impl<I, O> Block for Parallel<I, O>
+where
+    I: Send + 'static,
+    O: Send + Clone + 'static,
+{
+    type Input = Vec<I>;
+    type Output = Vec<O>;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        assert_eq!(input.len(), self.len, "Parallel needs one input per branch");
+
+        let branches = &mut self.branches;
+        let pool = self
+            .pool
+            .get_or_insert_with(|| Pool::new(core::mem::take(branches)));
+        let output = pool
+            .run(input.into_iter().enumerate().collect(), sim_state)
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect::<Vec<_>>();
+
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        match &self.pool {
+            Some(pool) => pool.reset(),
+            None => self.branches.iter_mut().for_each(|branch| branch.reset()),
+        }
+        self.last_output = None;
+    }
+}
+
+impl<A, B> Block for ParallelPair<A, B>
+where
+    A: Block + Send + 'static,
+    B: Block,
+    A::Input: Send + 'static,
+    A::Output: Send + Clone + 'static,
+{
+    type Input = (A::Input, B::Input);
+    type Output = (A::Output, B::Output);
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let worker = &self.first.workers[0];
+        worker
+            .jobs
+            .send(Job::Step(std::vec![(0, input.0)], sim_state))
+            .expect("Parallel branch panicked");
+        let second = self.second.block(input.1, sim_state);
+        let (_, first) = worker
+            .outputs
+            .recv()
+            .expect("Parallel branch panicked")
+            .pop()
+            .expect("The worker answers every step");
+
+        self.last_first = Some(first.clone());
+        (first, second)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        Some((self.last_first.clone()?, self.second.last_output()?))
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.last_first = None;
+    }
+}
+
+impl<T> Block for ParallelDiagram<T>
+where
+    T: Add<Output = T> + Clone + Send + 'static,
+{
+    type Input = T;
+    type Output = Vec<T>;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if self.pool.is_none() {
+            assert_eq!(
+                self.blocks.len(),
+                self.predecessors.len(),
+                "ParallelDiagram needs one block per node"
+            );
+        }
+
+        if let (None, Some((action, _))) = (&self.pool, self.nan_guard) {
+            self.guards = self
+                .labels
+                .iter()
+                .map(|label| NanGuard::new(label).with_action(action))
+                .collect();
+        }
+        let blocks = &mut self.blocks;
+        let pool = self
+            .pool
+            .get_or_insert_with(|| Pool::new(core::mem::take(blocks)));
+
+        let mut values: Vec<Option<T>> = std::vec![None; self.predecessors.len()];
+        for stage in &self.stages {
+            let inputs = stage
+                .iter()
+                .map(|&node| {
+                    let value = self.predecessors[node]
+                        .iter()
+                        .map(|&from| values[from].clone().expect("Earlier stage"))
+                        .reduce(|acc, value| acc + value)
+                        .unwrap_or_else(|| input.clone());
+                    (node, value)
+                })
+                .collect();
+
+            for (node, output) in pool.run(inputs, sim_state) {
+                let output = match self.nan_guard {
+                    Some((_, is_finite)) => {
+                        let finite = is_finite(&output);
+                        self.guards[node].check(output, finite, sim_state)
+                    }
+                    None => output,
+                };
+                values[node] = Some(output);
+            }
+        }
+
+        let output = values
+            .into_iter()
+            .map(|value| value.expect("Every node runs once per step"))
+            .collect::<Vec<_>>();
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        match &self.pool {
+            Some(pool) => pool.reset(),
+            None => self.blocks.iter_mut().for_each(|block| block.reset()),
+        }
+        self.guards.iter_mut().for_each(NanGuard::clear);
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use std::vec;
+
+    #[test]
+    fn test_parallel_branches_match_sequential_evaluation() {
+        let plant = |tau: f64| Tf::new(&[1.0], &[tau, 1.0]).to_ss_controllable(RK4);
+
+        let mut parallel = Parallel::new()
+            .with_branch(plant(1.0))
+            .with_branch(plant(2.0))
+            .with_branch(plant(4.0));
+        let mut pair = ParallelPair::new(plant(1.0), Gain::new(3.0));
+        let mut sequential = [plant(1.0), plant(2.0), plant(4.0)];
+
+        for sim_state in Simulation::new(0.01, 2.0) {
+            let outputs = vec![1.0; 3].as_signal(sim_state) >> parallel.as_block();
+            let (first, second) = ((1.0, 2.0).as_signal(sim_state) >> pair.as_block()).unpack();
+
+            for (plant, output) in sequential.iter_mut().zip(&outputs.value) {
+                assert_eq!(plant.block(1.0, sim_state), *output);
+            }
+            assert_eq!(first.value, outputs.value[0]);
+            assert_eq!(second.value, 6.0);
+        }
+
+        parallel.reset();
+        let sim_state = Simulation::new(0.01, 0.01).next().unwrap();
+        let outputs = vec![1.0; 3].as_signal(sim_state) >> parallel.as_block();
+        assert_eq!(outputs.value[0], plant(1.0).block(1.0, sim_state));
+    }
+
+    #[test]
+    fn test_parallel_diagram_runs_stages_in_order() {
+        let plant = |tau: f64| Tf::new(&[1.0], &[tau, 1.0]).to_ss_controllable(RK4);
+
+        // Gain feeding two plants in parallel, summed by a second gain.
+        let mut diagram = Diagram::new();
+        let input = diagram.add(&Gain::new(2.0));
+        let fast = diagram.add(&plant(1.0));
+        let slow = diagram.add(&plant(3.0));
+        let sum = diagram.add(&Gain::new(0.5));
+        diagram.connect(input, fast).connect(input, slow);
+        diagram.connect(fast, sum).connect(slow, sum);
+
+        let mut parallel = ParallelDiagram::new(&diagram)
+            .with_block(Gain::new(2.0))
+            .with_block(plant(1.0))
+            .with_block(plant(3.0))
+            .with_block(Gain::new(0.5));
+        assert_eq!(parallel.stages(), [vec![0], vec![1, 2], vec![3]]);
+
+        let (mut fast_plant, mut slow_plant) = (plant(1.0), plant(3.0));
+        for sim_state in Simulation::new(0.01, 2.0) {
+            let outputs = parallel.block(1.0, sim_state);
+            let expected =
+                0.5 * (fast_plant.block(2.0, sim_state) + slow_plant.block(2.0, sim_state));
+            assert_eq!(outputs[3], expected);
+        }
+    }
+
+    #[test]
+    fn test_parallel_diagram_guards_every_block() {
+        let mut diagram = Diagram::new();
+        let input = diagram.add(&Gain::new(1.0));
+        let root = diagram.add(&Gain::new(1.0));
+        diagram.connect(input, root);
+
+        let mut parallel = ParallelDiagram::new(&diagram)
+            .with_nan_guard(NanGuardAction::HoldLast)
+            .with_block(Gain::new(1.0))
+            .with_block(StaticFn::new(|x: f64| x.sqrt()));
+
+        for sim_state in Simulation::new(0.1, 1.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let outputs = parallel.block(0.55 - t, sim_state);
+            assert!(outputs[1] >= 0.0);
+        }
+
+        let fault = parallel.fault().unwrap();
+        assert!(fault.label.contains("StaticFn"));
+        assert!((fault.sim_time.as_secs_f64() - 0.6).abs() < 1e-6);
+
+        parallel.reset();
+        assert!(parallel.fault().is_none());
+    }
+}
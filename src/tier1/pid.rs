@@ -1,17 +1,14 @@
-use crate::{block::Block, prelude::SimulationState};
-use core::ops::{Div, Mul, Sub};
-use num_traits::{Zero, clamp};
+use crate::signal::from_f64;
+use crate::{
+    block::Block,
+    prelude::{LimitStats, Limited, SimulationState},
+};
+use num_traits::{Float, clamp};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PID<T>
 where
-    T: Zero
-        + Copy
-        + Mul<f64, Output = T>
-        + Mul<Output = T>
-        + Sub<Output = T>
-        + Div<f64, Output = T>
-        + PartialOrd,
+    T: Float,
 {
     kp: T,
     ki: T,
@@ -20,17 +17,12 @@ where
     last_integral: T,
     last_output: Option<T>,
     anti_windup: Option<(T, T)>,
+    limit_stats: LimitStats,
 }
 
 impl<T> PID<T>
 where
-    T: Zero
-        + Copy
-        + Mul<f64, Output = T>
-        + Mul<Output = T>
-        + Sub<Output = T>
-        + Div<f64, Output = T>
-        + PartialOrd,
+    T: Float,
 {
     pub fn new(kp: T, ki: T, kd: T) -> Self {
         PID {
@@ -41,6 +33,7 @@ where
             last_integral: T::zero(),
             last_output: None,
             anti_windup: None,
+            limit_stats: LimitStats::default(),
         }
     }
 
@@ -61,6 +54,11 @@ where
         &self.last_input
     }
 
+    /// `(kp, ki, kd)`.
+    pub fn gains(&self) -> (T, T, T) {
+        (self.kp, self.ki, self.kd)
+    }
+
     pub fn kp_mut(&mut self) -> &mut T {
         &mut self.kp
     }
@@ -72,37 +70,43 @@ where
     pub fn kd_mut(&mut self) -> &mut T {
         &mut self.kd
     }
+
+    /// Preloads the integral so the next output continues from `output` for
+    /// the current `error`, for bumpless transfer from another controller or
+    /// from manual mode.
+    pub fn bumpless(&mut self, output: T, error: T) {
+        if self.ki != T::zero() {
+            self.last_integral = (output - self.kp * error) / self.ki;
+        }
+        self.last_input = error;
+        self.last_output = Some(output);
+    }
 }
 
 impl<T> Block for PID<T>
 where
-    T: Zero
-        + Copy
-        + Mul<f64, Output = T>
-        + Mul<Output = T>
-        + Sub<Output = T>
-        + Div<f64, Output = T>
-        + PartialOrd,
+    T: Float,
 {
     type Input = T;
     type Output = T;
 
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
-        let dt = sim_state.dt().as_secs_f64();
+        let dt = from_f64::<T>(sim_state.dt().as_secs_f64());
         let proportional = input;
         let integral = self.last_integral + input * dt;
         let derivative = (input - self.last_input) / dt;
 
         let output = self.kp * proportional + self.ki * integral + self.kd * derivative;
-        let (output, integral) = if let Some((min, max)) = self.anti_windup {
+        let (output, integral, saturated) = if let Some((min, max)) = self.anti_windup {
             if output < min || output > max {
-                (clamp(output, min, max), self.last_integral)
+                (clamp(output, min, max), self.last_integral, true)
             } else {
-                (output, integral)
+                (output, integral, false)
             }
         } else {
-            (output, integral)
+            (output, integral, false)
         };
+        self.limit_stats.record(saturated, sim_state.dt());
 
         self.last_output = Some(output);
         self.last_input = input;
@@ -119,5 +123,15 @@ where
         self.last_input = T::zero();
         self.last_integral = T::zero();
         self.last_output = None;
+        self.limit_stats = LimitStats::default();
+    }
+}
+
+impl<T> Limited for PID<T>
+where
+    T: Float,
+{
+    fn limit_stats(&self) -> LimitStats {
+        self.limit_stats
     }
 }
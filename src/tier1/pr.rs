@@ -0,0 +1,175 @@
+use crate::signal::from_f64;
+use crate::{block::Block, prelude::SimulationState};
+use core::time::Duration;
+use num_traits::Float;
+
+/// Proportional-resonant controller, `kp + kr s / (s^2 + w^2)` in its ideal
+/// form or `kp + 2 kr wc s / (s^2 + 2 wc s + w^2)` in its damped form.
+///
+/// The resonant term has (ideally infinite) gain at `w`, so it tracks and
+/// rejects sinusoids at that frequency without steady-state error, which a
+/// PI cannot do. It is discretized with the Tustin method prewarped at `w`,
+/// so the resonance stays exactly on `w` at any sample time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PR<T>
+where
+    T: Float,
+{
+    kp: T,
+    kr: T,
+    omega: T,
+    cutoff: Option<T>,
+    dt: Duration,
+    coeffs: [T; 3],
+    z: [T; 2],
+    last_output: Option<T>,
+}
+
+impl<T> PR<T>
+where
+    T: Float,
+{
+    /// Ideal PR controller resonating at `omega` rad/s.
+    pub fn new(kp: T, kr: T, omega: T) -> Self {
+        assert!(omega > T::zero(), "Resonant frequency must be positive");
+
+        Self {
+            kp,
+            kr,
+            omega,
+            cutoff: None,
+            dt: Duration::ZERO,
+            coeffs: [T::zero(); 3],
+            z: [T::zero(); 2],
+            last_output: None,
+        }
+    }
+
+    /// Damped form with bandwidth `cutoff` rad/s around the resonance, which
+    /// keeps a finite gain and tolerates small frequency deviations.
+    pub fn with_cutoff(mut self, cutoff: T) -> Self {
+        assert!(cutoff > T::zero(), "Resonant cutoff must be positive");
+        self.cutoff = Some(cutoff);
+        self.dt = Duration::ZERO;
+        self
+    }
+
+    pub fn omega(&self) -> T {
+        self.omega
+    }
+
+    pub fn kp_mut(&mut self) -> &mut T {
+        &mut self.kp
+    }
+
+    pub fn kr_mut(&mut self) -> &mut T {
+        self.dt = Duration::ZERO;
+        &mut self.kr
+    }
+
+    /// Resonant term as `b0 (1 - z^-2) / (1 + a1 z^-1 + a2 z^-2)`, stored as
+    /// `[b0, a1, a2]`.
+    fn discretize(&mut self, dt: Duration) {
+        let two = from_f64::<T>(2.0);
+        let w = self.omega;
+        let k = w / (w * from_f64(dt.as_secs_f64()) / two).tan();
+        let (gain, damping) = match self.cutoff {
+            Some(wc) => (two * wc * self.kr, two * wc),
+            None => (self.kr, T::zero()),
+        };
+
+        let a0 = k * k + damping * k + w * w;
+        self.coeffs = [
+            gain * k / a0,
+            two * (w * w - k * k) / a0,
+            (k * k - damping * k + w * w) / a0,
+        ];
+        self.dt = dt;
+    }
+}
+
+impl<T> Block for PR<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if sim_state.dt() != self.dt {
+            self.discretize(sim_state.dt());
+        }
+
+        let [b0, a1, a2] = self.coeffs;
+        let resonant = input * b0 + self.z[0];
+        self.z[0] = self.z[1] - resonant * a1;
+        self.z[1] = -input * b0 - resonant * a2;
+
+        let output = input * self.kp + resonant;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.z = [T::zero(); 2];
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::f64::consts::PI;
+
+    /// Peak tracking error over the last grid period of an RL current loop
+    /// following a 50 Hz reference.
+    fn tracking_error(controller: &mut dyn Block<Input = f64, Output = f64>) -> f64 {
+        let omega = 2.0 * PI * 50.0;
+        // L = 5 mH, R = 0.5 Ohm.
+        let mut plant = SsFixed::<1, RK4>::new([[-100.0]], [200.0], [1.0], 0.0);
+
+        let mut y = 0.0;
+        let mut peak = 0.0f64;
+        for sim_state in Simulation::new(1e-4, 0.4) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let r = libm::sin(omega * t);
+            let u = controller.output((r - y).as_signal(sim_state));
+            y = (u >> plant.as_block()).value;
+            if t > 0.38 {
+                peak = peak.max((libm::sin(omega * (t + 1e-4)) - y).abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn test_pr_tracks_sinusoid() {
+        let omega = 2.0 * PI * 50.0;
+        let mut pr = PR::new(5.0, 500.0, omega);
+        let mut damped = PR::new(5.0, 500.0, omega).with_cutoff(5.0);
+        let mut pi = PID::new(5.0, 500.0, 0.0);
+
+        let pi_error = tracking_error(&mut pi);
+        assert!(pi_error > 0.1);
+        assert!(tracking_error(&mut pr) < 1e-3);
+        assert!(tracking_error(&mut damped) < 0.05 * pi_error);
+    }
+
+    #[test]
+    fn test_pr_f32_matches_f64() {
+        let omega = 2.0 * PI * 50.0;
+        let mut pr = PR::new(5.0, 500.0, omega);
+        let mut pr32 = PR::new(5.0f32, 500.0, omega as f32);
+
+        for sim_state in Simulation::new(1e-4, 0.02) {
+            let e = libm::sin(omega * sim_state.sim_time().as_secs_f64());
+            let y = pr.block(e, sim_state);
+            let y32 = pr32.block(e as f32, sim_state);
+            assert!((y - y32 as f64).abs() < 1e-2 * (1.0 + y.abs()));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::signal::{from_f64, to_f64};
+use crate::{block::Block, prelude::SimulationState};
+use core::time::Duration;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PwmCarrier {
+    #[default]
+    Sawtooth,
+    Triangle,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pwm<T>
+where
+    T: Float,
+{
+    switching_freq: T,
+    carrier: PwmCarrier,
+    last_output: Option<T>,
+}
+
+impl<T> Pwm<T>
+where
+    T: Float,
+{
+    pub fn new(switching_freq: T) -> Self {
+        assert!(
+            switching_freq > T::zero(),
+            "Switching frequency must be greater than zero"
+        );
+
+        Self {
+            switching_freq,
+            carrier: PwmCarrier::default(),
+            last_output: None,
+        }
+    }
+
+    pub fn with_carrier(mut self, carrier: PwmCarrier) -> Self {
+        self.carrier = carrier;
+        self
+    }
+
+    pub fn switching_freq(&self) -> T {
+        self.switching_freq
+    }
+
+    /// Carrier at `t`, with the phase computed in `f64` so it stays exact
+    /// over long runs.
+    fn carrier_value(&self, t: f64) -> T {
+        let phase = t * to_f64(self.switching_freq);
+        let phase = phase - libm::floor(phase);
+
+        let carrier = match self.carrier {
+            PwmCarrier::Sawtooth => phase,
+            PwmCarrier::Triangle => 1.0 - libm::fabs(2.0 * phase - 1.0),
+        };
+        from_f64(carrier)
+    }
+}
+
+impl<T> Block for Pwm<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let carrier = self.carrier_value(sim_state.sim_time().as_secs_f64());
+        let output = if input > carrier { T::one() } else { T::zero() };
+
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+
+    fn name(&self) -> &str {
+        "PWM"
+    }
+
+    /// Resolves the duty cycle in 1% steps.
+    fn preferred_sample_time(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f64(0.01 / to_f64(self.switching_freq)))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::time::Duration;
+
+    #[test]
+    fn test_block_metadata() {
+        let mut pwm = Pwm::new(1e3);
+        let block = pwm.as_block();
+
+        assert_eq!(block.name(), "PWM");
+        assert_eq!(block.ports().input, "f64");
+        assert_eq!(block.ports().output, "f64");
+        assert_eq!(
+            block.preferred_sample_time(),
+            Some(Duration::from_secs_f64(1e-5))
+        );
+
+        let mut pwm = Pwm::new(1e3f32);
+        assert_eq!(pwm.as_block().ports().input, "f32");
+
+        let gain = Gain::new(2.0f32);
+        assert!(gain.name().ends_with("Gain<f32>"));
+        assert_eq!(gain.preferred_sample_time(), None);
+    }
+}
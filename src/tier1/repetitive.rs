@@ -0,0 +1,187 @@
+use crate::signal::from_f64;
+use crate::{block::Block, prelude::SimulationState};
+use alloc::collections::VecDeque;
+use core::time::Duration;
+use num_traits::Float;
+
+/// Plug-in repetitive controller, an internal model of every harmonic of a
+/// signal with period `T`. It learns the periodic part of the error over
+/// successive periods and rejects it, usually added to the output of an
+/// existing stabilizing controller.
+///
+/// In discrete time, with `N = T / dt` samples per period,
+/// `m[k] = Q(m[k - N] + e[k - N + lead])` and the output is `gain * m[k]`,
+/// where `Q` is the zero-phase filter `q1 z^-1 + q0 + q1 z` (`q0 + 2 q1 = 1`)
+/// that bounds the learning bandwidth and keeps the loop stable, and `lead`
+/// compensates for the phase lag of the closed loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repetitive<T>
+where
+    T: Float,
+{
+    period: Duration,
+    gain: T,
+    q0: T,
+    lead: usize,
+    dt: Duration,
+    memory: VecDeque<T>,
+    errors: VecDeque<T>,
+    last_output: Option<T>,
+}
+
+impl<T> Repetitive<T>
+where
+    T: Float,
+{
+    pub fn new(period: Duration, gain: T) -> Self {
+        assert!(
+            !period.is_zero(),
+            "Repetitive period must be greater than zero"
+        );
+
+        Self {
+            period,
+            gain,
+            q0: from_f64(0.5),
+            lead: 0,
+            dt: Duration::ZERO,
+            memory: VecDeque::new(),
+            errors: VecDeque::new(),
+            last_output: None,
+        }
+    }
+
+    /// Center tap of `Q`, in `[1/3, 1]`. Lower values filter more and learn
+    /// fewer harmonics; 1 is a pure delay with no filtering. Defaults to 0.5.
+    pub fn with_q_filter(mut self, q0: T) -> Self {
+        assert!(
+            (from_f64(1.0 / 3.0)..=T::one()).contains(&q0),
+            "Q filter center tap must be in [1/3, 1]"
+        );
+        self.q0 = q0;
+        self
+    }
+
+    /// Phase lead, in samples, applied to the learned error.
+    pub fn with_lead(mut self, samples: usize) -> Self {
+        self.lead = samples;
+        self.dt = Duration::ZERO;
+        self
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    fn restart(&mut self, dt: Duration) {
+        let samples = libm::round(self.period.as_secs_f64() / dt.as_secs_f64()) as usize;
+        assert!(
+            samples >= 2,
+            "Repetitive period must span at least 2 samples"
+        );
+        assert!(
+            self.lead < samples,
+            "Repetitive lead must be shorter than the period"
+        );
+
+        self.dt = dt;
+        // m[k - N - 1] ..= m[k - 1] and e[k - N - 1] ..= e[k].
+        self.memory = VecDeque::from(alloc::vec![T::zero(); samples + 1]);
+        self.errors = VecDeque::from(alloc::vec![T::zero(); samples + 2]);
+    }
+}
+
+impl<T> Block for Repetitive<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if sim_state.dt() != self.dt {
+            self.restart(sim_state.dt());
+        }
+
+        self.errors.pop_front();
+        self.errors.push_back(input);
+
+        let q1 = (T::one() - self.q0) / from_f64(2.0);
+        let tap = |j: usize| self.memory[j] + self.errors[j + self.lead];
+        let m = tap(0) * q1 + tap(1) * self.q0 + tap(2) * q1;
+
+        self.memory.pop_front();
+        self.memory.push_back(m);
+
+        let output = m * self.gain;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.dt = Duration::ZERO;
+        self.memory.clear();
+        self.errors.clear();
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::{f64::consts::PI, time::Duration};
+
+    /// Peak error over the last period of a P-controlled first-order plant
+    /// with a 2 Hz periodic output disturbance.
+    fn residual_error(repetitive: Option<&mut Repetitive<f64>>) -> f64 {
+        let mut plant = SsFixed::<1, RK4>::new([[-10.0]], [10.0], [1.0], 0.0);
+        let mut controller = Gain::new(2.0);
+        let mut repetitive = repetitive;
+
+        let mut y = 0.0;
+        let mut peak = 0.0f64;
+        for sim_state in Simulation::new(0.001, 20.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let e = 0.0 - y;
+            let mut u = (e.as_signal(sim_state) >> controller.as_block()).value;
+            if let Some(repetitive) = repetitive.as_deref_mut() {
+                u += (e.as_signal(sim_state) >> repetitive.as_block()).value;
+            }
+            let d = libm::sin(4.0 * PI * t) + 0.5 * libm::sin(12.0 * PI * t);
+            y = (u.as_signal(sim_state) >> plant.as_block()).value + d;
+            if t > 19.5 {
+                peak = peak.max(y.abs());
+            }
+        }
+        peak
+    }
+
+    #[test]
+    fn test_repetitive_rejects_periodic_disturbance() {
+        let mut repetitive = Repetitive::new(Duration::from_millis(500), 1.0);
+
+        let without = residual_error(None);
+        let with = residual_error(Some(&mut repetitive));
+        assert!(with < 0.1 * without);
+
+        repetitive.reset();
+        assert_eq!(repetitive.last_output(), None);
+    }
+
+    #[test]
+    fn test_repetitive_f32_repeats_last_period() {
+        let mut repetitive = Repetitive::new(Duration::from_millis(4), 2.0f32).with_q_filter(1.0);
+
+        let outputs = Simulation::new(0.001, 0.012)
+            .map(|sim_state| repetitive.block(1.0f32, sim_state))
+            .collect::<std::vec::Vec<_>>();
+
+        assert_eq!(outputs[..4], [0.0; 4]);
+        assert_eq!(outputs[4..8], [2.0; 4]);
+        assert_eq!(outputs[8..], [4.0; 4]);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::block::Block;
-use crate::prelude::SimulationState;
+use crate::prelude::{LimitStats, Limited, SimulationState};
 use num_traits::Float;
 
 #[derive(Debug, Clone)]
@@ -9,6 +9,7 @@ where
 {
     min: T,
     max: T,
+    limit_stats: LimitStats,
     last_output: Option<T>,
 }
 
@@ -20,6 +21,7 @@ where
         Self {
             min,
             max,
+            limit_stats: LimitStats::default(),
             last_output: None,
         }
     }
@@ -32,8 +34,10 @@ where
     type Input = T;
     type Output = T;
 
-    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
         let saturated_value = input.clamp(self.min, self.max);
+        self.limit_stats
+            .record(saturated_value != input, sim_state.dt());
         self.last_output = Some(saturated_value);
         saturated_value
     }
@@ -43,6 +47,16 @@ where
     }
 
     fn reset(&mut self) {
+        self.limit_stats = LimitStats::default();
         self.last_output = None;
     }
 }
+
+impl<T> Limited for Saturation<T>
+where
+    T: Float,
+{
+    fn limit_stats(&self) -> LimitStats {
+        self.limit_stats
+    }
+}
@@ -0,0 +1,191 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use num_traits::Float;
+
+/// Affine map between a raw range and an engineering range, e.g. ADC counts
+/// to volts or a current command to DAC codes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scale<T>
+where
+    T: Float,
+{
+    raw: (T, T),
+    eng: (T, T),
+    clamp: bool,
+    last_output: Option<T>,
+}
+
+/// Maps a physical range onto `[0, 1]`, or `[-1, 1]` when symmetric, as
+/// expected by normalized controllers and learned models.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalize<T>
+where
+    T: Float,
+{
+    scale: Scale<T>,
+}
+
+impl<T> Scale<T>
+where
+    T: Float,
+{
+    pub fn from_range(raw: (T, T), eng: (T, T)) -> Self {
+        assert!(raw.0 != raw.1, "Raw range must not be empty");
+
+        Self {
+            raw,
+            eng,
+            clamp: false,
+            last_output: None,
+        }
+    }
+
+    /// `bits`-wide converter codes to `eng`.
+    pub fn from_bits(bits: u8, eng: (T, T)) -> Self {
+        assert!(
+            (1..=32).contains(&bits),
+            "Resolution must be between 1 and 32 bits"
+        );
+
+        let full_scale = T::from((1u64 << bits) - 1).unwrap();
+        Self::from_range((T::zero(), full_scale), eng)
+    }
+
+    /// Limits the output to the engineering range.
+    pub fn with_clamp(mut self) -> Self {
+        self.clamp = true;
+        self
+    }
+
+    /// Map from the engineering range back to the raw range.
+    pub fn inverse(&self) -> Self {
+        Self {
+            raw: self.eng,
+            eng: self.raw,
+            clamp: self.clamp,
+            last_output: None,
+        }
+    }
+
+    pub fn raw_range(&self) -> (T, T) {
+        self.raw
+    }
+
+    pub fn eng_range(&self) -> (T, T) {
+        self.eng
+    }
+
+    pub fn apply(&self, raw: T) -> T {
+        let ratio = (raw - self.raw.0) / (self.raw.1 - self.raw.0);
+        let output = self.eng.0 + ratio * (self.eng.1 - self.eng.0);
+
+        if self.clamp {
+            let (low, high) = (self.eng.0.min(self.eng.1), self.eng.0.max(self.eng.1));
+            output.max(low).min(high)
+        } else {
+            output
+        }
+    }
+}
+
+impl<T> Normalize<T>
+where
+    T: Float,
+{
+    /// `[min, max]` to `[0, 1]`.
+    pub fn new(min: T, max: T) -> Self {
+        Self {
+            scale: Scale::from_range((min, max), (T::zero(), T::one())),
+        }
+    }
+
+    /// `[min, max]` to `[-1, 1]`.
+    pub fn symmetric(min: T, max: T) -> Self {
+        Self {
+            scale: Scale::from_range((min, max), (-T::one(), T::one())),
+        }
+    }
+
+    pub fn with_clamp(mut self) -> Self {
+        self.scale = self.scale.with_clamp();
+        self
+    }
+
+    pub fn normalize(&self, value: T) -> T {
+        self.scale.apply(value)
+    }
+
+    pub fn denormalize(&self, value: T) -> T {
+        self.scale.inverse().apply(value)
+    }
+
+    /// Block mapping normalized values back to the physical range.
+    pub fn inverse(&self) -> Scale<T> {
+        self.scale.inverse()
+    }
+}
+
+impl<T> Block for Scale<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = self.apply(input);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<T> Block for Normalize<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.scale.block(input, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.scale.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.scale.reset();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_scale_round_trip() {
+        // 4-20 mA transmitter on a 12-bit ADC measuring 0-150 degC.
+        let counts_to_ma = Scale::<f64>::from_bits(12, (0.0, 25.0));
+        let ma_to_deg = Scale::<f64>::from_range((4.0, 20.0), (0.0, 150.0)).with_clamp();
+        let counts = 4095.0 * 12.0 / 25.0;
+
+        assert!((ma_to_deg.apply(counts_to_ma.apply(counts)) - 75.0).abs() < 1e-9);
+        assert_eq!(ma_to_deg.apply(2.0), 0.0);
+        assert!((ma_to_deg.inverse().apply(75.0) - 12.0).abs() < 1e-12);
+
+        let mut normalize = Normalize::symmetric(-24.0, 24.0).with_clamp();
+        let sim_state = Simulation::new(0.1, 1.0).next().unwrap();
+        assert_eq!(normalize.block(12.0, sim_state), 0.5);
+        assert_eq!(normalize.block(30.0, sim_state), 1.0);
+        assert_eq!(normalize.denormalize(-0.25), -6.0);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{block::Block, prelude::SimulationState};
+use core::marker::PhantomData;
+
+/// Models whose internal state vector can be read from outside.
+pub trait HasState<T> {
+    /// Number of states.
+    fn order(&self) -> usize;
+
+    /// Current value of the `index`-th state.
+    fn state_at(&self, index: usize) -> T;
+
+    /// Variant of this model emitting its full state vector each step
+    /// instead of the output.
+    fn state_output<const N: usize>(self) -> StateOutput<Self, T, N>
+    where
+        Self: Sized + Block,
+    {
+        StateOutput::new(self)
+    }
+}
+
+/// Wraps a model and emits its `N` states after each step, so they can be
+/// logged or plotted. The model output is still available via `inner()`.
+#[derive(Debug, Clone)]
+pub struct StateOutput<B, T, const N: usize> {
+    inner: B,
+    last_output: Option<[T; N]>,
+    _marker: PhantomData<T>,
+}
+
+impl<B, T, const N: usize> StateOutput<B, T, N>
+where
+    B: Block + HasState<T>,
+{
+    pub fn new(inner: B) -> Self {
+        assert_eq!(inner.order(), N, "Model must have {} states", N);
+        Self {
+            inner,
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B, T, const N: usize> Block for StateOutput<B, T, N>
+where
+    B: Block + HasState<T>,
+    T: Copy,
+{
+    type Input = B::Input;
+    type Output = [T; N];
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        crate::block::run(&mut self.inner, input, sim_state);
+        let output = core::array::from_fn(|i| self.inner.state_at(i));
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.last_output = None;
+    }
+
+    fn preferred_sample_time(&self) -> Option<core::time::Duration> {
+        self.inner.preferred_sample_time()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_state_output_emits_ss_states() {
+        let a = mat![[0.0, 1.0], [-2.0, -3.0]];
+        let b = mat![[0.0], [1.0]];
+        let c = mat![[1.0, 0.0]];
+        let mut plant = SS::<RK4, f64>::new(a, b, c, 0.0).state_output::<2>();
+
+        let mut last = [0.0; 2];
+        for sim_state in Simulation::new(0.01, 10.0) {
+            last = (1.0.as_signal(sim_state) >> plant.as_block()).value;
+            assert_eq!(plant.inner().last_output(), Some(last[0]));
+        }
+
+        // Unit step on 1 / (s^2 + 3s + 2) settles at x1 = 0.5, x2 = 0.
+        assert!((last[0] - 0.5).abs() < 1e-3);
+        assert!(last[1].abs() < 1e-3);
+
+        plant.reset();
+        assert_eq!(plant.last_output(), None);
+        assert_eq!(plant.inner().state_at(0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Model must have 3 states")]
+    fn test_state_output_checks_order() {
+        let _ =
+            SsFixed::<2, Euler>::new([[0.0; 2]; 2], [0.0; 2], [0.0; 2], 0.0).state_output::<3>();
+    }
+}
@@ -0,0 +1,130 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::marker::PhantomData;
+use num_traits::Float;
+
+pub struct StaticFn<I, O, F>
+where
+    O: Clone,
+    F: FnMut(I) -> O,
+{
+    function: F,
+    last_output: Option<O>,
+    _marker: PhantomData<I>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolyFn<T, const N: usize>
+where
+    T: Float,
+{
+    coeff: [T; N],
+    last_output: Option<T>,
+}
+
+impl<I, O, F> StaticFn<I, O, F>
+where
+    O: Clone,
+    F: FnMut(I) -> O,
+{
+    pub fn new(function: F) -> Self {
+        Self {
+            function,
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize> PolyFn<T, N>
+where
+    T: Float,
+{
+    pub fn new(coeff: [T; N]) -> Self {
+        assert!(N > 0, "PolyFn must have at least one coefficient");
+
+        Self {
+            coeff,
+            last_output: None,
+        }
+    }
+
+    pub fn coeff(&self) -> &[T; N] {
+        &self.coeff
+    }
+
+    pub fn eval(&self, x: T) -> T {
+        self.coeff
+            .iter()
+            .fold(T::zero(), |acc, &coeff| acc * x + coeff)
+    }
+}
+
+impl<I, O, F> Block for StaticFn<I, O, F>
+where
+    O: Clone,
+    F: FnMut(I) -> O,
+{
+    type Input = I;
+    type Output = O;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = (self.function)(input);
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+impl<T, const N: usize> Block for PolyFn<T, N>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let output = self.eval(input);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_static_fn_applies_closure() {
+        let sim_state = Simulation::new(0.1, 0.1).next().unwrap();
+        let mut valve = StaticFn::new(|x: f64| x.sqrt());
+
+        let output = 4.0.as_signal(sim_state) >> valve.as_block();
+
+        assert_eq!(output.value, 2.0);
+        assert_eq!(valve.last_output(), Some(2.0));
+    }
+
+    #[test]
+    fn test_poly_fn_uses_highest_degree_first() {
+        let poly = PolyFn::new([2.0, -3.0, 1.0]);
+
+        assert_eq!(poly.eval(0.0), 1.0);
+        assert_eq!(poly.eval(2.0), 3.0);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    block::Block,
+    continuous::{
+        solver::{FixedSolver, FixedStateEstimation},
+        static_ss::StaticSolver,
+    },
+    prelude::{HasState, SimulationState},
+};
+use core::{fmt::Debug, marker::PhantomData};
+use nalgebra::{RealField, RowSVector, SMatrix, SVector};
+use num_traits::Float;
+
+/// Luenberger observer of order `N` on nalgebra static matrices, the
+/// counterpart of [`Observer`](crate::prelude::Observer) without an
+/// allocator.
+///
+/// Takes `(control_input, measured_output)` and outputs the estimated
+/// output and state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObserverStatic<const N: usize, I, T = f64>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    a: SMatrix<T, N, N>,
+    b: SVector<T, N>,
+    c: RowSVector<T, N>,
+    d: T,
+    l: SVector<T, N>,
+    state: SVector<T, N>,
+    initial_state: SVector<T, N>,
+    current_input: (T, T),
+    last_output: Option<(T, SVector<T, N>)>,
+    _marker: PhantomData<I>,
+}
+
+impl<const N: usize, I, T> ObserverStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    /// Observer of the model `(A, B, C, D)` with the column gain `L`.
+    pub fn new(
+        a: SMatrix<T, N, N>,
+        b: SVector<T, N>,
+        c: RowSVector<T, N>,
+        d: T,
+        l: SVector<T, N>,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            d,
+            l,
+            state: SVector::zeros(),
+            initial_state: SVector::zeros(),
+            current_input: (
+                <T as num_traits::Zero>::zero(),
+                <T as num_traits::Zero>::zero(),
+            ),
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_initial_state(mut self, initial_state: SVector<T, N>) -> Self {
+        self.initial_state = initial_state;
+        self.state = initial_state;
+        self
+    }
+
+    pub fn state(&self) -> &SVector<T, N> {
+        &self.state
+    }
+
+    fn output_of(&self, state: &SVector<T, N>, control_input: T) -> T {
+        self.c.dot(&state.transpose()) + self.d * control_input
+    }
+}
+
+impl<const N: usize, I, T> FixedStateEstimation<T, N> for ObserverStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    fn estimate(&self, state: &[T; N]) -> [T; N] {
+        let (u, y) = self.current_input;
+        let state = SVector::<T, N>::from(*state);
+        let y_err = y - self.output_of(&state, u);
+        (self.a * state + self.b * u + self.l * y_err).into()
+    }
+}
+
+impl<const N: usize, I, T> Block for ObserverStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    type Input = (T, T);
+    type Output = (T, SVector<T, N>);
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.current_input = input;
+        self.state = I::integrate_static(self.state, sim_state.dt(), self);
+
+        let output = (self.output_of(&self.state, input.0), self.state);
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state = self.initial_state;
+        self.current_input = (
+            <T as num_traits::Zero>::zero(),
+            <T as num_traits::Zero>::zero(),
+        );
+        self.last_output = None;
+    }
+}
+
+impl<const N: usize, I, T> HasState<T> for ObserverStatic<N, I, T>
+where
+    T: Float + RealField,
+    I: FixedSolver<T> + Debug,
+{
+    fn order(&self) -> usize {
+        N
+    }
+
+    fn state_at(&self, index: usize) -> T {
+        self.state[index]
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use nalgebra::{Matrix2, RowVector2, Vector2};
+
+    #[test]
+    fn test_static_observer_converges() {
+        let (a, b, c) = (
+            Matrix2::new(0.0, 1.0, -2.0, -3.0),
+            Vector2::new(0.0, 1.0),
+            RowVector2::new(1.0, 0.0),
+        );
+        let mut plant =
+            SsStatic::<2, RK4>::new(a, b, c, 0.5).with_initial_state(Vector2::new(1.0, -1.0));
+        let mut observer = ObserverStatic::<2, RK4>::new(a, b, c, 0.5, Vector2::new(10.0, 20.0));
+
+        let mut estimate = (0.0, Vector2::zeros());
+        for sim_state in Simulation::new(0.01, 5.0) {
+            let y = plant.block(0.0, sim_state);
+            estimate = observer.block((0.0, y), sim_state);
+        }
+
+        // Down from an initial error of sqrt(2), up to the sample-and-hold of y.
+        assert!((estimate.1 - plant.state()).norm() < 1e-4);
+        assert!((estimate.0 - plant.last_output().unwrap()).abs() < 1e-4);
+    }
+}
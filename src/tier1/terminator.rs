@@ -0,0 +1,51 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::marker::PhantomData;
+
+/// Sink that consumes any signal, used to terminate unused outputs explicitly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Terminator<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<T> Terminator<T> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for Terminator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Block for Terminator<T> {
+    type Input = T;
+    type Output = ();
+
+    fn block(&mut self, _input: Self::Input, _sim_state: SimulationState) -> Self::Output {}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ground_into_terminator() {
+        let mut ground = Ground::<f64>::new();
+        let mut terminator = Terminator::default();
+        let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(Euler);
+
+        for sim_state in Simulation::new(0.1, 1.0) {
+            let u = sim_state >> ground.as_block();
+            assert_eq!(u.value, 0.0);
+
+            let y = u >> plant.as_block();
+            let done = y >> terminator.as_block();
+            assert_eq!(done.value, ());
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{block::Block, prelude::SimulationState};
+use num_traits::Float;
+
+/// Splits a torque command between two motors driving the same gear, adding
+/// opposite bias torques so each motor stays pressed against its own flank
+/// of the backlash. For small commands the motors preload the train; the bias
+/// fades out as `|u|` reaches `release`, so both motors then drive together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TorqueBias<T>
+where
+    T: Float,
+{
+    bias: T,
+    release: Option<T>,
+    last_output: Option<[T; 2]>,
+}
+
+impl<T> TorqueBias<T>
+where
+    T: Float,
+{
+    pub fn new(bias: T) -> Self {
+        assert!(bias >= T::zero(), "Torque bias must not be negative");
+
+        Self {
+            bias,
+            release: None,
+            last_output: None,
+        }
+    }
+
+    /// Command magnitude at which the bias has faded out completely. Without
+    /// it the bias is constant.
+    pub fn with_release(mut self, release: T) -> Self {
+        assert!(
+            release > T::zero(),
+            "Release torque must be greater than zero"
+        );
+        self.release = Some(release);
+        self
+    }
+
+    /// Bias applied for the command `u`.
+    pub fn bias(&self, u: T) -> T {
+        match self.release {
+            Some(release) => self.bias * (T::one() - u.abs() / release).max(T::zero()),
+            None => self.bias,
+        }
+    }
+}
+
+impl<T> Block for TorqueBias<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = [T; 2];
+
+    fn block(&mut self, input: Self::Input, _sim_state: SimulationState) -> Self::Output {
+        let bias = self.bias(input);
+        let half = input / (T::one() + T::one());
+        let output = [half + bias, half - bias];
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.last_output = None;
+    }
+}
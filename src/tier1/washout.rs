@@ -0,0 +1,78 @@
+use crate::block::Block;
+use crate::prelude::{SS, SimulationState, Solver, Tf};
+use core::fmt::Debug;
+use core::ops::AddAssign;
+use faer::traits::ComplexField;
+use num_traits::Float;
+
+#[derive(Debug, Clone)]
+pub struct Washout<I, T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+    I: Solver<T> + Debug,
+{
+    tau: T,
+    ss: SS<I, T>,
+}
+
+impl<I, T> Washout<I, T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+    I: Solver<T> + Debug,
+{
+    pub fn new(tau: T, integrator: I) -> Self {
+        assert!(
+            tau > T::zero(),
+            "Washout time constant must be greater than zero"
+        );
+
+        let tf = Tf::new_unchecked(&[tau, T::zero()], &[tau, T::one()]);
+
+        Self {
+            tau,
+            ss: tf.to_ss_controllable(integrator),
+        }
+    }
+
+    pub fn tau(&self) -> T {
+        self.tau
+    }
+}
+
+impl<I, T> Block for Washout<I, T>
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+    I: Solver<T> + Debug,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.ss.block(input, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.ss.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.ss.reset();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_washout_rejects_constant_input() {
+        let mut washout = Washout::new(0.5, RK4);
+
+        let last = Simulation::new(1e-3, 10.0)
+            .map(|sim_state| washout.block(1.0, sim_state))
+            .last()
+            .unwrap();
+
+        assert!(last.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,270 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use core::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TripCause {
+    /// The step came later than the allowed period, i.e. the loop stalled or
+    /// overran.
+    Overrun,
+    /// The fault flag of the input was raised.
+    Fault,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchdogTrip {
+    pub cause: TripCause,
+    pub sim_time: Duration,
+}
+
+/// Last block before an actuator. Passes the command through while the loop
+/// is healthy and forces `safe_output`, e.g. zero torque, once a step
+/// arrives with `dt` beyond `max_period` or the input fault flag is set.
+///
+/// The input is the command and the fault flag. A trip latches until
+/// [`Watchdog::clear`] unless [`Watchdog::with_auto_recover`] is used.
+///
+/// [`EmbeddedExecutor`](crate::prelude::EmbeddedExecutor) always passes the
+/// nominal period as `dt`, so under it the watchdog needs the executor clock
+/// through [`Watchdog::with_clock`] to see overruns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watchdog<T, C = fn() -> Duration>
+where
+    T: Clone,
+    C: FnMut() -> Duration,
+{
+    safe_output: T,
+    max_period: Duration,
+    clock: Option<C>,
+    last_tick: Option<Duration>,
+    recover_after: Option<u32>,
+    healthy_steps: u32,
+    trip: Option<WatchdogTrip>,
+    trips: u32,
+    last_output: Option<T>,
+}
+
+impl<T> Watchdog<T>
+where
+    T: Clone,
+{
+    pub fn new(safe_output: T, max_period: Duration) -> Self {
+        Self {
+            safe_output,
+            max_period,
+            clock: None,
+            last_tick: None,
+            recover_after: None,
+            healthy_steps: 0,
+            trip: None,
+            trips: 0,
+            last_output: None,
+        }
+    }
+
+    /// Measures the period on `clock`, a monotonic time like the one given
+    /// to an [`EmbeddedExecutor`](crate::prelude::EmbeddedExecutor), instead
+    /// of trusting `dt`. A step that comes more than `max_period` after the
+    /// previous one trips, whether the loop started late or the previous
+    /// step ran long.
+    pub fn with_clock<D>(self, clock: D) -> Watchdog<T, D>
+    where
+        D: FnMut() -> Duration,
+    {
+        Watchdog {
+            safe_output: self.safe_output,
+            max_period: self.max_period,
+            clock: Some(clock),
+            last_tick: None,
+            recover_after: self.recover_after,
+            healthy_steps: self.healthy_steps,
+            trip: self.trip,
+            trips: self.trips,
+            last_output: self.last_output,
+        }
+    }
+}
+
+impl<T, C> Watchdog<T, C>
+where
+    T: Clone,
+    C: FnMut() -> Duration,
+{
+    /// Releases the trip after `steps` consecutive healthy steps.
+    pub fn with_auto_recover(mut self, steps: u32) -> Self {
+        self.recover_after = Some(steps);
+        self
+    }
+
+    /// Active trip, if the safe output is being forced.
+    pub fn trip(&self) -> Option<WatchdogTrip> {
+        self.trip
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.trip.is_some()
+    }
+
+    /// Number of times the watchdog tripped since the last reset.
+    pub fn trips(&self) -> u32 {
+        self.trips
+    }
+
+    /// Releases a latched trip; the next healthy step passes through.
+    pub fn clear(&mut self) {
+        self.trip = None;
+        self.healthy_steps = 0;
+    }
+
+    /// Time since the previous step, on the clock when there is one.
+    fn period(&mut self, sim_state: SimulationState) -> Duration {
+        let Some(clock) = &mut self.clock else {
+            return sim_state.dt();
+        };
+
+        let now = clock();
+        let period = self
+            .last_tick
+            .map_or(Duration::ZERO, |last| now.saturating_sub(last));
+        self.last_tick = Some(now);
+        period
+    }
+}
+
+impl<T, C> Block for Watchdog<T, C>
+where
+    T: Clone,
+    C: FnMut() -> Duration,
+{
+    type Input = (T, bool);
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (command, fault) = input;
+        let period = self.period(sim_state);
+
+        let cause = if fault {
+            Some(TripCause::Fault)
+        } else if period > self.max_period {
+            Some(TripCause::Overrun)
+        } else {
+            None
+        };
+
+        match cause {
+            Some(cause) => {
+                if self.trip.is_none() {
+                    self.trips += 1;
+                }
+                self.trip = Some(WatchdogTrip {
+                    cause,
+                    sim_time: sim_state.sim_time(),
+                });
+                self.healthy_steps = 0;
+            }
+            None if self.trip.is_some() => {
+                self.healthy_steps += 1;
+                if self
+                    .recover_after
+                    .is_some_and(|steps| self.healthy_steps > steps)
+                {
+                    self.clear();
+                }
+            }
+            None => {}
+        }
+
+        let output = if self.trip.is_some() {
+            self.safe_output.clone()
+        } else {
+            command
+        };
+
+        self.last_output = Some(output.clone());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output.clone()
+    }
+
+    fn reset(&mut self) {
+        self.last_tick = None;
+        self.healthy_steps = 0;
+        self.trip = None;
+        self.trips = 0;
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use core::{cell::Cell, time::Duration};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_watchdog_forces_safe_output_on_overrun() {
+        let mut watchdog = Watchdog::new(0.0, Duration::from_millis(2)).with_auto_recover(3);
+        let ticks = [1u64, 1, 1, 5, 1, 1, 1, 1, 1];
+        let mut ticks = ticks.into_iter();
+        let simulation = ExternalSimulation::new(|| ticks.next().map(Duration::from_millis));
+
+        let outputs = simulation
+            .map(|sim_state| (1.0, false).as_signal(sim_state) >> watchdog.as_block())
+            .map(|signal| signal.value)
+            .collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(outputs, [1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(watchdog.trips(), 1);
+        assert!(!watchdog.is_tripped());
+    }
+
+    #[test]
+    fn test_watchdog_latches_fault() {
+        let mut watchdog = Watchdog::new([0.0; 2], Duration::from_millis(10));
+
+        for (k, sim_state) in Simulation::new(0.001, 1.0).take(10).enumerate() {
+            let output = ([3.0, -3.0], k == 4).as_signal(sim_state) >> watchdog.as_block();
+            assert_eq!(output.value[0] == 0.0, k >= 4);
+        }
+
+        let trip = watchdog.trip().unwrap();
+        assert_eq!(trip.cause, TripCause::Fault);
+        assert!((trip.sim_time.as_secs_f64() - 0.005).abs() < 1e-6);
+
+        watchdog.clear();
+        let sim_state = Simulation::new(0.001, 1.0).next().unwrap();
+        assert_eq!(watchdog.block(([3.0, -3.0], false), sim_state), [3.0, -3.0]);
+    }
+
+    #[test]
+    fn test_watchdog_sees_executor_overrun_through_clock() {
+        let now = Rc::new(Cell::new(Duration::ZERO));
+        let (executor_clock, watchdog_clock) = (now.clone(), now.clone());
+        let mut executor =
+            EmbeddedExecutor::new(Duration::from_millis(1), move || executor_clock.get());
+        let mut watchdog = Watchdog::new(0.0, Duration::from_micros(1500))
+            .with_clock(move || watchdog_clock.get());
+
+        // Paced by a 1 ms timer; step 5 takes 1.5 ms, so step 6 starts late.
+        let mut outputs = alloc::vec::Vec::new();
+        for k in 1..=8 {
+            let output = executor.step(|sim_state| {
+                let output = (1.0, false).as_signal(sim_state) >> watchdog.as_block();
+                if k == 5 {
+                    now.set(now.get() + Duration::from_micros(1500));
+                }
+                output.value
+            });
+            outputs.push(output);
+            now.set(now.get() + Duration::from_millis(1));
+        }
+
+        assert_eq!(executor.stats().overruns, 1);
+        assert_eq!(outputs, [1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+        let trip = watchdog.trip().unwrap();
+        assert_eq!(trip.cause, TripCause::Overrun);
+        assert_eq!(trip.sim_time, Duration::from_millis(6));
+    }
+}
@@ -0,0 +1,211 @@
+use crate::block::Block;
+use crate::prelude::{Delay, PID, SS, SimulationState, Solver, Tf};
+#[cfg(feature = "std")]
+use crate::prelude::{FirstOrderModel, FirstOrderModelError};
+use core::fmt::Debug;
+use core::time::Duration;
+
+/// Internal Model Control of a first order plus dead time process
+/// `k * e^(-theta s) / (tau s + 1)`.
+///
+/// The model runs alongside the plant and only the mismatch between the
+/// two is fed back. The controller `Q = (tau s + 1) / (k (lambda s + 1))`
+/// is the inverse of the invertible part of the model, the dead time being
+/// left out, detuned by the IMC filter `1 / (lambda s + 1)`. With a perfect
+/// model the loop follows `e^(-theta s) / (lambda s + 1)`. The input is
+/// `(reference, measured output)`.
+#[derive(Debug, Clone)]
+pub struct IMC<I>
+where
+    I: Solver<f64> + Debug,
+{
+    k: f64,
+    tau: f64,
+    theta: f64,
+    lambda: f64,
+    model: SS<I, f64>,
+    delay: Option<Delay<f64>>,
+    q: SS<I, f64>,
+    last_output: Option<f64>,
+}
+
+impl<I> IMC<I>
+where
+    I: Solver<f64> + Debug + Clone,
+{
+    pub fn new(k: f64, tau: f64, theta: f64, lambda: f64, integrator: I) -> Self {
+        assert!(k != 0.0, "Process gain must not be zero");
+        assert!(tau > 0.0, "Time constant must be greater than zero");
+        assert!(theta >= 0.0, "Dead time must not be negative");
+        assert!(
+            lambda > 0.0,
+            "Filter time constant must be greater than zero"
+        );
+
+        let model = Tf::new_unchecked(&[k], &[tau, 1.0]).to_ss_controllable(integrator.clone());
+        let q = Tf::new_unchecked(&[tau, 1.0], &[k * lambda, k]).to_ss_controllable(integrator);
+
+        Self {
+            k,
+            tau,
+            theta,
+            lambda,
+            model,
+            delay: (theta > 0.0).then(|| Delay::new(Duration::from_secs_f64(theta))),
+            q,
+            last_output: None,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_fopdt(
+        model: &FirstOrderModel,
+        lambda: f64,
+        integrator: I,
+    ) -> Result<Self, FirstOrderModelError> {
+        if model.theta.is_sign_negative() {
+            return Err(FirstOrderModelError::NegativeTheta(model.theta));
+        }
+
+        Ok(Self::new(
+            model.k,
+            model.tau,
+            model.theta,
+            lambda,
+            integrator,
+        ))
+    }
+
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+
+    /// Gains `(kp, ki, kd)` of the classical PID equivalent to this design,
+    /// with the dead time replaced by its first order Padé approximation:
+    /// `kc = (2 tau + theta) / (k (2 lambda + theta))`,
+    /// `ti = tau + theta / 2` and `td = tau theta / (2 tau + theta)`.
+    pub fn pid_gains(&self) -> (f64, f64, f64) {
+        let kc = (2.0 * self.tau + self.theta) / (self.k * (2.0 * self.lambda + self.theta));
+        let ti = self.tau + self.theta / 2.0;
+        let td = self.tau * self.theta / (2.0 * self.tau + self.theta);
+        (kc, kc / ti, kc * td)
+    }
+
+    /// The PID equivalent to this design, for a unity-feedback loop on the
+    /// error.
+    pub fn to_pid(&self) -> PID<f64> {
+        let (kp, ki, kd) = self.pid_gains();
+        PID::new(kp, ki, kd)
+    }
+}
+
+impl<I> Block for IMC<I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = (f64, f64);
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (reference, measured) = input;
+
+        // The model is strictly proper, so its last output is the one at
+        // this instant.
+        let disturbance = measured - self.model.last_output().unwrap_or(0.0);
+        let output = self.q.block(reference - disturbance, sim_state);
+
+        let delayed = match &mut self.delay {
+            Some(delay) => delay.block(output, sim_state),
+            None => output,
+        };
+        self.model.block(delayed, sim_state);
+
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.model.reset();
+        if let Some(delay) = &mut self.delay {
+            delay.reset();
+        }
+        self.q.reset();
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier2::IMC;
+    use core::time::Duration;
+
+    #[test]
+    fn test_imc_follows_filter_with_perfect_model() {
+        let (k, tau, theta, lambda) = (2.0, 1.0, 0.5, 0.4);
+        let mut imc = IMC::new(k, tau, theta, lambda, RK4);
+        let mut plant = Tf::new(&[k], &[tau, 1.0]).to_ss_controllable(RK4);
+        let mut delay = Delay::<f64>::new(Duration::from_secs_f64(theta));
+
+        let mut y = 0.0;
+        for sim_state in Simulation::new(0.001, 5.0) {
+            let t = sim_state.sim_time().as_secs_f64();
+            let u = (1.0, y).as_signal(sim_state) >> imc.as_block();
+            y = ((u >> plant.as_block()) >> delay.as_block()).value;
+
+            let expected = if t > theta {
+                1.0 - (-(t - theta) / lambda).exp()
+            } else {
+                0.0
+            };
+            assert!((y - expected).abs() < 0.02, "t = {}", t);
+        }
+    }
+
+    #[test]
+    fn test_imc_equivalent_pid() {
+        let imc = IMC::new(2.0, 1.0, 0.5, 0.25, RK4);
+        let (kp, ki, kd) = imc.pid_gains();
+        assert!((kp - 1.25).abs() < 1e-12);
+        assert!((ki - 1.0).abs() < 1e-12);
+        assert!((kd - 0.25).abs() < 1e-12);
+        assert_eq!(imc.to_pid().gains(), (kp, ki, kd));
+
+        let model = FirstOrderModel {
+            k: 1.0,
+            tau: 1.0,
+            theta: -0.1,
+        };
+        assert!(matches!(
+            IMC::from_fopdt(&model, 1.0, RK4),
+            Err(FirstOrderModelError::NegativeTheta(_))
+        ));
+    }
+
+    #[test]
+    fn test_imc_without_dead_time() {
+        let model = FirstOrderModel {
+            k: 2.0,
+            tau: 1.0,
+            theta: 0.0,
+        };
+        let mut imc = IMC::from_fopdt(&model, 0.5, RK4).unwrap();
+        let mut plant = Tf::new(&[2.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        let mut y = 0.0;
+        for sim_state in Simulation::new(0.001, 3.0) {
+            let u = (1.0, y).as_signal(sim_state) >> imc.as_block();
+            y = (u >> plant.as_block()).value;
+        }
+
+        assert!(
+            (y - (1.0 - (-3.0f64 / 0.5).exp())).abs() < 0.01,
+            "y = {}",
+            y
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::block::Block;
+use crate::prelude::{SimulationState, Solver, StateEstimation};
+use crate::signal::Signal;
+use core::{fmt::Debug, marker::PhantomData};
+use faer::{Mat, linalg::solvers::Solve};
+
+/// Linear-quadratic-Gaussian controller for a single-input single-output
+/// plant `x' = A x + B u`, `y = C x`: a steady-state Kalman filter feeding a
+/// LQR state feedback, with a static prefilter so the reference is tracked
+/// without steady-state error on the nominal model.
+#[derive(Debug, Clone)]
+pub struct LQG<I>
+where
+    I: Solver<f64> + Debug,
+{
+    a: Mat<f64>,
+    b: Mat<f64>,
+    c: Mat<f64>,
+    k: Mat<f64>,
+    l: Mat<f64>,
+    prefilter: f64,
+    state: Mat<f64>,
+    current_input: LQGInput,
+    current_control: f64,
+    last_output: Option<f64>,
+    _marker: PhantomData<I>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LQGInput {
+    pub reference: f64,
+    pub measured_output: f64,
+}
+
+impl<I> LQG<I>
+where
+    I: Solver<f64> + Debug,
+{
+    /// Controller from already designed gains: the state feedback `k`
+    /// (a row matrix) and the estimator gain `l` (a column matrix).
+    pub fn new(a: Mat<f64>, b: Mat<f64>, c: Mat<f64>, k: Mat<f64>, l: Mat<f64>) -> Self {
+        let n = a.shape().0;
+
+        assert_eq!(a.shape().0, a.shape().1, "A must be a square matrix");
+        assert_eq!(b.shape(), (n, 1), "B must be a {}x1 matrix", n);
+        assert_eq!(c.shape(), (1, n), "C must be a 1x{} matrix", n);
+        assert_eq!(k.shape(), (1, n), "K must be a 1x{} matrix", n);
+        assert_eq!(l.shape(), (n, 1), "L must be a {}x1 matrix", n);
+
+        // Static gain from the reference to y with u = N r - K x, so that
+        // N = -1 / (C (A - B K)^-1 B).
+        let closed_loop = &a - &b * &k;
+        let dc = &c * closed_loop.partial_piv_lu().solve(&b);
+        let prefilter = -1.0 / dc[(0, 0)];
+
+        Self {
+            a,
+            b,
+            c,
+            k,
+            l,
+            prefilter,
+            state: Mat::zeros(n, 1),
+            current_input: LQGInput::default(),
+            current_control: 0.0,
+            last_output: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Designs both gains from the LQR weights `q` (state) and `r`
+    /// (control), and the Kalman covariances `w` (process noise, on the
+    /// states) and `v` (measurement noise).
+    pub fn design(
+        a: Mat<f64>,
+        b: Mat<f64>,
+        c: Mat<f64>,
+        q: Mat<f64>,
+        r: f64,
+        w: Mat<f64>,
+        v: f64,
+    ) -> Self {
+        let k = lqr(&a, &b, &q, r);
+        let l = kalman(&a, &c, &w, v);
+        Self::new(a, b, c, k, l)
+    }
+
+    pub fn with_integrator(self, _integrator: I) -> Self {
+        self
+    }
+
+    pub fn gains(&self) -> (&Mat<f64>, &Mat<f64>) {
+        (&self.k, &self.l)
+    }
+
+    pub fn prefilter(&self) -> f64 {
+        self.prefilter
+    }
+
+    pub fn state_estimate(&self) -> &Mat<f64> {
+        &self.state
+    }
+}
+
+/// LQR state feedback `K = B' P / r`, `P` solving
+/// `A' P + P A - P B B' P / r + Q = 0`.
+pub fn lqr(a: &Mat<f64>, b: &Mat<f64>, q: &Mat<f64>, r: f64) -> Mat<f64> {
+    let p = care(a, b, q, r);
+    b.transpose() * p * (1.0 / r)
+}
+
+/// Steady-state Kalman gain `L = P C' / v`, `P` solving the filter Riccati
+/// equation `A P + P A' - P C' C P / v + W = 0`.
+pub fn kalman(a: &Mat<f64>, c: &Mat<f64>, w: &Mat<f64>, v: f64) -> Mat<f64> {
+    let p = care(&a.transpose().to_owned(), &c.transpose().to_owned(), w, v);
+    p * c.transpose() * (1.0 / v)
+}
+
+/// Stabilizing solution of `A' P + P A - P B B' P / r + Q = 0` from the
+/// matrix sign function of the Hamiltonian.
+fn care(a: &Mat<f64>, b: &Mat<f64>, q: &Mat<f64>, r: f64) -> Mat<f64> {
+    const MAX_ITERATIONS: usize = 100;
+    const TOLERANCE: f64 = 1e-12;
+
+    let n = a.nrows();
+    let g = b * b.transpose() * (1.0 / r);
+    let mut z = Mat::from_fn(2 * n, 2 * n, |i, j| match (i < n, j < n) {
+        (true, true) => a[(i, j)],
+        (true, false) => -g[(i, j - n)],
+        (false, true) => -q[(i - n, j)],
+        (false, false) => -a[(j - n, i - n)],
+    });
+    let identity = Mat::<f64>::identity(2 * n, 2 * n);
+
+    for _ in 0..MAX_ITERATIONS {
+        let lu = z.partial_piv_lu();
+        let inverse = lu.solve(&identity);
+        // Determinant scaling speeds up convergence considerably.
+        let det = z.determinant().abs();
+        let scale = libm::pow(det, 1.0 / (2 * n) as f64);
+        let next = (&z * (1.0 / scale) + inverse * scale) * 0.5;
+
+        let change = (&next - &z).norm_l1();
+        z = next;
+        if change <= TOLERANCE * z.norm_l1() {
+            break;
+        }
+    }
+
+    // The stable subspace [I; P] satisfies (Z + I) [I; P] = 0, so
+    // [Z12; Z22 + I] P = -[Z11 + I; Z21], solved by least squares.
+    let lhs = Mat::from_fn(2 * n, n, |i, j| {
+        z[(i, j + n)] + if i == j + n { 1.0 } else { 0.0 }
+    });
+    let rhs = Mat::from_fn(2 * n, n, |i, j| {
+        -(z[(i, j)] + if i == j { 1.0 } else { 0.0 })
+    });
+    let p = (lhs.transpose() * &lhs)
+        .partial_piv_lu()
+        .solve(lhs.transpose() * rhs);
+
+    // Symmetrize against round-off.
+    Mat::from_fn(n, n, |i, j| 0.5 * (p[(i, j)] + p[(j, i)]))
+}
+
+impl LQGInput {
+    pub fn from_signals(reference: Signal<f64>, measured_output: Signal<f64>) -> Signal<Self> {
+        Signal {
+            value: Self {
+                reference: reference.value,
+                measured_output: measured_output.value,
+            },
+            sim_state: reference.sim_state.merge(measured_output.sim_state),
+        }
+    }
+}
+
+impl<I> StateEstimation<f64> for LQG<I>
+where
+    I: Solver<f64> + Debug,
+{
+    fn estimate(&self, state: Mat<f64>) -> Mat<f64> {
+        let u = self.current_control;
+        let y_err = self.current_input.measured_output - (&self.c * &state)[(0, 0)];
+        &self.a * &state + &self.b * u + &self.l * y_err
+    }
+}
+
+impl<I> Block for LQG<I>
+where
+    I: Solver<f64> + Debug,
+{
+    type Input = LQGInput;
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let u = self.prefilter * input.reference - (&self.k * &self.state)[(0, 0)];
+
+        self.current_input = input;
+        self.current_control = u;
+        let state = core::mem::replace(&mut self.state, Mat::new());
+        self.state = I::integrate(state, sim_state.dt(), self);
+
+        self.last_output = Some(u);
+        u
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.state.fill(0.0);
+        self.current_input = LQGInput::default();
+        self.current_control = 0.0;
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier2::{LQG, LQGInput, lqr};
+
+    #[test]
+    fn test_lqr_scalar_riccati() {
+        // a = b = q = r = 1 gives P = 1 + sqrt(2).
+        let k = lqr(&mat![[1.0]], &mat![[1.0]], &mat![[1.0]], 1.0);
+        assert!((k[(0, 0)] - (1.0 + 2.0f64.sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lqg_tracks_reference() {
+        let a = mat![[0.0, 1.0], [-2.0, -3.0]];
+        let b = mat![[0.0], [1.0]];
+        let c = mat![[1.0, 0.0]];
+
+        let mut plant = SS::<RK4, f64>::new(a.clone(), b.clone(), c.clone(), 0.0)
+            .with_initial_state(mat![[0.5], [0.0]]);
+        let mut controller = LQG::<RK4>::design(
+            a,
+            b,
+            c,
+            Mat::<f64>::identity(2, 2) * 10.0,
+            0.1,
+            Mat::<f64>::identity(2, 2),
+            0.01,
+        );
+
+        let mut y = 0.0;
+        for sim_state in Simulation::new(0.001, 10.0) {
+            let input = LQGInput::from_signals(1.0.as_signal(sim_state), y.as_signal(sim_state));
+            let u = input >> controller.as_block();
+            y = (u >> plant.as_block()).value;
+        }
+
+        assert!((y - 1.0).abs() < 1e-3);
+        assert!((controller.state_estimate()[(0, 0)] - y).abs() < 1e-3);
+    }
+}
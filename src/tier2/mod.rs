@@ -1,6 +1,22 @@
 #[cfg(feature = "alloc")]
+pub mod imc;
+#[cfg(feature = "alloc")]
+pub mod lqg;
+#[cfg(feature = "alloc")]
+pub mod ppi;
+pub mod reference_governor;
+pub mod simc;
+#[cfg(feature = "alloc")]
 pub mod smith_predictor;
 
+#[cfg(feature = "alloc")]
+pub use imc::IMC;
+#[cfg(feature = "alloc")]
+pub use lqg::{LQG, LQGInput, kalman, lqr};
+#[cfg(feature = "alloc")]
+pub use ppi::PPI;
+pub use reference_governor::ReferenceGovernor;
+pub use simc::SimcPI;
 #[cfg(feature = "alloc")]
 pub use smith_predictor::SmithPredictor;
 #[cfg(feature = "alloc")]
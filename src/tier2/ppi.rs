@@ -0,0 +1,134 @@
+use crate::block::Block;
+use crate::prelude::{Delay, LimitStats, Limited, SimulationState};
+use crate::signal::{from_f64, to_f64};
+use core::time::Duration;
+use num_traits::Float;
+
+/// Hägglund's predictive PI controller for a first order plus dead time
+/// process `k * e^(-theta s) / (tau s + 1)`:
+///
+/// `U = K (1 + 1 / (T s)) E - (1 - e^(-theta s)) U / (T s)`
+///
+/// with `K = 1 / k` and `T = tau`. The delayed control signal fed back to the
+/// integral part acts as a dead-time compensator, and clamping the output to
+/// the actuator limits also prevents windup.
+#[derive(Debug, Clone)]
+pub struct PPI<T = f64>
+where
+    T: Float,
+{
+    gain: T,
+    tau: T,
+    delay: Option<Delay<T>>,
+    limits: Option<(T, T)>,
+    integral: T,
+    limit_stats: LimitStats,
+    last_output: Option<T>,
+}
+
+impl<T> PPI<T>
+where
+    T: Float,
+{
+    pub fn new(k: T, tau: T, theta: T) -> Self {
+        assert!(k != T::zero(), "Process gain must not be zero");
+        assert!(tau > T::zero(), "Time constant must be greater than zero");
+        assert!(theta >= T::zero(), "Dead time must not be negative");
+
+        Self {
+            gain: T::one() / k,
+            tau,
+            delay: (theta > T::zero()).then(|| Delay::new(Duration::from_secs_f64(to_f64(theta)))),
+            limits: None,
+            integral: T::zero(),
+            limit_stats: LimitStats::default(),
+            last_output: None,
+        }
+    }
+
+    /// Scales the controller gain, trading speed for robustness.
+    pub fn with_gain_factor(mut self, factor: T) -> Self {
+        self.gain = self.gain * factor;
+        self
+    }
+
+    pub fn with_limits(mut self, min: T, max: T) -> Self {
+        assert!(min <= max, "Minimum limit must not exceed maximum limit");
+
+        self.limits = Some((min, max));
+        self
+    }
+}
+
+impl<T> Block for PPI<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let dt = from_f64::<T>(sim_state.dt().as_secs_f64());
+
+        let unclamped = self.gain * input + self.integral;
+        let output = match self.limits {
+            Some((min, max)) => unclamped.max(min).min(max),
+            None => unclamped,
+        };
+        self.limit_stats.record(output != unclamped, sim_state.dt());
+        let delayed_output = match &mut self.delay {
+            Some(delay) => delay.block(output, sim_state),
+            None => output,
+        };
+
+        self.integral =
+            self.integral + dt * (self.gain * input - output + delayed_output) / self.tau;
+        self.last_output = Some(output);
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        if let Some(delay) = &mut self.delay {
+            delay.reset();
+        }
+        self.integral = T::zero();
+        self.limit_stats = LimitStats::default();
+        self.last_output = None;
+    }
+}
+
+impl<T> Limited for PPI<T>
+where
+    T: Float,
+{
+    fn limit_stats(&self) -> LimitStats {
+        self.limit_stats
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier2::PPI;
+    use core::time::Duration;
+
+    #[test]
+    fn test_ppi_tracks_setpoint_of_dead_time_process() {
+        let mut ppi = PPI::new(2.0, 5.0, 3.0).with_limits(-10.0, 10.0);
+        let mut plant = Tf::new(&[2.0], &[5.0, 1.0]).to_ss_controllable(RK4);
+        let mut delay = Delay::<f64>::new(Duration::from_secs(3));
+        let mut y = 0.0;
+
+        for sim_state in Simulation::new(0.01, 40.0) {
+            let error = (1.0 - y).as_signal(sim_state);
+            let u = error >> ppi.as_block();
+            y = ((u >> plant.as_block()) >> delay.as_block()).value;
+        }
+
+        assert!((y - 1.0).abs() < 1e-2, "y = {}", y);
+    }
+}
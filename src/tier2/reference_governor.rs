@@ -0,0 +1,148 @@
+use crate::block::Block;
+use crate::prelude::{Rewindable, SimulationState};
+use core::time::Duration;
+
+/// Filters the reference of a pre-stabilized closed loop so that it never
+/// violates its constraints.
+///
+/// `model` is the closed loop from reference to the constrained quantities
+/// (states, control effort, ...), and `admissible` tells whether one of its
+/// outputs is within the constraints. At each step the governor moves the
+/// applied reference `v` towards the requested `r` as far as it can,
+/// `v = v_prev + k (r - v_prev)` with the largest `k` in [0, 1], found by a
+/// bisection, for which holding `v` keeps the predicted response admissible
+/// over `horizon`.
+#[derive(Debug, Clone)]
+pub struct ReferenceGovernor<M, F>
+where
+    M: Block<Input = f64> + Clone,
+    F: Fn(&M::Output) -> bool,
+{
+    model: M,
+    admissible: F,
+    horizon: Duration,
+    bisection_steps: usize,
+    initial_reference: f64,
+    applied: f64,
+    last_output: Option<f64>,
+}
+
+impl<M, F> ReferenceGovernor<M, F>
+where
+    M: Block<Input = f64> + Clone,
+    F: Fn(&M::Output) -> bool,
+{
+    pub fn new(model: M, admissible: F, horizon: Duration) -> Self {
+        Self {
+            model,
+            admissible,
+            horizon,
+            bisection_steps: 10,
+            initial_reference: 0.0,
+            applied: 0.0,
+            last_output: None,
+        }
+    }
+
+    /// Reference applied before the first step. The loop must be at rest
+    /// under it, and it must be admissible.
+    pub fn with_initial_reference(mut self, reference: f64) -> Self {
+        self.initial_reference = reference;
+        self.applied = reference;
+        self
+    }
+
+    /// Number of bisection steps of the line search, 10 by default.
+    pub fn with_bisection_steps(mut self, steps: usize) -> Self {
+        self.bisection_steps = steps;
+        self
+    }
+
+    /// Whether holding `reference` from `sim_state` on keeps the loop within
+    /// its constraints up to the horizon.
+    fn is_admissible(&mut self, reference: f64, sim_state: SimulationState) -> bool {
+        let admissible = &self.admissible;
+        let horizon = self.horizon;
+        self.model.lookahead(|model| {
+            admissible(&model.block(reference, sim_state))
+                && sim_state
+                    .branch(sim_state.dt(), horizon)
+                    .all(|next| admissible(&model.block(reference, next)))
+        })
+    }
+}
+
+impl<M, F> Block for ReferenceGovernor<M, F>
+where
+    M: Block<Input = f64> + Clone,
+    F: Fn(&M::Output) -> bool,
+{
+    type Input = f64;
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let previous = self.applied;
+
+        let reference = if self.is_admissible(input, sim_state) {
+            input
+        } else {
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..self.bisection_steps {
+                let k = 0.5 * (low + high);
+                if self.is_admissible(previous + k * (input - previous), sim_state) {
+                    low = k;
+                } else {
+                    high = k;
+                }
+            }
+            previous + low * (input - previous)
+        };
+
+        self.model.block(reference, sim_state);
+        self.applied = reference;
+        self.last_output = Some(reference);
+        reference
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.last_output
+    }
+
+    fn reset(&mut self) {
+        self.model.reset();
+        self.applied = self.initial_reference;
+        self.last_output = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier2::ReferenceGovernor;
+    use core::time::Duration;
+
+    #[test]
+    fn test_reference_governor_prevents_overshoot_violation() {
+        // Closed loop w^2 / (s^2 + 2 zeta w s + w^2) with zeta = 0.2, which
+        // overshoots a unit step by about 50%.
+        let closed_loop =
+            SsFixed::<2, RK4>::new([[0.0, 1.0], [-1.0, -0.4]], [0.0, 1.0], [1.0, 0.0], 0.0);
+        let mut plant = closed_loop.clone();
+        let mut governor =
+            ReferenceGovernor::new(closed_loop, |y: &f64| *y <= 1.1, Duration::from_secs(10));
+
+        let mut peak = 0.0f64;
+        let mut y = 0.0;
+        let mut v = 0.0;
+        for sim_state in Simulation::new(0.05, 40.0) {
+            v = (1.0.as_signal(sim_state) >> governor.as_block()).value;
+            y = (v.as_signal(sim_state) >> plant.as_block()).value;
+            peak = peak.max(y);
+        }
+
+        assert!(peak <= 1.1 + 1e-9);
+        assert!(peak > 1.0);
+        assert!((v - 1.0).abs() < 1e-6);
+        assert!((y - 1.0).abs() < 1e-2);
+    }
+}
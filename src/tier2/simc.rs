@@ -0,0 +1,109 @@
+use crate::block::Block;
+use crate::prelude::{PID, SimulationState};
+use crate::signal::from_f64;
+use num_traits::Float;
+
+/// PI controller tuned with Skogestad's SIMC rules for a first order plus dead
+/// time process `k * e^(-theta s) / (tau s + 1)`. The closed loop time constant
+/// defaults to `theta`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimcPI<T = f64>
+where
+    T: Float,
+{
+    k: T,
+    tau: T,
+    theta: T,
+    kc: T,
+    ti: T,
+    pid: PID<T>,
+}
+
+impl<T> SimcPI<T>
+where
+    T: Float,
+{
+    pub fn new(k: T, tau: T, theta: T) -> Self {
+        assert!(k != T::zero(), "Process gain must not be zero");
+        assert!(tau > T::zero(), "Time constant must be greater than zero");
+        assert!(theta >= T::zero(), "Dead time must not be negative");
+
+        let mut simc = Self {
+            k,
+            tau,
+            theta,
+            kc: T::zero(),
+            ti: T::zero(),
+            pid: PID::new(T::zero(), T::zero(), T::zero()),
+        };
+        simc.tune(theta);
+        simc
+    }
+
+    pub fn with_closed_loop_time_constant(mut self, tau_c: T) -> Self {
+        assert!(
+            tau_c + self.theta > T::zero(),
+            "Closed loop time constant plus dead time must be greater than zero"
+        );
+
+        self.tune(tau_c);
+        self
+    }
+
+    pub fn with_anti_windup(mut self, min: T, max: T) -> Self {
+        self.pid = self.pid.with_anti_windup(min, max);
+        self
+    }
+
+    pub fn kc(&self) -> T {
+        self.kc
+    }
+
+    pub fn ti(&self) -> T {
+        self.ti
+    }
+
+    fn tune(&mut self, tau_c: T) {
+        self.kc = self.tau / (self.k * (tau_c + self.theta));
+        self.ti = self.tau.min(from_f64::<T>(4.0) * (tau_c + self.theta));
+
+        *self.pid.kp_mut() = self.kc;
+        *self.pid.ki_mut() = self.kc / self.ti;
+    }
+}
+
+impl<T> Block for SimcPI<T>
+where
+    T: Float,
+{
+    type Input = T;
+    type Output = T;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.pid.block(input, sim_state)
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.pid.last_output()
+    }
+
+    fn reset(&mut self) {
+        self.pid.reset();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::tier2::SimcPI;
+
+    #[test]
+    fn test_simc_pi_tuning_rules() {
+        let simc = SimcPI::new(2.0, 10.0, 1.0);
+        assert_eq!(simc.kc(), 2.5);
+        assert_eq!(simc.ti(), 8.0);
+
+        let simc = SimcPI::new(2.0, 4.0, 1.0).with_closed_loop_time_constant(3.0);
+        assert_eq!(simc.kc(), 0.5);
+        assert_eq!(simc.ti(), 4.0);
+    }
+}
@@ -1,35 +1,38 @@
 use crate::block::Block;
 use crate::prelude::{Delay, SimulationState};
+#[cfg(feature = "std")]
+use crate::prelude::{FirstOrderModel, FirstOrderModelError, SS, Solver, Tf};
 use crate::signal::Signal;
-use core::ops::{Mul, Sub};
+#[cfg(feature = "std")]
+use core::fmt::Debug;
 use core::time::Duration;
-use num_traits::Zero;
+use num_traits::Float;
 
 pub struct SmithPredictor<T, P>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
     P: Block<Input = T, Output = T>,
 {
     process: P,
-    delay: Delay<T>,
+    delay: Option<Delay<T>>,
     last_output: Option<T>,
 }
 
 pub struct SmithPredictorFiltered<T, P, F>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
     P: Block<Input = T, Output = T>,
     F: Block<Input = T, Output = T>,
 {
     process: P,
     filter: F,
-    delay: Delay<T>,
+    delay: Option<Delay<T>>,
     last_output: Option<T>,
 }
 
 pub struct SmithPredictorInput<T>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
 {
     pub control_signal: T,
     pub measured_output: T,
@@ -37,13 +40,13 @@ where
 
 impl<T, P> SmithPredictor<T, P>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
     P: Block<Input = T, Output = T>,
 {
     pub fn new(process: P, delay: Duration) -> Self {
         SmithPredictor {
             process,
-            delay: Delay::new(delay),
+            delay: delay_line(delay),
             last_output: None,
         }
     }
@@ -51,7 +54,7 @@ where
 
 impl<T, P, F> SmithPredictorFiltered<T, P, F>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
     P: Block<Input = T, Output = T>,
     F: Block<Input = T, Output = T>,
 {
@@ -59,15 +62,75 @@ where
         SmithPredictorFiltered {
             process,
             filter,
-            delay: Delay::new(delay),
+            delay: delay_line(delay),
             last_output: None,
         }
     }
 }
 
+/// Delay line for `delay`, none for a process without dead time.
+fn delay_line<T: Float>(delay: Duration) -> Option<Delay<T>> {
+    (!delay.is_zero()).then(|| Delay::new(delay))
+}
+
+fn delayed<T: Float>(delay: &mut Option<Delay<T>>, value: T, sim_state: SimulationState) -> T {
+    match delay {
+        Some(delay) => delay.block(value, sim_state),
+        None => value,
+    }
+}
+
+#[cfg(feature = "std")]
+fn fopdt_parts<I>(
+    model: &FirstOrderModel,
+    integrator: I,
+) -> Result<(SS<I, f64>, Duration), FirstOrderModelError>
+where
+    I: Solver<f64> + Debug,
+{
+    if model.theta.is_sign_negative() {
+        return Err(FirstOrderModelError::NegativeTheta(model.theta));
+    }
+
+    let process = Tf::new_unchecked(&[model.k], &[model.tau, 1.0]).to_ss_controllable(integrator);
+    Ok((process, Duration::from_secs_f64(model.theta)))
+}
+
+#[cfg(feature = "std")]
+impl<I> SmithPredictor<f64, SS<I, f64>>
+where
+    I: Solver<f64> + Debug,
+{
+    pub fn from_fopdt(
+        model: &FirstOrderModel,
+        integrator: I,
+    ) -> Result<Self, FirstOrderModelError> {
+        let (process, delay) = fopdt_parts(model, integrator)?;
+        Ok(Self::new(process, delay))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> SmithPredictorFiltered<f64, SS<I, f64>, SS<I, f64>>
+where
+    I: Solver<f64> + Debug + Clone,
+{
+    /// The model mismatch is filtered by `1 / (filter_tau * s + 1)`.
+    pub fn from_fopdt(
+        model: &FirstOrderModel,
+        filter_tau: f64,
+        integrator: I,
+    ) -> Result<Self, FirstOrderModelError> {
+        let (process, delay) = fopdt_parts(model, integrator.clone())?;
+        let filter = Tf::new_unchecked(&[1.0], &[filter_tau, 1.0]).to_ss_controllable(integrator);
+
+        Ok(Self::new(process, filter, delay))
+    }
+}
+
 impl<T> SmithPredictorInput<T>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
 {
     pub fn from_signals(control_signal: Signal<T>, measured_output: Signal<T>) -> Signal<Self> {
         Signal {
@@ -82,7 +145,7 @@ where
 
 impl<T, P> Block for SmithPredictor<T, P>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
     P: Block<Input = T, Output = T>,
 {
     type Input = SmithPredictorInput<T>;
@@ -90,7 +153,7 @@ where
 
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
         let predicted_output = self.process.block(input.control_signal, sim_state);
-        let delayed_predicted_output = self.delay.block(input.measured_output, sim_state);
+        let delayed_predicted_output = delayed(&mut self.delay, predicted_output, sim_state);
 
         let output_diff = input.measured_output - delayed_predicted_output;
 
@@ -105,14 +168,16 @@ where
 
     fn reset(&mut self) {
         self.process.reset();
-        self.delay.reset();
+        if let Some(delay) = &mut self.delay {
+            delay.reset();
+        }
         self.last_output = None;
     }
 }
 
 impl<T, P, F> Block for SmithPredictorFiltered<T, P, F>
 where
-    T: Zero + Copy + Mul<f64, Output = T> + Sub<Output = T>,
+    T: Float,
     P: Block<Input = T, Output = T>,
     F: Block<Input = T, Output = T>,
 {
@@ -121,7 +186,7 @@ where
 
     fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
         let predicted_output = self.process.block(input.control_signal, sim_state);
-        let delayed_predicted_output = self.delay.block(predicted_output, sim_state);
+        let delayed_predicted_output = delayed(&mut self.delay, predicted_output, sim_state);
 
         let output_diff = input.measured_output - delayed_predicted_output;
         let output_diff_filtered = self.filter.block(output_diff, sim_state);
@@ -138,7 +203,100 @@ where
     fn reset(&mut self) {
         self.process.reset();
         self.filter.reset();
-        self.delay.reset();
+        if let Some(delay) = &mut self.delay {
+            delay.reset();
+        }
         self.last_output = None;
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier2::smith_predictor::SmithPredictorInput;
+    use crate::tier2::{SmithPredictor, SmithPredictorFiltered};
+    use core::time::Duration;
+
+    #[test]
+    fn test_smith_predictor_filtered_from_fopdt_cancels_dead_time() {
+        let model = FirstOrderModel {
+            k: 2.0,
+            tau: 1.0,
+            theta: 0.5,
+        };
+        let mut predictor = SmithPredictorFiltered::from_fopdt(&model, 0.1, RK4).unwrap();
+        let mut plant = Tf::new(&[2.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut delay = Delay::<f64>::new(Duration::from_secs_f64(0.5));
+        let mut undelayed = Tf::new(&[2.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        for sim_state in Simulation::new(0.01, 3.0) {
+            let u = 1.0f64.as_signal(sim_state);
+            let y = (u >> plant.as_block()) >> delay.as_block();
+            let expected = u >> undelayed.as_block();
+
+            let input = SmithPredictorInput::from_signals(u, y);
+            let predicted = input >> predictor.as_block();
+
+            assert!((predicted.value - expected.value).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_smith_predictor_from_fopdt_cancels_dead_time() {
+        let model = FirstOrderModel {
+            k: 2.0,
+            tau: 1.0,
+            theta: 0.5,
+        };
+        let mut predictor = SmithPredictor::from_fopdt(&model, RK4).unwrap();
+        let mut plant = Tf::new(&[2.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut delay = Delay::<f64>::new(Duration::from_secs_f64(0.5));
+        let mut undelayed = Tf::new(&[2.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        for sim_state in Simulation::new(0.01, 3.0) {
+            let u = 1.0f64.as_signal(sim_state);
+            let y = (u >> plant.as_block()) >> delay.as_block();
+            let expected = u >> undelayed.as_block();
+
+            let input = SmithPredictorInput::from_signals(u, y);
+            let predicted = input >> predictor.as_block();
+
+            assert!((predicted.value - expected.value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_smith_predictor_filtered_from_fopdt_without_dead_time() {
+        let model = FirstOrderModel {
+            k: 2.0,
+            tau: 1.0,
+            theta: 0.0,
+        };
+        let mut predictor = SmithPredictorFiltered::from_fopdt(&model, 0.1, RK4).unwrap();
+        let mut plant = Tf::new(&[2.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        for sim_state in Simulation::new(0.01, 1.0) {
+            let u = 1.0f64.as_signal(sim_state);
+            let y = u >> plant.as_block();
+
+            let input = SmithPredictorInput::from_signals(u, y);
+            let predicted = input >> predictor.as_block();
+
+            assert!((predicted.value - y.value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_smith_predictor_from_fopdt_rejects_negative_theta() {
+        let model = FirstOrderModel {
+            k: 1.0,
+            tau: 1.0,
+            theta: -0.1,
+        };
+
+        assert!(matches!(
+            SmithPredictor::from_fopdt(&model, Euler),
+            Err(FirstOrderModelError::NegativeTheta(_))
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::vec::Vec;
+
+/// `sqrt(2 ln 2 / pi)`, the Allan deviation floor of unit bias instability.
+const BIAS_INSTABILITY_FLOOR: f64 = 0.664;
+
+/// Allan variance analysis of a sensor held still, e.g. a gyro axis, to
+/// read its noise terms off the log-log Allan deviation curve. As a block
+/// it records its input each step and passes it through.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AllanVariance {
+    samples: Vec<f64>,
+    dt: f64,
+}
+
+/// Noise terms fitted to an Allan deviation curve, in the sensor units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AllanNoise {
+    /// White noise density `N`, e.g. angle random walk for a gyro, read on
+    /// the `-1/2` slope as `σ(τ) sqrt(τ)`, in units per `sqrt(Hz)`.
+    pub white_noise: f64,
+    /// Bias instability `B`, the flat bottom of the curve divided by 0.664.
+    pub bias_instability: f64,
+    /// Averaging time at the bottom of the curve.
+    pub bias_instability_tau: f64,
+    /// Random walk `K` of the bias, read on the `+1/2` slope as
+    /// `σ(τ) sqrt(3 / τ)`, `None` if the record is too short to show it.
+    pub random_walk: Option<f64>,
+}
+
+impl AllanVariance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Analysis of a logged signal sampled every `dt` seconds.
+    pub fn from_samples(samples: &[f64], dt: f64) -> Self {
+        Self {
+            samples: samples.to_vec(),
+            dt,
+        }
+    }
+
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    /// Overlapping Allan deviation `(τ, σ(τ))` for averaging times growing
+    /// in octaves from one sample up to a ninth of the record.
+    pub fn deviation(&self) -> Vec<(f64, f64)> {
+        let n = self.samples.len();
+        let dt = self.dt;
+
+        // Integral of the signal, e.g. the angle for a rate gyro.
+        let mut theta = Vec::with_capacity(n + 1);
+        theta.push(0.0);
+        for sample in &self.samples {
+            theta.push(theta[theta.len() - 1] + sample * dt);
+        }
+
+        let mut deviation = Vec::new();
+        let mut m = 1;
+        while 9 * m <= n {
+            let tau = m as f64 * dt;
+            let terms = n + 1 - 2 * m;
+            let sum = (0..terms)
+                .map(|k| {
+                    let d = theta[k + 2 * m] - 2.0 * theta[k + m] + theta[k];
+                    d * d
+                })
+                .sum::<f64>();
+            let variance = sum / (2.0 * tau * tau * terms as f64);
+            deviation.push((tau, libm::sqrt(variance)));
+            m *= 2;
+        }
+        deviation
+    }
+
+    /// Fits the white noise, bias instability and random walk terms, `None`
+    /// if the record is shorter than 18 samples.
+    pub fn noise(&self) -> Option<AllanNoise> {
+        let deviation = self.deviation();
+        if deviation.len() < 2 {
+            return None;
+        }
+
+        let slopes = deviation
+            .windows(2)
+            .map(|w| libm::log(w[1].1 / w[0].1) / libm::log(w[1].0 / w[0].0))
+            .collect::<Vec<_>>();
+        let slopes = &slopes;
+        // Points whose neighbouring segments both run close to `slope`.
+        let on_slope = |slope: f64| {
+            (0..deviation.len()).filter(move |i| {
+                let before = i.checked_sub(1).map(|i| slopes[i]);
+                let after = slopes.get(*i).copied();
+                [before, after]
+                    .iter()
+                    .flatten()
+                    .all(|s| (s - slope).abs() < 0.25)
+            })
+        };
+        let mean = |values: Vec<f64>| {
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+        };
+
+        let white_noise = mean(
+            on_slope(-0.5)
+                .map(|i| deviation[i].1 * libm::sqrt(deviation[i].0))
+                .collect(),
+        )
+        .unwrap_or(deviation[0].1 * libm::sqrt(deviation[0].0));
+        let random_walk = mean(
+            on_slope(0.5)
+                .map(|i| deviation[i].1 * libm::sqrt(3.0 / deviation[i].0))
+                .collect(),
+        );
+        let (bias_instability_tau, floor) =
+            deviation
+                .iter()
+                .copied()
+                .fold((0.0, f64::INFINITY), |best, point| {
+                    if point.1 < best.1 { point } else { best }
+                });
+
+        Some(AllanNoise {
+            white_noise,
+            bias_instability: floor / BIAS_INSTABILITY_FLOOR,
+            bias_instability_tau,
+            random_walk,
+        })
+    }
+}
+
+impl Block for AllanVariance {
+    type Input = f64;
+    type Output = f64;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        self.dt = sim_state.dt().as_secs_f64();
+        self.samples.push(input);
+        input
+    }
+
+    fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::rng::SplitMix64;
+    use crate::tier3::AllanVariance;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_allan_variance_recovers_noise_terms() {
+        // Gyro at 100 Hz with white noise N and a bias random walk K, the
+        // curve bottoming out around sqrt(3) N / K.
+        let (dt, n_density, k_walk): (f64, f64, f64) = (0.01, 0.02, 0.01);
+        let mut rng = SplitMix64::new(11);
+        let mut bias = 0.0;
+        let samples = (0..400_000)
+            .map(|_| {
+                bias += k_walk * dt.sqrt() * rng.next_gaussian();
+                bias + n_density / dt.sqrt() * rng.next_gaussian()
+            })
+            .collect::<Vec<_>>();
+
+        let allan = AllanVariance::from_samples(&samples, dt);
+        let deviation = allan.deviation();
+        assert_eq!(deviation[0].0, dt);
+        let expected = n_density / dt.sqrt();
+        assert!((deviation[0].1 - expected).abs() < 0.02 * expected);
+
+        let noise = allan.noise().unwrap();
+        assert!((noise.white_noise - n_density).abs() < 0.1 * n_density);
+        let k = noise.random_walk.unwrap();
+        assert!((k - k_walk).abs() < 0.5 * k_walk);
+        assert!(noise.bias_instability_tau > 1.0 && noise.bias_instability_tau < 1000.0);
+
+        // Recorded through a simulation.
+        let mut recorder = AllanVariance::new();
+        for (sim_state, sample) in Simulation::new(0.01, 1000.0).zip(&samples[..1000]) {
+            let _ = (*sample).as_signal(sim_state) >> recorder.as_block();
+        }
+        assert!((recorder.deviation()[0].1 - deviation[0].1).abs() < 0.1 * expected);
+        assert_eq!(
+            AllanVariance::from_samples(&samples[..10], dt).noise(),
+            None
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::continuous::Tf;
+use crate::discrete::tf::DTf;
+use crate::prelude::BandStop;
+use crate::signal::from_f64;
+use alloc::vec::Vec;
+use core::{f64::consts::PI, time::Duration};
+use num_traits::Float;
+
+/// Finds the dominant resonance in logged data and designs a notch filter
+/// for it, the usual first step when tuning a servo loop.
+///
+/// The spectrum is taken with a Hann-windowed DFT of the mean-removed
+/// samples; the peak is refined by parabolic interpolation and its quality
+/// factor comes from the half-power bandwidth, so it cannot be sharper than
+/// the frequency resolution of the record allows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoNotch {
+    band: Option<(f64, f64)>,
+    depth: f64,
+}
+
+/// Resonance found by [`AutoNotch`], with the notch designed for it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resonance {
+    /// Center frequency in Hz.
+    pub frequency: f64,
+    pub q_factor: f64,
+    /// Amplitude of the spectral peak.
+    pub amplitude: f64,
+    /// Gain of the notch at its center frequency.
+    pub depth: f64,
+}
+
+impl AutoNotch {
+    pub fn new() -> Self {
+        Self {
+            band: None,
+            depth: 0.0,
+        }
+    }
+
+    /// Restricts the search to `[f_min, f_max]` Hz, e.g. above the loop
+    /// bandwidth. Defaults to the whole spectrum up to Nyquist.
+    pub fn with_band(mut self, f_min: f64, f_max: f64) -> Self {
+        assert!(f_min < f_max, "Band must have f_min < f_max");
+        self.band = Some((f_min, f_max));
+        self
+    }
+
+    /// Gain of the designed notch at the resonance, 0 (a full notch) by
+    /// default.
+    pub fn with_depth(mut self, depth: f64) -> Self {
+        assert!((0.0..1.0).contains(&depth), "Notch depth must be in [0, 1)");
+        self.depth = depth;
+        self
+    }
+
+    /// Dominant resonance in `samples` logged every `dt`, or `None` if the
+    /// record is too short or the band holds no spectral line.
+    pub fn detect(&self, samples: &[f64], dt: Duration) -> Option<Resonance> {
+        let n = samples.len();
+        if n < 8 {
+            return None;
+        }
+
+        let fs = 1.0 / dt.as_secs_f64();
+        let resolution = fs / n as f64;
+        let (f_min, f_max) = self.band.unwrap_or((0.0, fs / 2.0));
+        let first = (libm::ceil(f_min / resolution) as usize).max(1);
+        let last = (libm::floor(f_max / resolution) as usize).min(n / 2 - 1);
+        if first + 2 > last {
+            return None;
+        }
+
+        let spectrum = spectrum(samples, first - 1, last + 1);
+        let magnitude = |k: usize| spectrum[k + 1 - first];
+
+        let peak = (first..=last).max_by(|a, b| magnitude(*a).total_cmp(&magnitude(*b)))?;
+        let amplitude = magnitude(peak);
+        if amplitude == 0.0 {
+            return None;
+        }
+
+        let (left, center, right) = (magnitude(peak - 1), amplitude, magnitude(peak + 1));
+        let curvature = left - 2.0 * center + right;
+        let offset = if curvature < 0.0 {
+            0.5 * (left - right) / curvature
+        } else {
+            0.0
+        };
+        let frequency = (peak as f64 + offset) * resolution;
+
+        let half_power = amplitude / core::f64::consts::SQRT_2;
+        let crossing = |step: isize| {
+            let mut k = peak as isize;
+            loop {
+                let next = k + step;
+                if next < first as isize - 1 || next > last as isize + 1 {
+                    return k as f64;
+                }
+                let (m, m_next) = (magnitude(k as usize), magnitude(next as usize));
+                if m_next < half_power {
+                    return k as f64 + step as f64 * (m - half_power) / (m - m_next);
+                }
+                k = next;
+            }
+        };
+        let bandwidth = ((crossing(1) - crossing(-1)) * resolution).max(resolution);
+
+        Some(Resonance {
+            frequency,
+            q_factor: frequency / bandwidth,
+            amplitude,
+            depth: self.depth,
+        })
+    }
+}
+
+impl Default for AutoNotch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resonance {
+    /// `(s^2 + 2 depth zeta w s + w^2) / (s^2 + 2 zeta w s + w^2)`, with
+    /// `zeta = 1 / (2 Q)`.
+    pub fn tf(&self) -> Tf<f64> {
+        let (numerator, denominator) = self.continuous_coeffs();
+        Tf::new_unchecked(&numerator, &denominator)
+    }
+
+    /// Bilinear discretization of [`Resonance::tf`], prewarped so the notch
+    /// stays centered on the resonance.
+    pub fn dtf(&self, dt: Duration) -> DTf<f64> {
+        let omega = 2.0 * PI * self.frequency;
+        let k = omega / libm::tan(omega * dt.as_secs_f64() / 2.0);
+        let bilinear = |[c2, c1, c0]: [f64; 3]| {
+            [
+                c2 * k * k + c1 * k + c0,
+                2.0 * (c0 - c2 * k * k),
+                c2 * k * k - c1 * k + c0,
+            ]
+        };
+
+        let (numerator, denominator) = self.continuous_coeffs();
+        let b = bilinear(numerator);
+        let a = bilinear(denominator);
+        let a0 = a[0];
+        DTf::new(&b.map(|c| c / a0), &a.map(|c| c / a0))
+    }
+
+    /// Full-depth notch as a [`BandStop`] biquad.
+    pub fn band_stop<T>(&self, dt: Duration) -> BandStop<T>
+    where
+        T: Float,
+    {
+        BandStop::new(from_f64(self.frequency), from_f64(self.q_factor), dt)
+    }
+
+    fn continuous_coeffs(&self) -> ([f64; 3], [f64; 3]) {
+        let omega = 2.0 * PI * self.frequency;
+        let zeta = 1.0 / (2.0 * self.q_factor);
+        (
+            [1.0, 2.0 * self.depth * zeta * omega, omega * omega],
+            [1.0, 2.0 * zeta * omega, omega * omega],
+        )
+    }
+}
+
+/// Magnitudes of the DFT bins `first..=last` of the Hann-windowed,
+/// mean-removed samples, computed with the Goertzel recurrence.
+fn spectrum(samples: &[f64], first: usize, last: usize) -> Vec<f64> {
+    let n = samples.len();
+    let mean = samples.iter().sum::<f64>() / n as f64;
+    let windowed = samples
+        .iter()
+        .enumerate()
+        .map(|(i, x)| (x - mean) * 0.5 * (1.0 - libm::cos(2.0 * PI * i as f64 / n as f64)))
+        .collect::<Vec<_>>();
+
+    (first..=last)
+        .map(|k| {
+            let coeff = 2.0 * libm::cos(2.0 * PI * k as f64 / n as f64);
+            let (mut s1, mut s2) = (0.0, 0.0);
+            for x in &windowed {
+                let s0 = x + coeff * s1 - s2;
+                s2 = s1;
+                s1 = s0;
+            }
+            let power = s1 * s1 + s2 * s2 - coeff * s1 * s2;
+            2.0 * libm::sqrt(power.max(0.0)) / n as f64
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::AutoNotch;
+    use alloc::vec::Vec;
+    use core::f64::consts::PI;
+    use core::time::Duration;
+
+    #[test]
+    fn test_auto_notch_finds_resonance() {
+        // Lightly damped 40 Hz mode excited by pseudo-random noise.
+        let omega = 2.0 * PI * 40.0;
+        let mut mode = SsFixed::<2, RK4>::new(
+            [[0.0, 1.0], [-omega * omega, -2.0 * 0.02 * omega]],
+            [0.0, omega * omega],
+            [1.0, 0.0],
+            0.0,
+        );
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut noise = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        };
+
+        let samples = Simulation::new(0.001, 4.0)
+            .map(|sim_state| (noise().as_signal(sim_state) >> mode.as_block()).value)
+            .collect::<Vec<_>>();
+
+        let resonance = AutoNotch::new()
+            .with_band(10.0, 200.0)
+            .detect(&samples, Duration::from_millis(1))
+            .unwrap();
+        assert!((resonance.frequency - 40.0).abs() < 1.0);
+        assert!(resonance.q_factor > 5.0);
+
+        let notch = resonance.dtf(Duration::from_millis(1));
+        let gain = |f: f64| {
+            let h = notch.freq_response(&[2.0 * PI * f], 1e-3)[0];
+            libm::hypot(h.re, h.im)
+        };
+        assert!(gain(resonance.frequency) < 1e-6);
+        assert!((notch.dc_gain() - 1.0).abs() < 1e-9);
+        assert!(gain(5.0) > 0.95);
+
+        let tf = resonance.tf();
+        assert!((tf.dc_gain() - 1.0).abs() < 1e-9);
+    }
+}
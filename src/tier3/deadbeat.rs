@@ -0,0 +1,147 @@
+use crate::linalg::dense::eigenvalues;
+use crate::poly::Polynomial;
+use crate::prelude::{DSS, DTf};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Relative size below which a numerator coefficient counts as a delay.
+const DELAY_TOLERANCE: f64 = 1e-12;
+
+/// Minimum-time deadbeat controller for the unity-feedback loop around
+/// `plant`: the output reaches a step reference at the `d`-th sample, `d`
+/// the plant delay, and stays there at the sampling instants.
+///
+/// The controller `D = z^-d / (G (1 - z^-d))` cancels the plant zeros, so
+/// it is `None` when one of them lies on or outside the unit circle. Zeros
+/// close to `z = -1` make the input ring between the samples; see
+/// [`design_ripple_free`].
+pub fn design(plant: &DSS<f64>) -> Option<DTf<f64>> {
+    let (b, a) = coefficients(plant)?;
+    let delay = b.iter().take_while(|c| **c == 0.0).count();
+    let zeros = &b[delay..];
+    if !inside_unit_circle(zeros) {
+        return None;
+    }
+
+    // A strictly proper loop needs at least one sample to react.
+    let settle = delay.max(1);
+    let mut num = vec![0.0; settle - delay];
+    num.extend(a);
+    let mut one_minus = vec![0.0; settle + 1];
+    one_minus[0] = 1.0;
+    one_minus[settle] = -1.0;
+    let den = Polynomial::new(zeros) * Polynomial::new(&one_minus);
+
+    Some(DTf::new(&num, den.coeff()))
+}
+
+/// Ripple-free deadbeat controller `D = q0 A / (1 - q0 B)`, with
+/// `q0 = 1 / B(1)`, for the plant `G = B / A` in powers of `z^-1`.
+///
+/// The loop `T = q0 B` keeps every plant zero and settles in `n` samples
+/// for an order `n` plant, with both the output and the input constant from
+/// there on. It cancels the plant poles instead, so it is `None` unless all
+/// of them lie strictly inside the unit circle, and when the plant has a
+/// zero at `z = 1`.
+pub fn design_ripple_free(plant: &DSS<f64>) -> Option<DTf<f64>> {
+    let (b, a) = coefficients(plant)?;
+    let dc = b.iter().sum::<f64>();
+    if dc == 0.0 || !inside_unit_circle(&a) {
+        return None;
+    }
+
+    let q0 = 1.0 / dc;
+    let num = a.iter().map(|c| q0 * c).collect::<Vec<_>>();
+    let den = b
+        .iter()
+        .enumerate()
+        .map(|(i, c)| if i == 0 { 1.0 } else { 0.0 } - q0 * c)
+        .collect::<Vec<_>>();
+
+    Some(DTf::new(&num, &den))
+}
+
+/// `(b, a)` of the plant in powers of `z^-1`, `a[0] = 1`, with numerator
+/// round-off below the tolerance zeroed so delays show up exactly. `None`
+/// for a plant with no path from input to output.
+fn coefficients(plant: &DSS<f64>) -> Option<(Vec<f64>, Vec<f64>)> {
+    let tf = plant.to_dtf();
+    let a = tf.denominator().coeff().to_vec();
+    let mut b = tf.numerator().coeff().to_vec();
+    b.resize(a.len(), 0.0);
+
+    let scale = b.iter().fold(0.0, |acc: f64, c| acc.max(c.abs()));
+    if scale == 0.0 {
+        return None;
+    }
+    for c in b.iter_mut() {
+        if c.abs() < DELAY_TOLERANCE * scale {
+            *c = 0.0;
+        }
+    }
+    Some((b, a))
+}
+
+/// Whether the roots of `p[0] z^m + p[1] z^(m-1) + ... + p[m]` all lie
+/// strictly inside the unit circle.
+fn inside_unit_circle(p: &[f64]) -> bool {
+    let monic = p.iter().map(|c| c / p[0]).collect::<Vec<_>>();
+    let companion = Polynomial::new(&monic).transposed_companion_matrix();
+    eigenvalues(companion.as_ref())
+        .iter()
+        .all(|root| libm::hypot(root.re, root.im) < 1.0 - 1e-9)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::deadbeat;
+    use alloc::vec::Vec;
+
+    /// Step responses `(y, u)` of the unity-feedback loop.
+    fn closed_loop(plant: &mut DSS<f64>, controller: &mut DTf<f64>) -> (Vec<f64>, Vec<f64>) {
+        let (mut ys, mut us) = (Vec::new(), Vec::new());
+        for sim_state in Simulation::new(0.1, 2.0) {
+            // The plants are strictly proper, so `y[k] = C x[k]`.
+            let y = (plant.c() * plant.state())[(0, 0)];
+            let u = controller.block(1.0 - y, sim_state);
+            let _ = plant.block(u, sim_state);
+            ys.push(y);
+            us.push(u);
+        }
+        (ys, us)
+    }
+
+    #[test]
+    fn test_deadbeat_settles_in_minimum_time() {
+        // Two stable poles and a zero at -0.5, with one sample of delay.
+        let plant = DTf::new(&[0.0, 1.0, 0.5], &[1.0, -1.1, 0.3]);
+        let mut plant = plant.to_dss_controllable();
+        let mut controller = deadbeat::design(&plant).unwrap();
+
+        let (ys, us) = closed_loop(&mut plant, &mut controller);
+        // The plant output lags the controller by a sample.
+        assert!(ys[..1].iter().all(|y| y.abs() < 1e-12));
+        assert!(ys[1..].iter().all(|y| (y - 1.0).abs() < 1e-9), "{:?}", ys);
+
+        // Ripple-free: two samples, then a constant input.
+        plant.reset();
+        let mut controller = deadbeat::design_ripple_free(&plant).unwrap();
+        let (ys, us_free) = closed_loop(&mut plant, &mut controller);
+        assert!(ys[2..].iter().all(|y| (y - 1.0).abs() < 1e-9), "{:?}", ys);
+        let u_final = *us_free.last().unwrap();
+        assert!(us_free[2..].iter().all(|u| (u - u_final).abs() < 1e-9));
+        assert!(us[2..].iter().any(|u| (u - u_final).abs() > 1e-3));
+    }
+
+    #[test]
+    fn test_deadbeat_rejects_cancelling_unstable_dynamics() {
+        let non_minimum_phase = DTf::new(&[0.0, 1.0, 1.5], &[1.0, -0.5, 0.0]).to_dss_controllable();
+        assert!(deadbeat::design(&non_minimum_phase).is_none());
+        assert!(deadbeat::design_ripple_free(&non_minimum_phase).is_some());
+
+        let integrating = DTf::new(&[0.0, 0.1], &[1.0, -1.0]).to_dss_controllable();
+        assert!(deadbeat::design_ripple_free(&integrating).is_none());
+        assert!(deadbeat::design(&integrating).is_some());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::signal::Signal;
+use core::time::Duration;
+
+/// Estimates the dead time between an input `u` and an output `y` sampled at
+/// the same instants. The delay is the lag that maximizes the cross-correlation
+/// of the mean-removed signals, refined with a parabolic fit around the peak.
+/// Lags up to half of the record are searched. Works best with rich excitation
+/// (e.g. PRBS or noise) on `u`.
+pub fn delay_estimate(u: &[Signal<f64>], y: &[Signal<f64>]) -> Duration {
+    assert_eq!(
+        u.len(),
+        y.len(),
+        "Input and output must have the same length"
+    );
+    assert!(u.len() >= 4, "Delay estimation needs at least 4 samples");
+
+    let n = u.len();
+    let u_mean = u.iter().map(|s| s.value).sum::<f64>() / n as f64;
+    let y_mean = y.iter().map(|s| s.value).sum::<f64>() / n as f64;
+
+    let correlation = |lag: usize| {
+        let sum = u[..n - lag]
+            .iter()
+            .zip(&y[lag..])
+            .map(|(u, y)| (u.value - u_mean) * (y.value - y_mean))
+            .sum::<f64>();
+        sum / (n - lag) as f64
+    };
+
+    let max_lag = n / 2;
+    let (peak, peak_value) = (0..=max_lag).map(|lag| (lag, correlation(lag))).fold(
+        (0, f64::NEG_INFINITY),
+        |best, current| {
+            if current.1 > best.1 { current } else { best }
+        },
+    );
+
+    let offset = if peak > 0 && peak < max_lag {
+        let before = correlation(peak - 1);
+        let after = correlation(peak + 1);
+        let curvature = before - 2.0 * peak_value + after;
+
+        if curvature < 0.0 {
+            0.5 * (before - after) / curvature
+        } else {
+            0.0
+        }
+    } else {
+        0.0
+    };
+
+    let dt = u[1].sim_state.sim_time().as_secs_f64() - u[0].sim_state.sim_time().as_secs_f64();
+    Duration::from_secs_f64(((peak as f64 + offset) * dt).max(0.0))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::delay_estimate;
+    use core::time::Duration;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_delay_estimate_recovers_dead_time_of_prbs() {
+        let mut seed = 0x2545_f491_u32;
+        let mut u = Vec::new();
+        let mut y = Vec::new();
+        let mut delay = Delay::<f64>::new(Duration::from_millis(120));
+
+        for sim_state in Simulation::new(0.01, 20.0) {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let value: f64 = if seed >> 31 == 1 { 1.0 } else { -1.0 };
+            let input = value.as_signal(sim_state);
+
+            u.push(input);
+            y.push(input >> delay.as_block());
+        }
+
+        let theta = delay_estimate(&u, &y).as_secs_f64();
+        assert!((theta - 0.12).abs() < 0.011, "theta = {}", theta);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    block::Block,
+    prelude::{Simulation, WithDisturbance},
+};
+use alloc::vec::Vec;
+
+/// Recovery of a unity-feedback loop from a step load disturbance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisturbanceReport {
+    magnitude: f64,
+    time: Vec<f64>,
+    deviation: Vec<f64>,
+}
+
+/// Applies a load step of `magnitude` at the input of `plant`, in closed loop
+/// with `controller` and a zero reference, and records how far the plant
+/// output deviates and how fast it comes back. The loop starts from the
+/// current state of the blocks, which are cloned and left untouched.
+pub fn disturbance_response<C, P>(
+    controller: &C,
+    plant: &P,
+    magnitude: f64,
+    duration: f32,
+    dt: f32,
+) -> DisturbanceReport
+where
+    C: Block<Input = f64, Output = f64> + Clone,
+    P: Block<Input = f64, Output = f64> + Clone,
+{
+    let mut controller = controller.clone();
+    let baseline = plant.last_output().unwrap_or(0.0);
+    let mut plant = WithDisturbance::at_input(plant.clone());
+
+    let mut time = Vec::new();
+    let mut deviation = Vec::new();
+    let mut output = baseline;
+
+    for sim_state in Simulation::new(dt, duration) {
+        let control = crate::block::run(&mut controller, -output, sim_state);
+        output = crate::block::run(&mut plant, (control, magnitude), sim_state);
+
+        time.push(sim_state.sim_time().as_secs_f64());
+        deviation.push(output - baseline);
+    }
+
+    DisturbanceReport {
+        magnitude,
+        time,
+        deviation,
+    }
+}
+
+impl DisturbanceReport {
+    pub fn time(&self) -> &[f64] {
+        &self.time
+    }
+
+    /// Plant output minus its value before the disturbance.
+    pub fn deviation(&self) -> &[f64] {
+        &self.deviation
+    }
+
+    /// Largest deviation, with its sign.
+    pub fn peak_deviation(&self) -> f64 {
+        self.peak().map(|(_, d)| d).unwrap_or(0.0)
+    }
+
+    pub fn peak_time(&self) -> Option<f64> {
+        self.peak().map(|(t, _)| t)
+    }
+
+    /// Deviation at the end of the run, nonzero for loops without integral
+    /// action.
+    pub fn final_deviation(&self) -> f64 {
+        self.deviation.last().copied().unwrap_or(0.0)
+    }
+
+    /// Integral of the absolute deviation.
+    pub fn iae(&self) -> f64 {
+        let mut last_time = 0.0;
+        let mut iae = 0.0;
+        for (t, d) in self.time.iter().zip(&self.deviation) {
+            iae += d.abs() * (t - last_time);
+            last_time = *t;
+        }
+        iae
+    }
+
+    /// Time after which the deviation stays within 2% of the disturbance
+    /// magnitude.
+    pub fn recovery_time(&self) -> Option<f64> {
+        self.recovery_time_within(0.02)
+    }
+
+    /// Time after which the deviation stays within `band` times the
+    /// disturbance magnitude, or `None` if it does not recover within the
+    /// simulated duration.
+    pub fn recovery_time_within(&self, band: f64) -> Option<f64> {
+        let band = band * self.magnitude.abs();
+        match self.deviation.iter().rposition(|d| d.abs() > band) {
+            None => Some(0.0),
+            Some(last) if last + 1 < self.time.len() => Some(self.time[last + 1]),
+            Some(_) => None,
+        }
+    }
+
+    fn peak(&self) -> Option<(f64, f64)> {
+        self.time
+            .iter()
+            .zip(&self.deviation)
+            .map(|(t, d)| (*t, *d))
+            .max_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::disturbance_response;
+
+    #[test]
+    fn test_disturbance_response_recovers_with_integral_action() {
+        let plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+
+        let pi = disturbance_response(&PID::new(4.0, 4.0, 0.0), &plant, 1.0, 10.0, 0.01);
+        assert!(pi.peak_deviation() > 0.1 && pi.peak_deviation() < 0.2);
+        assert!(pi.peak_time().unwrap() < 1.0);
+        assert!(pi.final_deviation().abs() < 1e-3);
+        let recovery = pi.recovery_time().unwrap();
+        assert!(recovery > 0.5 && recovery < 5.0);
+        assert!(pi.iae() > 0.0);
+
+        // Proportional control only: the offset is d / (1 + kp).
+        let p = disturbance_response(&PID::new(4.0, 0.0, 0.0), &plant, 1.0, 10.0, 0.01);
+        assert!((p.final_deviation() - 0.2).abs() < 1e-3);
+        assert_eq!(p.recovery_time(), None);
+    }
+}
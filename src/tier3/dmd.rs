@@ -0,0 +1,244 @@
+use crate::linalg::dense::svd;
+use crate::prelude::DSS;
+use alloc::vec::Vec;
+use faer::{Mat, MatRef, c64};
+
+/// Dynamic Mode Decomposition: fits the linear map `x[k+1] = A x[k]`, or
+/// `x[k+1] = A x[k] + B u[k]` with control (DMDc), that best explains
+/// logged trajectories, a data-driven linearization of nonlinear dynamics.
+///
+/// Snapshots hold one sample per column, `n` measured (or lifted, Koopman
+/// style) states by `m + 1` samples taken every `dt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dmd<'a> {
+    snapshots: MatRef<'a, f64>,
+    inputs: Option<&'a [f64]>,
+    rank: Option<usize>,
+}
+
+/// Linear model identified by [`Dmd`], in the coordinates of its POD basis
+/// when truncated, or of the measured states otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DmdModel {
+    a: Mat<f64>,
+    b: Mat<f64>,
+    basis: Mat<f64>,
+    singular_values: Vec<f64>,
+}
+
+/// Fits the autonomous dynamics of `snapshots`, see [`Dmd`].
+pub fn fit(snapshots: MatRef<'_, f64>) -> DmdModel {
+    Dmd::new(snapshots).fit()
+}
+
+impl<'a> Dmd<'a> {
+    pub fn new(snapshots: MatRef<'a, f64>) -> Self {
+        assert!(snapshots.ncols() > 1, "DMD needs at least two snapshots");
+        Self {
+            snapshots,
+            inputs: None,
+            rank: None,
+        }
+    }
+
+    /// Input applied at each snapshot, at least one per transition, for DMDc.
+    pub fn with_inputs(mut self, inputs: &'a [f64]) -> Self {
+        assert!(
+            inputs.len() + 1 >= self.snapshots.ncols(),
+            "DMDc needs one input per transition"
+        );
+        self.inputs = Some(inputs);
+        self
+    }
+
+    /// Keeps only the `rank` dominant POD modes, a reduced-order model that
+    /// also filters measurement noise. By default the numerical rank of the
+    /// data is kept and no reduction is done.
+    pub fn with_rank(mut self, rank: usize) -> Self {
+        assert!(rank > 0, "Rank must be positive");
+        self.rank = Some(rank);
+        self
+    }
+
+    pub fn fit(&self) -> DmdModel {
+        let n = self.snapshots.nrows();
+        let m = self.snapshots.ncols() - 1;
+        let x = self.snapshots.subcols(0, m);
+        let x_next = self.snapshots.subcols(1, m);
+
+        let (a, b, (basis, singular_values)) = match self.inputs {
+            None => {
+                let (x_pinv, singular_values) = pinv(x, self.rank);
+                let a = x_next * &x_pinv;
+                let reduction = self.rank.filter(|r| *r < n).map(|r| pod(x, r));
+                (a, Mat::zeros(n, 1), (reduction, singular_values))
+            }
+            Some(inputs) => {
+                let omega =
+                    Mat::from_fn(n + 1, m, |i, j| if i < n { x[(i, j)] } else { inputs[j] });
+                let (omega_pinv, _) = pinv(omega.as_ref(), None);
+                let g = x_next * &omega_pinv;
+                let a = g.subcols(0, n).to_owned();
+                let b = g.subcols(n, 1).to_owned();
+                let singular_values = singular_values(x_next);
+                let reduction = self.rank.filter(|r| *r < n).map(|r| pod(x_next, r));
+                (a, b, (reduction, singular_values))
+            }
+        };
+
+        match basis {
+            Some(basis) => DmdModel {
+                a: basis.transpose() * &a * &basis,
+                b: basis.transpose() * &b,
+                basis,
+                singular_values,
+            },
+            None => DmdModel {
+                a,
+                b,
+                basis: Mat::identity(n, n),
+                singular_values,
+            },
+        }
+    }
+}
+
+impl DmdModel {
+    pub fn a(&self) -> &Mat<f64> {
+        &self.a
+    }
+
+    /// Input matrix, zero for a fit without inputs.
+    pub fn b(&self) -> &Mat<f64> {
+        &self.b
+    }
+
+    /// Columns mapping model states to measured states, `x = basis z`.
+    pub fn basis(&self) -> &Mat<f64> {
+        &self.basis
+    }
+
+    /// Singular values of the snapshots, showing how many modes the data
+    /// supports.
+    pub fn singular_values(&self) -> &[f64] {
+        &self.singular_values
+    }
+
+    /// Discrete-time eigenvalues of the identified dynamics.
+    pub fn eigenvalues(&self) -> Vec<c64> {
+        self.a
+            .eigenvalues()
+            .expect("Eigenvalues of A did not converge")
+    }
+
+    /// Model state for a measured state `x`.
+    pub fn reduce(&self, x: &Mat<f64>) -> Mat<f64> {
+        self.basis.transpose() * x
+    }
+
+    /// Discrete state-space model whose output is measured state `output`,
+    /// stepped once per snapshot interval.
+    pub fn to_dss(&self, output: usize) -> DSS<f64> {
+        let c = self.basis.subrows(output, 1).to_owned();
+        DSS::new(self.a.clone(), self.b.clone(), c, 0.0)
+    }
+}
+
+fn singular_values(x: MatRef<'_, f64>) -> Vec<f64> {
+    svd(x).1
+}
+
+/// Leading `rank` left singular vectors of `x`.
+fn pod(x: MatRef<'_, f64>, rank: usize) -> Mat<f64> {
+    let (u, _, _) = svd(x);
+    u.subcols(0, rank.min(u.ncols())).to_owned()
+}
+
+/// Pseudo-inverse keeping `rank` singular values, or those above the
+/// numerical tolerance, and the singular values of `x`.
+fn pinv(x: MatRef<'_, f64>, rank: Option<usize>) -> (Mat<f64>, Vec<f64>) {
+    let (u, s, v) = svd(x);
+
+    let tolerance =
+        s.first().copied().unwrap_or(0.0) * f64::EPSILON * x.nrows().max(x.ncols()) as f64;
+    let kept = s
+        .iter()
+        .take(rank.unwrap_or(s.len()))
+        .take_while(|s| **s > tolerance)
+        .count();
+
+    let u = u.subcols(0, kept);
+    let v = v.subcols(0, kept);
+    let v_scaled = Mat::from_fn(v.nrows(), kept, |i, j| v[(i, j)] / s[j]);
+    (v_scaled * u.transpose(), s)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::dmd::{Dmd, fit};
+
+    fn simulate(a: &Mat<f64>, b: &Mat<f64>, x0: Mat<f64>, inputs: &[f64]) -> Mat<f64> {
+        let n = a.nrows();
+        let mut snapshots = Mat::zeros(n, inputs.len() + 1);
+        let mut x = x0;
+        for (k, u) in inputs.iter().enumerate() {
+            snapshots.col_mut(k).copy_from(x.col(0));
+            x = a * &x + b * u;
+        }
+        snapshots.col_mut(inputs.len()).copy_from(x.col(0));
+        snapshots
+    }
+
+    #[test]
+    fn test_dmdc_recovers_linear_system() {
+        let a = mat![[0.9, 0.1], [-0.2, 0.8]];
+        let b = mat![[0.0], [0.5]];
+        let inputs = (0..40)
+            .map(|k| libm::sin(0.7 * k as f64) + if k % 3 == 0 { 1.0 } else { -0.5 })
+            .collect::<alloc::vec::Vec<_>>();
+        let snapshots = simulate(&a, &b, mat![[1.0], [0.0]], &inputs);
+
+        let model = Dmd::new(snapshots.as_ref()).with_inputs(&inputs).fit();
+        assert!((model.a() - &a).norm_l2() < 1e-9);
+        assert!((model.b() - &b).norm_l2() < 1e-9);
+
+        // The DSS replays the logged trajectory.
+        let mut dss = model.to_dss(1).with_initial_state(mat![[1.0], [0.0]]);
+        for (u, sim_state) in inputs.iter().zip(Simulation::new(1.0, 100.0)) {
+            let _ = (*u).as_signal(sim_state) >> dss.as_block();
+        }
+        let last = snapshots.col(inputs.len());
+        assert!((dss.state().col(0) - last).norm_l2() < 1e-9);
+    }
+
+    #[test]
+    fn test_dmd_finds_modes_of_low_rank_data() {
+        // Damped rotation embedded in four measured states.
+        let (r, theta) = (0.95, 0.3);
+        let z = mat![
+            [r * libm::cos(theta), -r * libm::sin(theta)],
+            [r * libm::sin(theta), r * libm::cos(theta)]
+        ];
+        let embed = mat![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [2.0, -1.0]];
+        let latent = simulate(&z, &Mat::zeros(2, 1), mat![[1.0], [0.0]], &[0.0; 30]);
+        let snapshots = &embed * &latent;
+
+        let model = Dmd::new(snapshots.as_ref()).with_rank(2).fit();
+        assert_eq!(model.a().nrows(), 2);
+        assert!(model.singular_values()[2] < 1e-9 * model.singular_values()[0]);
+        for eigenvalue in model.eigenvalues() {
+            assert!((libm::hypot(eigenvalue.re, eigenvalue.im) - r).abs() < 1e-9);
+            assert!((libm::atan2(eigenvalue.im, eigenvalue.re).abs() - theta).abs() < 1e-9);
+        }
+
+        // Full-order fit of the same data keeps the two modes.
+        let full = fit(snapshots.as_ref());
+        let dominant = full
+            .eigenvalues()
+            .iter()
+            .filter(|e| libm::hypot(e.re, e.im) > 0.5)
+            .count();
+        assert_eq!(dominant, 2);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    block::Block,
+    prelude::{EndlessSimulation, Metric},
+};
+
+/// Reinforcement-learning environment around a single-input single-output
+/// plant, with the `reset` / `step` semantics of Gym.
+///
+/// The observation is `[reference, output, error]`. The reward of a step is
+/// minus the increase of `metric` over the tracking error, so use an
+/// `Integral` or `Sum` accumulation, e.g. `ISE` for a quadratic cost. An
+/// episode ends after `max_steps` steps, or early when the output leaves the
+/// allowed range.
+#[derive(Debug, Clone)]
+pub struct ControlEnv<P, M> {
+    plant: P,
+    metric: M,
+    dt: f32,
+    simulation: EndlessSimulation,
+    reference: f64,
+    max_steps: usize,
+    steps: usize,
+    action_limits: Option<(f64, f64)>,
+    action_penalty: f64,
+    output_limit: Option<(f64, f64)>,
+    output: f64,
+}
+
+impl<P, M> ControlEnv<P, M>
+where
+    P: Block<Input = f64, Output = f64>,
+    M: Metric<f64> + Block<Input = f64>,
+{
+    pub fn new(plant: P, metric: M, dt: f32, max_steps: usize) -> Self {
+        let output = plant.last_output().unwrap_or(0.0);
+        Self {
+            plant,
+            metric,
+            dt,
+            simulation: EndlessSimulation::new(dt),
+            reference: 1.0,
+            max_steps,
+            steps: 0,
+            action_limits: None,
+            action_penalty: 0.0,
+            output_limit: None,
+            output,
+        }
+    }
+
+    /// Setpoint to track, one by default.
+    pub fn with_reference(mut self, reference: f64) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Clips actions to `[min, max]`, like an actuator.
+    pub fn with_action_limits(mut self, min: f64, max: f64) -> Self {
+        self.action_limits = Some((min, max));
+        self
+    }
+
+    /// Subtracts `weight * u^2 * dt` from every reward, penalizing effort.
+    pub fn with_action_penalty(mut self, weight: f64) -> Self {
+        self.action_penalty = weight;
+        self
+    }
+
+    /// Ends the episode, with a reward of `-penalty`, when the output leaves
+    /// `[-limit, limit]`.
+    pub fn with_output_limit(mut self, limit: f64, penalty: f64) -> Self {
+        self.output_limit = Some((limit, penalty));
+        self
+    }
+
+    /// Changes the setpoint, e.g. to a random one before each episode.
+    pub fn set_reference(&mut self, reference: f64) {
+        self.reference = reference;
+    }
+
+    pub fn plant(&self) -> &P {
+        &self.plant
+    }
+
+    /// Steps taken in the current episode.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Starts a new episode and returns its first observation.
+    pub fn reset(&mut self) -> [f64; 3] {
+        self.plant.reset();
+        self.metric.reset();
+        self.simulation = EndlessSimulation::new(self.dt);
+        self.steps = 0;
+        self.output = self.plant.last_output().unwrap_or(0.0);
+        self.observation()
+    }
+
+    /// Applies `action` for one sample and returns the observation, the
+    /// reward and whether the episode is over.
+    pub fn step(&mut self, action: f64) -> ([f64; 3], f64, bool) {
+        let action = match self.action_limits {
+            Some((min, max)) => action.clamp(min, max),
+            None => action,
+        };
+        let sim_state = self
+            .simulation
+            .next()
+            .expect("Endless simulation never ends");
+
+        self.output = crate::block::run(&mut self.plant, action, sim_state);
+        self.steps += 1;
+
+        let cost_before = self.metric.value();
+        crate::block::run(&mut self.metric, self.reference - self.output, sim_state);
+        let mut reward = cost_before
+            - self.metric.value()
+            - self.action_penalty * action * action * sim_state.dt().as_secs_f64();
+
+        let mut done = self.steps >= self.max_steps;
+        if let Some((limit, penalty)) = self.output_limit
+            && (self.output.abs() > limit || self.output.is_nan())
+        {
+            reward = -penalty;
+            done = true;
+        }
+
+        (self.observation(), reward, done)
+    }
+
+    fn observation(&self) -> [f64; 3] {
+        [self.reference, self.output, self.reference - self.output]
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::env::ControlEnv;
+
+    fn episode(
+        env: &mut ControlEnv<SS<RK4, f64>, ISE<f64>>,
+        policy: impl Fn([f64; 3]) -> f64,
+    ) -> (f64, usize) {
+        let mut observation = env.reset();
+        let mut total = 0.0;
+        loop {
+            let (next, reward, done) = env.step(policy(observation));
+            total += reward;
+            observation = next;
+            if done {
+                return (total, env.steps());
+            }
+        }
+    }
+
+    #[test]
+    fn test_control_env_rewards_better_policies() {
+        let plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let ise = ISE::default().with_accumulation(Accumulation::Integral);
+        let mut env = ControlEnv::new(plant, ise, 0.01, 500)
+            .with_reference(1.0)
+            .with_action_limits(-10.0, 10.0)
+            .with_output_limit(5.0, 100.0);
+
+        let (idle, steps) = episode(&mut env, |_| 0.0);
+        assert_eq!(steps, 500);
+        assert!((idle + 5.0).abs() < 0.02);
+
+        let (proportional, _) = episode(&mut env, |[_, _, error]| 5.0 * error);
+        assert!(proportional > idle);
+        assert_eq!(env.reset(), [1.0, 0.0, 1.0]);
+
+        // Saturated positive feedback drives the output out of range.
+        let (runaway, steps) = episode(&mut env, |[_, output, _]| 10.0 + output);
+        assert!(steps < 500);
+        assert!(runaway < -100.0);
+    }
+}
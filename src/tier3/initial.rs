@@ -0,0 +1,233 @@
+use crate::{
+    block::Block,
+    continuous::ss::SS,
+    prelude::{HasState, Simulation, Solver},
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use faer::{Mat, c64};
+
+/// Zero-input response of a continuous-time model from a given initial
+/// state, e.g. to check how fast an observer error or an LQR loop decays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitialResponse {
+    dt: f32,
+    duration: f32,
+    settling_band: f64,
+}
+
+/// Mode of `A`, i.e. one of its eigenvalues, with its time constant and
+/// damping ratio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mode {
+    pub pole: c64,
+    /// `-1 / Re(pole)`, infinite for modes that do not decay.
+    pub time_constant: f64,
+    /// `-Re(pole) / |pole|`, one for real poles.
+    pub damping: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InitialResponseReport {
+    time: Vec<f64>,
+    output: Vec<f64>,
+    state_norm: Vec<f64>,
+    settling_band: f64,
+    modes: Vec<Mode>,
+}
+
+impl InitialResponse {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            dt: 1e-3,
+            duration,
+            settling_band: 0.02,
+        }
+    }
+
+    pub fn with_dt(mut self, dt: f32) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    /// Fraction of the initial state norm the response must stay within to
+    /// count as settled, 2% by default.
+    pub fn with_settling_band(mut self, band: f64) -> Self {
+        self.settling_band = band;
+        self
+    }
+
+    pub fn run<I>(&self, model: &SS<I, f64>, initial_state: Mat<f64>) -> InitialResponseReport
+    where
+        I: Solver<f64> + Debug + Clone,
+    {
+        let mut model = model.clone().with_initial_state(initial_state.clone());
+        let n = model.order();
+
+        let mut time = Vec::new();
+        let mut output = Vec::new();
+        let mut state_norm = Vec::new();
+
+        time.push(0.0);
+        output.push(model.matrices().2.row(0) * initial_state.col(0));
+        state_norm.push(initial_state.norm_l2());
+
+        for sim_state in Simulation::new(self.dt, self.duration) {
+            time.push(sim_state.sim_time().as_secs_f64());
+            output.push(model.block(0.0, sim_state));
+            state_norm.push(libm::sqrt(
+                (0..n).map(|i| model.state_at(i) * model.state_at(i)).sum(),
+            ));
+        }
+
+        InitialResponseReport {
+            time,
+            output,
+            state_norm,
+            settling_band: self.settling_band,
+            modes: modes(model.matrices().0),
+        }
+    }
+}
+
+impl InitialResponseReport {
+    pub fn time(&self) -> &[f64] {
+        &self.time
+    }
+
+    pub fn output(&self) -> &[f64] {
+        &self.output
+    }
+
+    /// Euclidean norm of the state vector at each sample.
+    pub fn state_norm(&self) -> &[f64] {
+        &self.state_norm
+    }
+
+    /// Largest absolute output.
+    pub fn peak(&self) -> f64 {
+        self.output.iter().fold(0.0, |acc, y| acc.max(y.abs()))
+    }
+
+    /// First time after which the state norm stays within the settling band,
+    /// or `None` if it does not settle within the simulated duration.
+    pub fn settling_time(&self) -> Option<f64> {
+        let band = self.settling_band * self.state_norm[0];
+        match self.state_norm.iter().rposition(|norm| *norm > band) {
+            None => Some(0.0),
+            Some(last) if last + 1 < self.time.len() => Some(self.time[last + 1]),
+            Some(_) => None,
+        }
+    }
+
+    /// Exponential decay rate of the state norm, from a least-squares fit of
+    /// its logarithm over time. Negative when the response grows.
+    pub fn decay_rate(&self) -> f64 {
+        let samples = self
+            .time
+            .iter()
+            .zip(&self.state_norm)
+            .filter(|(_, norm)| **norm > f64::MIN_POSITIVE)
+            .map(|(t, norm)| (*t, libm::log(*norm)));
+
+        let (mut n, mut st, mut sl, mut stt, mut stl) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for (t, l) in samples {
+            n += 1.0;
+            st += t;
+            sl += l;
+            stt += t * t;
+            stl += t * l;
+        }
+
+        let denominator = n * stt - st * st;
+        if denominator == 0.0 {
+            return 0.0;
+        }
+        -(n * stl - st * sl) / denominator
+    }
+
+    /// Modes of the model, slowest first.
+    pub fn modes(&self) -> &[Mode] {
+        &self.modes
+    }
+
+    /// Slowest mode, the one dominating the tail of the response.
+    pub fn dominant_mode(&self) -> Option<Mode> {
+        self.modes.first().copied()
+    }
+}
+
+fn modes(a: &Mat<f64>) -> Vec<Mode> {
+    if a.nrows() == 0 {
+        return Vec::new();
+    }
+
+    let mut modes = a
+        .eigenvalues()
+        .expect("Eigenvalues of A did not converge")
+        .into_iter()
+        .map(|pole| {
+            let magnitude = libm::hypot(pole.re, pole.im);
+            Mode {
+                pole,
+                time_constant: if pole.re < 0.0 {
+                    -1.0 / pole.re
+                } else {
+                    f64::INFINITY
+                },
+                damping: if magnitude == 0.0 {
+                    0.0
+                } else {
+                    -pole.re / magnitude
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+    modes.sort_by(|a, b| b.pole.re.total_cmp(&a.pole.re));
+    modes
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::InitialResponse;
+
+    #[test]
+    fn test_initial_response_of_two_pole_system() {
+        // Poles at -1 and -5.
+        let a = mat![[0.0, 1.0], [-5.0, -6.0]];
+        let b = mat![[0.0], [1.0]];
+        let c = mat![[1.0, 0.0]];
+        let model = SS::<RK4, f64>::new(a, b, c, 0.0);
+
+        let report = InitialResponse::new(10.0)
+            .with_dt(0.01)
+            .run(&model, mat![[1.0], [0.0]]);
+
+        assert_eq!(report.output()[0], 1.0);
+        assert!(report.peak() >= 1.0 - 1e-9);
+        assert!(report.output().last().unwrap().abs() < 1e-3);
+
+        let dominant = report.dominant_mode().unwrap();
+        assert!((dominant.pole.re + 1.0).abs() < 1e-9);
+        assert!((dominant.time_constant - 1.0).abs() < 1e-9);
+        assert_eq!(dominant.damping, 1.0);
+        assert_eq!(report.modes().len(), 2);
+
+        // The tail decays with the slow pole; the fast one only bends the
+        // start of the fit.
+        assert!((report.decay_rate() - 1.0).abs() < 0.15);
+        let settling = report.settling_time().unwrap();
+        assert!(settling > 3.0 && settling < 5.0);
+    }
+
+    #[test]
+    fn test_initial_response_does_not_settle_when_unstable() {
+        let model = SS::<RK4, f64>::new(mat![[0.5]], mat![[1.0]], mat![[1.0]], 0.0);
+        let report = InitialResponse::new(2.0).run(&model, mat![[1.0]]);
+
+        assert_eq!(report.settling_time(), None);
+        assert!(report.decay_rate() < 0.0);
+        assert!(report.dominant_mode().unwrap().time_constant.is_infinite());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::continuous::Tf;
+use alloc::vec::Vec;
+use faer::c64;
+
+/// Loop-shaping template: a target gain crossover, plus optional weights `Ws`
+/// and `Wt` requiring `|Ws S| < 1` and `|Wt T| < 1` at every frequency, with
+/// `S = 1 / (1 + L)` and `T = L / (1 + L)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopShape {
+    target_crossover: f64,
+    crossover_tolerance: f64,
+    sensitivity_weight: Option<Tf<f64>>,
+    complementary_weight: Option<Tf<f64>>,
+    frequencies: (f64, f64, usize),
+}
+
+/// Loop shape at one frequency, in absolute (not dB) magnitudes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopShapePoint {
+    pub omega: f64,
+    pub loop_gain: f64,
+    pub sensitivity: f64,
+    pub complementary: f64,
+    /// `1 / |Ws|`, or infinity without a sensitivity weight.
+    pub sensitivity_bound: f64,
+    /// `1 / |Wt|`, or infinity without a complementary weight.
+    pub complementary_bound: f64,
+}
+
+impl LoopShapePoint {
+    pub fn passes(&self) -> bool {
+        self.sensitivity < self.sensitivity_bound && self.complementary < self.complementary_bound
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopShapeReport {
+    points: Vec<LoopShapePoint>,
+    crossover: Option<f64>,
+    crossover_ok: bool,
+}
+
+impl LoopShape {
+    /// Template around a target crossover in rad/s, checked by default over
+    /// three decades either side of it.
+    pub fn new(target_crossover: f64) -> Self {
+        assert!(
+            target_crossover > 0.0,
+            "Target crossover must be greater than zero"
+        );
+
+        Self {
+            target_crossover,
+            crossover_tolerance: 0.2,
+            sensitivity_weight: None,
+            complementary_weight: None,
+            frequencies: (target_crossover * 1e-3, target_crossover * 1e3, 600),
+        }
+    }
+
+    /// Accepted relative deviation of the achieved crossover from the target.
+    pub fn with_crossover_tolerance(mut self, tolerance: f64) -> Self {
+        self.crossover_tolerance = tolerance;
+        self
+    }
+
+    pub fn with_sensitivity_weight(mut self, weight: Tf<f64>) -> Self {
+        self.sensitivity_weight = Some(weight);
+        self
+    }
+
+    pub fn with_complementary_weight(mut self, weight: Tf<f64>) -> Self {
+        self.complementary_weight = Some(weight);
+        self
+    }
+
+    /// Logarithmic grid of `points` frequencies between `w_min` and `w_max`.
+    pub fn with_frequencies(mut self, w_min: f64, w_max: f64, points: usize) -> Self {
+        assert!(
+            0.0 < w_min && w_min < w_max,
+            "Frequency range must be positive and increasing"
+        );
+        assert!(points >= 2, "Frequency grid needs at least 2 points");
+
+        self.frequencies = (w_min, w_max, points);
+        self
+    }
+
+    pub fn check(&self, open_loop: &Tf<f64>) -> LoopShapeReport {
+        let (w_min, w_max, n) = self.frequencies;
+        let ratio = libm::pow(w_max / w_min, 1.0 / (n - 1) as f64);
+        let bound = |weight: &Option<Tf<f64>>, s: c64| {
+            weight
+                .as_ref()
+                .map(|weight| 1.0 / magnitude(weight.eval(s)))
+                .unwrap_or(f64::INFINITY)
+        };
+
+        let points = (0..n)
+            .map(|k| {
+                let omega = w_min * libm::pow(ratio, k as f64);
+                let s = c64::new(0.0, omega);
+                let l = open_loop.eval(s);
+                let one_plus_l = l + c64::new(1.0, 0.0);
+
+                LoopShapePoint {
+                    omega,
+                    loop_gain: magnitude(l),
+                    sensitivity: 1.0 / magnitude(one_plus_l),
+                    complementary: magnitude(l) / magnitude(one_plus_l),
+                    sensitivity_bound: bound(&self.sensitivity_weight, s),
+                    complementary_bound: bound(&self.complementary_weight, s),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let crossover = points.windows(2).find_map(|pair| {
+            let (a, b) = (pair[0], pair[1]);
+            (a.loop_gain >= 1.0 && b.loop_gain < 1.0).then(|| {
+                // Interpolate on the log-log plot.
+                let (ga, gb) = (libm::log(a.loop_gain), libm::log(b.loop_gain));
+                let t = ga / (ga - gb);
+                a.omega * libm::pow(b.omega / a.omega, t)
+            })
+        });
+        let crossover_ok = crossover.is_some_and(|wc| {
+            (wc - self.target_crossover).abs() <= self.crossover_tolerance * self.target_crossover
+        });
+
+        LoopShapeReport {
+            points,
+            crossover,
+            crossover_ok,
+        }
+    }
+}
+
+impl LoopShapeReport {
+    /// Per-frequency data, e.g. to plot `|S|` and `|T|` against their bounds.
+    pub fn points(&self) -> &[LoopShapePoint] {
+        &self.points
+    }
+
+    /// First frequency where `|L|` falls below one.
+    pub fn crossover(&self) -> Option<f64> {
+        self.crossover
+    }
+
+    pub fn crossover_ok(&self) -> bool {
+        self.crossover_ok
+    }
+
+    /// Frequency bands `(from, to)` where the template is violated.
+    pub fn violations(&self) -> Vec<(f64, f64)> {
+        let mut bands: Vec<(f64, f64)> = Vec::new();
+        let mut in_band = false;
+        for point in &self.points {
+            match (point.passes(), in_band) {
+                (false, true) => bands.last_mut().unwrap().1 = point.omega,
+                (false, false) => bands.push((point.omega, point.omega)),
+                _ => {}
+            }
+            in_band = !point.passes();
+        }
+        bands
+    }
+
+    pub fn passes(&self) -> bool {
+        self.crossover_ok && self.points.iter().all(LoopShapePoint::passes)
+    }
+}
+
+fn magnitude(value: c64) -> f64 {
+    libm::hypot(value.re, value.im)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loopshape_checks_template() {
+        // L = 10 / (s (s + 10)) crosses over close to 1 rad/s.
+        let open_loop = Tf::new(&[10.0], &[1.0, 10.0, 0.0]);
+        // |S| must stay below 2 everywhere and below s/0.5 at low frequency.
+        let ws = Tf::new(&[0.5, 0.25], &[1.0, 0.0]);
+
+        let report = LoopShape::new(1.0)
+            .with_sensitivity_weight(ws.clone())
+            .check(&open_loop);
+        assert!((report.crossover().unwrap() - 0.95).abs() < 0.05);
+        assert!(report.passes(), "{:?}", report.violations());
+
+        let report = LoopShape::new(10.0)
+            .with_sensitivity_weight(Tf::new(&[2.0, 0.25], &[1.0, 0.0]))
+            .check(&open_loop);
+        assert!(!report.crossover_ok());
+        // |S| tends to 1 at high frequency, above the 1/2 bound set by Ws.
+        let violations = report.violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].1, report.points().last().unwrap().omega);
+        assert!(!report.passes());
+    }
+}
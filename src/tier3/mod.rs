@@ -0,0 +1,67 @@
+#[cfg(feature = "alloc")]
+pub mod allan;
+#[cfg(feature = "alloc")]
+pub mod auto_notch;
+#[cfg(feature = "alloc")]
+pub mod deadbeat;
+pub mod delay_estimate;
+#[cfg(feature = "alloc")]
+pub mod disturbance_response;
+#[cfg(feature = "alloc")]
+pub mod dmd;
+pub mod env;
+#[cfg(feature = "alloc")]
+pub mod initial;
+#[cfg(feature = "alloc")]
+pub mod loopshape;
+#[cfg(feature = "alloc")]
+pub mod monte_carlo;
+#[cfg(feature = "alloc")]
+pub mod norms;
+#[cfg(feature = "alloc")]
+pub mod report;
+#[cfg(feature = "alloc")]
+pub mod run;
+#[cfg(feature = "alloc")]
+pub mod sensitivity;
+#[cfg(feature = "alloc")]
+pub mod steady_state;
+#[cfg(feature = "alloc")]
+pub mod surrogate;
+#[cfg(feature = "alloc")]
+pub mod sysid;
+#[cfg(feature = "alloc")]
+pub mod youla;
+
+#[cfg(feature = "alloc")]
+pub use allan::{AllanNoise, AllanVariance};
+#[cfg(feature = "alloc")]
+pub use auto_notch::{AutoNotch, Resonance};
+pub use delay_estimate::delay_estimate;
+#[cfg(feature = "alloc")]
+pub use disturbance_response::{DisturbanceReport, disturbance_response};
+#[cfg(feature = "alloc")]
+pub use dmd::{Dmd, DmdModel};
+pub use env::ControlEnv;
+#[cfg(feature = "alloc")]
+pub use initial::{InitialResponse, InitialResponseReport, Mode};
+#[cfg(feature = "alloc")]
+pub use loopshape::{LoopShape, LoopShapePoint, LoopShapeReport};
+#[cfg(feature = "alloc")]
+pub use monte_carlo::{MonteCarlo, Param, Sampling, Spread};
+#[cfg(feature = "alloc")]
+pub use norms::SystemNorms;
+#[cfg(feature = "alloc")]
+pub use report::{Report, Run, RunMetrics, compare};
+#[cfg(feature = "alloc")]
+pub use run::{Probes, Recordable, SimResult, Trace, simulate};
+#[cfg(feature = "alloc")]
+pub use sensitivity::{SensitivityReport, sensitivity};
+#[cfg(feature = "alloc")]
+pub use steady_state::{SteadyState, dc_analysis};
+#[cfg(feature = "alloc")]
+pub use surrogate::ResponseSurface;
+#[cfg(feature = "alloc")]
+pub use sysid::{MimoModel, N4sid};
+#[cfg(feature = "alloc")]
+pub use youla::{CoprimeFactors, is_internally_stable};
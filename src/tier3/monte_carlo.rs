@@ -0,0 +1,372 @@
+use crate::rng::SplitMix64;
+use alloc::vec::Vec;
+
+/// Distribution of an uncertain plant parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Param {
+    /// Uniform in `nominal * (1 +- tolerance)`, like a component rated `+-5%`.
+    Uniform { nominal: f64, tolerance: f64 },
+    /// Normal with mean `nominal` and standard deviation `nominal * sigma`.
+    Gaussian { nominal: f64, sigma: f64 },
+}
+
+impl Param {
+    pub fn uniform(nominal: f64, tolerance: f64) -> Self {
+        Param::Uniform { nominal, tolerance }
+    }
+
+    pub fn gaussian(nominal: f64, sigma: f64) -> Self {
+        Param::Gaussian { nominal, sigma }
+    }
+
+    pub fn nominal(&self) -> f64 {
+        match *self {
+            Param::Uniform { nominal, .. } | Param::Gaussian { nominal, .. } => nominal,
+        }
+    }
+
+    /// Extremes used by the worst-case corners; `+-3 sigma` for a Gaussian.
+    pub fn bounds(&self) -> (f64, f64) {
+        let (nominal, spread) = match *self {
+            Param::Uniform { nominal, tolerance } => (nominal, tolerance),
+            Param::Gaussian { nominal, sigma } => (nominal, 3.0 * sigma),
+        };
+        let (a, b) = (nominal * (1.0 - spread), nominal * (1.0 + spread));
+        (a.min(b), a.max(b))
+    }
+
+    /// Value at cumulative probability `u` in `(0, 1)`, mapping points of the
+    /// unit cube onto the parameter.
+    pub fn quantile(&self, u: f64) -> f64 {
+        match *self {
+            Param::Uniform { nominal, tolerance } => nominal * (1.0 + tolerance * (2.0 * u - 1.0)),
+            Param::Gaussian { nominal, sigma } => nominal * (1.0 + sigma * normal_quantile(u)),
+        }
+    }
+
+    fn sample(&self, rng: &mut SplitMix64) -> f64 {
+        match *self {
+            Param::Uniform { nominal, tolerance } => {
+                nominal * (1.0 + tolerance * (2.0 * rng.next_f64() - 1.0))
+            }
+            Param::Gaussian { nominal, sigma } => nominal * (1.0 + sigma * rng.next_gaussian()),
+        }
+    }
+}
+
+/// How the points of a [`MonteCarlo`] run cover the parameter space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Sampling {
+    /// Independent random draws.
+    #[default]
+    Random,
+    /// Latin hypercube: every parameter range is split in as many strata as
+    /// runs and each stratum is sampled exactly once.
+    LatinHypercube,
+    /// Sobol low-discrepancy sequence, for up to 16 parameters. Deterministic;
+    /// the seed is ignored.
+    Sobol,
+}
+
+/// Tolerance analysis and design-space exploration: evaluates a figure of
+/// merit over draws of the parameters, or over every worst-case corner of
+/// them.
+///
+/// Space-filling [`Sampling`] strategies cover the space far better than
+/// random draws or full grids once there are more than a few parameters.
+///
+/// The closure maps one set of parameter values, in the order given, to the
+/// result of interest, typically by building the `Tf`/`SS` of the loop and
+/// computing its margins or simulating its overshoot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarlo {
+    params: Vec<Param>,
+    runs: usize,
+    seed: u64,
+    sampling: Sampling,
+}
+
+impl MonteCarlo {
+    pub fn new(params: &[Param]) -> Self {
+        Self {
+            params: params.to_vec(),
+            runs: 1000,
+            seed: 0x5eed,
+            sampling: Sampling::Random,
+        }
+    }
+
+    pub fn with_runs(mut self, runs: usize) -> Self {
+        self.runs = runs;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    pub fn params(&self) -> &[Param] {
+        &self.params
+    }
+
+    pub fn nominal(&self) -> Vec<f64> {
+        self.params.iter().map(Param::nominal).collect()
+    }
+
+    pub fn run<R>(&self, f: impl FnMut(&[f64]) -> R) -> Vec<R> {
+        self.samples()
+            .iter()
+            .map(|values| values.as_slice())
+            .map(f)
+            .collect()
+    }
+
+    /// Parameter values of every run, in the order [`MonteCarlo::run`]
+    /// evaluates them.
+    pub fn samples(&self) -> Vec<Vec<f64>> {
+        let mut rng = SplitMix64::new(self.seed);
+        let n = self.params.len();
+
+        match self.sampling {
+            Sampling::Random => (0..self.runs)
+                .map(|_| self.params.iter().map(|p| p.sample(&mut rng)).collect())
+                .collect(),
+            Sampling::LatinHypercube => {
+                let mut samples = alloc::vec![Vec::with_capacity(n); self.runs];
+                let mut strata = (0..self.runs).collect::<Vec<_>>();
+                for param in &self.params {
+                    // Fisher-Yates shuffle of the strata.
+                    for i in (1..strata.len()).rev() {
+                        let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+                        strata.swap(i, j);
+                    }
+                    for (sample, stratum) in samples.iter_mut().zip(&strata) {
+                        // Keep away from 0 so Gaussian quantiles stay finite.
+                        let offset = rng.next_f64().max(f64::EPSILON);
+                        let u = (*stratum as f64 + offset) / self.runs as f64;
+                        sample.push(param.quantile(u));
+                    }
+                }
+                samples
+            }
+            Sampling::Sobol => sobol(self.runs, n)
+                .into_iter()
+                .map(|point| {
+                    self.params
+                        .iter()
+                        .zip(point)
+                        .map(|(p, u)| p.quantile(u))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Evaluates all `2^n` combinations of parameter bounds.
+    pub fn corners<R>(&self, mut f: impl FnMut(&[f64]) -> R) -> Vec<R> {
+        let n = self.params.len();
+        assert!(n < 20, "Too many parameters for a corner analysis");
+
+        let mut values = Vec::with_capacity(n);
+        (0..1usize << n)
+            .map(|mask| {
+                values.clear();
+                values.extend(self.params.iter().enumerate().map(|(i, p)| {
+                    let (low, high) = p.bounds();
+                    if mask & (1 << i) == 0 { low } else { high }
+                }));
+                f(&values)
+            })
+            .collect()
+    }
+}
+
+/// Direction numbers `(s, a, m_1..m_s)` of Joe and Kuo for the dimensions
+/// after the first.
+const SOBOL_DIRECTIONS: [(u32, u32, &[u32]); 15] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+];
+
+const SOBOL_BITS: usize = 32;
+
+/// First `count` points of the Sobol sequence in `dims` dimensions, skipping
+/// the origin.
+pub fn sobol(count: usize, dims: usize) -> Vec<Vec<f64>> {
+    assert!(
+        dims <= SOBOL_DIRECTIONS.len() + 1,
+        "Sobol sampling supports up to 16 parameters"
+    );
+    assert!(
+        count < 1 << SOBOL_BITS,
+        "Too many points for a 32-bit Sobol sequence"
+    );
+
+    let directions = (0..dims)
+        .map(|d| {
+            let mut v = [0u32; SOBOL_BITS];
+            if d == 0 {
+                for (k, v) in v.iter_mut().enumerate() {
+                    *v = 1 << (SOBOL_BITS - 1 - k);
+                }
+                return v;
+            }
+
+            let (s, a, m) = SOBOL_DIRECTIONS[d - 1];
+            let s = s as usize;
+            for k in 0..SOBOL_BITS {
+                v[k] = if k < s {
+                    m[k] << (SOBOL_BITS - 1 - k)
+                } else {
+                    let mut value = v[k - s] ^ (v[k - s] >> s);
+                    for i in 1..s {
+                        if (a >> (s - 1 - i)) & 1 == 1 {
+                            value ^= v[k - i];
+                        }
+                    }
+                    value
+                };
+            }
+            v
+        })
+        .collect::<Vec<_>>();
+
+    let scale = 1.0 / (1u64 << SOBOL_BITS) as f64;
+    let mut x = alloc::vec![0u32; dims];
+    (1..=count)
+        .map(|index| {
+            // Gray code order: flip the direction of the lowest zero bit.
+            let bit = (index - 1).trailing_ones() as usize;
+            for (x, v) in x.iter_mut().zip(&directions) {
+                *x ^= v[bit];
+            }
+            x.iter().map(|x| *x as f64 * scale).collect()
+        })
+        .collect()
+}
+
+/// Inverse of the standard normal CDF, by Acklam's rational approximation
+/// (relative error below `1.2e-9`).
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    if p <= 0.0 {
+        f64::NEG_INFINITY
+    } else if p >= 1.0 {
+        f64::INFINITY
+    } else if p < LOW {
+        tail(libm::sqrt(-2.0 * libm::log(p)))
+    } else if p > 1.0 - LOW {
+        -tail(libm::sqrt(-2.0 * libm::log(1.0 - p)))
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Summary of a set of scalar results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spread {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl Spread {
+    pub fn of(values: &[f64]) -> Self {
+        assert!(!values.is_empty(), "Spread of an empty set");
+
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n;
+
+        Self {
+            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            mean,
+            std_dev: libm::sqrt(variance),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rc_filter_tolerance() {
+        // Cutoff 1 / (R C) of an RC filter with 5% resistor and 10% capacitor.
+        let analysis = MonteCarlo::new(&[Param::uniform(1e3, 0.05), Param::uniform(1e-3, 0.1)]);
+        let cutoff = |values: &[f64]| {
+            let tf = Tf::new(&[1.0], &[values[0] * values[1], 1.0]);
+            let pole = tf.denominator().coeff();
+            pole[1] / pole[0]
+        };
+
+        let corners = Spread::of(&analysis.corners(cutoff));
+        let random = Spread::of(&analysis.run(cutoff));
+
+        assert!((corners.min - 1.0 / (1.05 * 1.1)).abs() < 1e-9);
+        assert!((corners.max - 1.0 / (0.95 * 0.9)).abs() < 1e-9);
+        assert!(random.min >= corners.min && random.max <= corners.max);
+        assert!((random.mean - 1.0).abs() < 0.02);
+        assert_eq!(analysis.run(cutoff), analysis.run(cutoff));
+    }
+}
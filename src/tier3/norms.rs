@@ -0,0 +1,166 @@
+use crate::{
+    continuous::{Tf, ss::SS},
+    prelude::{Euler, Solver},
+};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use faer::{Mat, c64, linalg::solvers::Solve};
+
+/// System norms of a stable continuous-time SISO model. Both norms are
+/// infinite for unstable models.
+pub trait SystemNorms {
+    /// Peak gain over frequency, `sup |H(jw)|`, found by a logarithmic sweep
+    /// refined with a golden-section search around the largest sample.
+    fn hinf_norm(&self) -> f64;
+
+    /// Root of the energy of the impulse response, from the controllability
+    /// Gramian. Infinite with a direct feedthrough term.
+    fn h2_norm(&self) -> f64;
+}
+
+impl<I> SystemNorms for SS<I, f64>
+where
+    I: Solver<f64> + Debug,
+{
+    fn hinf_norm(&self) -> f64 {
+        let (a, b, c, d) = self.matrices();
+        hinf_norm(a, b, c, d)
+    }
+
+    fn h2_norm(&self) -> f64 {
+        let (a, b, c, d) = self.matrices();
+        h2_norm(a, b, c, d)
+    }
+}
+
+impl SystemNorms for Tf<f64> {
+    fn hinf_norm(&self) -> f64 {
+        self.clone().to_ss_controllable(Euler).hinf_norm()
+    }
+
+    fn h2_norm(&self) -> f64 {
+        self.clone().to_ss_controllable(Euler).h2_norm()
+    }
+}
+
+/// Eigenvalues of `a`, or `None` if any lies in the closed right half-plane.
+fn stable_poles(a: &Mat<f64>) -> Option<Vec<c64>> {
+    if a.nrows() == 0 {
+        return Some(Vec::new());
+    }
+
+    let poles = a.eigenvalues().expect("Eigenvalues of A did not converge");
+    poles.iter().all(|p| p.re < 0.0).then_some(poles)
+}
+
+fn gain(a: &Mat<f64>, b: &Mat<f64>, c: &Mat<f64>, d: f64, omega: f64) -> f64 {
+    let n = a.nrows();
+    if n == 0 {
+        return d.abs();
+    }
+
+    let jw_minus_a = Mat::from_fn(n, n, |i, j| {
+        let diagonal = if i == j { omega } else { 0.0 };
+        c64::new(-a[(i, j)], diagonal)
+    });
+    let b = Mat::from_fn(n, 1, |i, _| c64::new(b[(i, 0)], 0.0));
+    let x = jw_minus_a.partial_piv_lu().solve(&b);
+
+    let h = (0..n).fold(c64::new(d, 0.0), |acc, i| acc + x[(i, 0)] * c[(0, i)]);
+    libm::hypot(h.re, h.im)
+}
+
+fn hinf_norm(a: &Mat<f64>, b: &Mat<f64>, c: &Mat<f64>, d: f64) -> f64 {
+    let Some(poles) = stable_poles(a) else {
+        return f64::INFINITY;
+    };
+    if poles.is_empty() {
+        return d.abs();
+    }
+
+    let magnitudes = poles.iter().map(|p| libm::hypot(p.re, p.im));
+    let w_min = magnitudes.clone().fold(f64::INFINITY, f64::min) * 1e-3;
+    let w_max = magnitudes.fold(0.0, f64::max) * 1e3;
+
+    const POINTS: usize = 1000;
+    let ratio = libm::pow(w_max / w_min, 1.0 / (POINTS - 1) as f64);
+    let omegas = (0..POINTS)
+        .map(|k| w_min * libm::pow(ratio, k as f64))
+        .collect::<Vec<_>>();
+
+    let (peak, mut best) = omegas
+        .iter()
+        .map(|&w| gain(a, b, c, d, w))
+        .enumerate()
+        .fold((0, gain(a, b, c, d, 0.0)), |best, (k, g)| {
+            if g > best.1 { (k, g) } else { best }
+        });
+
+    let mut lo = omegas[peak.saturating_sub(1)];
+    let mut hi = omegas[(peak + 1).min(POINTS - 1)];
+    let golden = (libm::sqrt(5.0) - 1.0) / 2.0;
+    for _ in 0..80 {
+        let w1 = hi - golden * (hi - lo);
+        let w2 = lo + golden * (hi - lo);
+        let (g1, g2) = (gain(a, b, c, d, w1), gain(a, b, c, d, w2));
+        best = best.max(g1).max(g2);
+        if g1 > g2 {
+            hi = w2;
+        } else {
+            lo = w1;
+        }
+    }
+
+    best
+}
+
+fn h2_norm(a: &Mat<f64>, b: &Mat<f64>, c: &Mat<f64>, d: f64) -> f64 {
+    if stable_poles(a).is_none() || d != 0.0 {
+        return f64::INFINITY;
+    }
+
+    let n = a.nrows();
+    if n == 0 {
+        return 0.0;
+    }
+
+    // A P + P A^T + B B^T = 0 as (I (x) A + A (x) I) vec(P) = -vec(B B^T).
+    let kron = Mat::from_fn(n * n, n * n, |row, col| {
+        let (i, j) = (row % n, row / n);
+        let (k, l) = (col % n, col / n);
+        let left = if j == l { a[(i, k)] } else { 0.0 };
+        let right = if i == k { a[(j, l)] } else { 0.0 };
+        left + right
+    });
+    let rhs = Mat::from_fn(n * n, 1, |row, _| -b[(row % n, 0)] * b[(row / n, 0)]);
+    let p = kron.partial_piv_lu().solve(&rhs);
+
+    let energy = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| c[(0, i)] * p[(i + j * n, 0)] * c[(0, j)])
+        .sum::<f64>();
+    libm::sqrt(energy.max(0.0))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_norms_of_known_systems() {
+        let first_order = Tf::new(&[1.0], &[1.0, 1.0]);
+        assert!((first_order.hinf_norm() - 1.0).abs() < 1e-6);
+        assert!((first_order.h2_norm() - libm::sqrt(0.5)).abs() < 1e-9);
+
+        let zeta: f64 = 0.1;
+        let resonant = Tf::new(&[1.0], &[1.0, 2.0 * zeta, 1.0]);
+        let peak = 1.0 / (2.0 * zeta * libm::sqrt(1.0 - zeta * zeta));
+        assert!((resonant.hinf_norm() - peak).abs() < 1e-6);
+        // H2^2 = 1 / (4 zeta wn^3) for wn^2 / (s^2 + 2 zeta wn s + wn^2).
+        assert!((resonant.h2_norm() - libm::sqrt(1.0 / (4.0 * zeta))).abs() < 1e-9);
+
+        let unstable = Tf::new(&[1.0], &[1.0, -1.0]);
+        assert!(unstable.hinf_norm().is_infinite());
+        assert!(Tf::new(&[1.0, 0.0], &[1.0, 1.0]).h2_norm().is_infinite());
+    }
+}
@@ -0,0 +1,386 @@
+use crate::block::Block;
+use crate::prelude::SimulationState;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+const COLORS: [&str; 6] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b",
+];
+const WIDTH: f64 = 640.0;
+const HEIGHT: f64 = 320.0;
+const MARGIN: f64 = 40.0;
+
+/// Recorded closed-loop run of one controller variant. As a block it
+/// records `(reference, output, control)` each step.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Run {
+    time: Vec<f64>,
+    reference: Vec<f64>,
+    output: Vec<f64>,
+    control: Vec<f64>,
+}
+
+/// Performance of a [`Run`] tracking a step in the reference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunMetrics {
+    pub iae: f64,
+    pub ise: f64,
+    /// Percentage of the step the output goes past the final reference.
+    pub overshoot: f64,
+    /// Time after which the output stays within 2% of the step around the
+    /// final reference, `None` if it never settles.
+    pub settling_time: Option<f64>,
+    /// Integral of the absolute control signal.
+    pub control_effort: f64,
+}
+
+/// Side-by-side comparison of controller variants, with a metric table and
+/// plots of the output and control signals of every variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    variants: Vec<(String, Run, RunMetrics)>,
+}
+
+/// Compares named runs, e.g. `compare(&[("PID", run_a), ("MPC", run_b)])`.
+pub fn compare(runs: &[(&str, Run)]) -> Report {
+    Report {
+        variants: runs
+            .iter()
+            .map(|(name, run)| (name.to_string(), run.clone(), run.metrics()))
+            .collect(),
+    }
+}
+
+impl Run {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(
+        &mut self,
+        sim_state: SimulationState,
+        reference: f64,
+        output: f64,
+        control: f64,
+    ) {
+        self.time.push(sim_state.sim_time().as_secs_f64());
+        self.reference.push(reference);
+        self.output.push(output);
+        self.control.push(control);
+    }
+
+    pub fn time(&self) -> &[f64] {
+        &self.time
+    }
+
+    pub fn reference(&self) -> &[f64] {
+        &self.reference
+    }
+
+    pub fn output(&self) -> &[f64] {
+        &self.output
+    }
+
+    pub fn control(&self) -> &[f64] {
+        &self.control
+    }
+
+    pub fn metrics(&self) -> RunMetrics {
+        let mut iae = 0.0;
+        let mut ise = 0.0;
+        let mut control_effort = 0.0;
+        let mut last_time = 0.0;
+        for k in 0..self.time.len() {
+            let dt = self.time[k] - last_time;
+            let error = self.reference[k] - self.output[k];
+            iae += error.abs() * dt;
+            ise += error * error * dt;
+            control_effort += self.control[k].abs() * dt;
+            last_time = self.time[k];
+        }
+
+        let start = self.output.first().copied().unwrap_or(0.0);
+        let target = self.reference.last().copied().unwrap_or(0.0);
+        let step = target - start;
+
+        let overshoot = if step == 0.0 {
+            0.0
+        } else {
+            let past = self
+                .output
+                .iter()
+                .map(|y| (y - target) / step)
+                .fold(0.0, f64::max);
+            100.0 * past
+        };
+
+        let band = 0.02 * step.abs();
+        let settling_time = match self.output.iter().rposition(|y| (y - target).abs() > band) {
+            None => self.time.first().copied(),
+            Some(last) if last + 1 < self.time.len() => Some(self.time[last + 1]),
+            Some(_) => None,
+        };
+
+        RunMetrics {
+            iae,
+            ise,
+            overshoot,
+            settling_time,
+            control_effort,
+        }
+    }
+}
+
+impl Block for Run {
+    type Input = (f64, f64, f64);
+    type Output = (f64, f64, f64);
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let (reference, output, control) = input;
+        self.record(sim_state, reference, output, control);
+        input
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Report {
+    pub fn metrics(&self) -> impl Iterator<Item = (&str, &RunMetrics)> {
+        self.variants
+            .iter()
+            .map(|(name, _, metrics)| (name.as_str(), metrics))
+    }
+
+    /// Markdown table of the metrics, one row per variant.
+    pub fn table(&self) -> String {
+        let mut table = String::from(
+            "| Variant | IAE | ISE | Overshoot (%) | Settling time (s) | Control effort |\n\
+             |---|---|---|---|---|---|\n",
+        );
+        for (name, _, m) in &self.variants {
+            let settling = m
+                .settling_time
+                .map(|t| format!("{:.3}", t))
+                .unwrap_or_else(|| "-".to_string());
+            let _ = writeln!(
+                table,
+                "| {} | {:.4} | {:.4} | {:.2} | {} | {:.4} |",
+                name, m.iae, m.ise, m.overshoot, settling, m.control_effort
+            );
+        }
+        table
+    }
+
+    /// Outputs of every variant and the reference of the first, as SVG.
+    pub fn output_svg(&self) -> String {
+        let mut series = self
+            .variants
+            .iter()
+            .map(|(name, run, _)| (name.as_str(), run.time(), run.output()))
+            .collect::<Vec<_>>();
+        if let Some((_, run, _)) = self.variants.first() {
+            series.push(("reference", run.time(), run.reference()));
+        }
+        svg_plot("Output", &series)
+    }
+
+    /// Control signals of every variant, as SVG.
+    pub fn control_svg(&self) -> String {
+        let series = self
+            .variants
+            .iter()
+            .map(|(name, run, _)| (name.as_str(), run.time(), run.control()))
+            .collect::<Vec<_>>();
+        svg_plot("Control", &series)
+    }
+
+    /// Markdown report linking the plots as `output.svg` and `control.svg`.
+    pub fn to_markdown(&self) -> String {
+        format!(
+            "# Controller comparison\n\n{}\n![Output](output.svg)\n\n![Control](control.svg)\n",
+            self.table()
+        )
+    }
+
+    /// Self-contained HTML report with the plots inlined.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>Controller comparison</title></head>\n<body>\n<h1>Controller comparison</h1>\n<table border=\"1\" cellspacing=\"0\" cellpadding=\"4\">\n<tr><th>Variant</th><th>IAE</th><th>ISE</th><th>Overshoot (%)</th><th>Settling time (s)</th><th>Control effort</th></tr>\n",
+        );
+        for (name, _, m) in &self.variants {
+            let settling = m
+                .settling_time
+                .map(|t| format!("{:.3}", t))
+                .unwrap_or_else(|| "-".to_string());
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{:.4}</td><td>{:.4}</td><td>{:.2}</td><td>{}</td><td>{:.4}</td></tr>",
+                escape(name),
+                m.iae,
+                m.ise,
+                m.overshoot,
+                settling,
+                m.control_effort
+            );
+        }
+        let _ = write!(
+            html,
+            "</table>\n{}\n{}\n</body>\n</html>\n",
+            self.output_svg(),
+            self.control_svg()
+        );
+        html
+    }
+
+    /// Writes `report.md`, `report.html`, `output.svg` and `control.svg`
+    /// into `dir`, creating it if needed.
+    #[cfg(feature = "std")]
+    pub fn save(&self, dir: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join("report.md"), self.to_markdown())?;
+        std::fs::write(dir.join("report.html"), self.to_html())?;
+        std::fs::write(dir.join("output.svg"), self.output_svg())?;
+        std::fs::write(dir.join("control.svg"), self.control_svg())
+    }
+}
+
+fn svg_plot(title: &str, series: &[(&str, &[f64], &[f64])]) -> String {
+    let points = || {
+        series
+            .iter()
+            .flat_map(|(_, time, values)| time.iter().zip(values.iter()))
+            .filter(|(t, v)| t.is_finite() && v.is_finite())
+    };
+    let (t0, t1, low, high) = points().fold(
+        (
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(t0, t1, low, high), (t, v)| (t0.min(*t), t1.max(*t), low.min(*v), high.max(*v)),
+    );
+    let (t0, t1) = if t1 > t0 { (t0, t1) } else { (0.0, 1.0) };
+    let (low, high) = if high > low {
+        (low, high)
+    } else if high == low {
+        (low - 0.5, high + 0.5)
+    } else {
+        (0.0, 1.0)
+    };
+
+    let x = |t: f64| MARGIN + (t - t0) / (t1 - t0) * (WIDTH - 2.0 * MARGIN);
+    let y = |v: f64| HEIGHT - MARGIN - (v - low) / (high - low) * (HEIGHT - 2.0 * MARGIN);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n\
+         <rect width=\"{w}\" height=\"{h}\" fill=\"white\"/>\n\
+         <text x=\"{cx}\" y=\"20\" text-anchor=\"middle\" font-family=\"sans-serif\" font-size=\"14\">{title}</text>\n\
+         <rect x=\"{m}\" y=\"{m}\" width=\"{pw}\" height=\"{ph}\" fill=\"none\" stroke=\"#888\"/>\n\
+         <text x=\"{m}\" y=\"{ty}\" font-family=\"sans-serif\" font-size=\"10\">{t0:.2} s</text>\n\
+         <text x=\"{xr}\" y=\"{ty}\" text-anchor=\"end\" font-family=\"sans-serif\" font-size=\"10\">{t1:.2} s</text>\n\
+         <text x=\"4\" y=\"{m}\" font-family=\"sans-serif\" font-size=\"10\">{high:.3}</text>\n\
+         <text x=\"4\" y=\"{yb}\" font-family=\"sans-serif\" font-size=\"10\">{low:.3}</text>\n",
+        w = WIDTH,
+        h = HEIGHT,
+        cx = WIDTH / 2.0,
+        title = escape(title),
+        m = MARGIN,
+        pw = WIDTH - 2.0 * MARGIN,
+        ph = HEIGHT - 2.0 * MARGIN,
+        ty = HEIGHT - MARGIN + 14.0,
+        xr = WIDTH - MARGIN,
+        yb = HEIGHT - MARGIN,
+    );
+
+    for (index, (name, time, values)) in series.iter().enumerate() {
+        let color = COLORS[index % COLORS.len()];
+        let path = time
+            .iter()
+            .zip(values.iter())
+            .filter(|(t, v)| t.is_finite() && v.is_finite())
+            .map(|(t, v)| format!("{:.1},{:.1}", x(*t), y(*v)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(
+            svg,
+            "<polyline fill=\"none\" stroke=\"{}\" stroke-width=\"1.5\" points=\"{}\"/>",
+            color, path
+        );
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" font-family=\"sans-serif\" font-size=\"11\" fill=\"{}\">{}</text>",
+            WIDTH - MARGIN + 4.0,
+            MARGIN + 14.0 * (index as f64 + 1.0),
+            color,
+            escape(name)
+        );
+    }
+
+    svg += "</svg>\n";
+    svg
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::report::{Run, compare};
+    use alloc::vec::Vec;
+
+    fn run(pid: PID<f64>) -> Run {
+        let mut pid = pid;
+        let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+        let mut run = Run::new();
+
+        for sim_state in Simulation::new(0.01, 10.0) {
+            let reference = 1.0.as_signal(sim_state);
+            let error = reference - plant.last_output();
+            let control = error >> pid.as_block();
+            let output = control >> plant.as_block();
+            let _ = (reference, output, control).pack() >> run.as_block();
+        }
+        run
+    }
+
+    #[test]
+    fn test_compare_reports_metrics_per_variant() {
+        let report = compare(&[
+            ("PI", run(PID::new(2.0, 2.0, 0.0))),
+            ("P", run(PID::new(2.0, 0.0, 0.0))),
+        ]);
+
+        let metrics = report.metrics().collect::<Vec<_>>();
+        assert_eq!(metrics[0].0, "PI");
+        assert!(metrics[0].1.settling_time.unwrap() < 5.0);
+        // Proportional only leaves a third of the step as error.
+        assert_eq!(metrics[1].1.settling_time, None);
+        assert!(metrics[1].1.iae > metrics[0].1.iae);
+        assert!(metrics[0].1.overshoot >= 0.0);
+        assert!(metrics[0].1.control_effort > 9.0);
+
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("| PI | "));
+        assert!(markdown.contains("| P | "));
+        assert!(markdown.contains("![Output](output.svg)"));
+        let html = report.to_html();
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert_eq!(report.output_svg().matches("<polyline").count(), 3);
+
+        let dir = std::env::temp_dir().join("aule_report_test");
+        report.save(&dir).unwrap();
+        assert!(dir.join("report.md").exists());
+        assert!(dir.join("control.svg").exists());
+    }
+}
@@ -0,0 +1,252 @@
+use crate::prelude::{Signal, SimulationState};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::{Deref, Index};
+
+/// Runs a diagram over `time`, e.g. a [`Simulation`](crate::prelude::Simulation),
+/// capturing every signal it records into memory.
+///
+/// `build` creates the blocks and returns the step of the diagram, which
+/// records signals by name through its [`Probes`]. A signal is registered
+/// the first time it is recorded; the steps where a signal is not recorded
+/// are kept as `NaN` so every trace lines up with the time.
+///
+/// ```ignore
+/// let result = simulate(Simulation::new(1e-3, 10.0), || {
+///     let mut pid = PID::new(40.0, 10.0, 10.0);
+///     let mut plant = Tf::new(&[1.0], &[1.0, 6.0, 11.0, 6.0]).to_ss_controllable(RK4);
+///     move |sim_state, probes| {
+///         let error = 1.0.as_signal(sim_state) - plant.last_output();
+///         let control = probes.record("control", error >> pid.as_block());
+///         probes.record("output", control >> plant.as_block());
+///     }
+/// });
+/// println!("peak {}", result["output"].max());
+/// ```
+pub fn simulate<S, B, F>(time: S, build: B) -> SimResult
+where
+    S: IntoIterator<Item = SimulationState>,
+    B: FnOnce() -> F,
+    F: FnMut(SimulationState, &mut Probes),
+{
+    let mut step = build();
+    let mut probes = Probes {
+        time: Vec::new(),
+        names: Vec::new(),
+        traces: Vec::new(),
+    };
+
+    for sim_state in time {
+        probes.time.push(sim_state.sim_time().as_secs_f64());
+        step(sim_state, &mut probes);
+        let len = probes.time.len();
+        for trace in probes.traces.iter_mut() {
+            trace.0.resize(len, f64::NAN);
+        }
+    }
+
+    SimResult {
+        time: Trace(probes.time),
+        names: probes.names,
+        traces: probes.traces,
+    }
+}
+
+/// Values that [`Probes`] can record.
+pub trait Recordable: Copy {
+    fn to_f64(self) -> f64;
+}
+
+impl Recordable for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl Recordable for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl<T: Recordable> Recordable for Signal<T> {
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+}
+
+/// Named signals recorded by the step of a [`simulate`] run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Probes {
+    time: Vec<f64>,
+    names: Vec<String>,
+    traces: Vec<Trace>,
+}
+
+impl Probes {
+    /// Records `value` under `name` at the current step and passes it on.
+    /// Recording the same name twice in a step keeps the last value.
+    pub fn record<V: Recordable>(&mut self, name: &str, value: V) -> V {
+        let len = self.time.len();
+        let index = match self.names.iter().position(|n| n == name) {
+            Some(index) => index,
+            None => {
+                self.names.push(name.to_string());
+                self.traces.push(Trace(Vec::with_capacity(len)));
+                self.traces.len() - 1
+            }
+        };
+
+        let trace = &mut self.traces[index].0;
+        trace.resize(len - 1, f64::NAN);
+        trace.push(value.to_f64());
+        value
+    }
+}
+
+/// Recorded signal of a [`SimResult`], one sample per step.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trace(Vec<f64>);
+
+impl Trace {
+    /// Largest sample, skipping `NaN`; `NaN` for an empty trace.
+    pub fn max(&self) -> f64 {
+        self.0.iter().copied().fold(f64::NAN, f64::max)
+    }
+
+    /// Smallest sample, skipping `NaN`; `NaN` for an empty trace.
+    pub fn min(&self) -> f64 {
+        self.0.iter().copied().fold(f64::NAN, f64::min)
+    }
+
+    /// Mean of the samples that are not `NaN`.
+    pub fn mean(&self) -> f64 {
+        let (sum, n) = self
+            .0
+            .iter()
+            .filter(|v| !v.is_nan())
+            .fold((0.0, 0), |(sum, n), v| (sum + v, n + 1));
+        sum / n as f64
+    }
+
+    /// Last sample, `NaN` for an empty trace.
+    pub fn final_value(&self) -> f64 {
+        self.0.last().copied().unwrap_or(f64::NAN)
+    }
+
+    pub fn into_vec(self) -> Vec<f64> {
+        self.0
+    }
+}
+
+impl Deref for Trace {
+    type Target = [f64];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Signals captured by [`simulate`], indexed by name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimResult {
+    time: Trace,
+    names: Vec<String>,
+    traces: Vec<Trace>,
+}
+
+impl SimResult {
+    /// Simulation time of each step, in seconds.
+    pub fn time(&self) -> &Trace {
+        &self.time
+    }
+
+    /// Names of the recorded signals, in the order they were registered.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Trace> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|index| &self.traces[index])
+    }
+
+    /// The recorded signals as CSV, with a `t` column followed by one
+    /// column per signal.
+    pub fn csv(&self) -> String {
+        let mut csv = String::from("t");
+        for name in &self.names {
+            csv.push(',');
+            csv.push_str(name);
+        }
+        csv.push('\n');
+
+        for (k, t) in self.time.iter().enumerate() {
+            csv.push_str(&t.to_string());
+            for trace in &self.traces {
+                csv.push(',');
+                csv.push_str(&trace[k].to_string());
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Writes [`csv`](Self::csv) to `path`, creating its directory.
+    #[cfg(feature = "std")]
+    pub fn to_csv(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.csv())
+    }
+}
+
+impl Index<&str> for SimResult {
+    type Output = Trace;
+
+    /// Panics if no signal was recorded under `name`.
+    fn index(&self, name: &str) -> &Self::Output {
+        self.get(name)
+            .unwrap_or_else(|| panic!("No signal recorded as {:?}", name))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::run::simulate;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_simulate_captures_named_signals() {
+        let result = simulate(Simulation::new(0.01, 5.0), || {
+            let mut plant = Tf::new(&[1.0], &[1.0, 1.0]).to_ss_controllable(RK4);
+            move |sim_state, probes| {
+                let y = probes.record("output", 1.0.as_signal(sim_state) >> plant.as_block());
+                if sim_state.sim_time().as_secs_f64() > 2.5 {
+                    probes.record("late", y.value as f32);
+                }
+            }
+        });
+
+        assert_eq!(result.names().collect::<Vec<_>>(), ["output", "late"]);
+        assert_eq!(result.time().len(), result["output"].len());
+        assert_eq!(result["late"].len(), result["output"].len());
+        assert!(result["late"][0].is_nan());
+        assert_signal_eq!(result["output"].max(), 1.0 - (-5.0f64).exp(), 0.0, 1e-6);
+        let first = result["late"].iter().position(|v| !v.is_nan()).unwrap();
+        assert!((result.time()[first] - 2.5).abs() < 0.02);
+        assert_signal_eq!(result["late"].min(), result["output"][first], 0.0, 1e-6);
+        assert!(result.get("missing").is_none());
+
+        let path = std::env::temp_dir().join("aule_simulate/result.csv");
+        result.to_csv(&path).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        assert!(csv.starts_with("t,output,late\n"));
+        assert_eq!(csv.lines().count(), result.time().len() + 1);
+    }
+}
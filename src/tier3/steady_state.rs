@@ -0,0 +1,110 @@
+use crate::continuous::Tf;
+use core::ops::AddAssign;
+use faer::traits::ComplexField;
+use num_traits::Float;
+
+/// Steady-state behaviour of a unity-feedback loop, from its open-loop
+/// transfer function.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteadyState {
+    /// Number of integrators in the open loop.
+    pub system_type: i32,
+    /// Position error constant, `lim s->0 L(s)`.
+    pub kp: f64,
+    /// Velocity error constant, `lim s->0 s L(s)`.
+    pub kv: f64,
+    /// Acceleration error constant, `lim s->0 s^2 L(s)`.
+    pub ka: f64,
+}
+
+impl SteadyState {
+    /// Final error to a unit step reference.
+    pub fn step_error(&self) -> f64 {
+        1.0 / (1.0 + self.kp)
+    }
+
+    /// Final output for a unit step reference.
+    pub fn step_output(&self) -> f64 {
+        1.0 - self.step_error()
+    }
+
+    /// Final error to a unit ramp reference.
+    pub fn ramp_error(&self) -> f64 {
+        1.0 / self.kv
+    }
+
+    /// Final error to a unit parabola `t^2 / 2` reference.
+    pub fn parabola_error(&self) -> f64 {
+        1.0 / self.ka
+    }
+}
+
+/// Error constants of the unity-feedback loop closed around `open_loop`.
+/// The errors assume the closed loop is stable. A zero open loop is type 0
+/// with all constants zero, so the error follows the reference.
+pub fn dc_analysis<T>(open_loop: &Tf<T>) -> SteadyState
+where
+    T: Float + Default + AddAssign<T> + ComplexField,
+{
+    let num = open_loop.numerator().real_coeffs();
+    let den = open_loop.denominator().real_coeffs();
+
+    let zeros_at_origin = num.iter().rev().take_while(|c| **c == 0.0).count();
+    if zeros_at_origin == num.len() {
+        return SteadyState {
+            system_type: 0,
+            kp: 0.0,
+            kv: 0.0,
+            ka: 0.0,
+        };
+    }
+    let poles_at_origin = den.iter().rev().take_while(|c| **c == 0.0).count();
+    let system_type = poles_at_origin as i32 - zeros_at_origin as i32;
+
+    // Gain of L(s) once the factors of s are taken out.
+    let num_gain = num[num.len() - 1 - zeros_at_origin];
+    let den_gain = den[den.len() - 1 - poles_at_origin];
+    let gain = num_gain / den_gain;
+
+    // lim s->0 s^order L(s) = lim s->0 s^(order - type) * gain.
+    let constant = |order: i32| match (order - system_type).cmp(&0) {
+        core::cmp::Ordering::Less => f64::INFINITY.copysign(gain),
+        core::cmp::Ordering::Equal => gain,
+        core::cmp::Ordering::Greater => 0.0,
+    };
+
+    SteadyState {
+        system_type,
+        kp: constant(0),
+        kv: constant(1),
+        ka: constant(2),
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dc_analysis_error_constants() {
+        // 10 / (s (s + 2)): type 1, Kv = 5.
+        let type1 = dc_analysis(&Tf::new(&[10.0], &[1.0, 2.0, 0.0]));
+        assert_eq!(type1.system_type, 1);
+        assert_eq!(type1.step_error(), 0.0);
+        assert_eq!(type1.step_output(), 1.0);
+        assert_eq!(type1.ramp_error(), 0.2);
+        assert_eq!(type1.ka, 0.0);
+        assert!(type1.parabola_error().is_infinite());
+
+        // 4 / (s + 1): type 0, Kp = 4.
+        let type0 = dc_analysis(&Tf::new(&[4.0], &[1.0, 1.0]));
+        assert_eq!(type0.system_type, 0);
+        assert_eq!(type0.step_error(), 0.2);
+        assert!(type0.ramp_error().is_infinite());
+
+        let zero = dc_analysis(&Tf::new(&[0.0, 0.0], &[1.0, 1.0]));
+        assert_eq!(zero.system_type, 0);
+        assert_eq!(zero.step_error(), 1.0);
+        assert_eq!(zero.step_output(), 0.0);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::tier3::monte_carlo::Param;
+use alloc::vec::Vec;
+use faer::{Mat, linalg::solvers::Solve};
+
+/// Quadratic response surface `y = b0 + sum b_i x_i + sum b_ij x_i x_j`
+/// fitted by least squares, a cheap surrogate of the simulation that shows
+/// which parameters and interactions drive the result.
+///
+/// Parameters are coded to `[-1, 1]` over their [`Param::bounds`], so the
+/// coefficients of different parameters are directly comparable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseSurface {
+    centers: Vec<f64>,
+    half_ranges: Vec<f64>,
+    intercept: f64,
+    linear: Vec<f64>,
+    /// Upper triangle of the quadratic terms, row by row, `i <= j`.
+    quadratic: Vec<f64>,
+    r_squared: f64,
+}
+
+impl ResponseSurface {
+    /// Fits the surface through `results`, evaluated at `samples`, e.g. from
+    /// [`MonteCarlo::samples`](crate::tier3::MonteCarlo::samples) and
+    /// [`MonteCarlo::run`](crate::tier3::MonteCarlo::run).
+    pub fn fit(params: &[Param], samples: &[Vec<f64>], results: &[f64]) -> Self {
+        let n = params.len();
+        let terms = 1 + n + n * (n + 1) / 2;
+        assert_eq!(samples.len(), results.len(), "One result per sample");
+        assert!(
+            samples.len() >= terms,
+            "A quadratic fit of {} parameters needs at least {} samples",
+            n,
+            terms
+        );
+
+        let (centers, half_ranges): (Vec<_>, Vec<_>) = params
+            .iter()
+            .map(|p| {
+                let (low, high) = p.bounds();
+                let half_range = (high - low) / 2.0;
+                (
+                    (low + high) / 2.0,
+                    if half_range > 0.0 { half_range } else { 1.0 },
+                )
+            })
+            .unzip();
+
+        let mut surface = Self {
+            centers,
+            half_ranges,
+            intercept: 0.0,
+            linear: alloc::vec![0.0; n],
+            quadratic: alloc::vec![0.0; n * (n + 1) / 2],
+            r_squared: 0.0,
+        };
+
+        let design = Mat::from_fn(samples.len(), terms, |row, col| {
+            surface.features(&samples[row])[col]
+        });
+        let y = Mat::from_fn(results.len(), 1, |row, _| results[row]);
+        let normal = design.transpose() * &design;
+        let coefficients = normal.partial_piv_lu().solve(design.transpose() * &y);
+
+        surface.intercept = coefficients[(0, 0)];
+        for i in 0..n {
+            surface.linear[i] = coefficients[(1 + i, 0)];
+        }
+        for k in 0..surface.quadratic.len() {
+            surface.quadratic[k] = coefficients[(1 + n + k, 0)];
+        }
+
+        let mean = results.iter().sum::<f64>() / results.len() as f64;
+        let total = results.iter().map(|r| (r - mean) * (r - mean)).sum::<f64>();
+        let residual = samples
+            .iter()
+            .zip(results)
+            .map(|(x, r)| (r - surface.predict(x)) * (r - surface.predict(x)))
+            .sum::<f64>();
+        surface.r_squared = if total > 0.0 {
+            1.0 - residual / total
+        } else {
+            1.0
+        };
+
+        surface
+    }
+
+    /// Surrogate estimate of the result at parameter values `x`.
+    pub fn predict(&self, x: &[f64]) -> f64 {
+        let features = self.features(x);
+        let n = self.linear.len();
+        self.intercept * features[0]
+            + (0..n)
+                .map(|i| self.linear[i] * features[1 + i])
+                .sum::<f64>()
+            + self
+                .quadratic
+                .iter()
+                .zip(&features[1 + n..])
+                .map(|(b, f)| b * f)
+                .sum::<f64>()
+    }
+
+    /// Fraction of the variance of the results explained by the surface.
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Result at the center of the parameter ranges.
+    pub fn intercept(&self) -> f64 {
+        self.intercept
+    }
+
+    /// Change of the result per half range of each parameter.
+    pub fn linear(&self) -> &[f64] {
+        &self.linear
+    }
+
+    /// Coefficient of `x_i x_j` in coded units; `i == j` for the curvature.
+    pub fn quadratic(&self, i: usize, j: usize) -> f64 {
+        let (i, j) = (i.min(j), i.max(j));
+        let n = self.linear.len();
+        self.quadratic[i * (2 * n - i + 1) / 2 + j - i]
+    }
+
+    /// Parameters ordered from most to least influential, by the size of
+    /// their linear and curvature terms.
+    pub fn ranking(&self) -> Vec<usize> {
+        let mut order = (0..self.linear.len()).collect::<Vec<_>>();
+        let influence = |i: usize| self.linear[i].abs() + self.quadratic(i, i).abs();
+        order.sort_by(|a, b| influence(*b).total_cmp(&influence(*a)));
+        order
+    }
+
+    fn features(&self, x: &[f64]) -> Vec<f64> {
+        let n = self.centers.len();
+        let coded = (0..n)
+            .map(|i| (x[i] - self.centers[i]) / self.half_ranges[i])
+            .collect::<Vec<_>>();
+
+        let mut features = Vec::with_capacity(1 + n + n * (n + 1) / 2);
+        features.push(1.0);
+        features.extend(&coded);
+        for i in 0..n {
+            for j in i..n {
+                features.push(coded[i] * coded[j]);
+            }
+        }
+        features
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::tier3::{MonteCarlo, Param, ResponseSurface, Sampling};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_response_surface_recovers_quadratic() {
+        let params = [
+            Param::uniform(1.0, 0.5),
+            Param::uniform(10.0, 0.2),
+            Param::uniform(0.1, 0.5),
+            Param::gaussian(5.0, 0.1),
+        ];
+        // In coded units: 3 + 2 a - b + 0.5 a b + c^2, d has no effect.
+        let model = |x: &[f64]| {
+            let a = (x[0] - 1.0) / 0.5;
+            let b = (x[1] - 10.0) / 2.0;
+            let c = (x[2] - 0.1) / 0.05;
+            3.0 + 2.0 * a - b + 0.5 * a * b + c * c
+        };
+
+        let analysis = MonteCarlo::new(&params)
+            .with_runs(64)
+            .with_sampling(Sampling::Sobol);
+        let samples = analysis.samples();
+        let results = analysis.run(model);
+        let surface = ResponseSurface::fit(&params, &samples, &results);
+
+        assert!((surface.r_squared() - 1.0).abs() < 1e-9);
+        assert!((surface.intercept() - 3.0).abs() < 1e-9);
+        assert!((surface.linear()[0] - 2.0).abs() < 1e-9);
+        assert!((surface.linear()[1] + 1.0).abs() < 1e-9);
+        assert!((surface.quadratic(1, 0) - 0.5).abs() < 1e-9);
+        assert!((surface.quadratic(2, 2) - 1.0).abs() < 1e-9);
+        assert!(surface.linear()[3].abs() < 1e-9);
+        assert_eq!(surface.ranking()[..3], [0, 1, 2]);
+        assert!((surface.predict(&[1.5, 12.0, 0.15, 5.0]) - 5.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_space_filling_sampling() {
+        let analysis =
+            MonteCarlo::new(&[Param::uniform(0.5, 1.0), Param::uniform(0.5, 1.0)]).with_runs(16);
+
+        // Every one of the 16 strata of each parameter is hit once.
+        let lhs = analysis.clone().with_sampling(Sampling::LatinHypercube);
+        for dim in 0..2 {
+            let mut strata = lhs
+                .samples()
+                .iter()
+                .map(|x| (x[dim] * 16.0) as usize)
+                .collect::<Vec<_>>();
+            strata.sort();
+            assert_eq!(strata, (0..16).collect::<Vec<_>>());
+        }
+
+        // The first 2^k Sobol points, with the origin, stratify each axis.
+        let sobol = crate::tier3::monte_carlo::sobol(7, 2);
+        assert_eq!(sobol[0], [0.5, 0.5]);
+        assert_eq!(sobol[1], [0.75, 0.25]);
+        for dim in 0..2 {
+            let mut strata = sobol
+                .iter()
+                .map(|x| (x[dim] * 8.0) as usize)
+                .collect::<Vec<_>>();
+            strata.push(0);
+            strata.sort();
+            assert_eq!(strata, (0..8).collect::<Vec<_>>());
+        }
+
+        let sobol = analysis.clone().with_sampling(Sampling::Sobol);
+        assert_eq!(sobol.samples(), sobol.samples());
+        assert_eq!(sobol.samples()[0], [0.5, 0.5]);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::linalg::dense::{pinv, svd};
+use crate::prelude::DSS;
+use alloc::vec::Vec;
+use faer::{Mat, MatRef, c64};
+
+/// Subspace identification by N4SID: fits a discrete state-space model of
+/// a given order to input/output data of a multivariable plant, without
+/// choosing a parametrization or iterating.
+#[derive(Debug, Clone, PartialEq)]
+pub struct N4sid {
+    order: usize,
+    horizon: usize,
+}
+
+/// Multi-input multi-output model `x[k+1] = A x[k] + B u[k]`,
+/// `y[k] = C x[k] + D u[k]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MimoModel {
+    a: Mat<f64>,
+    b: Mat<f64>,
+    c: Mat<f64>,
+    d: Mat<f64>,
+    singular_values: Vec<f64>,
+}
+
+impl N4sid {
+    pub fn new(order: usize) -> Self {
+        assert!(order > 0, "Order must be positive");
+        Self {
+            order,
+            horizon: 2 * order + 1,
+        }
+    }
+
+    /// Number of block rows of the Hankel matrices, `2 order + 1` by default.
+    /// Longer horizons average out more noise but need more data.
+    pub fn with_horizon(mut self, horizon: usize) -> Self {
+        self.horizon = horizon;
+        self
+    }
+
+    /// Fits the model to `inputs` (`m` channels by `N` samples) and `outputs`
+    /// (`p` channels by `N` samples). The inputs must be persistently
+    /// exciting, e.g. random or multisine.
+    pub fn fit(&self, inputs: MatRef<'_, f64>, outputs: MatRef<'_, f64>) -> MimoModel {
+        let (m, p, i) = (inputs.nrows(), outputs.nrows(), self.horizon);
+        let samples = inputs.ncols();
+        assert_eq!(
+            outputs.ncols(),
+            samples,
+            "One output sample per input sample"
+        );
+        assert!(
+            p * i >= self.order,
+            "Horizon too short for order {}",
+            self.order
+        );
+        assert!(
+            samples + 1 > 2 * i + 2 * (m + p) * i,
+            "Not enough samples for the horizon"
+        );
+        let j = samples - 2 * i + 1;
+
+        let u_past = hankel(inputs, 0, i, j);
+        let u_future = hankel(inputs, i, i, j);
+        let y_past = hankel(outputs, 0, i, j);
+        let y_future = hankel(outputs, i, i, j);
+        let w_past = stack(&u_past, &y_past);
+
+        // Oblique projection of the future outputs along the future inputs
+        // onto the past data: the free response from the past, `Gamma X`.
+        let y_perp = project_out(&y_future, &u_future);
+        let w_perp = project_out(&w_past, &u_future);
+        let oblique = y_perp * pinv(w_perp.as_ref(), 1e-10) * &w_past;
+
+        let (_, s, v) = svd(oblique.as_ref());
+        let n = self.order;
+        // States X = S^1/2 V^T in the basis of the observability matrix.
+        let states = Mat::from_fn(n, j, |r, c| libm::sqrt(s[r]) * v[(c, r)]);
+
+        // x[k+1] = A x[k] + B u[k], y[k] = C x[k] + D u[k] over the
+        // estimated state sequence, by least squares.
+        let k = j - 1;
+        let regressors = Mat::from_fn(n + m, k, |r, c| {
+            if r < n {
+                states[(r, c)]
+            } else {
+                inputs[(r - n, i + c)]
+            }
+        });
+        let targets = Mat::from_fn(n + p, k, |r, c| {
+            if r < n {
+                states[(r, c + 1)]
+            } else {
+                outputs[(r - n, i + c)]
+            }
+        });
+        let theta = targets * pinv(regressors.as_ref(), 1e-12);
+
+        MimoModel {
+            a: theta.submatrix(0, 0, n, n).to_owned(),
+            b: theta.submatrix(0, n, n, m).to_owned(),
+            c: theta.submatrix(n, 0, p, n).to_owned(),
+            d: theta.submatrix(n, n, p, m).to_owned(),
+            singular_values: s,
+        }
+    }
+}
+
+impl MimoModel {
+    pub fn a(&self) -> &Mat<f64> {
+        &self.a
+    }
+
+    pub fn b(&self) -> &Mat<f64> {
+        &self.b
+    }
+
+    pub fn c(&self) -> &Mat<f64> {
+        &self.c
+    }
+
+    pub fn d(&self) -> &Mat<f64> {
+        &self.d
+    }
+
+    pub fn order(&self) -> usize {
+        self.a.nrows()
+    }
+
+    /// Singular values of the projected data; the order is where they drop.
+    pub fn singular_values(&self) -> &[f64] {
+        &self.singular_values
+    }
+
+    pub fn eigenvalues(&self) -> Vec<c64> {
+        self.a
+            .eigenvalues()
+            .expect("Eigenvalues of A did not converge")
+    }
+
+    /// Single-input single-output model from `input` to `output`.
+    pub fn channel(&self, input: usize, output: usize) -> DSS<f64> {
+        DSS::new(
+            self.a.clone(),
+            self.b.subcols(input, 1).to_owned(),
+            self.c.subrows(output, 1).to_owned(),
+            self.d[(output, input)],
+        )
+    }
+
+    /// Response from rest to `inputs`, one sample per column, e.g. to
+    /// validate the model against held-out data.
+    pub fn simulate(&self, inputs: MatRef<'_, f64>) -> Mat<f64> {
+        let mut x = Mat::<f64>::zeros(self.order(), 1);
+        let mut outputs = Mat::zeros(self.c.nrows(), inputs.ncols());
+        for k in 0..inputs.ncols() {
+            let u = inputs.subcols(k, 1);
+            outputs
+                .subcols_mut(k, 1)
+                .copy_from(&self.c * &x + &self.d * u);
+            x = &self.a * &x + &self.b * u;
+        }
+        outputs
+    }
+}
+
+/// Block Hankel matrix of `rows` block rows and `cols` columns, starting at
+/// sample `start`.
+fn hankel(data: MatRef<'_, f64>, start: usize, rows: usize, cols: usize) -> Mat<f64> {
+    let channels = data.nrows();
+    Mat::from_fn(channels * rows, cols, |r, c| {
+        data[(r % channels, start + r / channels + c)]
+    })
+}
+
+fn stack(top: &Mat<f64>, bottom: &Mat<f64>) -> Mat<f64> {
+    Mat::from_fn(top.nrows() + bottom.nrows(), top.ncols(), |r, c| {
+        if r < top.nrows() {
+            top[(r, c)]
+        } else {
+            bottom[(r - top.nrows(), c)]
+        }
+    })
+}
+
+/// Rows of `a` projected onto the orthogonal complement of the row space
+/// of `b`.
+fn project_out(a: &Mat<f64>, b: &Mat<f64>) -> Mat<f64> {
+    let gram = b * b.transpose();
+    a - (a * b.transpose()) * pinv(gram.as_ref(), 1e-12) * b
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::rng::SplitMix64;
+    use crate::tier3::sysid::N4sid;
+
+    #[test]
+    fn test_n4sid_identifies_mimo_system() {
+        // Two inputs, two outputs, third order.
+        let a = mat![[0.8, 0.2, 0.0], [-0.2, 0.8, 0.0], [0.0, 0.0, 0.5]];
+        let b = mat![[1.0, 0.0], [0.0, 0.5], [0.3, 1.0]];
+        let c = mat![[1.0, 0.0, 1.0], [0.0, 1.0, -0.5]];
+        let d = mat![[0.0, 0.1], [0.0, 0.0]];
+
+        let samples = 400;
+        let mut rng = SplitMix64::new(7);
+        let inputs = Mat::from_fn(2, samples, |_, _| 2.0 * rng.next_f64() - 1.0);
+        let mut x = Mat::<f64>::zeros(3, 1);
+        let mut outputs = Mat::zeros(2, samples);
+        for k in 0..samples {
+            let u = inputs.subcols(k, 1);
+            outputs.subcols_mut(k, 1).copy_from(&c * &x + &d * u);
+            x = &a * &x + &b * u;
+        }
+
+        let model = N4sid::new(3).fit(inputs.as_ref(), outputs.as_ref());
+        let s = model.singular_values();
+        assert!(s[3] < 1e-8 * s[0]);
+
+        let mut expected = a.eigenvalues().unwrap();
+        let mut found = model.eigenvalues();
+        let key = |e: &c64| (e.re * 1e6).round() as i64 * 1_000_000 + (e.im * 1e6).round() as i64;
+        expected.sort_by_key(key);
+        found.sort_by_key(key);
+        for (e, f) in expected.iter().zip(&found) {
+            assert!((e.re - f.re).abs() < 1e-6 && (e.im - f.im).abs() < 1e-6);
+        }
+
+        let replay = model.simulate(inputs.as_ref());
+        assert!((&replay - &outputs).norm_l2() < 1e-6 * outputs.norm_l2());
+        assert!((model.d() - &d).norm_l2() < 1e-6);
+
+        // One channel as a SISO block.
+        let only_second = Mat::from_fn(2, 20, |i, k| if i == 1 { inputs[(1, k)] } else { 0.0 });
+        let expected = model.simulate(only_second.as_ref());
+        let mut channel = model.channel(1, 0);
+        for (k, sim_state) in Simulation::new(1.0, 1000.0).take(20).enumerate() {
+            let y = inputs[(1, k)].as_signal(sim_state) >> channel.as_block();
+            assert!((y.value - expected[(0, k)]).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::continuous::{Polynomial, Tf};
+use crate::linalg::dense::{eigenvalues, rank};
+use alloc::vec;
+use alloc::vec::Vec;
+use faer::{Mat, linalg::solvers::Solve};
+
+/// Relative size below which a leading coefficient is round-off.
+const TOLERANCE: f64 = 1e-10;
+
+/// Coprime factorization `G = N / M` of a plant over stable, proper
+/// transfer functions, with the Bezout pair `X`, `Y` such that
+/// `N X + M Y = 1`.
+///
+/// Every controller that stabilizes the unity-feedback loop around `G` is
+/// `K = (X + M Q) / (Y - N Q)` for some stable, proper `Q` (Youla-Kucera),
+/// and the loop it closes, `T = N (X + M Q)`, is affine in `Q`. `Q = 0`
+/// gives the central controller `X / Y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoprimeFactors {
+    n: Tf<f64>,
+    m: Tf<f64>,
+    x: Tf<f64>,
+    y: Tf<f64>,
+}
+
+impl CoprimeFactors {
+    /// Factorization given by hand. The factors must be stable and satisfy
+    /// the Bezout identity; neither is checked.
+    pub fn new(n: Tf<f64>, m: Tf<f64>, x: Tf<f64>, y: Tf<f64>) -> Self {
+        Self { n, m, x, y }
+    }
+
+    /// Factorization of `plant = b / a`, of order `n`, with `N = b / f^n`,
+    /// `M = a / f^n` and `X = x / f^(n-1)`, `Y = y / f^(n-1)` for
+    /// `f = s + pole`. The Bezout pair solves the Diophantine equation
+    /// `a y + b x = f^(2n-1)`, so the central controller places every
+    /// closed-loop pole at `-pole`.
+    ///
+    /// `None` if `b` and `a` share a root, in which case no controller
+    /// reaches the cancelled mode.
+    pub fn from_plant(plant: &Tf<f64>, pole: f64) -> Option<Self> {
+        assert!(pole > 0.0, "The factor pole must be in the left half plane");
+
+        let a = plant.denominator().real_coeffs();
+        let n = a.len() - 1;
+        assert!(n > 0, "The plant must have at least one pole");
+        let mut b = plant.numerator().real_coeffs();
+        while b.len() < a.len() {
+            b.insert(0, 0.0);
+        }
+
+        // Sylvester system for the `n` coefficients of `y` and of `x`, both
+        // highest degree first, matching the `2n` coefficients of `f^(2n-1)`.
+        let f = Polynomial::new(&[1.0, pole]);
+        let target = f.clone().pow(2 * n - 1);
+        let sylvester = Mat::from_fn(2 * n, 2 * n, |row, col| {
+            let (poly, shift) = if col < n { (&a, col) } else { (&b, col - n) };
+            row.checked_sub(shift)
+                .and_then(|i| poly.get(i).copied())
+                .unwrap_or(0.0)
+        });
+        if rank(sylvester.as_ref(), TOLERANCE) < 2 * n {
+            return None;
+        }
+        let rhs = Mat::from_fn(2 * n, 1, |i, _| target.coeff()[i]);
+        let solution = sylvester.partial_piv_lu().solve(&rhs);
+        let y = (0..n).map(|i| solution[(i, 0)]).collect::<Vec<_>>();
+        let x = (n..2 * n).map(|i| solution[(i, 0)]).collect::<Vec<_>>();
+
+        let f_n = f.clone().pow(n);
+        let f_n1 = f.pow(n - 1);
+        Some(Self {
+            n: Tf::new_unchecked(&trim(&b), f_n.coeff()),
+            m: Tf::new_unchecked(&a, f_n.coeff()),
+            x: Tf::new_unchecked(&trim(&x), f_n1.coeff()),
+            y: Tf::new_unchecked(&trim(&y), f_n1.coeff()),
+        })
+    }
+
+    pub fn n(&self) -> &Tf<f64> {
+        &self.n
+    }
+
+    pub fn m(&self) -> &Tf<f64> {
+        &self.m
+    }
+
+    pub fn x(&self) -> &Tf<f64> {
+        &self.x
+    }
+
+    pub fn y(&self) -> &Tf<f64> {
+        &self.y
+    }
+
+    /// Controller `K = (X + M Q) / (Y - N Q)` of the Youla parameter `q`.
+    /// It stabilizes the loop whenever `q` is stable.
+    pub fn controller(&self, q: &Tf<f64>) -> Tf<f64> {
+        let [(xn, xd), (mn, md), (yn, yd), (nn, nd), (qn, qd)] =
+            [&self.x, &self.m, &self.y, &self.n, q].map(parts);
+
+        // `X + M Q` and `Y - N Q` over their common denominators, the `qd`
+        // in both cancelling.
+        let num = xn * md.clone() * qd.clone() + mn * qn.clone() * xd.clone();
+        let den = yn * nd.clone() * qd - nn * qn * yd.clone();
+        let (num_extra, den_extra) = (yd * nd, xd * md);
+        let (num, den) = if same_roots(&num_extra, &den_extra) {
+            (num, den)
+        } else {
+            (num * num_extra, den * den_extra)
+        };
+
+        Tf::new_unchecked(&trim(num.coeff()), &trim(den.coeff()))
+    }
+
+    /// Closed loop `T = N (X + M Q)` from the reference to the output.
+    pub fn closed_loop(&self, q: &Tf<f64>) -> Tf<f64> {
+        let [(xn, xd), (mn, md), (nn, nd), (qn, qd)] = [&self.x, &self.m, &self.n, q].map(parts);
+        let num = nn * (xn * md.clone() * qd.clone() + mn * qn * xd.clone());
+        let den = nd * xd * md * qd;
+        Tf::new_unchecked(&trim(num.coeff()), &trim(den.coeff()))
+    }
+}
+
+/// Whether the unity-feedback loop of `plant = b / a` and
+/// `controller = p / l` is internally stable: every root of `a l + b p`,
+/// cancelled ones included, lies in the open left half plane.
+pub fn is_internally_stable(plant: &Tf<f64>, controller: &Tf<f64>) -> bool {
+    let (b, a) = parts(plant);
+    let (p, l) = parts(controller);
+    let characteristic = a * l + b * p;
+
+    let coeff = trim(characteristic.coeff());
+    if coeff.len() < 2 {
+        return true;
+    }
+    let monic = coeff.iter().map(|c| c / coeff[0]).collect::<Vec<_>>();
+    let companion = crate::poly::Polynomial::new(&monic).transposed_companion_matrix();
+    eigenvalues(companion.as_ref())
+        .iter()
+        .all(|root| root.re < 0.0)
+}
+
+fn parts(tf: &Tf<f64>) -> (Polynomial<f64>, Polynomial<f64>) {
+    (tf.numerator().clone(), tf.denominator().clone())
+}
+
+/// Whether two polynomials are equal up to a constant factor.
+fn same_roots(p: &Polynomial<f64>, q: &Polynomial<f64>) -> bool {
+    let (p, q) = (p.coeff(), q.coeff());
+    p.len() == q.len()
+        && p.iter()
+            .zip(q)
+            .all(|(a, b)| (a / p[0] - b / q[0]).abs() <= TOLERANCE * (1.0 + (a / p[0]).abs()))
+}
+
+/// Drops leading coefficients that are round-off of an exact cancellation.
+fn trim(coeff: &[f64]) -> Vec<f64> {
+    let scale = coeff.iter().fold(0.0, |acc: f64, c| acc.max(c.abs()));
+    let start = coeff
+        .iter()
+        .position(|c| c.abs() > TOLERANCE * scale)
+        .unwrap_or(coeff.len().saturating_sub(1));
+    if coeff.is_empty() {
+        vec![0.0]
+    } else {
+        coeff[start..].to_vec()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use crate::tier3::youla::{CoprimeFactors, is_internally_stable};
+
+    #[test]
+    fn test_youla_controllers_stabilize_unstable_plant() {
+        // G = 1 / ((s - 1)(s + 2)).
+        let plant = Tf::new(&[1.0], &[1.0, 1.0, -2.0]);
+        let factors = CoprimeFactors::from_plant(&plant, 3.0).unwrap();
+
+        // Bezout identity at a few points.
+        for s in [c64::new(0.0, 0.0), c64::new(1.0, 2.0), c64::new(-0.5, 4.0)] {
+            let value = factors.n().eval(s) * factors.x().eval(s)
+                + factors.m().eval(s) * factors.y().eval(s);
+            let error = value - c64::new(1.0, 0.0);
+            assert!(libm::hypot(error.re, error.im) < 1e-9);
+        }
+
+        let central = factors.controller(&Tf::new(&[0.0], &[1.0]));
+        assert!(is_internally_stable(&plant, &central));
+
+        for q in [
+            Tf::new(&[2.0], &[1.0, 5.0]),
+            Tf::new(&[-3.0, 1.0], &[1.0, 2.0, 4.0]),
+        ] {
+            let controller = factors.controller(&q);
+            assert!(is_internally_stable(&plant, &controller));
+
+            // The closed loop is the one the parameter predicts.
+            let s = c64::new(0.3, 1.7);
+            let l = plant.eval(s) * controller.eval(s);
+            let t = l / (c64::new(1.0, 0.0) + l);
+            let error = t - factors.closed_loop(&q).eval(s);
+            assert!(libm::hypot(error.re, error.im) < 1e-9);
+        }
+
+        let unstable_q = Tf::new(&[1.0], &[1.0, -3.0]);
+        assert!(!is_internally_stable(
+            &plant,
+            &factors.controller(&unstable_q)
+        ));
+    }
+
+    #[test]
+    fn test_internal_stability_sees_hidden_modes() {
+        let plant = Tf::new(&[1.0], &[1.0, -1.0]);
+        // The loop gain 1 / (s + 1) hides the unstable plant pole.
+        let cancelling = Tf::new(&[1.0, -1.0], &[1.0, 1.0]);
+        assert!(!is_internally_stable(&plant, &cancelling));
+        assert!(is_internally_stable(&plant, &Tf::new(&[3.0], &[1.0])));
+        assert!(!is_internally_stable(&plant, &Tf::new(&[0.5], &[1.0])));
+
+        // A plant with a cancelled unstable mode cannot be factored.
+        let hidden = Tf::new(&[1.0, -1.0], &[1.0, 0.0, -1.0]);
+        assert!(CoprimeFactors::from_plant(&hidden, 1.0).is_none());
+    }
+}
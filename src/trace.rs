@@ -0,0 +1,152 @@
+//! Block execution tracing. Every block step driven through the signal
+//! operators runs inside a `block` span named after [`Block::name`]. With
+//! `std`, [`Traced`] also accumulates the wall-clock time of the steps of the
+//! block it wraps.
+
+use crate::block::Block;
+#[cfg(feature = "std")]
+use crate::block::BlockPorts;
+use crate::prelude::SimulationState;
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockStats {
+    pub calls: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+#[cfg(feature = "std")]
+impl BlockStats {
+    pub fn mean(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_nanos((self.total.as_nanos() / self.calls as u128) as u64)
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        self.calls += 1;
+        self.total += elapsed;
+        self.max = self.max.max(elapsed);
+    }
+}
+
+/// Wraps a block and keeps the execution statistics of its steps, so each
+/// instance is measured on its own.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Traced<B> {
+    block: B,
+    stats: BlockStats,
+}
+
+#[cfg(feature = "std")]
+impl<B> Traced<B>
+where
+    B: Block,
+{
+    pub fn new(block: B) -> Self {
+        Self {
+            block,
+            stats: BlockStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> BlockStats {
+        self.stats
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.block
+    }
+
+    pub fn inner_mut(&mut self) -> &mut B {
+        &mut self.block
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B> Block for Traced<B>
+where
+    B: Block,
+{
+    type Input = B::Input;
+    type Output = B::Output;
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        let start = Instant::now();
+        let output = self.block.block(input, sim_state);
+        self.stats.record(start.elapsed());
+        output
+    }
+
+    fn last_output(&self) -> Option<Self::Output> {
+        self.block.last_output()
+    }
+
+    /// Resets the wrapped block and the statistics.
+    fn reset(&mut self) {
+        self.block.reset();
+        self.stats = BlockStats::default();
+    }
+
+    fn name(&self) -> &str {
+        self.block.name()
+    }
+
+    fn ports(&self) -> BlockPorts {
+        self.block.ports()
+    }
+
+    fn preferred_sample_time(&self) -> Option<Duration> {
+        self.block.preferred_sample_time()
+    }
+}
+
+pub(crate) fn instrument<B>(block: &mut B, input: B::Input, sim_state: SimulationState) -> B::Output
+where
+    B: Block + ?Sized,
+{
+    let span = tracing::trace_span!(
+        "block",
+        name = block.name(),
+        sim_time = sim_state.sim_time().as_secs_f64()
+    );
+    let _entered = span.enter();
+
+    block.block(input, sim_state)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_traced_stats_are_per_instance() {
+        let mut step = Step::new(1.0f64);
+        let mut fast = Traced::new(Pwm::new(10.0));
+        let mut slow = Traced::new(Pwm::new(10.0));
+
+        for (i, sim_state) in Simulation::new(0.01, 1.0).enumerate() {
+            let duty = sim_state >> step.as_block();
+            let _ = duty >> fast.as_block();
+            if i % 2 == 0 {
+                let _ = duty >> slow.as_block();
+            }
+        }
+
+        assert_eq!(fast.stats().calls, 100);
+        assert_eq!(slow.stats().calls, 50);
+        assert!(fast.stats().max >= fast.stats().mean());
+        assert_eq!(fast.name(), fast.inner().name());
+
+        fast.reset();
+        assert_eq!(fast.stats().calls, 0);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::prelude::{ParamError, Params, TunableParam};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+};
+
+/// Exposes a [`Params`] registry over TCP so gains can be adjusted while a
+/// simulation runs, e.g. next to an `RTPlotter`.
+///
+/// The protocol is one command per line, each answered by one line:
+///
+/// - `list` answers `name=value` pairs separated by spaces;
+/// - `get <name>` answers the value;
+/// - `set <name> <value>` answers `ok`.
+///
+/// Errors are answered as `err <reason>`. Any line-based client works, e.g.
+/// `nc 127.0.0.1 7878`.
+#[derive(Debug)]
+pub struct TuningServer {
+    addr: SocketAddr,
+    params: Params,
+}
+
+impl TuningServer {
+    /// Listens on `addr` from a background thread, one thread per client,
+    /// serving a new, empty registry.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Self::serve(Params::new(), addr)
+    }
+
+    /// Listens on `addr` serving `params`.
+    pub fn serve(params: Params, addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+
+        let shared = params.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let params = shared.clone();
+                std::thread::spawn(move || Self::client(stream, params));
+            }
+        });
+
+        Ok(Self { addr, params })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// Registers `name` in the served registry, see [`Params::register`].
+    pub fn register(&self, name: &str, initial: f64) -> TunableParam {
+        self.params.register(name, initial)
+    }
+
+    fn client(stream: TcpStream, params: Params) {
+        let Ok(mut writer) = stream.try_clone() else {
+            return;
+        };
+
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else {
+                return;
+            };
+            let reply = Self::handle(&line, &params);
+            if writeln!(writer, "{}", reply).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn handle(line: &str, params: &Params) -> String {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        match words.as_slice() {
+            ["list"] => params
+                .entries()
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join(" "),
+            ["get", name] => match params.get(name) {
+                Some(value) => value.to_string(),
+                None => format!("err unknown parameter {}", name),
+            },
+            ["set", name, value] => {
+                let result = value
+                    .parse::<f64>()
+                    .map_err(|_| ParamError::InvalidValue(value.to_string()))
+                    .and_then(|value| params.set(name, value));
+                match result {
+                    Ok(()) => "ok".to_string(),
+                    Err(ParamError::Unknown(name)) => format!("err unknown parameter {}", name),
+                    Err(_) => format!("err invalid value {}", value),
+                }
+            }
+            _ => "err expected list, get <name> or set <name> <value>".to_string(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpStream;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn test_tuning_server_sets_gains_live() {
+        let server = TuningServer::bind("127.0.0.1:0").unwrap();
+        let kp = server.register("kp", 1.0);
+        let ki = server.register("ki", 0.5);
+        let mut pid = PID::new(0.0, 0.0, 0.0);
+
+        let stream = TcpStream::connect(server.local_addr()).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut writer = stream;
+        let mut ask = |command: &str| {
+            writeln!(writer, "{}", command).unwrap();
+            let mut reply = String::new();
+            reader.read_line(&mut reply).unwrap();
+            reply.trim_end().to_string()
+        };
+
+        assert_eq!(ask("list"), "kp=1 ki=0.5");
+        assert_eq!(ask("set kp 4.2"), "ok");
+        assert_eq!(ask("get kp"), "4.2");
+        assert_eq!(ask("set kd 1"), "err unknown parameter kd");
+        assert_eq!(ask("set ki NaN"), "err invalid value NaN");
+
+        kp.apply(pid.kp_mut());
+        ki.apply(pid.ki_mut());
+        assert_eq!(*pid.kp_mut(), 4.2);
+        assert_eq!(*pid.ki_mut(), 0.5);
+    }
+}
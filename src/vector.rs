@@ -0,0 +1,144 @@
+use crate::signal::Elementwise;
+use core::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+use num_traits::Zero;
+
+/// Fixed-size vector of `N` elements with element-wise arithmetic, so a
+/// `Signal<Vector<N>>` takes the same operators as a scalar signal and a
+/// MIMO error reads `r - y`:
+///
+/// ```ignore
+/// let error = reference - output;
+/// let u = error.scale(2.0) >> controller.as_block();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector<const N: usize, T = f64>(pub [T; N]);
+
+impl<const N: usize, T> Vector<N, T> {
+    pub fn new(elements: [T; N]) -> Self {
+        Self(elements)
+    }
+
+    pub fn elements(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize, T> Default for Vector<N, T>
+where
+    T: Zero + Copy,
+{
+    fn default() -> Self {
+        Self([T::zero(); N])
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Vector<N, T> {
+    fn from(elements: [T; N]) -> Self {
+        Self(elements)
+    }
+}
+
+impl<const N: usize, T> From<Vector<N, T>> for [T; N] {
+    fn from(vector: Vector<N, T>) -> Self {
+        vector.0
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Vector<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Vector<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize, T> Add for Vector<N, T>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T> Sub for Vector<N, T>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<const N: usize, T> Neg for Vector<N, T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|a| -a))
+    }
+}
+
+/// Scales every element.
+impl<const N: usize, T> Mul<T> for Vector<N, T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|a| a * rhs))
+    }
+}
+
+impl<const N: usize, T> Elementwise for Vector<N, T>
+where
+    T: Copy,
+{
+    type Scalar = T;
+
+    fn map_each(self, f: impl FnMut(T) -> T) -> Self {
+        Self(self.0.map_each(f))
+    }
+
+    fn zip_each(self, rhs: Self, f: impl FnMut(T, T) -> T) -> Self {
+        Self(self.0.zip_each(rhs.0, f))
+    }
+
+    fn fold_each<A>(&self, init: A, f: impl FnMut(A, T) -> A) -> A {
+        self.0.fold_each(init, f)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_vector_signal_operators() {
+        let sim_state = Simulation::new(0.01, 0.01).next().unwrap();
+        let r = Vector::new([1.0, 2.0, 2.0]).as_signal(sim_state);
+        let y = Vector::new([0.5, 0.0, -1.0]).as_signal(sim_state);
+
+        let error = r - y;
+        assert_eq!(error.value, Vector([0.5, 2.0, 3.0]));
+        assert_eq!((r + y).scale(2.0).value, Vector([3.0, 4.0, 2.0]));
+        assert_eq!(y.value * 2.0, Vector([1.0, 0.0, -2.0]));
+        assert_eq!((-y).value[2], 1.0);
+        assert_eq!(error.norm().value, libm::sqrt(13.25));
+        assert_eq!(r.dot(y).value, -1.5);
+        assert_eq!(<[f64; 3]>::from(error.value), [0.5, 2.0, 3.0]);
+    }
+}
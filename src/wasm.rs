@@ -0,0 +1,228 @@
+use crate::{
+    block::Block,
+    prelude::{EndlessSimulation, PID, RK4, SS, SimulationState, Tf},
+};
+use alloc::collections::VecDeque;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use wasm_bindgen::{JsCast, JsValue, prelude::wasm_bindgen};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+const COLORS: [&str; 6] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b",
+];
+
+/// Plot sink keeping the last `capacity` samples of each channel and drawing
+/// them on an HTML canvas, auto-scaled. Stepping only records; call
+/// [`CanvasPlotter::draw`] once per animation frame.
+#[derive(Debug, Clone)]
+pub struct CanvasPlotter<const N: usize> {
+    context: CanvasRenderingContext2d,
+    width: f64,
+    height: f64,
+    capacity: usize,
+    history: VecDeque<(f64, [f64; N])>,
+}
+
+impl<const N: usize> CanvasPlotter<N> {
+    pub fn new(canvas: &HtmlCanvasElement, capacity: usize) -> Result<Self, JsValue> {
+        assert!(capacity > 1, "Capacity must hold at least two samples");
+
+        let context = canvas
+            .get_context("2d")?
+            .ok_or_else(|| JsValue::from_str("Canvas has no 2d context"))?
+            .dyn_into::<CanvasRenderingContext2d>()?;
+
+        Ok(Self {
+            context,
+            width: canvas.width() as f64,
+            height: canvas.height() as f64,
+            capacity,
+            history: VecDeque::with_capacity(capacity),
+        })
+    }
+
+    pub fn draw(&self) {
+        let context = &self.context;
+        context.clear_rect(0.0, 0.0, self.width, self.height);
+
+        let (Some(first), Some(last)) = (self.history.front(), self.history.back()) else {
+            return;
+        };
+        let (t0, t1) = (first.0, last.0.max(first.0 + f64::EPSILON));
+        let (low, high) = self
+            .history
+            .iter()
+            .flat_map(|(_, values)| values.iter().copied())
+            .filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), v| {
+                (low.min(v), high.max(v))
+            });
+        let span = if high > low { high - low } else { 1.0 };
+        let margin = 0.05 * span;
+
+        let x = |t: f64| (t - t0) / (t1 - t0) * self.width;
+        let y = |v: f64| self.height * (1.0 - (v - low + margin) / (span + 2.0 * margin));
+
+        context.set_line_width(1.5);
+        for channel in 0..N {
+            context.set_stroke_style_str(COLORS[channel % COLORS.len()]);
+            context.begin_path();
+            for (k, (t, values)) in self.history.iter().enumerate() {
+                if k == 0 {
+                    context.move_to(x(*t), y(values[channel]));
+                } else {
+                    context.line_to(x(*t), y(values[channel]));
+                }
+            }
+            context.stroke();
+        }
+    }
+}
+
+impl<const N: usize> Block for CanvasPlotter<N> {
+    type Input = [f64; N];
+    type Output = [f64; N];
+
+    fn block(&mut self, input: Self::Input, sim_state: SimulationState) -> Self::Output {
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history
+            .push_back((sim_state.sim_time().as_secs_f64(), input));
+
+        input
+    }
+
+    fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+/// Closed PID loop around a transfer-function plant, stepped from
+/// JavaScript, e.g. from `requestAnimationFrame` with sliders bound to the
+/// gains.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WasmLoop {
+    dt: f32,
+    simulation: EndlessSimulation,
+    reference: f64,
+    pid: PID<f64>,
+    plant: SS<RK4, f64>,
+    plotter: Option<CanvasPlotter<3>>,
+    last_state: Option<SimulationState>,
+}
+
+#[wasm_bindgen]
+impl WasmLoop {
+    #[wasm_bindgen(constructor)]
+    pub fn new(numerator: &[f64], denominator: &[f64], dt: f32) -> Result<WasmLoop, JsValue> {
+        let plant = Tf::try_new(numerator, denominator)
+            .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+        Ok(WasmLoop {
+            dt,
+            simulation: EndlessSimulation::new(dt),
+            reference: 1.0,
+            pid: PID::new(1.0, 0.0, 0.0),
+            plant: plant.to_ss_controllable(RK4),
+            plotter: None,
+            last_state: None,
+        })
+    }
+
+    pub fn set_gains(&mut self, kp: f64, ki: f64, kd: f64) {
+        *self.pid.kp_mut() = kp;
+        *self.pid.ki_mut() = ki;
+        *self.pid.kd_mut() = kd;
+    }
+
+    pub fn set_reference(&mut self, reference: f64) {
+        self.reference = reference;
+    }
+
+    /// Plots reference, control signal and output on `canvas`.
+    pub fn attach_canvas(
+        &mut self,
+        canvas: &HtmlCanvasElement,
+        capacity: usize,
+    ) -> Result<(), JsValue> {
+        self.plotter = Some(CanvasPlotter::new(canvas, capacity)?);
+        Ok(())
+    }
+
+    /// Advances `steps` samples and returns them flattened as
+    /// `[t, reference, control, output, ...]`.
+    pub fn step(&mut self, steps: u32) -> Vec<f64> {
+        let mut samples = Vec::with_capacity(4 * steps as usize);
+
+        for sim_state in self.simulation.by_ref().take(steps as usize) {
+            let reference = self.reference;
+            let error = reference - self.plant.last_output().unwrap_or(0.0);
+            let control = crate::block::run(&mut self.pid, error, sim_state);
+            let output = crate::block::run(&mut self.plant, control, sim_state);
+
+            if let Some(plotter) = &mut self.plotter {
+                crate::block::run(plotter, [reference, control, output], sim_state);
+            }
+            samples.extend([
+                sim_state.sim_time().as_secs_f64(),
+                reference,
+                control,
+                output,
+            ]);
+            self.last_state = Some(sim_state);
+        }
+
+        samples
+    }
+
+    pub fn draw(&self) {
+        if let Some(plotter) = &self.plotter {
+            plotter.draw();
+        }
+    }
+
+    pub fn time(&self) -> f64 {
+        self.last_state
+            .map(|sim_state| sim_state.sim_time().as_secs_f64())
+            .unwrap_or(0.0)
+    }
+
+    pub fn output(&self) -> f64 {
+        self.plant.last_output().unwrap_or(0.0)
+    }
+
+    pub fn reset(&mut self) {
+        self.simulation = EndlessSimulation::new(self.dt);
+        self.pid.reset();
+        self.plant.reset();
+        if let Some(plotter) = &mut self.plotter {
+            plotter.reset();
+        }
+        self.last_state = None;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::WasmLoop;
+
+    #[test]
+    fn test_wasm_loop_steps_closed_loop() {
+        let mut demo = WasmLoop::new(&[1.0], &[1.0, 3.0, 2.0], 0.01).unwrap();
+        demo.set_gains(10.0, 8.0, 0.5);
+        demo.set_reference(2.0);
+
+        let samples = demo.step(1000);
+        assert_eq!(samples.len(), 4000);
+        assert!((demo.time() - 10.0).abs() < 1e-3);
+        assert!((demo.output() - 2.0).abs() < 1e-2);
+        assert_eq!(samples[samples.len() - 1], demo.output());
+
+        demo.reset();
+        assert_eq!(demo.time(), 0.0);
+        assert_eq!(demo.output(), 0.0);
+    }
+}